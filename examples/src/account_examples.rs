@@ -132,7 +132,9 @@ fn get_account_credits<ApiClientType: HerokuApiClient>(api_client: &ApiClientTyp
 fn get_account_credit<ApiClientType: HerokuApiClient>(api_client: &ApiClientType) {
     let credit_id = "012abc";
 
-    let response = api_client.request(&account::AccountCreditDetails { credit_id });
+    let response = api_client.request(&account::AccountCreditDetails {
+        credit_id: credit_id.into(),
+    });
     print_response(response);
 }
 
@@ -209,7 +211,9 @@ fn get_account_features<ApiClientType: HerokuApiClient>(api_client: &ApiClientTy
 fn delete_user_account<ApiClientType: HerokuApiClient>(api_client: &ApiClientType) {
     let account_id = "USER_ID_OR_EMAIL";
 
-    let response = api_client.request(&account::UserAccountDelete { account_id });
+    let response = api_client.request(&account::UserAccountDelete {
+        account_id: account_id.into(),
+    });
     print_response(response);
 }
 
@@ -60,28 +60,36 @@ fn enable_app_review_configuration<T: HerokuApiClient>(api_client: &T) {
 // Delete app review list by review_id
 fn delete_app_review<T: HerokuApiClient>(api_client: &T) {
     let review_id = "REVIEW_ID";
-    let response = api_client.request(&review::ReviewAppDelete { review_id });
+    let response = api_client.request(&review::ReviewAppDelete {
+        review_id: review_id.into(),
+    });
     print_response(response);
 }
 
 // Get app review list by pipeline_id
 fn get_app_review_list_by_pipeline<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
-    let response = api_client.request(&review::ReviewAppByPipelineList { pipeline_id });
+    let response = api_client.request(&review::ReviewAppByPipelineList {
+        pipeline_id: pipeline_id.into(),
+    });
     print_response(response);
 }
 
 // Get app review by app_id
 fn get_app_review_by_app<T: HerokuApiClient>(api_client: &T) {
     let app_id = "APP_ID";
-    let response = api_client.request(&review::ReviewAppByAppDetails { app_id });
+    let response = api_client.request(&review::ReviewAppByAppDetails {
+        app_id: app_id.into(),
+    });
     print_response(response);
 }
 
 // Get app review
 fn get_app_review<T: HerokuApiClient>(api_client: &T) {
     let review_id = "REVIEW_ID";
-    let response = api_client.request(&review::ReviewAppDetails { review_id });
+    let response = api_client.request(&review::ReviewAppDetails {
+        review_id: review_id.into(),
+    });
     print_response(response);
 }
 
@@ -70,7 +70,7 @@ pub fn run<ApiClientType: HerokuApiClient>(api_client: &ApiClientType) {
 
     // create_slug(api_client, app_name);
     // get_slug(api_client, app_name);
-    // get_app_release(api_client, app_name, "4");
+    // get_app_release(api_client, app_name, 4);
     // list_app_releases(api_client, app_name);
     // create_app_release(api_client, app_name);
     // rollback_app_release(api_client, app_name);
@@ -145,7 +145,7 @@ fn get_app_ssl<T: HerokuApiClient>(api_client: &T, app_id: &str) {
 
 // get app SSL list
 fn get_app_ssl_list<T: HerokuApiClient>(api_client: &T, app_id: &str) {
-    let response = api_client.request(&apps::SSLList::new(&app_id));
+    let response = api_client.request(&apps::SSLList::new(app_id));
     print_response(response);
 }
 
@@ -216,7 +216,10 @@ fn create_app_setup<T: HerokuApiClient>(api_client: &T) {
 // get info about a slug
 fn get_slug<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     let slug_id = "SLUG_ID";
-    let response = api_client.request(&slugs::SlugDetails { app_id, slug_id });
+    let response = api_client.request(&slugs::SlugDetails {
+        app_id: app_id.into(),
+        slug_id: slug_id.into(),
+    });
     print_response(response);
 }
 
@@ -241,27 +244,38 @@ fn create_slug<T: HerokuApiClient>(api_client: &T, app_id: &str) {
 /// Stop dyno
 fn dyno_action_stop<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     let domain_id = "DYNO_ID_OR_NAME";
-    let response = api_client.request(&domains::DomainDelete { app_id, domain_id });
+    let response = api_client.request(&domains::DomainDelete {
+        app_id: app_id.into(),
+        domain_id: domain_id.into(),
+    });
     print_response(response);
 }
 
 /// Delete domain
 fn delete_app_domain<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     let domain_id = "DOMAIN_ID_OR_HOSTNAME";
-    let response = api_client.request(&domains::DomainDelete { app_id, domain_id });
+    let response = api_client.request(&domains::DomainDelete {
+        app_id: app_id.into(),
+        domain_id: domain_id.into(),
+    });
     print_response(response);
 }
 
 /// Get domain
 fn get_app_domain<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     let domain_id = "DOMAIN_ID_OR_HOSTNAME";
-    let response = api_client.request(&domains::DomainDetails { app_id, domain_id });
+    let response = api_client.request(&domains::DomainDetails {
+        app_id: app_id.into(),
+        domain_id: domain_id.into(),
+    });
     print_response(response);
 }
 
 /// Get domains list
 fn get_app_domains<T: HerokuApiClient>(api_client: &T, app_id: &str) {
-    let response = api_client.request(&domains::DomainList { app_id });
+    let response = api_client.request(&domains::DomainList {
+        app_id: app_id.into(),
+    });
     print_response(response);
 }
 
@@ -293,20 +307,27 @@ fn update_buildpack_installation<T: HerokuApiClient>(api_client: &T, app_id: &st
 
 /// Delete build cache
 fn delete_app_build<T: HerokuApiClient>(api_client: &T, app_id: &str) {
-    let response = api_client.request(&builds::BuildDelete { app_id });
+    let response = api_client.request(&builds::BuildDelete {
+        app_id: app_id.into(),
+    });
     print_response(response);
 }
 
 /// Gets info about a specific build
 fn get_app_build<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     let build_id = "Build_ID";
-    let response = api_client.request(&builds::BuildDetails { app_id, build_id });
+    let response = api_client.request(&builds::BuildDetails {
+        app_id: app_id.into(),
+        build_id: build_id.into(),
+    });
     print_response(response);
 }
 
 /// Gets a list of builds
 fn get_app_builds<T: HerokuApiClient>(api_client: &T, app_id: &str) {
-    let response = api_client.request(&builds::BuildList { app_id });
+    let response = api_client.request(&builds::BuildList {
+        app_id: app_id.into(),
+    });
     print_response(response);
 }
 
@@ -329,7 +350,9 @@ fn create_app_build<ApiClientType: HerokuApiClient>(api_client: &ApiClientType,
 
 /// Gets a list of webhook deliveries.
 fn get_app_webhook_deliveries<T: HerokuApiClient>(api_client: &T, app_name: &str) {
-    let response = api_client.request(&apps::AppWebhookDeliveryList { app_id: app_name });
+    let response = api_client.request(&apps::AppWebhookDeliveryList {
+        app_id: app_name.into(),
+    });
     print_response(response);
 }
 
@@ -395,14 +418,16 @@ fn patch_app_feature<ApiClientType: HerokuApiClient>(api_client: &ApiClientType,
 
 fn get_app_feature<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, app_name: &str) {
     let response = api_client.request(&apps::AppFeatureDetails {
-        app_id: app_name,
-        feature_id: "spaces-dns-discovery",
+        app_id: app_name.into(),
+        feature_id: "spaces-dns-discovery".into(),
     });
     print_response(response);
 }
 
 fn get_app_features<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, app_name: &str) {
-    let response = api_client.request(&apps::AppFeatureList { app_id: app_name });
+    let response = api_client.request(&apps::AppFeatureList {
+        app_id: app_name.into(),
+    });
     print_response(response);
 }
 
@@ -412,7 +437,9 @@ fn refresh_app_acm<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, a
 }
 
 fn disable_app_acm<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, app_name: &str) {
-    let response = api_client.request(&apps::AppDisableAcm { app_id: &app_name });
+    let response = api_client.request(&apps::AppDisableAcm {
+        app_id: app_name.into(),
+    });
     print_response(response);
 }
 
@@ -474,7 +501,7 @@ fn get_dyno_size_list<ApiClientType: HerokuApiClient>(api_client: &ApiClientType
 fn get_dyno_size_details<ApiClientType: HerokuApiClient>(api_client: &ApiClientType) {
     let dyno_size_id = "Shield-L";
     let response = api_client.request(&dynos::DynoSizeDetails {
-        size_id: dyno_size_id,
+        size_id: dyno_size_id.into(),
     });
     print_response(response);
 }
@@ -482,12 +509,17 @@ fn get_dyno_size_details<ApiClientType: HerokuApiClient>(api_client: &ApiClientT
 fn get_dyno<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, app_id: &str) {
     let dyno_id = "web.1";
 
-    let response = api_client.request(&dynos::DynoDetails { app_id, dyno_id });
+    let response = api_client.request(&dynos::DynoDetails {
+        app_id: app_id.into(),
+        dyno_id: dyno_id.into(),
+    });
     print_response(response);
 }
 
 fn list_dynos<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, app_id: &str) {
-    let resp = api_client.request(&dynos::DynoList { app_id });
+    let resp = api_client.request(&dynos::DynoList {
+        app_id: app_id.into(),
+    });
     print_response(resp);
 }
 
@@ -518,24 +550,31 @@ fn restart_dyno<ApiClientType: HerokuApiClient>(api_client: &ApiClientType) {
     let app_id = "heroku-rs-tests";
     let dyno_id = "web.1";
 
-    let resp = api_client.request(&dynos::DynoRestart { app_id, dyno_id });
+    let resp = api_client.request(&dynos::DynoRestart {
+        app_id: app_id.into(),
+        dyno_id: dyno_id.into(),
+    });
     print_response(resp);
 }
 
 fn restart_all_dynos<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, app_id: &str) {
-    let resp = api_client.request(&dynos::DynoAllRestart { app_id });
+    let resp = api_client.request(&dynos::DynoAllRestart {
+        app_id: app_id.into(),
+    });
     print_response(resp);
 }
 
 fn list_app_formations<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, app_id: &str) {
-    let resp = api_client.request(&formations::FormationList { app_id });
+    let resp = api_client.request(&formations::FormationList {
+        app_id: app_id.into(),
+    });
     print_response(resp);
 }
 
 fn get_app_formation<ApiClientType: HerokuApiClient>(api_client: &ApiClientType, app_id: &str) {
     let resp = api_client.request(&formations::FormationDetails {
-        app_id,
-        formation_id: "web",
+        app_id: app_id.into(),
+        formation_id: "web".into(),
     });
     print_response(resp);
 }
@@ -551,12 +590,17 @@ fn update_app_formation<ApiClientType: HerokuApiClient>(api_client: &ApiClientTy
 }
 
 fn list_app_releases<T: HerokuApiClient>(api_client: &T, app_id: &str) {
-    let resp = api_client.request(&releases::ReleaseList { app_id });
+    let resp = api_client.request(&releases::ReleaseList {
+        app_id: app_id.into(),
+    });
     print_response(resp);
 }
 
-fn get_app_release<T: HerokuApiClient>(api_client: &T, app_id: &str, release_id: &str) {
-    let resp = api_client.request(&releases::ReleaseInfo { app_id, release_id });
+fn get_app_release<T: HerokuApiClient>(api_client: &T, app_id: &str, release_id: u64) {
+    let resp = api_client.request(&releases::ReleaseInfo {
+        app_id: app_id.into(),
+        release_id: release_id.into(),
+    });
     print_response(resp);
 }
 
@@ -54,7 +54,9 @@ fn get_team_app_collaborator<T: HerokuApiClient>(api_client: &T, app_id: &str) {
 
 /// Get a list of team app collaborators
 fn get_team_app_collaborators<T: HerokuApiClient>(api_client: &T, app_id: &str) {
-    let response = api_client.request(&collaborators::TeamCollaboratorList { app_id });
+    let response = api_client.request(&collaborators::TeamCollaboratorList {
+        app_id: app_id.into(),
+    });
     //or
     // let response = api_client.request(&collaborators::TeamCollaboratorList::new(app_id));
     print_response(response);
@@ -76,8 +78,8 @@ fn create_team_app_collaborator<T: HerokuApiClient>(api_client: &T, app_id: &str
 fn delete_app_collaborator<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     let collaborator_id = "COLLAB_EMAIL_OR_ID";
     let response = api_client.request(&collaborators::CollaboratorDelete {
-        app_id,
-        collaborator_id,
+        app_id: app_id.into(),
+        collaborator_id: collaborator_id.into(),
     });
     print_response(response);
 }
@@ -86,15 +88,17 @@ fn delete_app_collaborator<T: HerokuApiClient>(api_client: &T, app_id: &str) {
 fn get_app_collaborator<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     let collaborator_id = "COLLAB_EMAIL_OR_ID";
     let response = api_client.request(&collaborators::CollaboratorDetails {
-        app_id,
-        collaborator_id,
+        app_id: app_id.into(),
+        collaborator_id: collaborator_id.into(),
     });
     print_response(response);
 }
 
 /// Get a list of app collaborators
 fn get_app_collaborators<T: HerokuApiClient>(api_client: &T, app_id: &str) {
-    let response = api_client.request(&collaborators::CollaboratorList { app_id });
+    let response = api_client.request(&collaborators::CollaboratorList {
+        app_id: app_id.into(),
+    });
     print_response(response);
 }
 
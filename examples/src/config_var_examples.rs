@@ -1,6 +1,7 @@
 extern crate heroku_rs;
 use super::print_response;
 use heroku_rs::endpoints::config_vars;
+use heroku_rs::endpoints::ids::ReleaseRef;
 use heroku_rs::framework::apiclient::HerokuApiClient;
 use std::collections::HashMap;
 
@@ -28,8 +29,8 @@ fn delete_pipeline_config_vars<T: HerokuApiClient>(api_client: &T) {
     cvar.insert(cvar_key, cvar_value);
 
     let response = api_client.request(&config_vars::PipelineConfigVarDelete {
-        pipeline_id,
-        stage_id,
+        pipeline_id: pipeline_id.into(),
+        stage_id: stage_id.into(),
         params: cvar,
     });
     print_response(response);
@@ -59,8 +60,8 @@ fn get_pipeline_config_vars<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
     let stage_id = "test";
     let response = api_client.request(&config_vars::PipelineConfigVarDetails {
-        pipeline_id,
-        stage_id,
+        pipeline_id: pipeline_id.into(),
+        stage_id: stage_id.into(),
     });
     print_response(response);
 }
@@ -76,7 +77,7 @@ fn delete_app_config_vars<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     cvar.insert(cvar_key, cvar_value);
 
     let response = api_client.request(&config_vars::AppConfigVarDelete {
-        app_id,
+        app_id: app_id.into(),
         params: cvar,
     });
     print_response(response);
@@ -91,7 +92,7 @@ fn update_app_config_vars<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     cvar.insert(cvar_key, cvar_value);
 
     let response = api_client.request(&config_vars::AppConfigVarUpdate {
-        app_id,
+        app_id: app_id.into(),
         params: cvar,
     });
     print_response(response);
@@ -100,13 +101,18 @@ fn update_app_config_vars<T: HerokuApiClient>(api_client: &T, app_id: &str) {
 // get app release config vars
 fn get_release_config_vars<T: HerokuApiClient>(api_client: &T, app_id: &str) {
     // release version or id
-    let release_id = "1";
-    let response = api_client.request(&config_vars::ReleaseConfigVarDetails { app_id, release_id });
+    let release_id = ReleaseRef::Version(1);
+    let response = api_client.request(&config_vars::ReleaseConfigVarDetails {
+        app_id: app_id.into(),
+        release_id,
+    });
     print_response(response);
 }
 
 // get config vars for an app
 fn get_app_config_vars<T: HerokuApiClient>(api_client: &T, app_id: &str) {
-    let response = api_client.request(&config_vars::AppConfigVarDetails { app_id });
+    let response = api_client.request(&config_vars::AppConfigVarDetails {
+        app_id: app_id.into(),
+    });
     print_response(response);
 }
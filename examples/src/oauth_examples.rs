@@ -24,7 +24,7 @@ pub fn run<ApiClientType: HerokuApiClient>(api_client: &ApiClientType) {
 // delete client
 fn delete_oauth_token<T: HerokuApiClient>(api_client: &T) {
     let token_id = "TOKEN_ID";
-    let response = api_client.request(&oauth::OAuthTokenDelete { token_id });
+    let response = api_client.request(&oauth::OAuthTokenDelete { token_id: token_id.into() });
     print_response(response);
 }
 
@@ -48,14 +48,16 @@ fn create_oauth_token<T: HerokuApiClient>(api_client: &T) {
 // delete client
 fn delete_client<T: HerokuApiClient>(api_client: &T) {
     let client_id = "CLIENT_ID";
-    let response = api_client.request(&oauth::OAuthClientDelete { client_id });
+    let response = api_client.request(&oauth::OAuthClientDelete { client_id: client_id.into() });
     print_response(response);
 }
 
 // rotate client credentials
 fn rotate_client_credentials<T: HerokuApiClient>(api_client: &T) {
     let client_id = "CLIENT_ID";
-    let response = api_client.request(&oauth::OAuthClientRotateCredentials { client_id });
+    let response = api_client.request(&oauth::OAuthClientRotateCredentials {
+        client_id: client_id.into(),
+    });
     print_response(response);
 }
 
@@ -81,7 +83,7 @@ fn get_oauth_clients<T: HerokuApiClient>(api_client: &T) {
 // get client with id
 fn get_oauth_client<T: HerokuApiClient>(api_client: &T) {
     let client_id = "CLIENT_ID";
-    let response = api_client.request(&oauth::OAuthClientDetails { client_id });
+    let response = api_client.request(&oauth::OAuthClientDetails { client_id: client_id.into() });
     print_response(response);
 }
 
@@ -97,14 +99,14 @@ fn create_oauth_client<T: HerokuApiClient>(api_client: &T) {
 // delete specific oauth token
 fn delete_oauth<T: HerokuApiClient>(api_client: &T) {
     let oauth_id = "OAUTH_ID";
-    let response = api_client.request(&oauth::OAuthDelete { oauth_id });
+    let response = api_client.request(&oauth::OAuthDelete { oauth_id: oauth_id.into() });
     print_response(response);
 }
 
 // regenerate specific oauth token
 fn regenerate_oauth<T: HerokuApiClient>(api_client: &T) {
     let oauth_id = "OAUTH_ID";
-    let response = api_client.request(&oauth::OAuthRegenerate { oauth_id });
+    let response = api_client.request(&oauth::OAuthRegenerate { oauth_id: oauth_id.into() });
     print_response(response);
 }
 
@@ -124,7 +126,7 @@ fn create_oauth<T: HerokuApiClient>(api_client: &T) {
 // get specific oauth info
 fn get_oauth_info<T: HerokuApiClient>(api_client: &T) {
     let oauth_id = "OAUTH_ID";
-    let response = api_client.request(&oauth::OAuthDetails { oauth_id });
+    let response = api_client.request(&oauth::OAuthDetails { oauth_id: oauth_id.into() });
     print_response(response);
 }
 
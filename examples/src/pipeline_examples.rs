@@ -49,28 +49,36 @@ fn create_pipeline_transfer<T: HerokuApiClient>(api_client: &T) {
 // get pipline stack
 fn get_pipeline_stack<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelineStackDetails { pipeline_id });
+    let response = api_client.request(&pipelines::PipelineStackDetails {
+        pipeline_id: pipeline_id.into(),
+    });
     print_response(response);
 }
 
 // get pipline release
 fn get_pipeline_releases<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelineLatestReleaseList { pipeline_id });
+    let response = api_client.request(&pipelines::PipelineLatestReleaseList {
+        pipeline_id: pipeline_id.into(),
+    });
     print_response(response);
 }
 
 // get pipline promotion target list
 fn get_pipeline_promotion_target_list<T: HerokuApiClient>(api_client: &T) {
     let promotion_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelinePromotionTargetList { promotion_id });
+    let response = api_client.request(&pipelines::PipelinePromotionTargetList {
+        promotion_id: promotion_id.into(),
+    });
     print_response(response);
 }
 
 // get pipline promotion
 fn get_pipeline_promotion<T: HerokuApiClient>(api_client: &T) {
     let promotion_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelinePromotionDetails { promotion_id });
+    let response = api_client.request(&pipelines::PipelinePromotionDetails {
+        promotion_id: promotion_id.into(),
+    });
     print_response(response);
 }
 
@@ -90,21 +98,27 @@ fn create_pipeline_promotion<T: HerokuApiClient>(api_client: &T) {
 // get pipline deployments
 fn get_pipline_deployments<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelineDeploymentList { pipeline_id });
+    let response = api_client.request(&pipelines::PipelineDeploymentList {
+        pipeline_id: pipeline_id.into(),
+    });
     print_response(response);
 }
 
 // delete pipeline coupling
 fn delete_pipeline_coupling<T: HerokuApiClient>(api_client: &T) {
     let coupling_id = "COUPLING_ID";
-    let response = api_client.request(&pipelines::PipelineCouplingDelete { coupling_id });
+    let response = api_client.request(&pipelines::PipelineCouplingDelete {
+        coupling_id: coupling_id.into(),
+    });
     print_response(response);
 }
 
 // get app pipeline coupling details
 fn get_app_pipeline_coupling<T: HerokuApiClient>(api_client: &T) {
     let app_id = "APP_ID";
-    let response = api_client.request(&pipelines::PipelineCouplingByAppDetails { app_id });
+    let response = api_client.request(&pipelines::PipelineCouplingByAppDetails {
+        app_id: app_id.into(),
+    });
     print_response(response);
 }
 
@@ -122,7 +136,9 @@ fn update_pipeline_coupling<T: HerokuApiClient>(api_client: &T) {
 // get pipeline coupling details
 fn get_pipeline_coupling<T: HerokuApiClient>(api_client: &T) {
     let coupling_id = "COUPLING_ID";
-    let response = api_client.request(&pipelines::PipelineCouplingDetails { coupling_id });
+    let response = api_client.request(&pipelines::PipelineCouplingDetails {
+        coupling_id: coupling_id.into(),
+    });
     print_response(response);
 }
 
@@ -146,7 +162,9 @@ fn get_pipeline_couplings<T: HerokuApiClient>(api_client: &T) {
 // get team pipeline couplings
 fn get_team_pipeline_couplings<T: HerokuApiClient>(api_client: &T) {
     let team_id = "TEAM_ID";
-    let response = api_client.request(&pipelines::PipelineCouplingByTeamList { team_id });
+    let response = api_client.request(&pipelines::PipelineCouplingByTeamList {
+        team_id: team_id.into(),
+    });
     print_response(response);
 }
 
@@ -159,21 +177,27 @@ fn get_user_pipeline_couplings<T: HerokuApiClient>(api_client: &T) {
 // get pipeline couplings by pipeline id
 fn get_pipeline_pipeline_couplings<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelineCouplingByPipelineList { pipeline_id });
+    let response = api_client.request(&pipelines::PipelineCouplingByPipelineList {
+        pipeline_id: pipeline_id.into(),
+    });
     print_response(response);
 }
 
 // get pipeline latest builds
 fn get_pipeline_latest_builds<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelineLatestBuildsList { pipeline_id });
+    let response = api_client.request(&pipelines::PipelineLatestBuildsList {
+        pipeline_id: pipeline_id.into(),
+    });
     print_response(response);
 }
 
 // delete pipeline
 fn delete_pipeline<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelineDelete { pipeline_id });
+    let response = api_client.request(&pipelines::PipelineDelete {
+        pipeline_id: pipeline_id.into(),
+    });
     print_response(response);
 }
 
@@ -191,7 +215,9 @@ fn update_pipeline<T: HerokuApiClient>(api_client: &T) {
 // get pipeline info
 fn get_pipeline<T: HerokuApiClient>(api_client: &T) {
     let pipeline_id = "PIPELINE_ID";
-    let response = api_client.request(&pipelines::PipelineDetails { pipeline_id });
+    let response = api_client.request(&pipelines::PipelineDetails {
+        pipeline_id: pipeline_id.into(),
+    });
     print_response(response);
 }
 
@@ -120,8 +120,8 @@ fn team_invoice<T: HerokuApiClient>(api_client: &T) {
     let invoice_id = "123";
     let team_id = "123";
     let response = api_client.request(&teams::TeamInvoiceDetails {
-        team_id,
-        invoice_id,
+        team_id: team_id.into(),
+        invoice_id: invoice_id.into(),
     });
     print_response(response);
 }
@@ -129,21 +129,27 @@ fn team_invoice<T: HerokuApiClient>(api_client: &T) {
 // get a list of existing invoices
 fn team_invoice_list<T: HerokuApiClient>(api_client: &T) {
     let team_id = "123";
-    let response = api_client.request(&teams::TeamInvoiceList { team_id });
+    let response = api_client.request(&teams::TeamInvoiceList {
+        team_id: team_id.into(),
+    });
     print_response(response);
 }
 
 // accept team invitation
 fn accept_team_invitation<T: HerokuApiClient>(api_client: &T) {
     let token_id = "123";
-    let response = api_client.request(&teams::TeamInvitationAccept { token_id });
+    let response = api_client.request(&teams::TeamInvitationAccept {
+        token_id: token_id.into(),
+    });
     print_response(response);
 }
 
 // get team invitation
 fn get_team_invitation<T: HerokuApiClient>(api_client: &T) {
     let token_id = "123";
-    let response = api_client.request(&teams::TeamInvitationDetails { token_id });
+    let response = api_client.request(&teams::TeamInvitationDetails {
+        token_id: token_id.into(),
+    });
     print_response(response);
 }
 
@@ -170,7 +176,9 @@ fn create_team_invitation<T: HerokuApiClient>(api_client: &T) {
 // get team invitations
 fn get_team_invitations<T: HerokuApiClient>(api_client: &T) {
     let team_id = "123";
-    let response = api_client.request(&teams::TeamInvitationList { team_id });
+    let response = api_client.request(&teams::TeamInvitationList {
+        team_id: team_id.into(),
+    });
     print_response(response);
 }
 
@@ -179,8 +187,8 @@ fn get_team_feature<T: HerokuApiClient>(api_client: &T) {
     let team_id = "123";
     let feature_id = "123";
     let response = api_client.request(&teams::TeamFeatureDetails {
-        team_id,
-        feature_id,
+        team_id: team_id.into(),
+        feature_id: feature_id.into(),
     });
     print_response(response);
 }
@@ -188,7 +196,9 @@ fn get_team_feature<T: HerokuApiClient>(api_client: &T) {
 // get team features
 fn get_team_features<T: HerokuApiClient>(api_client: &T) {
     let team_id = "123";
-    let response = api_client.request(&teams::TeamFeatureList { team_id });
+    let response = api_client.request(&teams::TeamFeatureList {
+        team_id: team_id.into(),
+    });
     print_response(response);
 }
 
@@ -201,7 +211,9 @@ fn team_permissions<T: HerokuApiClient>(api_client: &T) {
 // get team app list
 fn team_app_list<T: HerokuApiClient>(api_client: &T) {
     let team_id = "123";
-    let response = api_client.request(&teams::TeamAppList { team_id });
+    let response = api_client.request(&teams::TeamAppList {
+        team_id: team_id.into(),
+    });
     print_response(response);
 }
 
@@ -247,7 +259,9 @@ fn update_team<T: HerokuApiClient>(api_client: &T) {
 /// Create a new team app
 fn get_team_app<T: HerokuApiClient>(api_client: &T) {
     let app_id = "123";
-    let response = api_client.request(&teams::TeamAppDetails { app_id });
+    let response = api_client.request(&teams::TeamAppDetails {
+        app_id: app_id.into(),
+    });
     print_response(response);
 }
 
@@ -265,14 +279,18 @@ fn create_team_app<T: HerokuApiClient>(api_client: &T) {
 // delete team
 fn delete_team<T: HerokuApiClient>(api_client: &T) {
     let team_id = "123";
-    let response = api_client.request(&teams::TeamDelete { team_id });
+    let response = api_client.request(&teams::TeamDelete {
+        team_id: team_id.into(),
+    });
     print_response(response);
 }
 
 // get team list by account id
 fn get_enterprise_account_teams<T: HerokuApiClient>(api_client: &T) {
     let account_id = "123";
-    let response = api_client.request(&teams::TeamListByEA { account_id });
+    let response = api_client.request(&teams::TeamListByEA {
+        account_id: account_id.into(),
+    });
     print_response(response);
 }
 
@@ -285,7 +303,9 @@ fn get_teams<T: HerokuApiClient>(api_client: &T) {
 // get a specific team by id
 fn get_team<T: HerokuApiClient>(api_client: &T) {
     let team_id = "123"; // team identifier
-    let response = api_client.request(&teams::TeamDetails { team_id });
+    let response = api_client.request(&teams::TeamDetails {
+        team_id: team_id.into(),
+    });
     print_response(response);
 }
 
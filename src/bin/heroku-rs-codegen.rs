@@ -0,0 +1,89 @@
+//! heroku-rs-codegen
+//!
+//! Generates endpoint modules from a local copy of Heroku's platform API hyper-schema, or reports how the schema,
+//! the endpoints in `src/endpoints` and `docs/ENDPOINTS.md` drifted apart.
+//!
+//! ```sh
+//! heroku-rs-codegen generate schema.json generated/ enterprise-account
+//! heroku-rs-codegen drift schema.json
+//! ```
+
+use heroku_rs::tools::codegen::{self, drift, Drift, Schema};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const USAGE: &str = "usage:
+  heroku-rs-codegen generate SCHEMA OUT_DIR [RESOURCE...]
+  heroku-rs-codegen drift SCHEMA [CRATE_DIR]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["generate", schema, out_dir, resources @ ..] => generate(schema, out_dir, resources),
+        ["drift", schema] => drift(schema, Path::new(env!("CARGO_MANIFEST_DIR"))),
+        ["drift", schema, crate_dir] => drift(schema, Path::new(crate_dir)),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Generate the modules of `resources`, or of every resource with links when none is given.
+fn generate(
+    schema: &str,
+    out_dir: &str,
+    resources: &[&str],
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let schema = Schema::from_file(schema)?;
+    let selected: Vec<_> = if resources.is_empty() {
+        schema
+            .resources
+            .iter()
+            .filter(|resource| !resource.links.is_empty())
+            .collect()
+    } else {
+        let mut selected = Vec::new();
+        for name in resources {
+            match schema.resource(name) {
+                Some(resource) => selected.push(resource),
+                None => return Err(format!("no resource `{}` in the schema", name).into()),
+            }
+        }
+        selected
+    };
+
+    for resource in selected {
+        let module = codegen::generate(resource);
+        module.write_to(out_dir)?;
+        println!("{}", PathBuf::from(out_dir).join(&module.name).display());
+    }
+    Ok(true)
+}
+
+/// Print the drift, returns false when there is any.
+fn drift(schema: &str, crate_dir: &Path) -> Result<bool, Box<dyn std::error::Error>> {
+    let schema = Schema::from_file(schema)?;
+    let endpoints = drift::scan_endpoint_dir(crate_dir.join("src/endpoints"))?;
+    let docs = drift::scan_docs(&fs::read_to_string(crate_dir.join("docs/ENDPOINTS.md"))?);
+
+    let drift = Drift::check(&schema, &endpoints, &docs);
+    print!("{}", drift);
+    Ok(drift.is_empty())
+}
@@ -0,0 +1,450 @@
+//Anything related to running the subcommands goes here.
+
+use crate::output::{print, Table};
+use crate::{
+    AddonsCommand, AppsCommand, ConfigCommand, LogsCommand, PipelinesCommand, PsCommand,
+    ReleasesCommand,
+};
+use chrono::SecondsFormat;
+use heroku_rs::endpoints::addons::post::Attachment;
+use heroku_rs::endpoints::addons::{AddonCreate, AddonCreateParams, AddonListByApp};
+use heroku_rs::endpoints::apps::{AppDetails, AppList};
+use heroku_rs::endpoints::config_vars::{
+    AppConfigVarDelete, AppConfigVarDetails, AppConfigVarUpdate,
+};
+use heroku_rs::endpoints::dynos::DynoList;
+use heroku_rs::endpoints::formations::{FormationUpdate, FormationUpdateParams};
+use heroku_rs::endpoints::logs::{LogSessionCreate, LogSessionCreateParams};
+use heroku_rs::endpoints::pipelines::post::{AppParam, PipelineParam, SourceParam, TargetParam};
+use heroku_rs::endpoints::pipelines::{
+    PipelineCouplingByAppDetails, PipelineCouplingByPipelineList, PipelinePromotionCreate,
+    PipelinePromotionCreateParams,
+};
+use heroku_rs::endpoints::releases::{
+    ReleaseInfo, ReleaseList, ReleaseRollback, ReleaseRollbackParams,
+};
+use heroku_rs::endpoints::teams::TeamAppList;
+use heroku_rs::framework::apiclient::HerokuApiClient;
+use heroku_rs::framework::endpoint::Ranged;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::io;
+
+type CommandResult = Result<(), Box<dyn Error>>;
+
+pub fn apps<C: HerokuApiClient>(api_client: &C, command: AppsCommand, json: bool) -> CommandResult {
+    match command {
+        AppsCommand::List { team: Some(team) } => {
+            let apps = api_client.request(&TeamAppList {
+                team_id: team.as_str().into(),
+            })?;
+            print(json, &apps, || {
+                let mut table = Table::new(vec!["NAME", "REGION", "STACK"]);
+                for app in &apps {
+                    table.row(vec![
+                        app.name.clone(),
+                        app.region.name.clone(),
+                        app.build_stack.name.clone(),
+                    ]);
+                }
+                table
+            });
+        }
+        AppsCommand::List { team: None } => {
+            let apps = api_client.request(&AppList {})?;
+            print(json, &apps, || {
+                let mut table = Table::new(vec!["NAME", "REGION", "OWNER"]);
+                for app in &apps {
+                    table.row(vec![
+                        app.name.clone(),
+                        app.region.name.clone(),
+                        app.owner.email.clone(),
+                    ]);
+                }
+                table
+            });
+        }
+        AppsCommand::Info { app } => {
+            let app = api_client.request(&AppDetails {
+                app_id: app.as_str().into(),
+            })?;
+            print(json, &app, || {
+                let mut table = Table::new(vec!["KEY", "VALUE"]);
+                table
+                    .row(vec!["name".to_string(), app.name.clone()])
+                    .row(vec!["id".to_string(), app.id.clone()])
+                    .row(vec!["region".to_string(), app.region.name.clone()])
+                    .row(vec!["stack".to_string(), app.build_stack.name.clone()])
+                    .row(vec!["owner".to_string(), app.owner.email.clone()])
+                    .row(vec!["web url".to_string(), app.web_url.clone()])
+                    .row(vec!["git url".to_string(), app.git_url.clone()]);
+                table
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn config<C: HerokuApiClient>(
+    api_client: &C,
+    command: ConfigCommand,
+    json: bool,
+) -> CommandResult {
+    match command {
+        ConfigCommand::Get { app, key } => {
+            let vars = api_client.request(&AppConfigVarDetails {
+                app_id: app.as_str().into(),
+            })?;
+            match key {
+                Some(key) => {
+                    let value = vars.get(&key).cloned().flatten();
+                    if json {
+                        println!("{}", serde_json::to_string(&value)?);
+                    } else {
+                        println!("{}", value.unwrap_or_default());
+                    }
+                }
+                None => {
+                    let vars: BTreeMap<_, _> = vars.into_iter().collect();
+                    print(json, &vars, || config_table(&vars));
+                }
+            }
+        }
+        ConfigCommand::Set { app, vars } => {
+            let mut params = HashMap::new();
+            for var in vars {
+                match var.split_once('=') {
+                    Some((key, value)) if !key.is_empty() => {
+                        params.insert(key.to_string(), value.to_string());
+                    }
+                    _ => return Err(format!("`{}` is not in the KEY=VALUE format", var).into()),
+                }
+            }
+            let vars = api_client.request(&AppConfigVarUpdate {
+                app_id: app.as_str().into(),
+                params,
+            })?;
+            let vars: BTreeMap<_, _> = vars.into_iter().map(|(k, v)| (k, Some(v))).collect();
+            print(json, &vars, || config_table(&vars));
+        }
+        ConfigCommand::Unset { app, keys } => {
+            let params = keys.into_iter().map(|key| (key, None)).collect();
+            let vars = api_client.request(&AppConfigVarDelete {
+                app_id: app.as_str().into(),
+                params,
+            })?;
+            let vars: BTreeMap<_, _> = vars.into_iter().collect();
+            print(json, &vars, || config_table(&vars));
+        }
+    }
+    Ok(())
+}
+
+fn config_table(vars: &BTreeMap<String, Option<String>>) -> Table {
+    let mut table = Table::new(vec!["KEY", "VALUE"]);
+    for (key, value) in vars {
+        table.row(vec![key.clone(), value.clone().unwrap_or_default()]);
+    }
+    table
+}
+
+pub fn ps<C: HerokuApiClient>(api_client: &C, command: PsCommand, json: bool) -> CommandResult {
+    match command {
+        PsCommand::List { app } => {
+            let dynos = api_client.request(&DynoList {
+                app_id: app.as_str().into(),
+            })?;
+            print(json, &dynos, || {
+                let mut table = Table::new(vec!["NAME", "SIZE", "STATE", "COMMAND"]);
+                for dyno in &dynos {
+                    table.row(vec![
+                        dyno.name.clone(),
+                        dyno.size.clone(),
+                        dyno.state.to_string(),
+                        dyno.command.clone(),
+                    ]);
+                }
+                table
+            });
+        }
+        PsCommand::Scale { app, processes } => {
+            let mut formation = Vec::new();
+            for process in &processes {
+                let (process_type, quantity, size) = parse_scale(process)?;
+                formation.push(api_client.request(&FormationUpdate {
+                    app_id: app.as_str().into(),
+                    formation_id: process_type.into(),
+                    params: FormationUpdateParams {
+                        quantity,
+                        size: size.map(Cow::from),
+                    },
+                })?);
+            }
+            print(json, &formation, || {
+                let mut table = Table::new(vec!["TYPE", "QUANTITY", "SIZE"]);
+                for process in &formation {
+                    table.row(vec![
+                        process.r#type.clone(),
+                        process.quantity.to_string(),
+                        process.size.clone(),
+                    ]);
+                }
+                table
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Parse `TYPE=QUANTITY`, `TYPE=QUANTITY:SIZE` or `TYPE=SIZE`.
+fn parse_scale(process: &str) -> Result<(&str, Option<i32>, Option<&str>), String> {
+    let invalid = || format!("`{}` is not in the TYPE=QUANTITY[:SIZE] format", process);
+    let (process_type, value) = process.split_once('=').ok_or_else(invalid)?;
+    if process_type.is_empty() || value.is_empty() {
+        return Err(invalid());
+    }
+    let (quantity, size) = match value.split_once(':') {
+        Some((quantity, size)) => (quantity, Some(size)),
+        None if value.parse::<i32>().is_ok() => (value, None),
+        None => ("", Some(value)),
+    };
+    let quantity = match quantity {
+        "" => None,
+        quantity => Some(quantity.parse::<i32>().map_err(|_| invalid())?),
+    };
+    Ok((process_type, quantity, size))
+}
+
+pub fn releases<C: HerokuApiClient>(
+    api_client: &C,
+    command: ReleasesCommand,
+    json: bool,
+) -> CommandResult {
+    let table = |releases: &[heroku_rs::endpoints::releases::Release]| {
+        let mut table = Table::new(vec!["VERSION", "STATUS", "DESCRIPTION", "USER", "CREATED"]);
+        for release in releases {
+            table.row(vec![
+                format!("v{}", release.version),
+                release.status.to_string(),
+                release.description.clone(),
+                release.user.email.clone(),
+                release
+                    .created_at
+                    .to_rfc3339_opts(SecondsFormat::Secs, true),
+            ]);
+        }
+        table
+    };
+
+    match command {
+        ReleasesCommand::List { app, num } => {
+            let list = ReleaseList {
+                app_id: app.as_str().into(),
+            };
+            let releases = api_client.request(&Ranged::new(
+                &list,
+                format!("version ..; order=desc, max={}", num),
+            ))?;
+            print(json, &releases, || table(&releases));
+        }
+        ReleasesCommand::Rollback { app, release } => {
+            let target = api_client.request(&ReleaseInfo {
+                app_id: app.as_str().into(),
+                release_id: release,
+            })?;
+            let release = api_client.request(&ReleaseRollback {
+                app_id: app.as_str().into(),
+                params: ReleaseRollbackParams {
+                    release: target.id.as_str().into(),
+                },
+            })?;
+            print(json, &release, || table(std::slice::from_ref(&release)));
+        }
+    }
+    Ok(())
+}
+
+pub fn addons<C: HerokuApiClient>(
+    api_client: &C,
+    command: AddonsCommand,
+    json: bool,
+) -> CommandResult {
+    let table = |addons: &[heroku_rs::endpoints::addons::Addon]| {
+        let mut table = Table::new(vec!["NAME", "PLAN", "STATE", "APP"]);
+        for addon in addons {
+            table.row(vec![
+                addon.name.clone(),
+                addon.plan.name.clone(),
+                addon.state.to_string(),
+                addon.app.name.clone(),
+            ]);
+        }
+        table
+    };
+
+    match command {
+        AddonsCommand::List { app } => {
+            let addons = api_client.request(&AddonListByApp {
+                app_id: app.as_str().into(),
+            })?;
+            print(json, &addons, || table(&addons));
+        }
+        AddonsCommand::Create {
+            app,
+            plan,
+            attachment,
+            name,
+        } => {
+            let addon = api_client.request(&AddonCreate {
+                app_id: app.as_str().into(),
+                params: AddonCreateParams {
+                    attachment: attachment.as_deref().map(|name| Attachment {
+                        name: Some(name.into()),
+                    }),
+                    config: None,
+                    confirm: None,
+                    plan: plan.as_str().into(),
+                    name: name.as_deref().map(Cow::from),
+                },
+            })?;
+            print(json, &addon, || table(std::slice::from_ref(&addon)));
+        }
+    }
+    Ok(())
+}
+
+pub fn logs<C: HerokuApiClient>(api_client: &C, command: LogsCommand) -> CommandResult {
+    match command {
+        LogsCommand::Tail {
+            app,
+            dyno,
+            source,
+            lines,
+        } => {
+            let session = api_client.request(&LogSessionCreate {
+                app_id: app.as_str().into(),
+                params: LogSessionCreateParams {
+                    dyno: dyno.as_deref().map(Cow::from),
+                    lines,
+                    source: source.as_deref().map(Cow::from),
+                    tail: Some(true),
+                },
+            })?;
+            // the log stream stays open, it must not time out like API requests do
+            let client = reqwest::blocking::Client::builder().timeout(None).build()?;
+            let mut response = client
+                .get(&session.logplex_url)
+                .send()?
+                .error_for_status()?;
+            io::copy(&mut response, &mut io::stdout())?;
+        }
+    }
+    Ok(())
+}
+
+/// The stage apps are promoted to from `stage`.
+fn next_stage(stage: &str) -> Option<&'static str> {
+    match stage {
+        "review" | "development" => Some("staging"),
+        "staging" => Some("production"),
+        _ => None,
+    }
+}
+
+pub fn pipelines<C: HerokuApiClient>(
+    api_client: &C,
+    command: PipelinesCommand,
+    json: bool,
+) -> CommandResult {
+    match command {
+        PipelinesCommand::Promote { app, to } => {
+            let coupling = api_client.request(&PipelineCouplingByAppDetails {
+                app_id: app.as_str().into(),
+            })?;
+            let targets: Vec<String> = if to.is_empty() {
+                let stage = next_stage(&coupling.stage).ok_or_else(|| {
+                    format!("apps in the {} stage can't be promoted", coupling.stage)
+                })?;
+                let couplings = api_client.request(&PipelineCouplingByPipelineList {
+                    pipeline_id: coupling.pipeline.id.as_str().into(),
+                })?;
+                couplings
+                    .into_iter()
+                    .filter(|c| c.stage == stage)
+                    .map(|c| c.app.id)
+                    .collect()
+            } else {
+                // promotion targets only accept ids
+                to.iter()
+                    .map(|app| {
+                        api_client
+                            .request(&AppDetails {
+                                app_id: app.as_str().into(),
+                            })
+                            .map(|app| app.id)
+                    })
+                    .collect::<Result<_, _>>()?
+            };
+            if targets.is_empty() {
+                return Err("no app to promote to".into());
+            }
+
+            let promotion = api_client.request(&PipelinePromotionCreate {
+                params: PipelinePromotionCreateParams {
+                    pipeline: PipelineParam {
+                        id: coupling.pipeline.id.as_str().into(),
+                    },
+                    source: SourceParam {
+                        app: AppParam {
+                            id: coupling.app.id.as_str().into(),
+                        },
+                    },
+                    targets: targets
+                        .iter()
+                        .map(|id| TargetParam {
+                            app: AppParam { id: id.into() },
+                        })
+                        .collect(),
+                },
+            })?;
+            print(json, &promotion, || {
+                let mut table = Table::new(vec!["PROMOTION", "STATUS", "TARGETS"]);
+                table.row(vec![
+                    promotion.id.clone(),
+                    promotion.status.to_string(),
+                    targets.len().to_string(),
+                ]);
+                table
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scale() {
+        assert_eq!(parse_scale("web=2"), Ok(("web", Some(2), None)));
+        assert_eq!(
+            parse_scale("worker=1:standard-2x"),
+            Ok(("worker", Some(1), Some("standard-2x")))
+        );
+        assert_eq!(
+            parse_scale("web=performance-m"),
+            Ok(("web", None, Some("performance-m")))
+        );
+        assert!(parse_scale("web").is_err());
+        assert!(parse_scale("web=x:standard-1x").is_err());
+    }
+
+    #[test]
+    fn test_next_stage() {
+        assert_eq!(next_stage("development"), Some("staging"));
+        assert_eq!(next_stage("staging"), Some("production"));
+        assert_eq!(next_stage("production"), None);
+    }
+}
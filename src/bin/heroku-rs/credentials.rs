@@ -0,0 +1,101 @@
+//Anything related to finding the API key goes here.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// the machine the Heroku CLI writes to the netrc file
+const MACHINE: &str = "api.heroku.com";
+
+/// Find the API key, from `HEROKU_API_KEY` first, then from the netrc file.
+///
+/// The netrc file is read from `NETRC` when set, `~/.netrc` otherwise.
+pub fn api_key() -> Result<String, String> {
+    if let Ok(key) = env::var("HEROKU_API_KEY") {
+        if !key.trim().is_empty() {
+            return Ok(key.trim().to_string());
+        }
+    }
+
+    let path = match env::var_os("NETRC") {
+        Some(path) => PathBuf::from(path),
+        None => match env::var_os("HOME") {
+            Some(home) => PathBuf::from(home).join(".netrc"),
+            None => return Err(missing()),
+        },
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(content) => netrc_password(&content, MACHINE).ok_or_else(missing),
+        Err(_) => Err(missing()),
+    }
+}
+
+fn missing() -> String {
+    format!(
+        "no credentials found, set HEROKU_API_KEY or add {} to your netrc file",
+        MACHINE
+    )
+}
+
+/// Find the password of `machine` in the content of a netrc file.
+pub fn netrc_password(content: &str, machine: &str) -> Option<String> {
+    let mut tokens = content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(|line| line.split_whitespace());
+
+    let mut in_machine = false;
+    while let Some(token) = tokens.next() {
+        match token {
+            "machine" => in_machine = tokens.next() == Some(machine),
+            "default" => in_machine = false,
+            "password" => {
+                let password = tokens.next();
+                if in_machine {
+                    return password.map(String::from);
+                }
+            }
+            // every other keyword is followed by a value
+            "login" | "account" => {
+                tokens.next();
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_netrc_password() {
+        let netrc = "
+# written by the Heroku CLI
+machine git.heroku.com
+  login me@example.com
+  password git-token
+machine api.heroku.com
+  login me@example.com
+  password api-token
+";
+        assert_eq!(
+            netrc_password(netrc, MACHINE),
+            Some("api-token".to_string())
+        );
+        assert_eq!(
+            netrc_password(netrc, "git.heroku.com"),
+            Some("git-token".to_string())
+        );
+    }
+
+    #[test]
+    fn test_netrc_single_line() {
+        let netrc =
+            "machine other.com login a password b machine api.heroku.com login c password d";
+        assert_eq!(netrc_password(netrc, MACHINE), Some("d".to_string()));
+        assert_eq!(netrc_password(netrc, "missing.com"), None);
+    }
+}
@@ -0,0 +1,219 @@
+//! heroku-rs
+//!
+//! A small command-line client for the Heroku API, built on the `heroku_rs` crate.
+//!
+//! Credentials are read from `HEROKU_API_KEY`, or from the `api.heroku.com` entry of the netrc file written by `heroku login`.
+//! Every command prints a table, or the raw API response with `--json`.
+
+mod commands;
+mod credentials;
+mod output;
+
+use clap::{Parser, Subcommand};
+use heroku_rs::endpoints::ids::ReleaseRef;
+use heroku_rs::framework::HttpApiClient;
+use std::process;
+
+#[derive(Parser)]
+#[command(
+    name = "heroku-rs",
+    version,
+    about = "Command-line client for the Heroku API"
+)]
+struct Cli {
+    /// print the API response as JSON instead of a table
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// manage apps
+    #[command(subcommand)]
+    Apps(AppsCommand),
+    /// manage config vars
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// manage dynos
+    #[command(subcommand)]
+    Ps(PsCommand),
+    /// manage releases
+    #[command(subcommand)]
+    Releases(ReleasesCommand),
+    /// manage add-ons
+    #[command(subcommand)]
+    Addons(AddonsCommand),
+    /// display logs
+    #[command(subcommand)]
+    Logs(LogsCommand),
+    /// manage pipelines
+    #[command(subcommand)]
+    Pipelines(PipelinesCommand),
+}
+
+#[derive(Subcommand)]
+pub enum AppsCommand {
+    /// list your apps, or the apps of a team
+    List {
+        /// team name or id
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// show an app
+    Info {
+        /// app name or id
+        app: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// show the config vars of an app, or the value of a single one
+    Get {
+        /// app name or id
+        app: String,
+        /// config var name
+        key: Option<String>,
+    },
+    /// set config vars
+    Set {
+        /// app name or id
+        app: String,
+        /// config vars, as KEY=VALUE
+        #[arg(required = true)]
+        vars: Vec<String>,
+    },
+    /// remove config vars
+    Unset {
+        /// app name or id
+        app: String,
+        /// config var names
+        #[arg(required = true)]
+        keys: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PsCommand {
+    /// list the dynos of an app
+    List {
+        /// app name or id
+        app: String,
+    },
+    /// scale process types, as TYPE=QUANTITY or TYPE=QUANTITY:SIZE
+    Scale {
+        /// app name or id
+        app: String,
+        #[arg(required = true)]
+        processes: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ReleasesCommand {
+    /// list the latest releases of an app, newest first
+    List {
+        /// app name or id
+        app: String,
+        /// number of releases to show
+        #[arg(long, short = 'n', default_value_t = 15)]
+        num: u32,
+    },
+    /// roll back to a previous release
+    Rollback {
+        /// app name or id
+        app: String,
+        /// release version or id
+        release: ReleaseRef,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AddonsCommand {
+    /// list the add-ons of an app
+    List {
+        /// app name or id
+        app: String,
+    },
+    /// provision an add-on
+    Create {
+        /// app name or id
+        app: String,
+        /// add-on service and plan, e.g. heroku-postgresql:standard-0
+        plan: String,
+        /// name of the attachment
+        #[arg(long = "as")]
+        attachment: Option<String>,
+        /// globally unique name of the add-on
+        #[arg(long)]
+        name: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum LogsCommand {
+    /// stream the logs of an app
+    Tail {
+        /// app name or id
+        app: String,
+        /// only show the logs of this dyno, e.g. web.1
+        #[arg(long)]
+        dyno: Option<String>,
+        /// only show the logs of this source, e.g. app or heroku
+        #[arg(long)]
+        source: Option<String>,
+        /// number of past lines to show first
+        #[arg(long, short = 'n')]
+        lines: Option<i64>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PipelinesCommand {
+    /// promote the current release of an app to the downstream stage
+    Promote {
+        /// app name or id to promote from
+        #[arg(long)]
+        app: String,
+        /// app names or ids to promote to, defaults to every app of the next stage
+        #[arg(long)]
+        to: Vec<String>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let api_key = match credentials::api_key() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let api_client = match HttpApiClient::create(&api_key) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("could not create the client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let result = match cli.command {
+        Command::Apps(command) => commands::apps(&api_client, command, cli.json),
+        Command::Config(command) => commands::config(&api_client, command, cli.json),
+        Command::Ps(command) => commands::ps(&api_client, command, cli.json),
+        Command::Releases(command) => commands::releases(&api_client, command, cli.json),
+        Command::Addons(command) => commands::addons(&api_client, command, cli.json),
+        Command::Logs(command) => commands::logs(&api_client, command),
+        Command::Pipelines(command) => commands::pipelines(&api_client, command, cli.json),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,98 @@
+//Anything related to printing results goes here.
+
+use serde::Serialize;
+
+/// A table of strings, printed with aligned columns.
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<&'static str>) -> Table {
+        Table {
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn row(&mut self, row: Vec<String>) -> &mut Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Render the table, columns are separated by two spaces and trailing whitespace is trimmed.
+    pub fn render(&self) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                if i < widths.len() {
+                    widths[i] = widths[i].max(cell.chars().count());
+                }
+            }
+        }
+
+        let line = |cells: Vec<&str>| {
+            let mut line = String::new();
+            for (i, cell) in cells.iter().enumerate() {
+                if i > 0 {
+                    line.push_str("  ");
+                }
+                line.push_str(cell);
+                let padding = widths
+                    .get(i)
+                    .unwrap_or(&0)
+                    .saturating_sub(cell.chars().count());
+                line.push_str(&" ".repeat(padding));
+            }
+            line.trim_end().to_string()
+        };
+
+        let mut output = line(self.headers.clone());
+        output.push('\n');
+        let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        output.push_str(&line(separator.iter().map(String::as_str).collect()));
+        output.push('\n');
+        for row in &self.rows {
+            output.push_str(&line(row.iter().map(String::as_str).collect()));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// Print `value` as pretty JSON when `json` is set, the table otherwise.
+pub fn print<T: Serialize>(json: bool, value: &T, table: impl FnOnce() -> Table) {
+    if json {
+        match serde_json::to_string_pretty(value) {
+            Ok(output) => println!("{}", output),
+            Err(e) => eprintln!("could not serialize the response: {}", e),
+        }
+    } else {
+        print!("{}", table().render());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut table = Table::new(vec!["NAME", "REGION"]);
+        table
+            .row(vec!["my-app".to_string(), "eu".to_string()])
+            .row(vec!["a".to_string(), "us".to_string()]);
+
+        assert_eq!(
+            table.render(),
+            "NAME    REGION\n------  ------\nmy-app  eu\na       us\n"
+        );
+    }
+
+    #[test]
+    fn test_render_empty() {
+        let table = Table::new(vec!["KEY", "VALUE"]);
+        assert_eq!(table.render(), "KEY  VALUE\n---  -----\n");
+    }
+}
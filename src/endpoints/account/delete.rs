@@ -2,6 +2,7 @@
 use super::{Account, AppTransfer};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Account Delete.
 ///
@@ -73,13 +74,13 @@ impl HerokuEndpoint<Account> for AccountDelete {
 /// [response]: ../struct.Account.html
 pub struct UserAccountDelete<'a> {
     /// account_id can be the account email or id.
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> UserAccountDelete<'a> {
-    pub fn new(account_id: &'a str) -> UserAccountDelete<'a> {
-        UserAccountDelete { account_id }
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> UserAccountDelete<'a> {
+        UserAccountDelete { account_id: account_id.into() }
     }
 }
 
@@ -119,13 +120,13 @@ impl<'a> HerokuEndpoint<Account> for UserAccountDelete<'a> {
 /// [response]: ../struct.AppTransfer.html
 pub struct AppTransferDelete<'a> {
     /// transfer_id can be the transfer name or id.
-    pub transfer_id: &'a str,
+    pub transfer_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppTransferDelete<'a> {
-    pub fn new(transfer_id: &'a str) -> AppTransferDelete {
-        AppTransferDelete { transfer_id }
+    pub fn new(transfer_id: impl Into<Cow<'a, str>>) -> AppTransferDelete<'a> {
+        AppTransferDelete { transfer_id: transfer_id.into() }
     }
 }
 
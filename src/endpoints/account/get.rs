@@ -1,9 +1,11 @@
 //Anything related to GET requests for account and it's properties goes here.
 use super::{
     Account, AccountFeature, AppTransfer, Credit, Invoice, InvoiceAddress, Key, SmsNumber,
+    UserPreferences,
 };
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Account Info
 ///
@@ -74,13 +76,13 @@ impl HerokuEndpoint<Account> for AccountDetails {
 /// [response]: ../struct.Account.html
 pub struct UserAccountDetails<'a> {
     /// account_id can be the account email or id.
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> UserAccountDetails<'a> {
-    pub fn new(account_id: &'a str) -> UserAccountDetails {
-        UserAccountDetails { account_id }
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> UserAccountDetails<'a> {
+        UserAccountDetails { account_id: account_id.into() }
     }
 }
 
@@ -162,13 +164,13 @@ impl HerokuEndpoint<Vec<AccountFeature>> for AccountFeatureList {
 /// [response]: ../struct.AccountFeature.html
 pub struct AccountFeatureDetails<'a> {
     /// feature_id can be the feature name or id.
-    pub feature_id: &'a str,
+    pub feature_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AccountFeatureDetails<'a> {
-    pub fn new(feature_id: &'a str) -> AccountFeatureDetails {
-        AccountFeatureDetails { feature_id }
+    pub fn new(feature_id: impl Into<Cow<'a, str>>) -> AccountFeatureDetails<'a> {
+        AccountFeatureDetails { feature_id: feature_id.into() }
     }
 }
 
@@ -251,13 +253,13 @@ impl HerokuEndpoint<Vec<AppTransfer>> for AppTransferList {
 /// [response]: ../struct.AppTransfer.html
 pub struct AppTransferDetails<'a> {
     /// transfer_id can be the transfer name or id.
-    pub transfer_id: &'a str,
+    pub transfer_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppTransferDetails<'a> {
-    pub fn new(transfer_id: &'a str) -> AppTransferDetails {
-        AppTransferDetails { transfer_id }
+    pub fn new(transfer_id: impl Into<Cow<'a, str>>) -> AppTransferDetails<'a> {
+        AppTransferDetails { transfer_id: transfer_id.into() }
     }
 }
 
@@ -298,13 +300,13 @@ impl<'a> HerokuEndpoint<AppTransfer> for AppTransferDetails<'a> {
 /// [response]: ../struct.Credit.html
 pub struct AccountCreditDetails<'a> {
     /// credit_id is the credit identifier.
-    pub credit_id: &'a str,
+    pub credit_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AccountCreditDetails<'a> {
-    pub fn new(credit_id: &'a str) -> AccountCreditDetails {
-        AccountCreditDetails { credit_id }
+    pub fn new(credit_id: impl Into<Cow<'a, str>>) -> AccountCreditDetails<'a> {
+        AccountCreditDetails { credit_id: credit_id.into() }
     }
 }
 
@@ -388,13 +390,13 @@ impl HerokuEndpoint<Vec<Credit>> for AccountCreditList {
 /// [response]: ../struct.SmsNumber.html
 pub struct SmsNumberDetails<'a> {
     /// unique identifier, email or account id
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SmsNumberDetails<'a> {
-    pub fn new(account_id: &'a str) -> SmsNumberDetails {
-        SmsNumberDetails { account_id }
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> SmsNumberDetails<'a> {
+        SmsNumberDetails { account_id: account_id.into() }
     }
 }
 
@@ -435,13 +437,13 @@ impl<'a> HerokuEndpoint<SmsNumber> for SmsNumberDetails<'a> {
 /// [response]: ../struct.Invoice.html
 pub struct InvoiceDetails<'a> {
     /// invoice number
-    pub invoice_id: &'a str,
+    pub invoice_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> InvoiceDetails<'a> {
-    pub fn new(invoice_id: &'a str) -> InvoiceDetails {
-        InvoiceDetails { invoice_id }
+    pub fn new(invoice_id: impl Into<Cow<'a, str>>) -> InvoiceDetails<'a> {
+        InvoiceDetails { invoice_id: invoice_id.into() }
     }
 }
 
@@ -568,13 +570,13 @@ impl HerokuEndpoint<InvoiceAddress> for InvoiceAddressDetails {
 /// [response]: ../struct.Key.html
 pub struct KeyDetails<'a> {
     /// unique key identifier, either key_id or fingerprint
-    pub key_id: &'a str,
+    pub key_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> KeyDetails<'a> {
-    pub fn new(key_id: &'a str) -> KeyDetails {
-        KeyDetails { key_id }
+    pub fn new(key_id: impl Into<Cow<'a, str>>) -> KeyDetails<'a> {
+        KeyDetails { key_id: key_id.into() }
     }
 }
 
@@ -629,3 +631,45 @@ impl HerokuEndpoint<Vec<Key>> for KeyList {
         format!("account/keys")
     }
 }
+
+/// User Preferences List
+///
+/// Retrieve User Preferences
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#user-preferences-list)
+///
+/// # Example:
+///
+/// UserPreferencesDetails takes no parameters, and returns the [`UserPreferences`][response] of the current user.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create(&"API_KEY").unwrap();
+///let response = api_client.request(&UserPreferencesDetails::new());
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.UserPreferences.html
+pub struct UserPreferencesDetails {}
+
+#[cfg(feature = "builder")]
+impl UserPreferencesDetails {
+    pub fn new() -> UserPreferencesDetails {
+        UserPreferencesDetails {}
+    }
+}
+
+impl HerokuEndpoint<UserPreferences> for UserPreferencesDetails {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("users/~/preferences")
+    }
+}
@@ -1,4 +1,7 @@
+use crate::endpoints::status::FeatureState;
 use crate::framework::response::ApiResult;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use serde::Deserialize;
 
 pub mod delete;
@@ -11,11 +14,12 @@ pub use delete::{AccountDelete, AppTransferDelete, UserAccountDelete};
 pub use get::{
     AccountCreditDetails, AccountCreditList, AccountDetails, AccountFeatureDetails,
     AccountFeatureList, AppTransferDetails, AppTransferList, InvoiceAddressDetails, InvoiceDetails,
-    InvoiceList, KeyDetails, KeyList, SmsNumberDetails, UserAccountDetails,
+    InvoiceList, KeyDetails, KeyList, SmsNumberDetails, UserAccountDetails, UserPreferencesDetails,
 };
 pub use patch::{
     AccountFeatureUpdate, AccountFeatureUpdateParams, AccountUpdate, AccountUpdateParams,
     AppTransferUpdate, AppTransferUpdateParams, UserAccountUpdate, UserAccountUpdateParams,
+    UserPreferencesUpdate, UserPreferencesUpdateParams,
 };
 
 pub use post::{
@@ -31,6 +35,7 @@ pub use invoice_address::InvoiceAddress;
 pub use key::Key;
 pub use password::PasswordResetResponse;
 pub use sms_number::SmsNumber;
+pub use user_preferences::UserPreferences;
 
 impl ApiResult for Account {}
 
@@ -56,6 +61,8 @@ impl ApiResult for Vec<InvoiceAddress> {}
 impl ApiResult for Key {}
 impl ApiResult for Vec<Key> {}
 
+impl ApiResult for UserPreferences {}
+
 /// # Account
 ///
 /// ## Stability: production
@@ -70,7 +77,7 @@ pub struct Account {
     /// whether allowed to utilize beta Heroku features
     pub beta: bool,
     /// when account was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// unique email address of account
     pub email: String,
     /// whether the user is federated and belongs to an Identity Provider
@@ -86,13 +93,13 @@ pub struct Account {
     /// SMS number of account
     pub sms_number: Option<String>,
     /// when account was suspended
-    pub suspended_at: Option<String>,
+    pub suspended_at: Option<DateTime<Utc>>,
     /// when account became delinquent
-    pub delinquent_at: Option<String>,
+    pub delinquent_at: Option<DateTime<Utc>>,
     /// whether two-factor auth is enabled on the account
     pub two_factor_authentication: bool,
     /// when account was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// whether account has been verified with billing information
     pub verified: bool,
     /// team selected by default
@@ -171,7 +178,7 @@ pub struct DefaultTeam {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct AccountFeature {
     /// when account feature was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// description of account feature
     pub description: String,
     /// documentation URL of account feature
@@ -183,9 +190,9 @@ pub struct AccountFeature {
     /// unique name of account feature
     pub name: String,
     /// state of account feature
-    pub state: String,
+    pub state: FeatureState,
     /// when account feature was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// user readable feature name
     pub display_name: Option<String>,
     /// e-mail to send feedback about the feature
@@ -204,7 +211,7 @@ pub struct AppTransfer {
     /// the app struct containing the app id and name
     pub app: AppTransferApp,
     /// when app transfer was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// unique identifier of app transfer
     pub id: String,
     /// the owner struct containing the owner email and id
@@ -214,7 +221,7 @@ pub struct AppTransfer {
     /// the current state of an app transfer, one of:"pending" or "accepted" or "declined"
     pub state: String,
     /// when app transfer was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// AppTransferApp
@@ -258,15 +265,15 @@ pub struct Credit {
     /// remaining value of credit in cents
     pub balance: i64,
     /// when credit was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// when credit will expire
-    pub expires_at: String,
+    pub expires_at: DateTime<Utc>,
     /// unique identifier of credit
     pub id: String,
     /// a name for credit
     pub title: String,
     /// when credit was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 // password submodule, anything from /password-resets goes here.
@@ -410,3 +417,46 @@ mod key {
         pub updated_at: DateTime<Utc>,
     }
 }
+
+mod user_preferences {
+    /// User Preferences
+    ///
+    /// Stability: production
+    ///
+    /// Tracks a user's preferences and message dismissals
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#user-preferences)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct UserPreferences {
+        /// User's default timezone
+        #[serde(rename = "timezone")]
+        pub timezone: Option<String>,
+        /// User's default team
+        #[serde(rename = "default-organization")]
+        pub default_organization: Option<String>,
+        /// Whether the user has dismissed the GitHub link banner
+        #[serde(rename = "dismissed-github-banner")]
+        pub dismissed_github_banner: Option<bool>,
+        /// Whether the user has dismissed the getting started banner
+        #[serde(rename = "dismissed-getting-started")]
+        pub dismissed_getting_started: Option<bool>,
+        /// Whether the user has dismissed the Organization Access Controls banner
+        #[serde(rename = "dismissed-org-access-controls")]
+        pub dismissed_org_access_controls: Option<bool>,
+        /// Whether the user has dismissed the Organization Wide Notifications banner
+        #[serde(rename = "dismissed-org-wide-notifications")]
+        pub dismissed_org_wide_notifications: Option<bool>,
+        /// Whether the user has dismissed the Pipelines banner
+        #[serde(rename = "dismissed-pipelines-banner")]
+        pub dismissed_pipelines_banner: Option<bool>,
+        /// Whether the user has dismissed the GitHub banner on a pipeline overview
+        #[serde(rename = "dismissed-pipelines-github-banner")]
+        pub dismissed_pipelines_github_banner: Option<bool>,
+        /// Which pipeline uuids the user has dismissed the GitHub banner for
+        #[serde(rename = "dismissed-pipelines-github-banners")]
+        pub dismissed_pipelines_github_banners: Option<Vec<String>>,
+        /// Whether the user has dismissed the 2FA SMS banner
+        #[serde(rename = "dismissed-sms-banner")]
+        pub dismissed_sms_banner: Option<bool>,
+    }
+}
@@ -1,7 +1,8 @@
 //Anything related to PATCH requests for account and it's properties goes here.
-use super::{Account, AccountFeature, AppTransfer};
+use super::{Account, AccountFeature, AppTransfer, UserPreferences};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Account Update
 ///
@@ -65,8 +66,8 @@ impl<'a> AccountUpdate<'a> {
     }
 
     /// # name: full name of the account owner
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
@@ -75,7 +76,7 @@ impl<'a> AccountUpdate<'a> {
             params: AccountUpdateParams {
                 allow_tracking: self.params.allow_tracking,
                 beta: self.params.beta,
-                name: self.params.name,
+                name: self.params.name.clone(),
             },
         }
     }
@@ -95,7 +96,7 @@ pub struct AccountUpdateParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub beta: Option<bool>,
     /// full name of the account owner [Nullable]
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Account, (), AccountUpdateParams<'a>> for AccountUpdate<'a> {
@@ -143,16 +144,16 @@ impl<'a> HerokuEndpoint<Account, (), AccountUpdateParams<'a>> for AccountUpdate<
 /// [response]: ../struct.Account.html
 pub struct UserAccountUpdate<'a> {
     /// account_id can be the account email or id.
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: UserAccountUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> UserAccountUpdate<'a> {
-    pub fn new(account_id: &'a str) -> UserAccountUpdate<'a> {
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> UserAccountUpdate<'a> {
         UserAccountUpdate {
-            account_id,
+            account_id: account_id.into(),
             params: UserAccountUpdateParams {
                 allow_tracking: None,
                 beta: None,
@@ -176,18 +177,18 @@ impl<'a> UserAccountUpdate<'a> {
     }
 
     /// # name: full name of the account owner
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     pub fn build(&self) -> UserAccountUpdate<'a> {
         UserAccountUpdate {
-            account_id: self.account_id,
+            account_id: self.account_id.clone(),
             params: UserAccountUpdateParams {
                 allow_tracking: self.params.allow_tracking,
                 beta: self.params.beta,
-                name: self.params.name,
+                name: self.params.name.clone(),
             },
         }
     }
@@ -207,7 +208,7 @@ pub struct UserAccountUpdateParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub beta: Option<bool>,
     /// full name of the account owner [Nullable]
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Account, (), UserAccountUpdateParams<'a>> for UserAccountUpdate<'a> {
@@ -251,16 +252,16 @@ impl<'a> HerokuEndpoint<Account, (), UserAccountUpdateParams<'a>> for UserAccoun
 /// [response]: ../struct.AccountFeature.html
 pub struct AccountFeatureUpdate<'a> {
     /// feature_id can be the feature name or id.
-    pub feature_id: &'a str,
+    pub feature_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: AccountFeatureUpdateParams,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AccountFeatureUpdate<'a> {
-    pub fn new(feature_id: &'a str, enabled: bool) -> AccountFeatureUpdate {
+    pub fn new(feature_id: impl Into<Cow<'a, str>>, enabled: bool) -> AccountFeatureUpdate<'a> {
         AccountFeatureUpdate {
-            feature_id,
+            feature_id: feature_id.into(),
             params: AccountFeatureUpdateParams { enabled },
         }
     }
@@ -320,17 +321,20 @@ impl<'a> HerokuEndpoint<AccountFeature, (), AccountFeatureUpdateParams>
 /// [response]: ../struct.AppTransfer.html
 pub struct AppTransferUpdate<'a> {
     /// unique identifier or the transfer name
-    pub transfer_id: &'a str,
+    pub transfer_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: AppTransferUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppTransferUpdate<'a> {
-    pub fn new(transfer_id: &'a str, state: &'a str) -> AppTransferUpdate<'a> {
+    pub fn new(
+        transfer_id: impl Into<Cow<'a, str>>,
+        state: impl Into<Cow<'a, str>>,
+    ) -> AppTransferUpdate<'a> {
         AppTransferUpdate {
-            transfer_id,
-            params: AppTransferUpdateParams { state },
+            transfer_id: transfer_id.into(),
+            params: AppTransferUpdateParams { state: state.into() },
         }
     }
 }
@@ -341,7 +345,7 @@ impl<'a> AppTransferUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AppTransferUpdateParams<'a> {
     /// the current state of an app transfer, one of:"pending" or "accepted" or "declined"
-    pub state: &'a str,
+    pub state: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<AppTransfer, (), AppTransferUpdateParams<'a>> for AppTransferUpdate<'a> {
@@ -355,3 +359,195 @@ impl<'a> HerokuEndpoint<AppTransfer, (), AppTransferUpdateParams<'a>> for AppTra
         Some(self.params.clone())
     }
 }
+
+/// User Preferences Update
+///
+/// Update User Preferences
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#user-preferences-update)
+///
+/// # Example:
+///
+/// UserPreferencesUpdate takes no required parameters, and returns the updated [`UserPreferences`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create(&"API_KEY").unwrap();
+///let response = api_client.request(
+///     &UserPreferencesUpdate::new()
+///         .timezone("UTC")
+///         .dismissed_sms_banner(true)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.UserPreferences.html
+pub struct UserPreferencesUpdate<'a> {
+    /// The parameters to pass to the Heroku API
+    pub params: UserPreferencesUpdateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> UserPreferencesUpdate<'a> {
+    pub fn new() -> UserPreferencesUpdate<'a> {
+        UserPreferencesUpdate {
+            params: UserPreferencesUpdateParams {
+                timezone: None,
+                default_organization: None,
+                dismissed_github_banner: None,
+                dismissed_getting_started: None,
+                dismissed_org_access_controls: None,
+                dismissed_org_wide_notifications: None,
+                dismissed_pipelines_banner: None,
+                dismissed_pipelines_github_banner: None,
+                dismissed_pipelines_github_banners: None,
+                dismissed_sms_banner: None,
+            },
+        }
+    }
+
+    /// # timezone: User's default timezone
+    pub fn timezone(&mut self, timezone: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.timezone = Some(timezone.into());
+        self
+    }
+
+    /// # default_organization: User's default team
+    pub fn default_organization(
+        &mut self,
+        default_organization: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        self.params.default_organization = Some(default_organization.into());
+        self
+    }
+
+    /// # dismissed_github_banner: Whether the user has dismissed the GitHub link banner
+    pub fn dismissed_github_banner(&mut self, dismissed_github_banner: bool) -> &mut Self {
+        self.params.dismissed_github_banner = Some(dismissed_github_banner);
+        self
+    }
+
+    /// # dismissed_getting_started: Whether the user has dismissed the getting started banner
+    pub fn dismissed_getting_started(&mut self, dismissed_getting_started: bool) -> &mut Self {
+        self.params.dismissed_getting_started = Some(dismissed_getting_started);
+        self
+    }
+
+    /// # dismissed_org_access_controls: Whether the user has dismissed the Organization Access Controls banner
+    pub fn dismissed_org_access_controls(
+        &mut self,
+        dismissed_org_access_controls: bool,
+    ) -> &mut Self {
+        self.params.dismissed_org_access_controls = Some(dismissed_org_access_controls);
+        self
+    }
+
+    /// # dismissed_org_wide_notifications: Whether the user has dismissed the Organization Wide Notifications banner
+    pub fn dismissed_org_wide_notifications(
+        &mut self,
+        dismissed_org_wide_notifications: bool,
+    ) -> &mut Self {
+        self.params.dismissed_org_wide_notifications = Some(dismissed_org_wide_notifications);
+        self
+    }
+
+    /// # dismissed_pipelines_banner: Whether the user has dismissed the Pipelines banner
+    pub fn dismissed_pipelines_banner(&mut self, dismissed_pipelines_banner: bool) -> &mut Self {
+        self.params.dismissed_pipelines_banner = Some(dismissed_pipelines_banner);
+        self
+    }
+
+    /// # dismissed_pipelines_github_banner: Whether the user has dismissed the GitHub banner on a pipeline overview
+    pub fn dismissed_pipelines_github_banner(
+        &mut self,
+        dismissed_pipelines_github_banner: bool,
+    ) -> &mut Self {
+        self.params.dismissed_pipelines_github_banner = Some(dismissed_pipelines_github_banner);
+        self
+    }
+
+    /// # dismissed_pipelines_github_banners: Which pipeline uuids the user has dismissed the GitHub banner for
+    pub fn dismissed_pipelines_github_banners(
+        &mut self,
+        dismissed_pipelines_github_banners: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        self.params.dismissed_pipelines_github_banners = Some(
+            dismissed_pipelines_github_banners
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        );
+        self
+    }
+
+    /// # dismissed_sms_banner: Whether the user has dismissed the 2FA SMS banner
+    pub fn dismissed_sms_banner(&mut self, dismissed_sms_banner: bool) -> &mut Self {
+        self.params.dismissed_sms_banner = Some(dismissed_sms_banner);
+        self
+    }
+
+    pub fn build(&self) -> UserPreferencesUpdate<'a> {
+        UserPreferencesUpdate {
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Update user preferences with parameters.
+///
+/// [See Heroku documentation for more information about these optional parameters](https://devcenter.heroku.com/articles/platform-api-reference#user-preferences-update-optional-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct UserPreferencesUpdateParams<'a> {
+    /// User's default timezone
+    #[serde(rename = "timezone")]
+    pub timezone: Option<Cow<'a, str>>,
+    /// User's default team
+    #[serde(rename = "default-organization")]
+    pub default_organization: Option<Cow<'a, str>>,
+    /// Whether the user has dismissed the GitHub link banner
+    #[serde(rename = "dismissed-github-banner")]
+    pub dismissed_github_banner: Option<bool>,
+    /// Whether the user has dismissed the getting started banner
+    #[serde(rename = "dismissed-getting-started")]
+    pub dismissed_getting_started: Option<bool>,
+    /// Whether the user has dismissed the Organization Access Controls banner
+    #[serde(rename = "dismissed-org-access-controls")]
+    pub dismissed_org_access_controls: Option<bool>,
+    /// Whether the user has dismissed the Organization Wide Notifications banner
+    #[serde(rename = "dismissed-org-wide-notifications")]
+    pub dismissed_org_wide_notifications: Option<bool>,
+    /// Whether the user has dismissed the Pipelines banner
+    #[serde(rename = "dismissed-pipelines-banner")]
+    pub dismissed_pipelines_banner: Option<bool>,
+    /// Whether the user has dismissed the GitHub banner on a pipeline overview
+    #[serde(rename = "dismissed-pipelines-github-banner")]
+    pub dismissed_pipelines_github_banner: Option<bool>,
+    /// Which pipeline uuids the user has dismissed the GitHub banner for
+    #[serde(rename = "dismissed-pipelines-github-banners")]
+    pub dismissed_pipelines_github_banners: Option<Vec<Cow<'a, str>>>,
+    /// Whether the user has dismissed the 2FA SMS banner
+    #[serde(rename = "dismissed-sms-banner")]
+    pub dismissed_sms_banner: Option<bool>,
+}
+
+impl<'a> HerokuEndpoint<UserPreferences, (), UserPreferencesUpdateParams<'a>>
+    for UserPreferencesUpdate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Patch
+    }
+    fn path(&self) -> String {
+        format!("users/~/preferences")
+    }
+    fn body(&self) -> Option<UserPreferencesUpdateParams<'a>> {
+        Some(self.params.clone())
+    }
+}
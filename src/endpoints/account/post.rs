@@ -2,6 +2,7 @@
 use super::{AppTransfer, Credit, PasswordResetResponse, SmsNumber};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// App Transfer Create
 ///
@@ -40,11 +41,14 @@ pub struct AppTransferCreate<'a> {
 
 #[cfg(feature = "builder")]
 impl<'a> AppTransferCreate<'a> {
-    pub fn new(app: &'a str, recipient: &'a str) -> AppTransferCreate<'a> {
+    pub fn new(
+        app: impl Into<Cow<'a, str>>,
+        recipient: impl Into<Cow<'a, str>>,
+    ) -> AppTransferCreate<'a> {
         AppTransferCreate {
             params: AppTransferCreateParams {
-                app: app,
-                recipient: recipient,
+                app: app.into(),
+                recipient: recipient.into(),
                 silent: None,
             },
         }
@@ -59,8 +63,8 @@ impl<'a> AppTransferCreate<'a> {
     pub fn build(&self) -> AppTransferCreate<'a> {
         AppTransferCreate {
             params: AppTransferCreateParams {
-                app: self.params.app,
-                recipient: self.params.recipient,
+                app: self.params.app.clone(),
+                recipient: self.params.recipient.clone(),
                 silent: self.params.silent,
             },
         }
@@ -74,9 +78,9 @@ impl<'a> AppTransferCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AppTransferCreateParams<'a> {
     /// unique identifier or name of app
-    pub app: &'a str,
+    pub app: Cow<'a, str>,
     /// unique email address, identifier of an account or implicit reference to currently authorized user
-    pub recipient: &'a str,
+    pub recipient: Cow<'a, str>,
     /// whether to suppress email notification when transferring apps
     pub silent: Option<bool>,
 }
@@ -138,21 +142,21 @@ impl<'a> AccountCreditCreate<'a> {
         }
     }
     /// # code_1: first code from a discount card
-    pub fn code_1(&mut self, code1: &'a str) -> &mut Self {
-        self.params.code1 = Some(code1);
+    pub fn code_1(&mut self, code1: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.code1 = Some(code1.into());
         self
     }
     /// # code_2: second code from a discount card
-    pub fn code_2(&mut self, code2: &'a str) -> &mut Self {
-        self.params.code2 = Some(code2);
+    pub fn code_2(&mut self, code2: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.code2 = Some(code2.into());
         self
     }
 
     pub fn build(&self) -> AccountCreditCreate<'a> {
         AccountCreditCreate {
             params: AccountCreditCreateParams {
-                code1: self.params.code1,
-                code2: self.params.code2,
+                code1: self.params.code1.clone(),
+                code2: self.params.code2.clone(),
             },
         }
     }
@@ -165,9 +169,9 @@ impl<'a> AccountCreditCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AccountCreditCreateParams<'a> {
     /// first code from a discount card
-    pub code1: Option<&'a str>,
+    pub code1: Option<Cow<'a, str>>,
     /// second code from a discount card
-    pub code2: Option<&'a str>,
+    pub code2: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Credit, (), AccountCreditCreateParams<'a>> for AccountCreditCreate<'a> {
@@ -215,9 +219,9 @@ pub struct PasswordReset<'a> {
 
 #[cfg(feature = "builder")]
 impl<'a> PasswordReset<'a> {
-    pub fn new(email: &'a str) -> PasswordReset {
+    pub fn new(email: impl Into<Cow<'a, str>>) -> PasswordReset<'a> {
         PasswordReset {
-            params: PasswordResetParams { email },
+            params: PasswordResetParams { email: email.into() },
         }
     }
 }
@@ -228,7 +232,7 @@ impl<'a> PasswordReset<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct PasswordResetParams<'a> {
     /// unique email address
-    pub email: &'a str, // this isn't optional(inacurate Heroku docs)
+    pub email: Cow<'a, str>, // this isn't optional(inacurate Heroku docs)
 }
 
 impl<'a> HerokuEndpoint<PasswordResetResponse, (), PasswordResetParams<'a>> for PasswordReset<'a> {
@@ -274,16 +278,16 @@ impl<'a> HerokuEndpoint<PasswordResetResponse, (), PasswordResetParams<'a>> for
 /// [response]: ../struct.PasswordResetResponse.html
 pub struct PasswordResetConfirm<'a> {
     /// Password token
-    pub password_id: &'a str,
+    pub password_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: PasswordResetConfirmParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PasswordResetConfirm<'a> {
-    pub fn new(password_id: &'a str) -> PasswordResetConfirm<'a> {
+    pub fn new(password_id: impl Into<Cow<'a, str>>) -> PasswordResetConfirm<'a> {
         PasswordResetConfirm {
-            password_id,
+            password_id: password_id.into(),
             params: PasswordResetConfirmParams {
                 password: None,
                 password_confirmation: None,
@@ -292,22 +296,25 @@ impl<'a> PasswordResetConfirm<'a> {
     }
 
     /// # password: current password on the account
-    pub fn password(&mut self, password: &'a str) -> &mut Self {
-        self.params.password = Some(password);
+    pub fn password(&mut self, password: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.password = Some(password.into());
         self
     }
     /// # password_confirmation: confirmation of the new password
-    pub fn password_confirmation(&mut self, password_confirmation: &'a str) -> &mut Self {
-        self.params.password_confirmation = Some(password_confirmation);
+    pub fn password_confirmation(
+        &mut self,
+        password_confirmation: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        self.params.password_confirmation = Some(password_confirmation.into());
         self
     }
 
     pub fn build(&self) -> PasswordResetConfirm<'a> {
         PasswordResetConfirm {
-            password_id: self.password_id,
+            password_id: self.password_id.clone(),
             params: PasswordResetConfirmParams {
-                password: self.params.password,
-                password_confirmation: self.params.password_confirmation,
+                password: self.params.password.clone(),
+                password_confirmation: self.params.password_confirmation.clone(),
             },
         }
     }
@@ -320,9 +327,9 @@ impl<'a> PasswordResetConfirm<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct PasswordResetConfirmParams<'a> {
     /// current password on the account
-    pub password: Option<&'a str>,
+    pub password: Option<Cow<'a, str>>,
     /// confirmation of the new password
-    pub password_confirmation: Option<&'a str>,
+    pub password_confirmation: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<PasswordResetResponse, (), PasswordResetConfirmParams<'a>>
@@ -366,13 +373,13 @@ impl<'a> HerokuEndpoint<PasswordResetResponse, (), PasswordResetConfirmParams<'a
 /// [response]: ../struct.SmsNumber.html
 pub struct SmsNumberRecover<'a> {
     /// unique identifier, email or account id
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SmsNumberRecover<'a> {
-    pub fn new(account_id: &'a str) -> SmsNumberRecover<'a> {
-        SmsNumberRecover { account_id }
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> SmsNumberRecover<'a> {
+        SmsNumberRecover { account_id: account_id.into() }
     }
 }
 
@@ -412,13 +419,13 @@ impl<'a> HerokuEndpoint<SmsNumber> for SmsNumberRecover<'a> {
 /// [response]: ../struct.SmsNumber.html
 pub struct SmsNumberConfirm<'a> {
     /// unique identifier, email or account id
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SmsNumberConfirm<'a> {
-    pub fn new(account_id: &'a str) -> SmsNumberConfirm<'a> {
-        SmsNumberConfirm { account_id }
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> SmsNumberConfirm<'a> {
+        SmsNumberConfirm { account_id: account_id.into() }
     }
 }
 
@@ -2,6 +2,7 @@
 use super::InvoiceAddress;
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Invoice Address update
 ///
@@ -61,38 +62,38 @@ impl<'a> InvoiceAddressUpdate<'a> {
     }
 
     /// # address_1: invoice street address line 1
-    pub fn address_1(&mut self, address_1: &'a str) -> &mut Self {
-        self.params.address_1 = Some(address_1);
+    pub fn address_1(&mut self, address_1: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.address_1 = Some(address_1.into());
         self
     }
     /// # address_2: invoice street address line 2
-    pub fn address_2(&mut self, address_2: &'a str) -> &mut Self {
-        self.params.address_2 = Some(address_2);
+    pub fn address_2(&mut self, address_2: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.address_2 = Some(address_2.into());
         self
     }
     /// # city: invoice city
-    pub fn city(&mut self, city: &'a str) -> &mut Self {
-        self.params.city = Some(city);
+    pub fn city(&mut self, city: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.city = Some(city.into());
         self
     }
     /// # country: country
-    pub fn country(&mut self, country: &'a str) -> &mut Self {
-        self.params.country = Some(country);
+    pub fn country(&mut self, country: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.country = Some(country.into());
         self
     }
     /// # other: metadata / additional information to go on invoice
-    pub fn other(&mut self, other: &'a str) -> &mut Self {
-        self.params.country = Some(other);
+    pub fn other(&mut self, other: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.country = Some(other.into());
         self
     }
     /// # postal_code: invoice zip code
-    pub fn postal_code(&mut self, postal_code: &'a str) -> &mut Self {
-        self.params.postal_code = Some(postal_code);
+    pub fn postal_code(&mut self, postal_code: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.postal_code = Some(postal_code.into());
         self
     }
     /// # state: invoice state
-    pub fn state(&mut self, state: &'a str) -> &mut Self {
-        self.params.state = Some(state);
+    pub fn state(&mut self, state: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.state = Some(state.into());
         self
     }
     /// # use_invoice_address: flag to use the invoice address for an account or not
@@ -104,13 +105,13 @@ impl<'a> InvoiceAddressUpdate<'a> {
     pub fn build(&self) -> InvoiceAddressUpdate<'a> {
         InvoiceAddressUpdate {
             params: InvoiceAddressUpdateParams {
-                address_1: self.params.address_1,
-                address_2: self.params.address_2,
-                city: self.params.city,
-                country: self.params.country,
-                other: self.params.other,
-                postal_code: self.params.postal_code,
-                state: self.params.state,
+                address_1: self.params.address_1.clone(),
+                address_2: self.params.address_2.clone(),
+                city: self.params.city.clone(),
+                country: self.params.country.clone(),
+                other: self.params.other.clone(),
+                postal_code: self.params.postal_code.clone(),
+                state: self.params.state.clone(),
                 use_invoice_address: self.params.use_invoice_address,
             },
         }
@@ -124,19 +125,19 @@ impl<'a> InvoiceAddressUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct InvoiceAddressUpdateParams<'a> {
     /// invoice street address line 1
-    pub address_1: Option<&'a str>,
+    pub address_1: Option<Cow<'a, str>>,
     /// invoice street address line 2
-    pub address_2: Option<&'a str>,
+    pub address_2: Option<Cow<'a, str>>,
     /// invoice city
-    pub city: Option<&'a str>,
+    pub city: Option<Cow<'a, str>>,
     /// country
-    pub country: Option<&'a str>,
+    pub country: Option<Cow<'a, str>>,
     /// metadata / additional information to go on invoice
-    pub other: Option<&'a str>,
+    pub other: Option<Cow<'a, str>>,
     /// invoice zip code
-    pub postal_code: Option<&'a str>,
+    pub postal_code: Option<Cow<'a, str>>,
     /// invoice state
-    pub state: Option<&'a str>,
+    pub state: Option<Cow<'a, str>>,
     /// flag to use the invoice address for an account or not
     pub use_invoice_address: Option<bool>,
 }
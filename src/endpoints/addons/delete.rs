@@ -1,7 +1,9 @@
 //Anything related to DELETE requests for Addons and it's variations goes here.
 use super::{Addon, AddonAttachment, AddonWebhook};
 
+use crate::endpoints::ids::{AddonRef, AppRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Add-on Delete
 ///
@@ -32,16 +34,19 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Addon.html
 pub struct AddonDelete<'a> {
     /// unique app identifier, either app name or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique add-on identifier, either add-on id or add-on name
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonDelete<'a> {
     /// Delete addon
-    pub fn new(app_id: &'a str, addon_id: &'a str) -> AddonDelete<'a> {
-        AddonDelete { app_id, addon_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        addon_id: impl Into<AddonRef<'a>>,
+    ) -> AddonDelete<'a> {
+        AddonDelete { app_id: app_id.into(), addon_id: addon_id.into() }
     }
 }
 
@@ -81,14 +86,14 @@ impl<'a> HerokuEndpoint<Addon> for AddonDelete<'a> {
 /// [response]: ../struct.AddonAttachment.html
 pub struct AttachmentDelete<'a> {
     /// unique addon attachment identifier
-    pub attachment_id: &'a str,
+    pub attachment_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AttachmentDelete<'a> {
     /// Delete addon
-    pub fn new(attachment_id: &'a str) -> AttachmentDelete<'a> {
-        AttachmentDelete { attachment_id }
+    pub fn new(attachment_id: impl Into<Cow<'a, str>>) -> AttachmentDelete<'a> {
+        AttachmentDelete { attachment_id: attachment_id.into() }
     }
 }
 
@@ -130,18 +135,21 @@ impl<'a> HerokuEndpoint<AddonAttachment> for AttachmentDelete<'a> {
 /// [response]: ../struct.AddonWebhook.html
 pub struct WebhookDelete<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// unique addon webhook identifier
-    pub webhook_id: &'a str,
+    pub webhook_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookDelete<'a> {
     /// Delete webhook addon
-    pub fn new(addon_id: &'a str, webhook_id: &'a str) -> WebhookDelete<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        webhook_id: impl Into<Cow<'a, str>>,
+    ) -> WebhookDelete<'a> {
         WebhookDelete {
-            addon_id,
-            webhook_id,
+            addon_id: addon_id.into(),
+            webhook_id: webhook_id.into(),
         }
     }
 }
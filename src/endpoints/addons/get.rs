@@ -1,10 +1,12 @@
 //Anything related to GET requests for Addons and it's variations goes here.
 use super::{
-    Addon, AddonAttachment, AddonConfig, AddonRegionCapability, AddonService, AddonWebhook,
-    AddonWebhookDelivery, AddonWebhookEvent,
+    Addon, AddonAttachment, AddonConfig, AddonPlan, AddonRegionCapability, AddonService,
+    AddonWebhook, AddonWebhookDelivery, AddonWebhookEvent,
 };
 
+use crate::endpoints::ids::{AddonRef, AppRef, TeamRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Add-on Info
 ///
@@ -33,13 +35,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.Addon.html
 pub struct AddonDetails<'a> {
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonDetails<'a> {
-    pub fn new(addon_id: &'a str) -> AddonDetails {
-        AddonDetails { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> AddonDetails<'a> {
+        AddonDetails { addon_id: addon_id.into() }
     }
 }
 
@@ -124,15 +126,18 @@ impl HerokuEndpoint<Vec<Addon>> for AddonList {
 /// [response]: ../struct.Addon.html
 pub struct AddonDetailsByApp<'a> {
     /// unique app identifier, either app name or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique add-on identifier, either add-on id or add-on name
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonDetailsByApp<'a> {
-    pub fn new(app_id: &'a str, addon_id: &'a str) -> AddonDetailsByApp<'a> {
-        AddonDetailsByApp { app_id, addon_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        addon_id: impl Into<AddonRef<'a>>,
+    ) -> AddonDetailsByApp<'a> {
+        AddonDetailsByApp { app_id: app_id.into(), addon_id: addon_id.into() }
     }
 }
 
@@ -173,13 +178,13 @@ impl<'a> HerokuEndpoint<Addon> for AddonDetailsByApp<'a> {
 /// [response]: ../struct.Addon.html
 pub struct AddonListByApp<'a> {
     /// unique app identifier, either app name or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonListByApp<'a> {
-    pub fn new(app_id: &'a str) -> AddonListByApp<'a> {
-        AddonListByApp { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AddonListByApp<'a> {
+        AddonListByApp { app_id: app_id.into() }
     }
 }
 
@@ -220,13 +225,13 @@ impl<'a> HerokuEndpoint<Vec<Addon>> for AddonListByApp<'a> {
 /// [response]: ../struct.Addon.html
 pub struct AddonListByAccount<'a> {
     /// unique account identifier, either account email or account id
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonListByAccount<'a> {
-    pub fn new(account_id: &'a str) -> AddonListByAccount<'a> {
-        AddonListByAccount { account_id }
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> AddonListByAccount<'a> {
+        AddonListByAccount { account_id: account_id.into() }
     }
 }
 
@@ -267,13 +272,13 @@ impl<'a> HerokuEndpoint<Vec<Addon>> for AddonListByAccount<'a> {
 /// [response]: ../struct.Addon.html
 pub struct AddonListByTeam<'a> {
     /// unique team identifier, either team name or team id
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonListByTeam<'a> {
-    pub fn new(team_id: &'a str) -> AddonListByTeam<'a> {
-        AddonListByTeam { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> AddonListByTeam<'a> {
+        AddonListByTeam { team_id: team_id.into() }
     }
 }
 
@@ -314,13 +319,13 @@ impl<'a> HerokuEndpoint<Vec<Addon>> for AddonListByTeam<'a> {
 /// [response]: ../struct.AddonAttachment.html
 pub struct AttachmentDetails<'a> {
     /// unique addoon attachment identifier
-    pub attachment_id: &'a str,
+    pub attachment_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AttachmentDetails<'a> {
-    pub fn new(attachment_id: &'a str) -> AttachmentDetails<'a> {
-        AttachmentDetails { attachment_id }
+    pub fn new(attachment_id: impl Into<Cow<'a, str>>) -> AttachmentDetails<'a> {
+        AttachmentDetails { attachment_id: attachment_id.into() }
     }
 }
 
@@ -404,13 +409,13 @@ impl HerokuEndpoint<Vec<AddonAttachment>> for AttachmentList {
 /// [response]: ../struct.AddonAttachment.html
 pub struct AttachmentListByAddon<'a> {
     /// unique add-on identifier.
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AttachmentListByAddon<'a> {
-    pub fn new(addon_id: &'a str) -> AttachmentListByAddon {
-        AttachmentListByAddon { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> AttachmentListByAddon<'a> {
+        AttachmentListByAddon { addon_id: addon_id.into() }
     }
 }
 
@@ -451,13 +456,13 @@ impl<'a> HerokuEndpoint<Vec<AddonAttachment>> for AttachmentListByAddon<'a> {
 /// [response]: ../struct.AddonAttachment.html
 pub struct AttachmentListByApp<'a> {
     /// unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AttachmentListByApp<'a> {
-    pub fn new(app_id: &'a str) -> AttachmentListByApp {
-        AttachmentListByApp { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AttachmentListByApp<'a> {
+        AttachmentListByApp { app_id: app_id.into() }
     }
 }
 
@@ -499,17 +504,20 @@ impl<'a> HerokuEndpoint<Vec<AddonAttachment>> for AttachmentListByApp<'a> {
 /// [response]: ../struct.AddonAttachment.html
 pub struct AttachmentDetailsByApp<'a> {
     /// unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique attachment identifier,
-    pub attachment_id: &'a str,
+    pub attachment_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AttachmentDetailsByApp<'a> {
-    pub fn new(app_id: &'a str, attachment_id: &'a str) -> AttachmentDetailsByApp<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        attachment_id: impl Into<Cow<'a, str>>,
+    ) -> AttachmentDetailsByApp<'a> {
         AttachmentDetailsByApp {
-            app_id,
-            attachment_id,
+            app_id: app_id.into(),
+            attachment_id: attachment_id.into(),
         }
     }
 }
@@ -554,13 +562,13 @@ impl<'a> HerokuEndpoint<AddonAttachment> for AttachmentDetailsByApp<'a> {
 /// [response]: ../struct.AddonConfig.html
 pub struct AddonConfigList<'a> {
     /// unique addon identifier.
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonConfigList<'a> {
-    pub fn new(addon_id: &'a str) -> AddonConfigList<'a> {
-        AddonConfigList { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> AddonConfigList<'a> {
+        AddonConfigList { addon_id: addon_id.into() }
     }
 }
 
@@ -644,13 +652,13 @@ impl HerokuEndpoint<Vec<AddonRegionCapability>> for RegionCapabilityList {
 /// [response]: ../struct.AddonRegionCapability.html
 pub struct RegionCapabilityListByService<'a> {
     /// unique service identifier, either name or id
-    pub service_id: &'a str,
+    pub service_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> RegionCapabilityListByService<'a> {
-    pub fn new(service_id: &'a str) -> RegionCapabilityListByService {
-        RegionCapabilityListByService { service_id }
+    pub fn new(service_id: impl Into<Cow<'a, str>>) -> RegionCapabilityListByService<'a> {
+        RegionCapabilityListByService { service_id: service_id.into() }
     }
 }
 
@@ -691,13 +699,13 @@ impl<'a> HerokuEndpoint<Vec<AddonRegionCapability>> for RegionCapabilityListBySe
 /// [response]: ../struct.AddonRegionCapability.html
 pub struct RegionCapabilityListByRegion<'a> {
     /// unique region identifier, either name or id
-    pub region_id: &'a str,
+    pub region_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> RegionCapabilityListByRegion<'a> {
-    pub fn new(region_id: &'a str) -> RegionCapabilityListByRegion {
-        RegionCapabilityListByRegion { region_id }
+    pub fn new(region_id: impl Into<Cow<'a, str>>) -> RegionCapabilityListByRegion<'a> {
+        RegionCapabilityListByRegion { region_id: region_id.into() }
     }
 }
 
@@ -738,13 +746,13 @@ impl<'a> HerokuEndpoint<Vec<AddonRegionCapability>> for RegionCapabilityListByRe
 /// [response]: ../struct.AddonService.html
 pub struct AddonServiceDetails<'a> {
     /// unique service identifier, either name or id
-    pub service_id: &'a str,
+    pub service_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonServiceDetails<'a> {
-    pub fn new(service_id: &'a str) -> AddonServiceDetails {
-        AddonServiceDetails { service_id }
+    pub fn new(service_id: impl Into<Cow<'a, str>>) -> AddonServiceDetails<'a> {
+        AddonServiceDetails { service_id: service_id.into() }
     }
 }
 
@@ -800,6 +808,152 @@ impl<'a> HerokuEndpoint<Vec<AddonService>> for AddonServiceList {
     }
 }
 
+/// Plan Info
+///
+/// Info for existing plan.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#plan-info)
+///
+/// # Example:
+///
+/// PlanDetails takes one required parameter, plan_id, and returns a [`AddonPlan`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create(&"API_KEY").unwrap();
+///
+/// let response = api_client.request(&PlanDetails::new("heroku-postgresql:standard-0"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.AddonPlan.html
+pub struct PlanDetails<'a> {
+    /// unique plan identifier, either name or id
+    pub plan_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PlanDetails<'a> {
+    pub fn new(plan_id: impl Into<Cow<'a, str>>) -> PlanDetails<'a> {
+        PlanDetails { plan_id: plan_id.into() }
+    }
+}
+
+impl<'a> HerokuEndpoint<AddonPlan> for PlanDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("plans/{}", self.plan_id)
+    }
+}
+
+/// Plan Info By Add-on
+///
+/// Info for existing plan by Add-on.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#plan-info-by-add-on)
+///
+/// # Example:
+///
+/// PlanDetailsByService takes two required parameters, service_id and plan_id, and returns a [`AddonPlan`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create(&"API_KEY").unwrap();
+///
+/// let response = api_client.request(&PlanDetailsByService::new("heroku-postgresql", "standard-0"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.AddonPlan.html
+pub struct PlanDetailsByService<'a> {
+    /// unique service identifier, either name or id
+    pub service_id: Cow<'a, str>,
+    /// unique plan identifier, either name or id
+    pub plan_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PlanDetailsByService<'a> {
+    pub fn new(
+        service_id: impl Into<Cow<'a, str>>,
+        plan_id: impl Into<Cow<'a, str>>,
+    ) -> PlanDetailsByService<'a> {
+        PlanDetailsByService {
+            service_id: service_id.into(),
+            plan_id: plan_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<AddonPlan> for PlanDetailsByService<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("addon-services/{}/plans/{}", self.service_id, self.plan_id)
+    }
+}
+
+/// Plan List By Add-on
+///
+/// List existing plans by Add-on.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#plan-list-by-add-on)
+///
+/// # Example:
+///
+/// PlanList takes one required parameter, service_id, and returns a list of [`AddonPlans`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create(&"API_KEY").unwrap();
+///
+/// let response = api_client.request(&PlanList::new("heroku-postgresql"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.AddonPlan.html
+pub struct PlanList<'a> {
+    /// unique service identifier, either name or id
+    pub service_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PlanList<'a> {
+    pub fn new(service_id: impl Into<Cow<'a, str>>) -> PlanList<'a> {
+        PlanList { service_id: service_id.into() }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<AddonPlan>> for PlanList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("addon-services/{}/plans", self.service_id)
+    }
+}
+
 /// Add-on Webhook List
 ///
 /// List all webhook subscriptions for a particular add-on. Can only be accessed by the add-on partner providing this add-on.
@@ -828,13 +982,13 @@ impl<'a> HerokuEndpoint<Vec<AddonService>> for AddonServiceList {
 /// [response]: ../struct.AddonWebhook.html
 pub struct WebhookList<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookList<'a> {
-    pub fn new(addon_id: &'a str) -> WebhookList {
-        WebhookList { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> WebhookList<'a> {
+        WebhookList { addon_id: addon_id.into() }
     }
 }
 
@@ -876,17 +1030,20 @@ impl<'a> HerokuEndpoint<Vec<AddonWebhook>> for WebhookList<'a> {
 /// [response]: ../struct.AddonWebhook.html
 pub struct WebhookDetails<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// unique webhook identifier
-    pub webhook_id: &'a str,
+    pub webhook_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookDetails<'a> {
-    pub fn new(addon_id: &'a str, webhook_id: &'a str) -> WebhookDetails<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        webhook_id: impl Into<Cow<'a, str>>,
+    ) -> WebhookDetails<'a> {
         WebhookDetails {
-            addon_id,
-            webhook_id,
+            addon_id: addon_id.into(),
+            webhook_id: webhook_id.into(),
         }
     }
 }
@@ -929,17 +1086,20 @@ impl<'a> HerokuEndpoint<AddonWebhook> for WebhookDetails<'a> {
 /// [response]: ../struct.AddonWebhookDelivery.html
 pub struct WebhookDeliveryDetails<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// unique webhook delivery identifier
-    pub delivery_id: &'a str,
+    pub delivery_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookDeliveryDetails<'a> {
-    pub fn new(addon_id: &'a str, delivery_id: &'a str) -> WebhookDeliveryDetails<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        delivery_id: impl Into<Cow<'a, str>>,
+    ) -> WebhookDeliveryDetails<'a> {
         WebhookDeliveryDetails {
-            addon_id,
-            delivery_id,
+            addon_id: addon_id.into(),
+            delivery_id: delivery_id.into(),
         }
     }
 }
@@ -984,13 +1144,13 @@ impl<'a> HerokuEndpoint<AddonWebhookDelivery> for WebhookDeliveryDetails<'a> {
 /// [response]: ../struct.AddonWebhookDelivery.html
 pub struct WebhookDeliveryList<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookDeliveryList<'a> {
-    pub fn new(addon_id: &'a str) -> WebhookDeliveryList {
-        WebhookDeliveryList { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> WebhookDeliveryList<'a> {
+        WebhookDeliveryList { addon_id: addon_id.into() }
     }
 }
 
@@ -1031,13 +1191,13 @@ impl<'a> HerokuEndpoint<Vec<AddonWebhookDelivery>> for WebhookDeliveryList<'a> {
 /// [response]: ../struct.AddonWebhookEvent.html
 pub struct WebhookEventList<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookEventList<'a> {
-    pub fn new(addon_id: &'a str) -> WebhookEventList {
-        WebhookEventList { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> WebhookEventList<'a> {
+        WebhookEventList { addon_id: addon_id.into() }
     }
 }
 
@@ -1079,15 +1239,18 @@ impl<'a> HerokuEndpoint<Vec<AddonWebhookEvent>> for WebhookEventList<'a> {
 /// [response]: ../struct.AddonWebhookDelivery.html
 pub struct WebhookEventDetails<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// unique webhook event identifier
-    pub event_id: &'a str,
+    pub event_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookEventDetails<'a> {
-    pub fn new(addon_id: &'a str, event_id: &'a str) -> WebhookEventDetails<'a> {
-        WebhookEventDetails { addon_id, event_id }
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        event_id: impl Into<Cow<'a, str>>,
+    ) -> WebhookEventDetails<'a> {
+        WebhookEventDetails { addon_id: addon_id.into(), event_id: event_id.into() }
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::endpoints::ids::AddonRef;
 use crate::framework::response::ApiResult;
 
 pub mod delete;
@@ -11,7 +12,7 @@ pub use get::{
     AddonConfigList, AddonDetails, AddonDetailsByApp, AddonList, AddonListByAccount,
     AddonListByApp, AddonListByTeam, AddonServiceDetails, AddonServiceList, AttachmentDetails,
     AttachmentDetailsByApp, AttachmentList, AttachmentListByAddon, AttachmentListByApp,
-    RegionCapabilityList, RegionCapabilityListByRegion, RegionCapabilityListByService,
+    PlanDetails, PlanDetailsByService, PlanList, RegionCapabilityList, RegionCapabilityListByRegion, RegionCapabilityListByService,
     WebhookDeliveryDetails, WebhookDeliveryList, WebhookDetails, WebhookEventDetails,
     WebhookEventList, WebhookList,
 };
@@ -29,12 +30,22 @@ pub use post::{
 impl ApiResult for Addon {}
 impl ApiResult for Vec<Addon> {}
 
+/// Address an add-on by its id, e.g. a database on the data APIs.
+impl<'a> From<&'a Addon> for AddonRef<'a> {
+    fn from(addon: &'a Addon) -> Self {
+        AddonRef::from(&addon.id)
+    }
+}
+
 impl ApiResult for AddonAttachment {}
 impl ApiResult for Vec<AddonAttachment> {}
 
 impl ApiResult for AddonConfig {}
 impl ApiResult for Vec<AddonConfig> {}
 
+impl ApiResult for AddonPlan {}
+impl ApiResult for Vec<AddonPlan> {}
+
 impl ApiResult for AddonRegionCapability {}
 impl ApiResult for Vec<AddonRegionCapability> {}
 
@@ -53,6 +64,7 @@ impl ApiResult for Vec<AddonWebhookEvent> {}
 pub use addon::Addon;
 pub use addon_attachment::AddonAttachment;
 pub use addon_config::AddonConfig;
+pub use addon_plan::AddonPlan;
 pub use addon_region::AddonRegionCapability;
 pub use addon_services::AddonService;
 pub use addon_webhook::AddonWebhook;
@@ -62,6 +74,7 @@ pub use addon_webhook_event::AddonWebhookEvent;
 mod addon {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::AddonState;
 
     /// Add-on
     ///
@@ -97,7 +110,7 @@ mod addon {
         pub provider_id: String,
         /// state in the add-on’s lifecycle
         /// one of:"provisioning" or "provisioned" or "deprovisioned"
-        pub state: String,
+        pub state: AddonState,
         /// when add-on was updated
         pub updated_at: DateTime<Utc>,
         /// URL for logging into web interface of add-on (e.g. a dashboard)
@@ -229,9 +242,75 @@ mod addon_config {
     }
 }
 
+mod addon_plan {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+    use crate::endpoints::status::AddonPlanState;
+
+    /// Plan
+    ///
+    /// Stability: production
+    ///
+    /// Plans represent different configurations of add-ons that may be added to apps. Endpoints under add-on services can be accessed without authentication.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#plan)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct AddonPlan {
+        /// identity of add-on service
+        pub addon_service: AddonService,
+        /// when plan was created
+        pub created_at: DateTime<Utc>,
+        /// the compliance regimes applied to an add-on plan
+        /// one of:"HIPAA" or "PCI"
+        pub compliance: Option<Vec<String>>,
+        /// whether this plan is the default for its add-on service
+        pub default: bool,
+        /// description of plan
+        pub description: String,
+        /// human readable name of the add-on plan
+        pub human_name: String,
+        /// unique identifier of this plan
+        pub id: String,
+        /// whether this plan is installable to a Private Spaces app
+        pub installable_inside_private_space: bool,
+        /// whether this plan is installable to a Common Runtime app
+        pub installable_outside_private_space: bool,
+        /// unique name of this plan
+        pub name: String,
+        /// price
+        pub price: Price,
+        /// whether this plan is the default for apps in Private Spaces
+        pub space_default: bool,
+        /// release status for plan
+        /// one of:"public" or "private" or "shutdown"
+        pub state: AddonPlanState,
+        /// when plan was updated
+        pub updated_at: DateTime<Utc>,
+        /// whether this plan is publicly visible
+        pub visible: bool,
+    }
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct AddonService {
+        /// unique identifier of this add-on-service
+        pub id: String,
+        /// unique name of this add-on-service
+        pub name: String,
+    }
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Price {
+        /// price in cents per unit of plan
+        pub cents: i64,
+        /// price is negotiated in a contract outside of monthly add-on billing
+        pub contract: bool,
+        /// unit of price for plan, e.g. `month`
+        pub unit: String,
+    }
+}
+
 mod addon_region {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::AddonServiceState;
 
     /// Add-on Region Capability
     ///
@@ -265,7 +344,7 @@ mod addon_region {
         pub name: String,
         /// release status for add-on service
         /// one of:"alpha" or "beta" or "ga" or "shutdown"
-        pub state: String,
+        pub state: AddonServiceState,
         /// whether or not apps can have access to more than one instance of this add-on at the same time
         pub supports_multiple_installations: bool,
         /// whether or not apps can have access to add-ons billed to a different app
@@ -308,6 +387,7 @@ mod addon_region {
 mod addon_services {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::AddonServiceState;
 
     /// Add-on Service
     ///
@@ -330,7 +410,7 @@ mod addon_services {
         pub name: String,
         /// release status for add-on service
         ///  one of:"alpha" or "beta" or "ga" or "shutdown"
-        pub state: String,
+        pub state: AddonServiceState,
         /// whether or not apps can have access to more than one instance of this add-on at the same time
         pub supports_multiple_installations: bool,
         /// whether or not apps can have access to add-ons billed to a different app
@@ -382,6 +462,7 @@ mod addon_webhook {
 mod addon_webhook_delivery {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::WebhookDeliveryStatus;
 
     /// Add-on Webhook Delivery
     ///
@@ -406,7 +487,7 @@ mod addon_webhook_delivery {
         pub last_attempt: Option<LastAttempt>,
         /// the delivery’s status
         ///  one of:"pending" or "scheduled" or "retrying" or "failed" or "succeeded"
-        pub status: String,
+        pub status: WebhookDeliveryStatus,
         /// when the delivery was last updated
         pub updated_at: DateTime<Utc>,
         /// the webhook which we get the deliveries for
@@ -429,7 +510,7 @@ mod addon_webhook_delivery {
         pub error_class: Option<String>,
         /// status of an attempt
         ///  one of:"scheduled" or "succeeded" or "failed"
-        pub status: String,
+        pub status: WebhookDeliveryStatus,
         /// when attempt was created
         pub created_at: DateTime<Utc>,
         /// when attempt was updated
@@ -1,7 +1,9 @@
 //Anything related to PATCH requests for Addons and it's variations goes here.
 use super::{Addon, AddonConfig, AddonWebhook};
 
+use crate::endpoints::ids::{AddonRef, AppRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Add-on Update
 ///
@@ -34,21 +36,25 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Addon.html
 pub struct AddonUpdate<'a> {
     /// unique app identifier, either app name or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique add-on identifier, either add-on id or add-on name
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// parameters to pass to the Heroku API
     pub params: AddonUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonUpdate<'a> {
-    pub fn new(app_id: &'a str, addon_id: &'a str, plan: &'a str) -> AddonUpdate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        addon_id: impl Into<AddonRef<'a>>,
+        plan: impl Into<Cow<'a, str>>,
+    ) -> AddonUpdate<'a> {
         AddonUpdate {
-            app_id,
-            addon_id,
+            app_id: app_id.into(),
+            addon_id: addon_id.into(),
             params: AddonUpdateParams {
-                plan: plan,
+                plan: plan.into(),
                 name: None,
             },
         }
@@ -56,18 +62,18 @@ impl<'a> AddonUpdate<'a> {
     /// # name: globally unique name of the add-on
     ///
     /// `pattern:` ^[a-zA-Z][A-Za-z0-9_-]+$
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     pub fn build(&self) -> AddonUpdate<'a> {
         AddonUpdate {
-            app_id: self.app_id,
-            addon_id: self.addon_id,
+            app_id: self.app_id.clone(),
+            addon_id: self.addon_id.clone(),
             params: AddonUpdateParams {
-                plan: self.params.plan,
-                name: self.params.name,
+                plan: self.params.plan.clone(),
+                name: self.params.name.clone(),
             },
         }
     }
@@ -80,10 +86,10 @@ impl<'a> AddonUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AddonUpdateParams<'a> {
     /// unique identifier or name of this plan
-    pub plan: &'a str,
+    pub plan: Cow<'a, str>,
     /// globally unique name of the add-on
     ///  pattern: ^[a-zA-Z][A-Za-z0-9_-]+$
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Addon, (), AddonUpdateParams<'a>> for AddonUpdate<'a> {
@@ -128,32 +134,36 @@ impl<'a> HerokuEndpoint<Addon, (), AddonUpdateParams<'a>> for AddonUpdate<'a> {
 /// [response]: ../struct.AddonConfig.html
 pub struct AddonConfigUpdate<'a> {
     /// unique add-on identifier, either add-on id or add-on name
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// parameters to pass to the Heroku API
     pub params: AddonConfigUpdateParams,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonConfigUpdate<'a> {
-    pub fn new(addon_id: &'a str) -> AddonConfigUpdate<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> AddonConfigUpdate<'a> {
         AddonConfigUpdate {
-            addon_id,
+            addon_id: addon_id.into(),
             params: AddonConfigUpdateParams { config: None },
         }
     }
 
     /// # config: a addon config to update
-    pub fn config(&mut self, config_name: &'a str, config_value: &'a str) -> &mut Self {
+    pub fn config(
+        &mut self,
+        config_name: impl Into<String>,
+        config_value: impl Into<String>,
+    ) -> &mut Self {
         self.params.config = Some(vec![AddonConfig {
-            name: config_name.to_owned(),
-            value: config_value.to_owned(),
+            name: config_name.into(),
+            value: config_value.into(),
         }]);
         self
     }
 
     pub fn build(&self) -> AddonConfigUpdate<'a> {
         AddonConfigUpdate {
-            addon_id: self.addon_id,
+            addon_id: self.addon_id.clone(),
             params: AddonConfigUpdateParams {
                 config: self.params.config.clone(),
             },
@@ -218,9 +228,9 @@ impl<'a> HerokuEndpoint<Vec<AddonConfig>, (), AddonConfigUpdateParams> for Addon
 /// [response]: ../struct.AddonWebhook.html
 pub struct WebhookUpdate<'a> {
     /// unique add-on identifier, either add-on id or add-on name
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// unique webhook identifier
-    pub webhook_id: &'a str,
+    pub webhook_id: Cow<'a, str>,
     /// parameters to pass to the Heroku API
     pub params: WebhookUpdateParams<'a>,
 }
@@ -228,10 +238,13 @@ pub struct WebhookUpdate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> WebhookUpdate<'a> {
     /// Update webhook with optional parameters
-    pub fn new(addon_id: &'a str, webhook_id: &'a str) -> WebhookUpdate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        webhook_id: impl Into<Cow<'a, str>>,
+    ) -> WebhookUpdate<'a> {
         WebhookUpdate {
-            addon_id,
-            webhook_id,
+            addon_id: addon_id.into(),
+            webhook_id: webhook_id.into(),
             params: WebhookUpdateParams {
                 authorization: None,
                 include: None,
@@ -243,46 +256,49 @@ impl<'a> WebhookUpdate<'a> {
     }
 
     /// # authorization: a custom Authorization header that Heroku will include with all webhook notifications
-    pub fn authorization(&mut self, authorization: &'a str) -> &mut Self {
-        self.params.authorization = Some(authorization);
+    pub fn authorization(&mut self, authorization: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.authorization = Some(authorization.into());
         self
     }
     /// # include: the entities that the subscription provides notifications for
-    pub fn include(&mut self, include: Vec<&'a str>) -> &mut Self {
-        self.params.include = Some(include);
+    pub fn include(
+        &mut self,
+        include: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        self.params.include = Some(include.into_iter().map(Into::into).collect());
         self
     }
 
     /// # level: if notify, Heroku makes a single, fire-and-forget delivery attempt. If sync, Heroku attempts multiple deliveries until the request is successful or a limit is reached
     /// 
     /// `one of`: "notify" or "sync"
-    pub fn level(&mut self, level: &'a str) -> &mut Self {
-        self.params.level = Some(level);
+    pub fn level(&mut self, level: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.level = Some(level.into());
         self
     }
 
     /// # secret: a value that Heroku will use to sign all webhook notification requests (the signature is included in the request’s Heroku-Webhook-Hmac-SHA256 header)
-    pub fn secret(&mut self, secret: &'a str) -> &mut Self {
-        self.params.secret = Some(secret);
+    pub fn secret(&mut self, secret: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.secret = Some(secret.into());
         self
     }
 
     /// # url: the URL where the webhook’s notification requests are sent
-    pub fn url(&mut self, url: &'a str) -> &mut Self {
-        self.params.url = Some(url);
+    pub fn url(&mut self, url: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.url = Some(url.into());
         self
     }
 
     pub fn build(&self) -> WebhookUpdate<'a> {
         WebhookUpdate {
-            addon_id: self.addon_id,
-            webhook_id: self.webhook_id,
+            addon_id: self.addon_id.clone(),
+            webhook_id: self.webhook_id.clone(),
             params: WebhookUpdateParams {
-                authorization: self.params.authorization,
+                authorization: self.params.authorization.clone(),
                 include: self.params.include.clone(),
-                level: self.params.level,
-                secret: self.params.secret,
-                url: self.params.url,
+                level: self.params.level.clone(),
+                secret: self.params.secret.clone(),
+                url: self.params.url.clone(),
             },
         }
     }
@@ -294,19 +310,19 @@ impl<'a> WebhookUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct WebhookUpdateParams<'a> {
     /// a custom Authorization header that Heroku will include with all webhook notifications. [Nullable]
-    pub authorization: Option<&'a str>,
+    pub authorization: Option<Cow<'a, str>>,
     /// the entities that the subscription provides notifications for
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<&'a str>>,
+    pub include: Option<Vec<Cow<'a, str>>>,
     /// if notify, Heroku makes a single, fire-and-forget delivery attempt. If sync, Heroku attempts multiple deliveries until the request is successful or a limit is reached
     ///  one of:"notify" or "sync"
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub level: Option<&'a str>,
+    pub level: Option<Cow<'a, str>>,
     /// a value that Heroku will use to sign all webhook notification requests (the signature is included in the request’s Heroku-Webhook-Hmac-SHA256 header). [Nullable]
-    pub secret: Option<&'a str>,
+    pub secret: Option<Cow<'a, str>>,
     /// the URL where the webhook’s notification requests are sent
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<&'a str>,
+    pub url: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<AddonWebhook, (), WebhookUpdateParams<'a>> for WebhookUpdate<'a> {
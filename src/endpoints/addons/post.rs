@@ -1,6 +1,8 @@
 //Anything related to POST requests for Addons and it's variations goes here.
 use super::{Addon, AddonAttachment, AddonWebhook};
+use crate::endpoints::ids::{AddonRef, AppRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// Add-on Create
@@ -43,21 +45,21 @@ use std::collections::HashMap;
 /// [response]: ../struct.Addon.html
 pub struct AddonCreate<'a> {
     /// unique app identifier, either app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// parameters to pass to the Heroku API
-    params: AddonCreateParams<'a>,
+    pub params: AddonCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonCreate<'a> {
     /// Create a new addon without required parameters only
-    pub fn new(app_id: &'a str, plan: &'a str) -> AddonCreate<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>, plan: impl Into<Cow<'a, str>>) -> AddonCreate<'a> {
         AddonCreate {
-            app_id,
+            app_id: app_id.into(),
             params: AddonCreateParams {
                 attachment: None,
                 config: None,
-                plan: plan,
+                plan: plan.into(),
                 confirm: None,
                 name: None,
             },
@@ -65,42 +67,50 @@ impl<'a> AddonCreate<'a> {
     }
 
     /// # attachment_name: unique name for this add-on attachment to this app
-    pub fn attachment_name(&mut self, attachment_name: &'a str) -> &mut Self {
+    pub fn attachment_name(&mut self, attachment_name: impl Into<Cow<'a, str>>) -> &mut Self {
         self.params.attachment = Some(Attachment {
-            name: Some(attachment_name),
+            name: Some(attachment_name.into()),
         });
         self
     }
 
     /// # config: custom add-on provisioning options
-    pub fn config(&mut self, config: HashMap<&'a str, &'a str>) -> &mut Self {
-        self.params.config = Some(config);
+    pub fn config(
+        &mut self,
+        config: impl IntoIterator<Item = (impl Into<Cow<'a, str>>, impl Into<Cow<'a, str>>)>,
+    ) -> &mut Self {
+        self.params.config = Some(
+            config
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect(),
+        );
         self
     }
 
     /// # confirm: name of billing entity for confirmation
-    pub fn confirm(&mut self, confirm: &'a str) -> &mut Self {
-        self.params.confirm = Some(confirm);
+    pub fn confirm(&mut self, confirm: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.confirm = Some(confirm.into());
         self
     }
 
     /// # name: globally unique name of the add-on
     ///
     /// `pattern:`  pattern: ^[a-zA-Z][A-Za-z0-9_-]+$
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     pub fn build(&self) -> AddonCreate<'a> {
         AddonCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: AddonCreateParams {
                 attachment: self.params.attachment.clone(),
                 config: self.params.config.clone(),
-                plan: self.params.plan,
-                confirm: self.params.confirm,
-                name: self.params.name,
+                plan: self.params.plan.clone(),
+                confirm: self.params.confirm.clone(),
+                name: self.params.name.clone(),
             },
         }
     }
@@ -115,20 +125,20 @@ pub struct AddonCreateParams<'a> {
     /// unique name for this add-on attachment to this app
     pub attachment: Option<Attachment<'a>>,
     /// custom add-on provisioning options
-    pub config: Option<HashMap<&'a str, &'a str>>,
+    pub config: Option<HashMap<Cow<'a, str>, Cow<'a, str>>>,
     /// name of billing entity for confirmation
-    pub confirm: Option<&'a str>,
+    pub confirm: Option<Cow<'a, str>>,
     /// unique identifier or name of this plan
-    pub plan: &'a str,
+    pub plan: Cow<'a, str>,
     /// globally unique name of the add-on
     ///  pattern: ^[a-zA-Z][A-Za-z0-9_-]+$
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
 pub struct Attachment<'a> {
     /// unique name for this add-on attachment to this app
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Addon, (), AddonCreateParams<'a>> for AddonCreate<'a> {
@@ -182,34 +192,34 @@ pub struct AddonResolutionCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> AddonResolutionCreate<'a> {
     /// Create a new addon resolution without optional parameters
-    pub fn new(addon: &'a str) -> AddonResolutionCreate<'a> {
+    pub fn new(addon: impl Into<Cow<'a, str>>) -> AddonResolutionCreate<'a> {
         AddonResolutionCreate {
             params: AddonResolutionCreateParams {
-                addon: addon,
+                addon: addon.into(),
                 addon_service: None,
                 app: None,
             },
         }
     }
     /// # app: unique name of this add-on-service
-    pub fn addon_service(&mut self, addon_service: &'a str) -> &mut Self {
-        self.params.addon_service = Some(addon_service);
+    pub fn addon_service(&mut self, addon_service: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.addon_service = Some(addon_service.into());
         self
     }
     /// # app: unique name of app
     ///
     /// `pattern:` ^[a-z][a-z0-9-]{1,28}[a-z0-9]$ 	"example"
-    pub fn app(&mut self, app: &'a str) -> &mut Self {
-        self.params.app = Some(app);
+    pub fn app(&mut self, app: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.app = Some(app.into());
         self
     }
 
     pub fn build(&self) -> AddonResolutionCreate<'a> {
         AddonResolutionCreate {
             params: AddonResolutionCreateParams {
-                addon: self.params.addon,
-                addon_service: self.params.addon_service,
-                app: self.params.app,
+                addon: self.params.addon.clone(),
+                addon_service: self.params.addon_service.clone(),
+                app: self.params.app.clone(),
             },
         }
     }
@@ -223,12 +233,12 @@ impl<'a> AddonResolutionCreate<'a> {
 pub struct AddonResolutionCreateParams<'a> {
     /// globally unique name of the add-on
     ///  pattern: ^[a-zA-Z][A-Za-z0-9_-]+$
-    pub addon: &'a str,
+    pub addon: Cow<'a, str>,
     /// unique name of this add-on-service
-    pub addon_service: Option<&'a str>,
+    pub addon_service: Option<Cow<'a, str>>,
     /// unique name of app
     ///  pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub app: Option<&'a str>,
+    pub app: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Vec<Addon>, (), AddonResolutionCreateParams<'a>>
@@ -274,13 +284,13 @@ impl<'a> HerokuEndpoint<Vec<Addon>, (), AddonResolutionCreateParams<'a>>
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.Addon.html
 pub struct AddonActionProvision<'a> {
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonActionProvision<'a> {
-    pub fn new(addon_id: &'a str) -> AddonActionProvision<'a> {
-        AddonActionProvision { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> AddonActionProvision<'a> {
+        AddonActionProvision { addon_id: addon_id.into() }
     }
 }
 
@@ -321,13 +331,13 @@ impl<'a> HerokuEndpoint<Addon> for AddonActionProvision<'a> {
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.Addon.html
 pub struct AddonActionDeprovision<'a> {
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AddonActionDeprovision<'a> {
-    pub fn new(addon_id: &'a str) -> AddonActionDeprovision<'a> {
-        AddonActionDeprovision { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> AddonActionDeprovision<'a> {
+        AddonActionDeprovision { addon_id: addon_id.into() }
     }
 }
 
@@ -380,11 +390,14 @@ pub struct AttachmentCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> AttachmentCreate<'a> {
     /// Create a new addon resolution without optional parameters
-    pub fn new(addon: &'a str, app: &'a str) -> AttachmentCreate<'a> {
+    pub fn new(
+        addon: impl Into<Cow<'a, str>>,
+        app: impl Into<Cow<'a, str>>,
+    ) -> AttachmentCreate<'a> {
         AttachmentCreate {
             params: AttachmentCreateParams {
-                addon: addon,
-                app: app,
+                addon: addon.into(),
+                app: app.into(),
                 confirm: None,
                 name: None,
                 namespace: None,
@@ -393,29 +406,29 @@ impl<'a> AttachmentCreate<'a> {
     }
 
     /// # confirm: name of owning app for confirmation
-    pub fn confirm(&mut self, confirm: &'a str) -> &mut Self {
-        self.params.confirm = Some(confirm);
+    pub fn confirm(&mut self, confirm: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.confirm = Some(confirm.into());
         self
     }
     /// # name: unique name for this add-on attachment to this app
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
     /// # namespace: attachment namespace
-    pub fn namespace(&mut self, namespace: &'a str) -> &mut Self {
-        self.params.namespace = Some(namespace);
+    pub fn namespace(&mut self, namespace: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.namespace = Some(namespace.into());
         self
     }
 
     pub fn build(&self) -> AttachmentCreate<'a> {
         AttachmentCreate {
             params: AttachmentCreateParams {
-                addon: self.params.addon,
-                app: self.params.app,
-                confirm: self.params.confirm,
-                name: self.params.name,
-                namespace: self.params.namespace,
+                addon: self.params.addon.clone(),
+                app: self.params.app.clone(),
+                confirm: self.params.confirm.clone(),
+                name: self.params.name.clone(),
+                namespace: self.params.namespace.clone(),
             },
         }
     }
@@ -428,18 +441,18 @@ impl<'a> AttachmentCreate<'a> {
 pub struct AttachmentCreateParams<'a> {
     /// globally unique name of the add-on
     ///  pattern: ^[a-zA-Z][A-Za-z0-9_-]+$
-    pub addon: &'a str,
+    pub addon: Cow<'a, str>,
     /// unique name of app
     ///  pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub app: &'a str,
+    pub app: Cow<'a, str>,
     /// name of owning app for confirmation
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub confirm: Option<&'a str>,
+    pub confirm: Option<Cow<'a, str>>,
     /// unique name for this add-on attachment to this app
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
     /// attachment namespace. [Nullable]
-    pub namespace: Option<&'a str>,
+    pub namespace: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<AddonAttachment, (), AttachmentCreateParams<'a>> for AttachmentCreate<'a> {
@@ -493,10 +506,10 @@ pub struct AttachmentResolutionCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> AttachmentResolutionCreate<'a> {
     /// Create a new addon resolution without optional parameters
-    pub fn new(addon_attachment: &'a str) -> AttachmentResolutionCreate<'a> {
+    pub fn new(addon_attachment: impl Into<Cow<'a, str>>) -> AttachmentResolutionCreate<'a> {
         AttachmentResolutionCreate {
             params: AttachmentResolutionCreateParams {
-                addon_attachment: addon_attachment,
+                addon_attachment: addon_attachment.into(),
                 addon_service: None,
                 app: None,
             },
@@ -505,23 +518,23 @@ impl<'a> AttachmentResolutionCreate<'a> {
     /// # confirm: name of app
     /// 
     /// `pattern`:  pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$ 
-    pub fn app(&mut self, app: &'a str) -> &mut Self {
-        self.params.app = Some(app);
+    pub fn app(&mut self, app: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.app = Some(app.into());
         self
     }
 
     /// # addon_service: unique name of this add-on-service
-    pub fn addon_service(&mut self, addon_service: &'a str) -> &mut Self {
-        self.params.addon_service = Some(addon_service);
+    pub fn addon_service(&mut self, addon_service: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.addon_service = Some(addon_service.into());
         self
     }
 
     pub fn build(&self) -> AttachmentResolutionCreate<'a> {
         AttachmentResolutionCreate {
             params: AttachmentResolutionCreateParams {
-                addon_attachment: self.params.addon_attachment,
-                addon_service: self.params.addon_service,
-                app: self.params.app,
+                addon_attachment: self.params.addon_attachment.clone(),
+                addon_service: self.params.addon_service.clone(),
+                app: self.params.app.clone(),
             },
         }
     }
@@ -534,12 +547,12 @@ impl<'a> AttachmentResolutionCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AttachmentResolutionCreateParams<'a> {
     /// unique name for this add-on attachment to this app
-    pub addon_attachment: &'a str,
+    pub addon_attachment: Cow<'a, str>,
     /// unique name of this add-on-service
-    pub addon_service: Option<&'a str>,
+    pub addon_service: Option<Cow<'a, str>>,
     /// unique name of app
     ///  pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub app: Option<&'a str>,
+    pub app: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Vec<AddonAttachment>, (), AttachmentResolutionCreateParams<'a>>
@@ -592,7 +605,7 @@ impl<'a> HerokuEndpoint<Vec<AddonAttachment>, (), AttachmentResolutionCreatePara
 /// [response]: ../struct.AddonWebhook.html
 pub struct WebhookCreate<'a> {
     /// unique addon indentifier, either id or name
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// parameters to pass to the Heroku API
     pub params: WebhookCreateParams<'a>,
 }
@@ -601,44 +614,44 @@ pub struct WebhookCreate<'a> {
 impl<'a> WebhookCreate<'a> {
     /// Create a new addon webhook without optional parameters
     pub fn new(
-        addon_id: &'a str,
-        include: Vec<&'a str>,
-        level: &'a str,
-        url: &'a str,
+        addon_id: impl Into<AddonRef<'a>>,
+        include: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+        level: impl Into<Cow<'a, str>>,
+        url: impl Into<Cow<'a, str>>,
     ) -> WebhookCreate<'a> {
         WebhookCreate {
-            addon_id,
+            addon_id: addon_id.into(),
             params: WebhookCreateParams {
                 authorization: None,
-                include: include,
-                level: level,
+                include: include.into_iter().map(Into::into).collect(),
+                level: level.into(),
                 secret: None,
-                url: url,
+                url: url.into(),
             },
         }
     }
 
     /// # authorization: a custom Authorization header that Heroku will include with all webhook notifications
-    pub fn authorization(&mut self, authorization: &'a str) -> &mut Self {
-        self.params.authorization = Some(authorization);
+    pub fn authorization(&mut self, authorization: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.authorization = Some(authorization.into());
         self
     }
 
     /// # secret: a value that Heroku will use to sign all webhook notification requests (the signature is included in the request’s Heroku-Webhook-Hmac-SHA256 header)
-    pub fn secret(&mut self, secret: &'a str) -> &mut Self {
-        self.params.secret = Some(secret);
+    pub fn secret(&mut self, secret: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.secret = Some(secret.into());
         self
     }
 
     pub fn build(&self) -> WebhookCreate<'a> {
         WebhookCreate {
-            addon_id: self.addon_id,
+            addon_id: self.addon_id.clone(),
             params: WebhookCreateParams {
                 authorization: None,
                 include: self.params.include.clone(),
-                level: self.params.level,
-                secret: self.params.secret,
-                url: self.params.url,
+                level: self.params.level.clone(),
+                secret: self.params.secret.clone(),
+                url: self.params.url.clone(),
             },
         }
     }
@@ -650,16 +663,16 @@ impl<'a> WebhookCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct WebhookCreateParams<'a> {
     /// a custom Authorization header that Heroku will include with all webhook notifications. [Nullable]
-    pub authorization: Option<&'a str>,
+    pub authorization: Option<Cow<'a, str>>,
     /// the entities that the subscription provides notifications for
-    pub include: Vec<&'a str>,
+    pub include: Vec<Cow<'a, str>>,
     /// if notify, Heroku makes a single, fire-and-forget delivery attempt. If sync, Heroku attempts multiple deliveries until the request is successful or a limit is reached
     ///  one of:"notify" or "sync"
-    pub level: &'a str,
+    pub level: Cow<'a, str>,
     /// a value that Heroku will use to sign all webhook notification requests (the signature is included in the request’s Heroku-Webhook-Hmac-SHA256 header). [Nullable]
-    pub secret: Option<&'a str>,
+    pub secret: Option<Cow<'a, str>>,
     /// the URL where the webhook’s notification requests are sent
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<AddonWebhook, (), WebhookCreateParams<'a>> for WebhookCreate<'a> {
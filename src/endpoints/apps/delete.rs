@@ -1,7 +1,9 @@
 //Anything related to deleting apps and it's properties goes here.
 use super::{App, AppWebhook, SNI, SSL};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// App Delete
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.App.html
 pub struct AppDelete<'a> {
     /// app_id can be the app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppDelete<'a> {
-    pub fn new(app_id: &'a str) -> AppDelete {
-        AppDelete { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppDelete<'a> {
+        AppDelete { app_id: app_id.into() }
     }
 }
 
@@ -76,13 +78,13 @@ impl<'a> HerokuEndpoint<App> for AppDelete<'a> {
 /// [response]: ../struct.App.html
 pub struct AppDisableAcm<'a> {
     /// app_id can be the app id or name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppDisableAcm<'a> {
-    pub fn new(app_id: &'a str) -> AppDisableAcm {
-        AppDisableAcm { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppDisableAcm<'a> {
+        AppDisableAcm { app_id: app_id.into() }
     }
 }
 
@@ -122,14 +124,17 @@ impl<'a> HerokuEndpoint<App> for AppDisableAcm<'a> {
 /// [response]: ../struct.AppWebhook.html
 pub struct AppWebhookDelete<'a> {
     /// app_id can be the app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// webhook_id is the webhook id.
-    pub webhook_id: &'a str,
+    pub webhook_id: Cow<'a, str>,
 }
 #[cfg(feature = "builder")]
 impl<'a> AppWebhookDelete<'a> {
-    pub fn new(app_id: &'a str, webhook_id: &'a str) -> AppWebhookDelete<'a> {
-        AppWebhookDelete { app_id, webhook_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        webhook_id: impl Into<Cow<'a, str>>,
+    ) -> AppWebhookDelete<'a> {
+        AppWebhookDelete { app_id: app_id.into(), webhook_id: webhook_id.into() }
     }
 }
 
@@ -169,15 +174,15 @@ impl<'a> HerokuEndpoint<AppWebhook> for AppWebhookDelete<'a> {
 /// [response]: ../struct.SNI.html
 pub struct SNIDelete<'a> {
     /// app_id can be the app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// sni unique identifier or name
-    pub sni_id: &'a str,
+    pub sni_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SNIDelete<'a> {
-    pub fn new(app_id: &'a str, sni_id: &'a str) -> SNIDelete<'a> {
-        SNIDelete { app_id, sni_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>, sni_id: impl Into<Cow<'a, str>>) -> SNIDelete<'a> {
+        SNIDelete { app_id: app_id.into(), sni_id: sni_id.into() }
     }
 }
 
@@ -217,15 +222,15 @@ impl<'a> HerokuEndpoint<SNI> for SNIDelete<'a> {
 /// [response]: ../struct.SSL.html
 pub struct SSLDelete<'a> {
     /// app_id can be the app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// ssl unique identifier or name
-    pub ssl_id: &'a str,
+    pub ssl_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SSLDelete<'a> {
-    pub fn new(app_id: &'a str, ssl_id: &'a str) -> SSLDelete<'a> {
-        SSLDelete { app_id, ssl_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>, ssl_id: impl Into<Cow<'a, str>>) -> SSLDelete<'a> {
+        SSLDelete { app_id: app_id.into(), ssl_id: ssl_id.into() }
     }
 }
 
@@ -1,7 +1,9 @@
 //Anything related to getting apps and it's properties goes here.
 use super::{App, AppFeature, AppSetup, AppWebhook, AppWebhookDelivery, WebhookEvent, SNI, SSL};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// App Info
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.App.html
 pub struct AppDetails<'a> {
     /// app_id can be the app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppDetails<'a> {
-    pub fn new(app_id: &'a str) -> AppDetails {
-        AppDetails { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppDetails<'a> {
+        AppDetails { app_id: app_id.into() }
     }
 }
 
@@ -119,13 +121,13 @@ impl HerokuEndpoint<Vec<App>> for AppList {
 /// [response]: ../struct.App.html
 pub struct AccountAppList<'a> {
     /// account_id can be the account email, id or self.
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AccountAppList<'a> {
-    pub fn new(account_id: &'a str) -> AccountAppList<'a> {
-        AccountAppList { account_id }
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> AccountAppList<'a> {
+        AccountAppList { account_id: account_id.into() }
     }
 }
 
@@ -165,15 +167,18 @@ impl<'a> HerokuEndpoint<Vec<App>> for AccountAppList<'a> {
 /// [response]: ../struct.AppFeature.html
 pub struct AppFeatureDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// feature_id can be the feature name or id.
-    pub feature_id: &'a str,
+    pub feature_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppFeatureDetails<'a> {
-    pub fn new(app_id: &'a str, feature_id: &'a str) -> AppFeatureDetails<'a> {
-        AppFeatureDetails { app_id, feature_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        feature_id: impl Into<Cow<'a, str>>,
+    ) -> AppFeatureDetails<'a> {
+        AppFeatureDetails { app_id: app_id.into(), feature_id: feature_id.into() }
     }
 }
 
@@ -213,13 +218,13 @@ impl<'a> HerokuEndpoint<AppFeature> for AppFeatureDetails<'a> {
 /// [response]: ../struct.AppFeature.html
 pub struct AppFeatureList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppFeatureList<'a> {
-    pub fn new(app_id: &'a str) -> AppFeatureList {
-        AppFeatureList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppFeatureList<'a> {
+        AppFeatureList { app_id: app_id.into() }
     }
 }
 
@@ -259,13 +264,13 @@ impl<'a> HerokuEndpoint<Vec<AppFeature>> for AppFeatureList<'a> {
 /// [response]: ../struct.AppWebhook.html
 pub struct AppWebhookList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppWebhookList<'a> {
-    pub fn new(app_id: &'a str) -> AppWebhookList<'a> {
-        AppWebhookList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppWebhookList<'a> {
+        AppWebhookList { app_id: app_id.into() }
     }
 }
 
@@ -305,15 +310,18 @@ impl<'a> HerokuEndpoint<Vec<AppWebhook>> for AppWebhookList<'a> {
 /// [response]: ../struct.AppWebhook.html
 pub struct AppWebhookDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// webhook_id is the webhook id.
-    pub webhook_id: &'a str,
+    pub webhook_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppWebhookDetails<'a> {
-    pub fn new(app_id: &'a str, webhook_id: &'a str) -> AppWebhookDetails<'a> {
-        AppWebhookDetails { app_id, webhook_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        webhook_id: impl Into<Cow<'a, str>>,
+    ) -> AppWebhookDetails<'a> {
+        AppWebhookDetails { app_id: app_id.into(), webhook_id: webhook_id.into() }
     }
 }
 
@@ -353,17 +361,20 @@ impl<'a> HerokuEndpoint<AppWebhook> for AppWebhookDetails<'a> {
 /// [response]: ../struct.AppWebhookDelivery.html
 pub struct AppWebhookDeliveryDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// webhook_delivery_id is the webhook delivery id.
-    pub webhook_delivery_id: &'a str,
+    pub webhook_delivery_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppWebhookDeliveryDetails<'a> {
-    pub fn new(app_id: &'a str, webhook_delivery_id: &'a str) -> AppWebhookDeliveryDetails<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        webhook_delivery_id: impl Into<Cow<'a, str>>,
+    ) -> AppWebhookDeliveryDetails<'a> {
         AppWebhookDeliveryDetails {
-            app_id,
-            webhook_delivery_id,
+            app_id: app_id.into(),
+            webhook_delivery_id: webhook_delivery_id.into(),
         }
     }
 }
@@ -407,13 +418,13 @@ impl<'a> HerokuEndpoint<AppWebhookDelivery> for AppWebhookDeliveryDetails<'a> {
 /// [response]: ../struct.AppWebhookDelivery.html
 pub struct AppWebhookDeliveryList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppWebhookDeliveryList<'a> {
-    pub fn new(app_id: &'a str) -> AppWebhookDeliveryList {
-        AppWebhookDeliveryList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppWebhookDeliveryList<'a> {
+        AppWebhookDeliveryList { app_id: app_id.into() }
     }
 }
 
@@ -453,13 +464,13 @@ impl<'a> HerokuEndpoint<Vec<AppWebhookDelivery>> for AppWebhookDeliveryList<'a>
 /// [response]: ../struct.AppSetup.html
 pub struct AppSetupDetails<'a> {
     /// setup_id is the unique setup identifier.
-    pub setup_id: &'a str,
+    pub setup_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppSetupDetails<'a> {
-    pub fn new(setup_id: &'a str) -> AppSetupDetails<'a> {
-        AppSetupDetails { setup_id }
+    pub fn new(setup_id: impl Into<Cow<'a, str>>) -> AppSetupDetails<'a> {
+        AppSetupDetails { setup_id: setup_id.into() }
     }
 }
 
@@ -499,15 +510,15 @@ impl<'a> HerokuEndpoint<AppSetup> for AppSetupDetails<'a> {
 /// [response]: ../struct.SNI.html
 pub struct SNIDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// sni unique identifier
-    pub sni_id: &'a str,
+    pub sni_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SNIDetails<'a> {
-    pub fn new(app_id: &'a str, sni_id: &'a str) -> SNIDetails<'a> {
-        SNIDetails { app_id, sni_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>, sni_id: impl Into<Cow<'a, str>>) -> SNIDetails<'a> {
+        SNIDetails { app_id: app_id.into(), sni_id: sni_id.into() }
     }
 }
 
@@ -547,13 +558,13 @@ impl<'a> HerokuEndpoint<SNI> for SNIDetails<'a> {
 /// [response]: ../struct.SNI.html
 pub struct SNIList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SNIList<'a> {
-    pub fn new(app_id: &'a str) -> SNIList<'a> {
-        SNIList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> SNIList<'a> {
+        SNIList { app_id: app_id.into() }
     }
 }
 
@@ -593,13 +604,13 @@ impl<'a> HerokuEndpoint<Vec<SNI>> for SNIList<'a> {
 /// [response]: ../struct.SSL.html
 pub struct SSLList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SSLList<'a> {
-    pub fn new(app_id: &'a str) -> SSLList<'a> {
-        SSLList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> SSLList<'a> {
+        SSLList { app_id: app_id.into() }
     }
 }
 
@@ -639,15 +650,15 @@ impl<'a> HerokuEndpoint<Vec<SSL>> for SSLList<'a> {
 /// [response]: ../struct.SSL.html
 pub struct SSLDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// ssl unique identifier
-    pub ssl_id: &'a str,
+    pub ssl_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SSLDetails<'a> {
-    pub fn new(app_id: &'a str, ssl_id: &'a str) -> SSLDetails<'a> {
-        SSLDetails { app_id, ssl_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>, ssl_id: impl Into<Cow<'a, str>>) -> SSLDetails<'a> {
+        SSLDetails { app_id: app_id.into(), ssl_id: ssl_id.into() }
     }
 }
 
@@ -687,15 +698,18 @@ impl<'a> HerokuEndpoint<SSL> for SSLDetails<'a> {
 /// [response]: ../struct.WebhookEvent.html
 pub struct WebhookEventDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// webhook event's unique identifier
-    pub event_id: &'a str,
+    pub event_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookEventDetails<'a> {
-    pub fn new(app_id: &'a str, event_id: &'a str) -> WebhookEventDetails<'a> {
-        WebhookEventDetails { app_id, event_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        event_id: impl Into<Cow<'a, str>>,
+    ) -> WebhookEventDetails<'a> {
+        WebhookEventDetails { app_id: app_id.into(), event_id: event_id.into() }
     }
 }
 
@@ -735,13 +749,13 @@ impl<'a> HerokuEndpoint<WebhookEvent> for WebhookEventDetails<'a> {
 /// [response]: ../struct.WebhookEvent.html
 pub struct WebhookEventList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> WebhookEventList<'a> {
-    pub fn new(app_id: &'a str) -> WebhookEventList<'a> {
-        WebhookEventList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> WebhookEventList<'a> {
+        WebhookEventList { app_id: app_id.into() }
     }
 }
 
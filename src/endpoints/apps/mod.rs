@@ -1,4 +1,7 @@
+use crate::endpoints::status::{FeatureState, WebhookDeliveryStatus};
 use crate::framework::response::ApiResult;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use serde::Deserialize;
 
 pub mod delete;
@@ -65,13 +68,13 @@ pub struct App {
     /// ACM status of this app
     pub acm: bool,
     /// when app was archived
-    pub archived_at: Option<String>,
+    pub archived_at: Option<DateTime<Utc>>,
     /// description from buildpack of app
     pub buildpack_provided_description: Option<String>,
     /// Stacks are the different application execution environments available in the Heroku platform.
     pub build_stack: BuildStack,
     /// when app was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// git repo URL of app
     pub git_url: String,
     /// unique identifier
@@ -91,7 +94,7 @@ pub struct App {
     /// A region represents a geographic location in which your application may run.
     pub region: Region,
     /// when app was released
-    pub released_at: Option<String>,
+    pub released_at: Option<DateTime<Utc>>,
     /// git repo size in bytes of app
     pub repo_size: Option<i64>,
     /// slug size in bytes of app
@@ -101,7 +104,7 @@ pub struct App {
     /// Stacks are the different application execution environments available in the Heroku platform.
     pub stack: Stack,
     /// when app was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// web URL of app
     pub web_url: String,
 }
@@ -184,7 +187,7 @@ pub struct Stack {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct AppFeature {
     /// when app feature was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// description of app feature
     pub description: String,
     /// documentation URL of app feature
@@ -196,9 +199,9 @@ pub struct AppFeature {
     /// unique name of app feature
     pub name: String,
     /// state of app feature
-    pub state: String,
+    pub state: FeatureState,
     /// when app feature was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// user readable feature name
     pub display_name: Option<String>,
     /// e-mail to send feedback about the feature
@@ -217,7 +220,7 @@ pub struct AppWebhook {
     /// the app that has the webhook
     pub app: WebhookApp,
     /// when app webhook was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// unique identifier of app webhook
     pub id: String,
     /// the entities that the subscription provides notifications for
@@ -227,7 +230,7 @@ pub struct AppWebhook {
     /// one of: "notify" or "sync"
     pub level: String,
     /// when app webhook was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// the URL where the webhook’s notification requests are sent
     pub url: String,
 }
@@ -251,7 +254,7 @@ pub struct WebhookApp {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct AppWebhookDelivery {
     /// when the delivery was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// the event’s struct containing
     pub event: WebhookDeliveryEvent,
     /// the delivery’s unique identifier
@@ -259,13 +262,13 @@ pub struct AppWebhookDelivery {
     /// number of times a delivery has been attempted
     pub num_attempts: i64,
     /// when delivery will be attempted again
-    pub next_attempt_at: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
     /// last attempt of a delivery
     pub last_attempt: Option<WebhookDeliveryLastAttempt>,
     /// the delivery’s status one of:"pending" or "scheduled" or "retrying" or "failed" or "succeeded"
-    pub status: String,
+    pub status: WebhookDeliveryStatus,
     /// when the delivery was last updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// the webhook which we get the deliveries for
     pub webhook: WebhookDeliveryWebhook,
 }
@@ -289,11 +292,11 @@ pub struct WebhookDeliveryLastAttempt {
     /// error class encountered during attempt
     pub error_class: Option<String>,
     /// status of an attempt. One of:"scheduled" or "succeeded" or "failed"
-    pub status: String,
+    pub status: WebhookDeliveryStatus,
     /// when attempt was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// when attempt was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// WebhookDeliveryWebhook
@@ -308,6 +311,7 @@ pub struct WebhookDeliveryWebhook {
 }
 
 mod app_setup {
+    use crate::endpoints::status::{AppSetupStatus, BuildStatus};
     use chrono::offset::Utc;
     use chrono::DateTime;
 
@@ -331,7 +335,7 @@ mod app_setup {
         pub updated_at: DateTime<Utc>,
         /// the overall status of app setup
         ///  one of:"failed" or "pending" or "succeeded"
-        pub status: String,
+        pub status: AppSetupStatus,
         /// reason that app setup has failed
         pub failure_message: Option<String>,
         /// app
@@ -361,7 +365,7 @@ mod app_setup {
         pub id: String,
         /// status of build
         ///  one of:"failed" or "pending" or "succeeded"
-        pub status: String,
+        pub status: BuildStatus,
         /// Build process output will be available from this URL as a stream. The stream is available as either text/plain or text/event-stream.
         /// Clients should be prepared to handle disconnects and can resume the stream by sending a Range header (for text/plain) or a Last-Event-Id header (for text/event-stream).
         pub output_stream_url: String,
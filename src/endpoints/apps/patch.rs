@@ -1,7 +1,9 @@
 //Anything related to patching(updating) apps and it's properties goes here.
 use super::{App, AppFeature, AppWebhook, SNI, SSL};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// App Update
 ///
@@ -35,7 +37,7 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.App.html
 pub struct AppUpdate<'a> {
     /// app_id can be either app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// params are the parameters sent to the API to patch the App.
     pub params: AppUpdateParams<'a>,
 }
@@ -43,9 +45,9 @@ pub struct AppUpdate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> AppUpdate<'a> {
     /// Update a Heroku app without optional parameters
-    pub fn new(app_id: &'a str) -> AppUpdate {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppUpdate<'a> {
         AppUpdate {
-            app_id: app_id,
+            app_id: app_id.into(),
             params: AppUpdateParams {
                 build_stack: None,
                 maintenance: None,
@@ -54,8 +56,8 @@ impl<'a> AppUpdate<'a> {
         }
     }
     /// # build_stack: unique name or identifier of stack
-    pub fn build_stack(&mut self, build_stack: &'a str) -> &mut Self {
-        self.params.build_stack = Some(build_stack);
+    pub fn build_stack(&mut self, build_stack: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.build_stack = Some(build_stack.into());
         self
     }
 
@@ -68,18 +70,18 @@ impl<'a> AppUpdate<'a> {
     /// # name: name of app
     ///
     /// `pattern`: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     pub fn build(&self) -> AppUpdate<'a> {
         AppUpdate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: AppUpdateParams {
-                build_stack: self.params.build_stack,
+                build_stack: self.params.build_stack.clone(),
                 maintenance: self.params.maintenance,
-                name: self.params.name,
+                name: self.params.name.clone(),
             },
         }
     }
@@ -92,11 +94,11 @@ impl<'a> AppUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AppUpdateParams<'a> {
     /// unique name or identifier of stack
-    pub build_stack: Option<&'a str>,
+    pub build_stack: Option<Cow<'a, str>>,
     /// maintenance status of app
     pub maintenance: Option<bool>,
     /// name of app. pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<App, (), AppUpdateParams<'a>> for AppUpdate<'a> {
@@ -138,13 +140,13 @@ impl<'a> HerokuEndpoint<App, (), AppUpdateParams<'a>> for AppUpdate<'a> {
 /// [response]: ../struct.App.html
 pub struct AppRefreshAcm<'a> {
     /// app_id can be either app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppRefreshAcm<'a> {
-    pub fn new(app_id: &'a str) -> AppRefreshAcm<'a> {
-        AppRefreshAcm { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppRefreshAcm<'a> {
+        AppRefreshAcm { app_id: app_id.into() }
     }
 }
 
@@ -185,19 +187,23 @@ impl<'a> HerokuEndpoint<App> for AppRefreshAcm<'a> {
 /// [response]: ../struct.AppFeature.html
 pub struct AppFeatureUpdate<'a> {
     /// app_id can be either app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// feature_id can be either feature id or feature name.
-    pub feature_id: &'a str,
+    pub feature_id: Cow<'a, str>,
     /// params are the parameters sent to the API to patch the feature.
     pub params: AppFeatureUpdateParams,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppFeatureUpdate<'a> {
-    pub fn new(app_id: &'a str, feature_id: &'a str, enabled: bool) -> AppFeatureUpdate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        feature_id: impl Into<Cow<'a, str>>,
+        enabled: bool,
+    ) -> AppFeatureUpdate<'a> {
         AppFeatureUpdate {
-            app_id,
-            feature_id,
+            app_id: app_id.into(),
+            feature_id: feature_id.into(),
             params: AppFeatureUpdateParams { enabled },
         }
     }
@@ -259,19 +265,22 @@ impl<'a> HerokuEndpoint<AppFeature, (), AppFeatureUpdateParams> for AppFeatureUp
 /// [response]: ../struct.AppWebhook.html
 pub struct AppWebhookUpdate<'a> {
     /// app_id can be the app id or app name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// webhook_id is the webhook id.
-    pub webhook_id: &'a str,
+    pub webhook_id: Cow<'a, str>,
     /// params are the parameters sent to the API to patch the webhook.
     pub params: AppWebhookUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppWebhookUpdate<'a> {
-    pub fn new(app_id: &'a str, webhook_id: &'a str) -> AppWebhookUpdate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        webhook_id: impl Into<Cow<'a, str>>,
+    ) -> AppWebhookUpdate<'a> {
         AppWebhookUpdate {
-            app_id,
-            webhook_id,
+            app_id: app_id.into(),
+            webhook_id: webhook_id.into(),
             params: AppWebhookUpdateParams {
                 authorization: None,
                 include: None,
@@ -283,47 +292,50 @@ impl<'a> AppWebhookUpdate<'a> {
     }
 
     /// # authorization: a custom Authorization header that Heroku will include with all webhook notifications
-    pub fn authorization(&mut self, authorization: &'a str) -> &mut Self {
-        self.params.authorization = Some(authorization);
+    pub fn authorization(&mut self, authorization: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.authorization = Some(authorization.into());
         self
     }
 
     /// # include: the entities that the subscription provides notifications for
-    pub fn include(&mut self, include: Vec<&'a str>) -> &mut Self {
-        self.params.include = Some(include);
+    pub fn include(
+        &mut self,
+        include: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        self.params.include = Some(include.into_iter().map(Into::into).collect());
         self
     }
 
     /// # level: if notify, Heroku makes a single, fire-and-forget delivery attempt. If sync, Heroku attempts multiple deliveries until the request is successful or a limit is reached
     /// 
     /// `one of`: "notify" or "sync"
-    pub fn level(&mut self, level: &'a str) -> &mut Self {
-        self.params.level = Some(level);
+    pub fn level(&mut self, level: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.level = Some(level.into());
         self
     }
 
     /// # secret: a value that Heroku will use to sign all webhook notification requests (the signature is included in the request’s Heroku-Webhook-Hmac-SHA256 header)
-    pub fn secret(&mut self, secret: &'a str) -> &mut Self {
-        self.params.secret = Some(secret);
+    pub fn secret(&mut self, secret: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.secret = Some(secret.into());
         self
     }
 
     /// # url: the URL where the webhook’s notification requests are sent
-    pub fn url(&mut self, url: &'a str) -> &mut Self {
-        self.params.url = Some(url);
+    pub fn url(&mut self, url: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.url = Some(url.into());
         self
     }
 
     pub fn build(&self) -> AppWebhookUpdate<'a> {
         AppWebhookUpdate {
-            app_id: self.app_id,
-            webhook_id: self.webhook_id,
+            app_id: self.app_id.clone(),
+            webhook_id: self.webhook_id.clone(),
             params: AppWebhookUpdateParams {
-                authorization: self.params.authorization,
+                authorization: self.params.authorization.clone(),
                 include: self.params.include.clone(),
-                level: self.params.level,
-                secret: self.params.secret,
-                url: self.params.url,
+                level: self.params.level.clone(),
+                secret: self.params.secret.clone(),
+                url: self.params.url.clone(),
             },
         }
     }
@@ -337,19 +349,19 @@ impl<'a> AppWebhookUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AppWebhookUpdateParams<'a> {
     /// A custom Authorization header that Heroku will include with all webhook notifications [Nullable]
-    pub authorization: Option<&'a str>,
+    pub authorization: Option<Cow<'a, str>>,
     /// The entities that the subscription provides notifications for
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<&'a str>>,
+    pub include: Option<Vec<Cow<'a, str>>>,
     /// One of: "notify" or "sync"
     /// If notify, Heroku makes a single, fire-and-forget delivery attempt. If sync, Heroku attempts multiple deliveries until the request is successful or a limit is reached
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub level: Option<&'a str>,
+    pub level: Option<Cow<'a, str>>,
     /// A value that Heroku will use to sign all webhook notification requests (the signature is included in the request’s Heroku-Webhook-Hmac-SHA256 header) [Nullable]
-    pub secret: Option<&'a str>,
+    pub secret: Option<Cow<'a, str>>,
     /// The URL where the webhook’s notification requests are sent
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<&'a str>,
+    pub url: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<AppWebhook, (), AppWebhookUpdateParams<'a>> for AppWebhookUpdate<'a> {
@@ -398,9 +410,9 @@ impl<'a> HerokuEndpoint<AppWebhook, (), AppWebhookUpdateParams<'a>> for AppWebho
 /// [response]: ../struct.SNI.html
 pub struct SNIUpdate<'a> {
     /// unique app identifier, either app id or app name
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique sni identifier
-    pub sni_id: &'a str,
+    pub sni_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: SNIUpdateParams<'a>,
 }
@@ -409,17 +421,17 @@ pub struct SNIUpdate<'a> {
 impl<'a> SNIUpdate<'a> {
     /// Update Heroku app's SNI with parameters
     pub fn new(
-        app_id: &'a str,
-        sni_id: &'a str,
-        certificate_chain: &'a str,
-        private_key: &'a str,
+        app_id: impl Into<AppRef<'a>>,
+        sni_id: impl Into<Cow<'a, str>>,
+        certificate_chain: impl Into<Cow<'a, str>>,
+        private_key: impl Into<Cow<'a, str>>,
     ) -> SNIUpdate<'a> {
         SNIUpdate {
-            app_id,
-            sni_id,
+            app_id: app_id.into(),
+            sni_id: sni_id.into(),
             params: SNIUpdateParams {
-                certificate_chain,
-                private_key,
+                certificate_chain: certificate_chain.into(),
+                private_key: private_key.into(),
             },
         }
     }
@@ -431,9 +443,9 @@ impl<'a> SNIUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct SNIUpdateParams<'a> {
     /// raw contents of the public certificate chain (eg: .crt or .pem file)
-    pub certificate_chain: &'a str,
+    pub certificate_chain: Cow<'a, str>,
     /// contents of the private key (eg .key file)
-    pub private_key: &'a str,
+    pub private_key: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<SNI, (), SNIUpdateParams<'a>> for SNIUpdate<'a> {
@@ -480,9 +492,9 @@ impl<'a> HerokuEndpoint<SNI, (), SNIUpdateParams<'a>> for SNIUpdate<'a> {
 /// [response]: ../struct.SSL.html
 pub struct SSLUpdate<'a> {
     /// unique app identifier, either app id or app name
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique ssl identifier
-    pub ssl_id: &'a str,
+    pub ssl_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: SSLUpdateParams<'a>,
 }
@@ -490,10 +502,10 @@ pub struct SSLUpdate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> SSLUpdate<'a> {
     /// Update Heroku app SSL with parameters
-    pub fn new(app_id: &'a str, ssl_id: &'a str) -> SSLUpdate<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>, ssl_id: impl Into<Cow<'a, str>>) -> SSLUpdate<'a> {
         SSLUpdate {
-            app_id,
-            ssl_id,
+            app_id: app_id.into(),
+            ssl_id: ssl_id.into(),
             params: SSLUpdateParams {
                 certificate_chain: None,
                 private_key: None,
@@ -503,14 +515,14 @@ impl<'a> SSLUpdate<'a> {
     }
 
     /// # certificate_chain: raw contents of the public certificate chain (eg: .crt or .pem file)
-    pub fn certificate_chain(&mut self, certificate_chain: &'a str) -> &mut Self {
-        self.params.certificate_chain = Some(certificate_chain);
+    pub fn certificate_chain(&mut self, certificate_chain: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.certificate_chain = Some(certificate_chain.into());
         self
     }
 
     /// # private_key: contents of the private key (eg .key file)
-    pub fn private_key(&mut self, private_key: &'a str) -> &mut Self {
-        self.params.private_key = Some(private_key);
+    pub fn private_key(&mut self, private_key: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.private_key = Some(private_key.into());
         self
     }
 
@@ -523,11 +535,11 @@ impl<'a> SSLUpdate<'a> {
     }
     pub fn build(&self) -> SSLUpdate<'a> {
         SSLUpdate {
-            app_id: self.app_id,
-            ssl_id: self.ssl_id,
+            app_id: self.app_id.clone(),
+            ssl_id: self.ssl_id.clone(),
             params: SSLUpdateParams {
-                certificate_chain: self.params.certificate_chain,
-                private_key: self.params.private_key,
+                certificate_chain: self.params.certificate_chain.clone(),
+                private_key: self.params.private_key.clone(),
                 preprocess: self.params.preprocess,
             },
         }
@@ -541,9 +553,9 @@ impl<'a> SSLUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct SSLUpdateParams<'a> {
     /// raw contents of the public certificate chain (eg: .crt or .pem file)
-    pub certificate_chain: Option<&'a str>,
+    pub certificate_chain: Option<Cow<'a, str>>,
     /// contents of the private key (eg .key file)
-    pub private_key: Option<&'a str>,
+    pub private_key: Option<Cow<'a, str>>,
     /// allow Heroku to modify an uploaded public certificate chain if deemed advantageous by adding missing intermediaries, stripping unnecessary ones, etc.
     ///  default: true
     pub preprocess: Option<bool>,
@@ -2,7 +2,9 @@
 use super::{App, AppSetup, AppWebhook, SNI, SSL};
 use std::collections::HashMap;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// App Create
 ///
@@ -55,29 +57,29 @@ impl<'a> AppCreate<'a> {
     /// # name: name of app
     ///
     /// `pattern`:  ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     /// # region: unique identifier or name of region
-    pub fn region(&mut self, region: &'a str) -> &mut Self {
-        self.params.region = Some(region);
+    pub fn region(&mut self, region: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.region = Some(region.into());
         self
     }
 
     /// # stack: unique name or identifier of stack
-    pub fn stack(&mut self, stack: &'a str) -> &mut Self {
-        self.params.stack = Some(stack);
+    pub fn stack(&mut self, stack: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.stack = Some(stack.into());
         self
     }
 
     pub fn build(&self) -> AppCreate<'a> {
         AppCreate {
             params: AppCreateParams {
-                name: self.params.name,
-                region: self.params.region,
-                stack: self.params.stack,
+                name: self.params.name.clone(),
+                region: self.params.region.clone(),
+                stack: self.params.stack.clone(),
             },
         }
     }
@@ -92,11 +94,11 @@ impl<'a> AppCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AppCreateParams<'a> {
     /// name of app. pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
     /// unique identifier or name of region
-    pub region: Option<&'a str>,
+    pub region: Option<Cow<'a, str>>,
     /// unique name or identifier of stack
-    pub stack: Option<&'a str>,
+    pub stack: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<App, (), AppCreateParams<'a>> for AppCreate<'a> {
@@ -138,13 +140,13 @@ impl<'a> HerokuEndpoint<App, (), AppCreateParams<'a>> for AppCreate<'a> {
 /// [response]: ../struct.App.html
 pub struct AppEnableAcm<'a> {
     /// app_id can be the app id or name.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppEnableAcm<'a> {
-    pub fn new(app_id: &'a str) -> AppEnableAcm<'a> {
-        AppEnableAcm { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppEnableAcm<'a> {
+        AppEnableAcm { app_id: app_id.into() }
     }
 }
 
@@ -191,7 +193,7 @@ impl<'a> HerokuEndpoint<App> for AppEnableAcm<'a> {
 /// [response]: ../struct.AppWebhook.html
 pub struct AppWebhookCreate<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: AppWebhookCreateParams<'a>,
 }
@@ -200,44 +202,44 @@ pub struct AppWebhookCreate<'a> {
 impl<'a> AppWebhookCreate<'a> {
     /// Create a new webhook without optional parameters
     pub fn new(
-        app_id: &'a str,
-        include: Vec<&'a str>,
-        level: &'a str,
-        url: &'a str,
+        app_id: impl Into<AppRef<'a>>,
+        include: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+        level: impl Into<Cow<'a, str>>,
+        url: impl Into<Cow<'a, str>>,
     ) -> AppWebhookCreate<'a> {
         AppWebhookCreate {
-            app_id: app_id,
+            app_id: app_id.into(),
             params: AppWebhookCreateParams {
                 authorization: None,
-                include: include,
-                level: level,
+                include: include.into_iter().map(Into::into).collect(),
+                level: level.into(),
                 secret: None,
-                url: url,
+                url: url.into(),
             },
         }
     }
 
     /// # authorization: a custom Authorization header that Heroku will include with all webhook notifications
-    pub fn authorization(&mut self, authorization: &'a str) -> &mut Self {
-        self.params.authorization = Some(authorization);
+    pub fn authorization(&mut self, authorization: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.authorization = Some(authorization.into());
         self
     }
 
     /// # secret: a value that Heroku will use to sign all webhook notification requests (the signature is included in the request’s Heroku-Webhook-Hmac-SHA256 header)
-    pub fn secret(&mut self, secret: &'a str) -> &mut Self {
-        self.params.secret = Some(secret);
+    pub fn secret(&mut self, secret: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.secret = Some(secret.into());
         self
     }
 
     pub fn build(&self) -> AppWebhookCreate<'a> {
         AppWebhookCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: AppWebhookCreateParams {
-                authorization: self.params.authorization,
+                authorization: self.params.authorization.clone(),
                 include: self.params.include.clone(),
-                level: self.params.level,
-                secret: self.params.secret,
-                url: self.params.url,
+                level: self.params.level.clone(),
+                secret: self.params.secret.clone(),
+                url: self.params.url.clone(),
             },
         }
     }
@@ -249,16 +251,16 @@ impl<'a> AppWebhookCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct AppWebhookCreateParams<'a> {
     /// A custom Authorization header that Heroku will include with all webhook notifications
-    pub authorization: Option<&'a str>,
+    pub authorization: Option<Cow<'a, str>>,
     /// The entities that the subscription provides notifications for
-    pub include: Vec<&'a str>,
+    pub include: Vec<Cow<'a, str>>,
     /// One of: "notify" or "sync"
     /// If notify, Heroku makes a single, fire-and-forget delivery attempt. If sync, Heroku attempts multiple deliveries until the request is successful or a limit is reached
-    pub level: &'a str,
+    pub level: Cow<'a, str>,
     /// A value that Heroku will use to sign all webhook notification requests (the signature is included in the request’s Heroku-Webhook-Hmac-SHA256 header)
-    pub secret: Option<&'a str>,
+    pub secret: Option<Cow<'a, str>>,
     /// The URL where the webhook’s notification requests are sent
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<AppWebhook, (), AppWebhookCreateParams<'a>> for AppWebhookCreate<'a> {
@@ -328,7 +330,7 @@ pub struct AppSetupCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> AppSetupCreate<'a> {
     /// Create a new setup app with required parameters only
-    pub fn new(url: &'a str) -> AppSetupCreate<'a> {
+    pub fn new(url: impl Into<Cow<'a, str>>) -> AppSetupCreate<'a> {
         AppSetupCreate {
             params: AppSetupCreateParams {
                 app: SetupApp {
@@ -342,7 +344,7 @@ impl<'a> AppSetupCreate<'a> {
                 },
                 source_blob: SourceBlob {
                     checksum: None,
-                    url: url,
+                    url: url.into(),
                     version: None,
                 },
                 overrides: Overrides {
@@ -354,13 +356,13 @@ impl<'a> AppSetupCreate<'a> {
     }
 
     /// # version: Version of the gzipped tarball.
-    pub fn version(&mut self, version: &'a str) -> &mut Self {
-        self.params.source_blob.version = Some(version);
+    pub fn version(&mut self, version: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.source_blob.version = Some(version.into());
         self
     }
     /// # checksum: an optional checksum of the gzipped tarball for verifying its integrity
-    pub fn checksum(&mut self, checksum: &'a str) -> &mut Self {
-        self.params.source_blob.checksum = Some(checksum);
+    pub fn checksum(&mut self, checksum: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.source_blob.checksum = Some(checksum.into());
         self
     }
 
@@ -372,13 +374,13 @@ impl<'a> AppSetupCreate<'a> {
     /// # name: name of app
     ///
     /// `pattern`:  pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.app.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.app.name = Some(name.into());
         self
     }
     /// # organization: unique name of team
-    pub fn organization(&mut self, organization: &'a str) -> &mut Self {
-        self.params.app.organization = Some(organization);
+    pub fn organization(&mut self, organization: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.app.organization = Some(organization.into());
         self
     }
     /// # personal: force creation of the app in the user account even if a default team is set.
@@ -387,35 +389,46 @@ impl<'a> AppSetupCreate<'a> {
         self
     }
     /// # region: name of region
-    pub fn region(&mut self, region: &'a str) -> &mut Self {
-        self.params.app.region = Some(region);
+    pub fn region(&mut self, region: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.app.region = Some(region.into());
         self
     }
     /// # space: unique name of space
     ///
     /// `pattern`:  pattern: `^[a-z0-9](?:[a-z0-9]
-    pub fn space(&mut self, space: &'a str) -> &mut Self {
-        self.params.app.space = Some(space);
+    pub fn space(&mut self, space: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.app.space = Some(space.into());
         self
     }
     /// # stack: unique name of stack
-    pub fn stack(&mut self, stack: &'a str) -> &mut Self {
-        self.params.app.stack = Some(stack);
+    pub fn stack(&mut self, stack: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.app.stack = Some(stack.into());
         self
     }
 
     /// # buildpacks: overrides the buildpacks specified in the app.json manifest file
-    pub fn buildpacks(&mut self, buildpacks_list: Vec<&'a str>) -> &mut Self {
+    pub fn buildpacks(
+        &mut self,
+        buildpacks_list: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
         let mut buildpacks: Vec<Buildpack> = Vec::new();
         for var in buildpacks_list {
-            buildpacks.push(Buildpack { url: var });
+            buildpacks.push(Buildpack { url: var.into() });
         }
         self.params.overrides.buildpacks = Some(buildpacks);
         self
     }
     /// # env: overrides of the env specified in the app.json manifest file
-    pub fn env(&mut self, env: HashMap<&'a str, &'a str>) -> &mut Self {
-        self.params.overrides.env = Some(env);
+    pub fn env(
+        &mut self,
+        env: impl IntoIterator<Item = (impl Into<Cow<'a, str>>, impl Into<Cow<'a, str>>)>,
+    ) -> &mut Self {
+        self.params.overrides.env = Some(
+            env
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect(),
+        );
         self
     }
     /// Create a new Heroku app with required  and optional parameters
@@ -450,28 +463,28 @@ pub struct SetupApp<'a> {
     pub locked: Option<bool>,
     /// name of app
     ///  pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
     /// unique name of team
-    pub organization: Option<&'a str>,
+    pub organization: Option<Cow<'a, str>>,
     /// force creation of the app in the user account even if a default team is set.
     pub personal: Option<bool>,
     /// name of region
-    pub region: Option<&'a str>,
+    pub region: Option<Cow<'a, str>>,
     /// unique name of space
     ///  pattern: `^[a-z0-9](?:[a-z0-9]
-    pub space: Option<&'a str>,
+    pub space: Option<Cow<'a, str>>,
     /// unique name
-    pub stack: Option<&'a str>,
+    pub stack: Option<Cow<'a, str>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
 pub struct SourceBlob<'a> {
     /// an optional checksum of the gzipped tarball for verifying its integrity. [Nullable]
-    pub checksum: Option<&'a str>,
+    pub checksum: Option<Cow<'a, str>>,
     /// URL of gzipped tarball of source code containing app.json manifest file.
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
     /// Version of the gzipped tarball. [Nullable]
-    pub version: Option<&'a str>,
+    pub version: Option<Cow<'a, str>>,
 }
 
 #[serde_with::skip_serializing_none]
@@ -480,13 +493,13 @@ pub struct Overrides<'a> {
     /// overrides the buildpacks specified in the app.json manifest file
     pub buildpacks: Option<Vec<Buildpack<'a>>>,
     /// overrides of the env specified in the app.json manifest file
-    pub env: Option<HashMap<&'a str, &'a str>>,
+    pub env: Option<HashMap<Cow<'a, str>, Cow<'a, str>>>,
 }
 
 #[serde_with::skip_serializing_none]
 #[derive(Serialize, Clone, Debug)]
 pub struct Buildpack<'a> {
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<AppSetup, (), AppSetupCreateParams<'a>> for AppSetupCreate<'a> {
@@ -534,7 +547,7 @@ impl<'a> HerokuEndpoint<AppSetup, (), AppSetupCreateParams<'a>> for AppSetupCrea
 /// [response]: ../struct.SNI.html
 pub struct SNICreate<'a> {
     /// unique app identifier, either app id or app name
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: SNICreateParams<'a>,
 }
@@ -542,12 +555,16 @@ pub struct SNICreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> SNICreate<'a> {
     /// Create a new Heroku app SNI with parameters
-    pub fn new(app_id: &'a str, certificate_chain: &'a str, private_key: &'a str) -> SNICreate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        certificate_chain: impl Into<Cow<'a, str>>,
+        private_key: impl Into<Cow<'a, str>>,
+    ) -> SNICreate<'a> {
         SNICreate {
-            app_id,
+            app_id: app_id.into(),
             params: SNICreateParams {
-                certificate_chain,
-                private_key,
+                certificate_chain: certificate_chain.into(),
+                private_key: private_key.into(),
             },
         }
     }
@@ -559,9 +576,9 @@ impl<'a> SNICreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct SNICreateParams<'a> {
     /// raw contents of the public certificate chain (eg: .crt or .pem file)
-    pub certificate_chain: &'a str,
+    pub certificate_chain: Cow<'a, str>,
     /// contents of the private key (eg .key file)
-    pub private_key: &'a str,
+    pub private_key: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<SNI, (), SNICreateParams<'a>> for SNICreate<'a> {
@@ -607,7 +624,7 @@ impl<'a> HerokuEndpoint<SNI, (), SNICreateParams<'a>> for SNICreate<'a> {
 /// [response]: ../struct.SSL.html
 pub struct SSLCreate<'a> {
     /// unique app identifier, either app id or app name
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: SSLCreateParams<'a>,
 }
@@ -615,12 +632,16 @@ pub struct SSLCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> SSLCreate<'a> {
     /// Update Heroku app SSL with parameters
-    pub fn new(app_id: &'a str, certificate_chain: &'a str, private_key: &'a str) -> SSLCreate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        certificate_chain: impl Into<Cow<'a, str>>,
+        private_key: impl Into<Cow<'a, str>>,
+    ) -> SSLCreate<'a> {
         SSLCreate {
-            app_id,
+            app_id: app_id.into(),
             params: SSLCreateParams {
-                certificate_chain: certificate_chain,
-                private_key: private_key,
+                certificate_chain: certificate_chain.into(),
+                private_key: private_key.into(),
                 preprocess: None,
             },
         }
@@ -635,10 +656,10 @@ impl<'a> SSLCreate<'a> {
     }
     pub fn build(&self) -> SSLCreate<'a> {
         SSLCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: SSLCreateParams {
-                certificate_chain: self.params.certificate_chain,
-                private_key: self.params.private_key,
+                certificate_chain: self.params.certificate_chain.clone(),
+                private_key: self.params.private_key.clone(),
                 preprocess: self.params.preprocess,
             },
         }
@@ -652,9 +673,9 @@ impl<'a> SSLCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct SSLCreateParams<'a> {
     /// raw contents of the public certificate chain (eg: .crt or .pem file)
-    pub certificate_chain: &'a str,
+    pub certificate_chain: Cow<'a, str>,
     /// contents of the private key (eg .key file)
-    pub private_key: &'a str,
+    pub private_key: Cow<'a, str>,
     /// allow Heroku to modify an uploaded public certificate chain if deemed advantageous by adding missing intermediaries, stripping unnecessary ones, etc.
     ///  default: true
     pub preprocess: Option<bool>,
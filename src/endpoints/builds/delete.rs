@@ -1,5 +1,6 @@
 //Anything related to DELETE requests for build and it's properties goes here.
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Build Delete cache
 ///
@@ -27,13 +28,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct BuildDelete<'a> {
     /// app_id can be the app id or name.
-    pub app_id: &'a str,
+    pub app_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> BuildDelete<'a> {
-    pub fn new(app_id: &'a str) -> BuildDelete<'a> {
-        BuildDelete { app_id }
+    pub fn new(app_id: impl Into<Cow<'a, str>>) -> BuildDelete<'a> {
+        BuildDelete { app_id: app_id.into() }
     }
 }
 
@@ -1,7 +1,9 @@
 //Anything related to GET requests for build and it's properties goes here.
 use super::{Build, BuildpackInstallation};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Build List
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Build.html
 pub struct BuildList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> BuildList<'a> {
-    pub fn new(app_id: &'a str) -> BuildList<'a> {
-        BuildList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> BuildList<'a> {
+        BuildList { app_id: app_id.into() }
     }
 }
 
@@ -76,15 +78,18 @@ impl<'a> HerokuEndpoint<Vec<Build>> for BuildList<'a> {
 /// [response]: ../struct.Build.html
 pub struct BuildDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// build_id is the build identifier which you want to get
-    pub build_id: &'a str,
+    pub build_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> BuildDetails<'a> {
-    pub fn new(app_id: &'a str, build_id: &'a str) -> BuildDetails<'a> {
-        BuildDetails { app_id, build_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        build_id: impl Into<Cow<'a, str>>,
+    ) -> BuildDetails<'a> {
+        BuildDetails { app_id: app_id.into(), build_id: build_id.into() }
     }
 }
 
@@ -124,13 +129,13 @@ impl<'a> HerokuEndpoint<Build> for BuildDetails<'a> {
 /// [response]: ../struct.BuildpackInstallation.html
 pub struct BuildPackInstallationList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> BuildPackInstallationList<'a> {
-    pub fn new(app_id: &'a str) -> BuildPackInstallationList<'a> {
-        BuildPackInstallationList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> BuildPackInstallationList<'a> {
+        BuildPackInstallationList { app_id: app_id.into() }
     }
 }
 
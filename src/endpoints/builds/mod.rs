@@ -1,4 +1,7 @@
+use crate::endpoints::status::BuildStatus;
 use crate::framework::response::ApiResult;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use serde::Deserialize;
 
 pub mod delete;
@@ -33,7 +36,7 @@ pub struct Build {
     /// buildpacks executed for this build, in order
     pub buildpacks: Option<Vec<Buildpack>>,
     /// when build was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// unique identifier of build
     pub id: String,
     /// Build process output will be available from this URL as a stream.
@@ -49,9 +52,9 @@ pub struct Build {
     /// stack of build
     pub stack: String,
     /// status of build. One of:"failed" or "pending" or "succeeded"
-    pub status: String,
+    pub status: BuildStatus,
     /// when build was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// identifier of an account
     pub user: User,
 }
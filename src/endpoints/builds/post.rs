@@ -1,7 +1,9 @@
 //Anything related to POST requests for build and it's properties goes here.
 use super::Build;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Build Create
 ///
@@ -41,7 +43,7 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Build.html
 pub struct BuildCreate<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: BuildCreateParams<'a>,
 }
@@ -50,14 +52,17 @@ pub struct BuildCreate<'a> {
 impl<'a> BuildCreate<'a> {
     /// Create a new build only with required parameters
     /// NOTE: Fields that are not passed are sent as NULL to the api.
-    pub fn new(app_id: &'a str, source_blob_url: &'a str) -> BuildCreate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        source_blob_url: impl Into<Cow<'a, str>>,
+    ) -> BuildCreate<'a> {
         BuildCreate {
-            app_id,
+            app_id: app_id.into(),
             params: BuildCreateParams {
                 buildpacks: None,
                 source_blob: SourceBlobParam {
                     checksum: None,
-                    url: source_blob_url,
+                    url: source_blob_url.into(),
                     version: None,
                 },
             },
@@ -65,33 +70,37 @@ impl<'a> BuildCreate<'a> {
     }
 
     /// # checksum: an optional checksum of the gzipped tarball for verifying its integrity
-    pub fn checksum(&mut self, checksum: &'a str) -> &mut Self {
-        self.params.source_blob.checksum = Some(checksum);
+    pub fn checksum(&mut self, checksum: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.source_blob.checksum = Some(checksum.into());
         self
     }
     /// # version: Version of the gzipped tarball.
-    pub fn version(&mut self, version: &'a str) -> &mut Self {
-        self.params.source_blob.version = Some(version);
+    pub fn version(&mut self, version: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.source_blob.version = Some(version.into());
         self
     }
 
     /// # buildpack: buildpacks executed for this build, in order
     /// ## url: the URL of the buildpack for the app
     /// ## name: Buildpack Registry name of the buildpack for the app
-    pub fn buildpack(&mut self, url: &'a str, name: &'a str) -> &mut Self {
-        self.params.buildpacks = Some(vec![BuildpackParam { url, name }]);
+    pub fn buildpack(
+        &mut self,
+        url: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        self.params.buildpacks = Some(vec![BuildpackParam { url: url.into(), name: name.into() }]);
         self
     }
 
     pub fn build(&self) -> BuildCreate<'a> {
         BuildCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: BuildCreateParams {
                 buildpacks: self.params.buildpacks.clone(),
                 source_blob: SourceBlobParam {
-                    checksum: self.params.source_blob.checksum,
-                    url: self.params.source_blob.url,
-                    version: self.params.source_blob.version,
+                    checksum: self.params.source_blob.checksum.clone(),
+                    url: self.params.source_blob.url.clone(),
+                    version: self.params.source_blob.version.clone(),
                 },
             },
         }
@@ -112,19 +121,19 @@ pub struct BuildCreateParams<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct SourceBlobParam<'a> {
     /// an optional checksum of the gzipped tarball for verifying its integrity [Nullable]
-    pub checksum: Option<&'a str>,
+    pub checksum: Option<Cow<'a, str>>,
     /// URL where gzipped tar archive of source code for build was downloaded.
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
     /// Version of the gzipped tarball. [Nullable]
-    pub version: Option<&'a str>,
+    pub version: Option<Cow<'a, str>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
 pub struct BuildpackParam<'a> {
     /// location of the buildpack for the app. Either a url (unofficial buildpacks) or an internal urn (heroku official buildpacks).
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
     /// either the Buildpack Registry name or a URL of the buildpack for the app
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<Build, (), BuildCreateParams<'a>> for BuildCreate<'a> {
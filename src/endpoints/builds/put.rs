@@ -1,6 +1,7 @@
 //Anything related to PUT requests for build and it's properties goes here.
 use super::{BuildpackInstallation, Update};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
 
 /// Buildpack Installations Update
@@ -37,23 +38,26 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.BuildpackInstallation.html
 pub struct BuildpackInstallationUpdate<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: BuildpackInstallationUpdateParams,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> BuildpackInstallationUpdate<'a> {
-    pub fn new(app_id: &'a str, buildpacks: Vec<&'a str>) -> BuildpackInstallationUpdate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        buildpacks: impl IntoIterator<Item = impl Into<String>>,
+    ) -> BuildpackInstallationUpdate<'a> {
         let mut updates = Vec::new();
         for var in buildpacks {
             updates.push(Update {
-                buildpack: var.to_owned(),
+                buildpack: var.into(),
             });
         }
 
         BuildpackInstallationUpdate {
-            app_id,
+            app_id: app_id.into(),
             params: BuildpackInstallationUpdateParams { updates },
         }
     }
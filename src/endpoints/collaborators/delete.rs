@@ -1,7 +1,9 @@
 //Anything related to DELETE requests for collaborators and it's properties goes here.
 use super::{Collaborator, TeamCollaborator};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Collaborator Delete
 ///
@@ -30,17 +32,20 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Collaborator.html
 pub struct CollaboratorDelete<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// collaborator_id can be the collaborator email or id.
-    pub collaborator_id: &'a str,
+    pub collaborator_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> CollaboratorDelete<'a> {
-    pub fn new(app_id: &'a str, collaborator_id: &'a str) -> CollaboratorDelete<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        collaborator_id: impl Into<Cow<'a, str>>,
+    ) -> CollaboratorDelete<'a> {
         CollaboratorDelete {
-            app_id,
-            collaborator_id,
+            app_id: app_id.into(),
+            collaborator_id: collaborator_id.into(),
         }
     }
 }
@@ -84,17 +89,20 @@ impl<'a> HerokuEndpoint<Collaborator> for CollaboratorDelete<'a> {
 /// [response]: ../struct.TeamCollaborator.html
 pub struct TeamCollaboratorDelete<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// collaborator_id can be the collaborator email or id.
-    pub collaborator_id: &'a str,
+    pub collaborator_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamCollaboratorDelete<'a> {
-    pub fn new(app_id: &'a str, collaborator_id: &'a str) -> TeamCollaboratorDelete<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        collaborator_id: impl Into<Cow<'a, str>>,
+    ) -> TeamCollaboratorDelete<'a> {
         TeamCollaboratorDelete {
-            app_id,
-            collaborator_id,
+            app_id: app_id.into(),
+            collaborator_id: collaborator_id.into(),
         }
     }
 }
@@ -1,7 +1,9 @@
 //Anything related to GET requests for collaborators and it's properties goes here.
 use super::{Collaborator, TeamCollaborator};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Collaborator List
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Collaborator.html
 pub struct CollaboratorList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> CollaboratorList<'a> {
-    pub fn new(app_id: &'a str) -> CollaboratorList {
-        CollaboratorList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> CollaboratorList<'a> {
+        CollaboratorList { app_id: app_id.into() }
     }
 }
 
@@ -76,17 +78,20 @@ impl<'a> HerokuEndpoint<Vec<Collaborator>> for CollaboratorList<'a> {
 /// [response]: ../struct.Collaborator.html
 pub struct CollaboratorDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// collaborator_id can be the collaborator email or id.
-    pub collaborator_id: &'a str,
+    pub collaborator_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> CollaboratorDetails<'a> {
-    pub fn new(app_id: &'a str, collaborator_id: &'a str) -> CollaboratorDetails<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        collaborator_id: impl Into<Cow<'a, str>>,
+    ) -> CollaboratorDetails<'a> {
         CollaboratorDetails {
-            app_id,
-            collaborator_id,
+            app_id: app_id.into(),
+            collaborator_id: collaborator_id.into(),
         }
     }
 }
@@ -130,12 +135,12 @@ impl<'a> HerokuEndpoint<Collaborator> for CollaboratorDetails<'a> {
 /// [response]: ../struct.TeamCollaborator.html
 pub struct TeamCollaboratorList<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 #[cfg(feature = "builder")]
 impl<'a> TeamCollaboratorList<'a> {
-    pub fn new(app_id: &'a str) -> TeamCollaboratorList<'a> {
-        TeamCollaboratorList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> TeamCollaboratorList<'a> {
+        TeamCollaboratorList { app_id: app_id.into() }
     }
 }
 
@@ -174,17 +179,20 @@ impl<'a> HerokuEndpoint<Vec<TeamCollaborator>> for TeamCollaboratorList<'a> {
 /// [response]: ../struct.TeamCollaborator.html
 pub struct TeamCollaboratorDetails<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// collaborator_id can be the collaborator email or id.
-    pub collaborator_id: &'a str,
+    pub collaborator_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamCollaboratorDetails<'a> {
-    pub fn new(app_id: &'a str, collaborator_id: &'a str) -> TeamCollaboratorDetails<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        collaborator_id: impl Into<Cow<'a, str>>,
+    ) -> TeamCollaboratorDetails<'a> {
         TeamCollaboratorDetails {
-            app_id,
-            collaborator_id,
+            app_id: app_id.into(),
+            collaborator_id: collaborator_id.into(),
         }
     }
 }
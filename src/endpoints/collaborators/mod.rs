@@ -1,4 +1,6 @@
 use crate::framework::response::ApiResult;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use serde::Deserialize;
 
 pub mod delete;
@@ -41,7 +43,7 @@ pub struct Collaborator {
     /// App struct
     pub app: App,
     /// when collaborator was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// unique identifier of collaborator
     pub id: String,
     /// list of permissions this collaborator has.
@@ -49,7 +51,7 @@ pub struct Collaborator {
     /// role in the team. One of:"admin" or "collaborator" or "member" or "owner" or null
     pub role: Option<String>,
     /// when collaborator was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// Account struct
     pub user: User,
 }
@@ -1,7 +1,9 @@
 //Anything related to PATCH requests for collaborators and it's properties goes here.
 use super::TeamCollaborator;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Team App Collaborator Update
 ///
@@ -36,9 +38,9 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.TeamCollaborator.html
 pub struct TeamCollaboratorUpdate<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// collaborator_id can be the collaborator email or id.
-    pub collaborator_id: &'a str,
+    pub collaborator_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: TeamCollaboratorUpdateParams<'a>,
 }
@@ -46,14 +48,16 @@ pub struct TeamCollaboratorUpdate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> TeamCollaboratorUpdate<'a> {
     pub fn new(
-        app_id: &'a str,
-        collaborator_id: &'a str,
-        permissions: Vec<&'a str>,
+        app_id: impl Into<AppRef<'a>>,
+        collaborator_id: impl Into<Cow<'a, str>>,
+        permissions: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
     ) -> TeamCollaboratorUpdate<'a> {
         TeamCollaboratorUpdate {
-            app_id,
-            collaborator_id,
-            params: TeamCollaboratorUpdateParams { permissions },
+            app_id: app_id.into(),
+            collaborator_id: collaborator_id.into(),
+            params: TeamCollaboratorUpdateParams {
+                permissions: permissions.into_iter().map(Into::into).collect(),
+            },
         }
     }
 }
@@ -65,17 +69,20 @@ impl<'a> TeamCollaboratorUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TeamCollaboratorUpdateParams<'a> {
     /// An array of permissions to give to the collaborator.
-    pub permissions: Vec<&'a str>,
+    pub permissions: Vec<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<TeamCollaborator, (), TeamCollaboratorUpdateParams<'a>>
     for TeamCollaboratorUpdate<'a>
 {
     fn method(&self) -> Method {
-        Method::Post
+        Method::Patch
     }
     fn path(&self) -> String {
-        format!("teams/apps/{}/collaborators", self.app_id)
+        format!(
+            "teams/apps/{}/collaborators/{}",
+            self.app_id, self.collaborator_id
+        )
     }
     fn body(&self) -> Option<TeamCollaboratorUpdateParams<'a>> {
         Some(self.params.clone())
@@ -1,7 +1,9 @@
 //Anything related to POST requests for collaborators and it's properties goes here.
 use super::{Collaborator, TeamCollaborator};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Collaborator Create
 ///
@@ -33,17 +35,20 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Collaborator.html
 pub struct CollaboratorCreate<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: CollaboratorCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> CollaboratorCreate<'a> {
-    pub fn new(app_id: &'a str, user: &'a str) -> CollaboratorCreate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        user: impl Into<Cow<'a, str>>,
+    ) -> CollaboratorCreate<'a> {
         CollaboratorCreate {
-            app_id,
-            params: CollaboratorCreateParams { user, silent: None },
+            app_id: app_id.into(),
+            params: CollaboratorCreateParams { user: user.into(), silent: None },
         }
     }
 
@@ -55,9 +60,9 @@ impl<'a> CollaboratorCreate<'a> {
 
     pub fn build(&self) -> CollaboratorCreate<'a> {
         CollaboratorCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: CollaboratorCreateParams {
-                user: self.params.user,
+                user: self.params.user.clone(),
                 silent: self.params.silent,
             },
         }
@@ -70,7 +75,7 @@ impl<'a> CollaboratorCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct CollaboratorCreateParams<'a> {
     /// unique email address, identifier of an account or Implicit reference to currently authorized user
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
     /// whether to suppress email invitation when creating collaborator
     #[serde(skip_serializing_if = "Option::is_none")]
     pub silent: Option<bool>,
@@ -121,18 +126,21 @@ impl<'a> HerokuEndpoint<Collaborator, (), CollaboratorCreateParams<'a>> for Coll
 /// [response]: ../struct.TeamCollaborator.html
 pub struct TeamCollaboratorCreate<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: TeamCollaboratorCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamCollaboratorCreate<'a> {
-    pub fn new(app_id: &'a str, user: &'a str) -> TeamCollaboratorCreate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        user: impl Into<Cow<'a, str>>,
+    ) -> TeamCollaboratorCreate<'a> {
         TeamCollaboratorCreate {
-            app_id,
+            app_id: app_id.into(),
             params: TeamCollaboratorCreateParams {
-                user: user,
+                user: user.into(),
                 silent: None,
                 permissions: None,
             },
@@ -146,16 +154,19 @@ impl<'a> TeamCollaboratorCreate<'a> {
     }
 
     /// # permissions: An array of permissions to give to the collaborator.
-    pub fn permissions(&mut self, permissions: Vec<&'a str>) -> &mut Self {
-        self.params.permissions = Some(permissions);
+    pub fn permissions(
+        &mut self,
+        permissions: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        self.params.permissions = Some(permissions.into_iter().map(Into::into).collect());
         self
     }
 
     pub fn build(&self) -> TeamCollaboratorCreate<'a> {
         TeamCollaboratorCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: TeamCollaboratorCreateParams {
-                user: self.params.user,
+                user: self.params.user.clone(),
                 silent: self.params.silent,
                 permissions: self.params.permissions.clone(),
             },
@@ -170,11 +181,11 @@ impl<'a> TeamCollaboratorCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TeamCollaboratorCreateParams<'a> {
     /// unique email address, identifier of an account or Implicit reference to currently authorized user
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
     /// whether to suppress email invitation when creating collaborator
     pub silent: Option<bool>,
     /// An array of permissions to give to the collaborator.
-    pub permissions: Option<Vec<&'a str>>,
+    pub permissions: Option<Vec<Cow<'a, str>>>,
 }
 
 impl<'a> HerokuEndpoint<TeamCollaborator, (), TeamCollaboratorCreateParams<'a>>
@@ -1,6 +1,8 @@
 //Anything related to DELETE requests for config vars and it's variations goes here.
 
+use crate::endpoints::ids::{AppRef, PipelineRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// Config Vars DELETE
@@ -33,22 +35,25 @@ use std::collections::HashMap;
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct AppConfigVarDelete<'a> {
     /// app_id is the unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: HashMap<String, Option<String>>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppConfigVarDelete<'a> {
-    pub fn new(app_id: &'a str, params: HashMap<String, Option<String>>) -> AppConfigVarDelete {
-        AppConfigVarDelete { app_id, params }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        params: HashMap<String, Option<String>>,
+    ) -> AppConfigVarDelete<'a> {
+        AppConfigVarDelete { app_id: app_id.into(), params }
     }
 
-    pub fn create(app_id: &'a str, delete_key: String) -> AppConfigVarDelete<'a> {
+    pub fn create(app_id: impl Into<AppRef<'a>>, delete_key: String) -> AppConfigVarDelete<'a> {
         let mut params = HashMap::new();
         params.insert(delete_key, None);
 
-        AppConfigVarDelete { app_id, params }
+        AppConfigVarDelete { app_id: app_id.into(), params }
     }
 }
 
@@ -99,9 +104,9 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>, (), HashMap<String, Opt
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct PipelineConfigVarDelete<'a> {
     /// pipeline_id is the unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
     /// pipeline coupling stage
-    pub stage_id: &'a str,
+    pub stage_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: HashMap<String, Option<String>>,
 }
@@ -109,28 +114,28 @@ pub struct PipelineConfigVarDelete<'a> {
 #[cfg(feature = "builder")]
 impl<'a> PipelineConfigVarDelete<'a> {
     pub fn new(
-        pipeline_id: &'a str,
-        stage_id: &'a str,
+        pipeline_id: impl Into<PipelineRef<'a>>,
+        stage_id: impl Into<Cow<'a, str>>,
         params: HashMap<String, Option<String>>,
     ) -> PipelineConfigVarDelete<'a> {
         PipelineConfigVarDelete {
-            pipeline_id,
-            stage_id,
+            pipeline_id: pipeline_id.into(),
+            stage_id: stage_id.into(),
             params,
         }
     }
 
     pub fn create(
-        pipeline_id: &'a str,
-        stage_id: &'a str,
+        pipeline_id: impl Into<PipelineRef<'a>>,
+        stage_id: impl Into<Cow<'a, str>>,
         delete_key: String,
     ) -> PipelineConfigVarDelete<'a> {
         let mut params = HashMap::new();
         params.insert(delete_key, None);
 
         PipelineConfigVarDelete {
-            pipeline_id,
-            stage_id,
+            pipeline_id: pipeline_id.into(),
+            stage_id: stage_id.into(),
             params,
         }
     }
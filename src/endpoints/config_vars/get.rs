@@ -1,7 +1,9 @@
 //Anything related to GET requests for config vars and it's variations goes here.
 use std::collections::HashMap;
 
+use crate::endpoints::ids::{AppRef, PipelineRef, ReleaseRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Config Vars Info for App
 ///
@@ -10,13 +12,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#config-vars-info-for-app)
 pub struct AppConfigVarDetails<'a> {
     /// unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> AppConfigVarDetails<'a> {
-    pub fn new(app_id: &'a str) -> AppConfigVarDetails {
-        AppConfigVarDetails { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> AppConfigVarDetails<'a> {
+        AppConfigVarDetails { app_id: app_id.into() }
     }
 }
 
@@ -42,7 +44,7 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>> for AppConfigVarDetails
 /// use heroku_rs::prelude::*;
 ///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
 ///
-/// let response = api_client.request(&ReleaseConfigVarDetails::new("APP_ID_HERE", "RELEASE_ID"));
+/// let response = api_client.request(&ReleaseConfigVarDetails::new("APP_ID_HERE", ReleaseRef::Version(12)));
 ///
 ///match response {
 ///     Ok(success) => println!("Success: {:#?}", success),
@@ -55,15 +57,18 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>> for AppConfigVarDetails
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct ReleaseConfigVarDetails<'a> {
     /// unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     // unique release identifier, release id or release version
-    pub release_id: &'a str,
+    pub release_id: ReleaseRef,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReleaseConfigVarDetails<'a> {
-    pub fn new(app_id: &'a str, release_id: &'a str) -> ReleaseConfigVarDetails<'a> {
-        ReleaseConfigVarDetails { app_id, release_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        release_id: impl Into<ReleaseRef>,
+    ) -> ReleaseConfigVarDetails<'a> {
+        ReleaseConfigVarDetails { app_id: app_id.into(), release_id: release_id.into() }
     }
 }
 
@@ -105,17 +110,20 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>> for ReleaseConfigVarDet
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct PipelineConfigVarDetails<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
     /// pipeline stage
-    pub stage_id: &'a str,
+    pub stage_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineConfigVarDetails<'a> {
-    pub fn new(pipeline_id: &'a str, stage_id: &'a str) -> PipelineConfigVarDetails<'a> {
+    pub fn new(
+        pipeline_id: impl Into<PipelineRef<'a>>,
+        stage_id: impl Into<Cow<'a, str>>,
+    ) -> PipelineConfigVarDetails<'a> {
         PipelineConfigVarDetails {
-            pipeline_id,
-            stage_id,
+            pipeline_id: pipeline_id.into(),
+            stage_id: stage_id.into(),
         }
     }
 }
@@ -1,6 +1,8 @@
 //Anything related to PATCH requests for config vars and it's variations goes here.
 
+use crate::endpoints::ids::{AppRef, PipelineRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// Config Vars Update
@@ -37,7 +39,7 @@ use std::collections::HashMap;
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct AppConfigVarUpdate<'a> {
     /// app_id is the unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /**
      * If you're coming from the Heroku docs, you'll notice that DELETE is implemented by setting the `value` of the config var to null and sending it as a PATCH request.
      * I didn't want to do both PATCH and DELETE on the same `AppConfigVarUpdate` struct. The delete request is moved to it's own file `AppConfigVarDelete`.
@@ -49,8 +51,11 @@ pub struct AppConfigVarUpdate<'a> {
 
 #[cfg(feature = "builder")]
 impl<'a> AppConfigVarUpdate<'a> {
-    pub fn new(app_id: &'a str, params: HashMap<String, String>) -> AppConfigVarUpdate {
-        AppConfigVarUpdate { app_id, params }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        params: HashMap<String, String>,
+    ) -> AppConfigVarUpdate<'a> {
+        AppConfigVarUpdate { app_id: app_id.into(), params }
     }
 }
 
@@ -106,9 +111,9 @@ impl<'a> HerokuEndpoint<HashMap<String, String>, (), HashMap<String, String>>
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct PipelineConfigVarUpdate<'a> {
     /// pipeline_id is the unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
     /// pipeline coupling stage
-    pub stage_id: &'a str,
+    pub stage_id: Cow<'a, str>,
     /**
      * If you're coming from the Heroku docs, you'll notice that DELETE is implemented by setting the `value` of the config var to null and sending it as a PATCH request.
      * I didn't want to do both PATCH and DELETE on the same `PipelineConfigVarUpdate` struct. The delete request is moved to it's own file `PipelineConfigVarDelete`.
@@ -121,13 +126,13 @@ pub struct PipelineConfigVarUpdate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> PipelineConfigVarUpdate<'a> {
     pub fn new(
-        pipeline_id: &'a str,
-        stage_id: &'a str,
+        pipeline_id: impl Into<PipelineRef<'a>>,
+        stage_id: impl Into<Cow<'a, str>>,
         params: HashMap<String, String>,
     ) -> PipelineConfigVarUpdate<'a> {
         PipelineConfigVarUpdate {
-            pipeline_id,
-            stage_id,
+            pipeline_id: pipeline_id.into(),
+            stage_id: stage_id.into(),
             params,
         }
     }
@@ -1,11 +1,7 @@
 use crate::framework::endpoint::{HerokuEndpoint, Method};
-use crate::framework::response::ApiResult;
 use serde::Serialize;
 use serde_json::Value;
 
-impl ApiResult for Value {}
-impl ApiResult for Vec<Value> {}
-
 /// CustomEndpoint
 /// 
 /// CustomEndpoint is way to query Heroku endpoints that have not been supported by the library yet.
@@ -1,7 +1,9 @@
 //Anything related to DELETE requests for domains and it's properties goes here.
 use super::Domain;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Domain Delete
 ///
@@ -30,15 +32,18 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Domain.html
 pub struct DomainDelete<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// domain_id can be the domain hostname or id.
-    pub domain_id: &'a str,
+    pub domain_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DomainDelete<'a> {
-    pub fn new(app_id: &'a str, domain_id: &'a str) -> DomainDelete<'a> {
-        DomainDelete { app_id, domain_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        domain_id: impl Into<Cow<'a, str>>,
+    ) -> DomainDelete<'a> {
+        DomainDelete { app_id: app_id.into(), domain_id: domain_id.into() }
     }
 }
 
@@ -1,4 +1,7 @@
+use crate::endpoints::status::{AcmStatus, DomainStatus};
 use crate::framework::response::ApiResult;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use serde::Deserialize;
 
 pub mod delete;
@@ -24,7 +27,7 @@ impl ApiResult for Vec<Domain> {}
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct Domain {
     /// status of this record’s ACM
-    pub acm_status: Option<String>,
+    pub acm_status: Option<AcmStatus>,
     /// reason for the status of this record’s ACM
     pub acm_status_reason: Option<String>,
     /// App
@@ -32,7 +35,7 @@ pub struct Domain {
     /// canonical name record, the address to point a domain at
     pub cname: Option<String>,
     /// when domain was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// full hostname of the domain
     pub hostname: String,
     /// unique identifier of this domain
@@ -40,9 +43,9 @@ pub struct Domain {
     /// type of domain name. One of:"heroku" or "custom"
     pub kind: String,
     /// when domain was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// status of this record’s cname
-    pub status: String,
+    pub status: DomainStatus,
 }
 
 /// An app represents the program that you would like to deploy and run on Heroku.
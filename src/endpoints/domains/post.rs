@@ -1,7 +1,9 @@
 //Anything related to POST requests for domains and it's properties goes here.
 use super::Domain;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Domain Create
 ///
@@ -32,17 +34,20 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Domain.html
 pub struct DomainCreate<'a> {
     /// app_id can be the app name or id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: DomainCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DomainCreate<'a> {
-    pub fn new(app_id: &'a str, hostname: &'a str) -> DomainCreate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        hostname: impl Into<Cow<'a, str>>,
+    ) -> DomainCreate<'a> {
         DomainCreate {
-            app_id,
-            params: DomainCreateParams { hostname },
+            app_id: app_id.into(),
+            params: DomainCreateParams { hostname: hostname.into() },
         }
     }
 }
@@ -53,7 +58,7 @@ impl<'a> DomainCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct DomainCreateParams<'a> {
     /// full hostname
-    pub hostname: &'a str,
+    pub hostname: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<Domain, (), DomainCreateParams<'a>> for DomainCreate<'a> {
@@ -1,6 +1,7 @@
 //Anything related to DELETE requests for dynos and it's properties goes here.
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Dyno Restart
 ///
@@ -28,15 +29,18 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct DynoRestart<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: Cow<'a, str>,
     /// dyno_id can be the dyno name or the dyno id
-    pub dyno_id: &'a str,
+    pub dyno_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DynoRestart<'a> {
-    pub fn new(app_id: &'a str, dyno_id: &'a str) -> DynoRestart<'a> {
-        DynoRestart { app_id, dyno_id }
+    pub fn new(
+        app_id: impl Into<Cow<'a, str>>,
+        dyno_id: impl Into<Cow<'a, str>>,
+    ) -> DynoRestart<'a> {
+        DynoRestart { app_id: app_id.into(), dyno_id: dyno_id.into() }
     }
 }
 
@@ -75,13 +79,13 @@ impl<'a> HerokuEndpoint for DynoRestart<'a> {
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 pub struct DynoAllRestart<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DynoAllRestart<'a> {
-    pub fn new(app_id: &'a str) -> DynoAllRestart<'a> {
-        DynoAllRestart { app_id }
+    pub fn new(app_id: impl Into<Cow<'a, str>>) -> DynoAllRestart<'a> {
+        DynoAllRestart { app_id: app_id.into() }
     }
 }
 
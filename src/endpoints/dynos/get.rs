@@ -2,7 +2,9 @@
 
 use super::{Dyno, DynoSize};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Dyno Info
 ///
@@ -31,15 +33,15 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Dyno.html
 pub struct DynoDetails<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// dyno_id can be the dyno name or the dyno id
-    pub dyno_id: &'a str,
+    pub dyno_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DynoDetails<'a> {
-    pub fn new(app_id: &'a str, dyno_id: &'a str) -> DynoDetails<'a> {
-        DynoDetails { app_id, dyno_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>, dyno_id: impl Into<Cow<'a, str>>) -> DynoDetails<'a> {
+        DynoDetails { app_id: app_id.into(), dyno_id: dyno_id.into() }
     }
 }
 
@@ -79,13 +81,13 @@ impl<'a> HerokuEndpoint<Dyno> for DynoDetails<'a> {
 /// [response]: ../struct.Dyno.html
 pub struct DynoList<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DynoList<'a> {
-    pub fn new(app_id: &'a str) -> DynoList<'a> {
-        DynoList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> DynoList<'a> {
+        DynoList { app_id: app_id.into() }
     }
 }
 
@@ -168,13 +170,13 @@ impl HerokuEndpoint<Vec<DynoSize>> for DynoSizeList {
 /// [response]: ../struct.DynoSize.html
 pub struct DynoSizeDetails<'a> {
     /// unique dyno size identifier
-    pub size_id: &'a str,
+    pub size_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DynoSizeDetails<'a> {
-    pub fn new(size_id: &'a str) -> DynoSizeDetails {
-        DynoSizeDetails { size_id }
+    pub fn new(size_id: impl Into<Cow<'a, str>>) -> DynoSizeDetails<'a> {
+        DynoSizeDetails { size_id: size_id.into() }
     }
 }
 
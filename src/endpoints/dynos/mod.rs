@@ -1,4 +1,7 @@
+use crate::endpoints::status::DynoState;
 use crate::framework::response::ApiResult;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use serde::Deserialize;
 
 pub mod delete;
@@ -34,7 +37,7 @@ pub struct Dyno {
     /// command used to start this process
     pub command: String,
     /// when dyno was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// unique identifier of this dyno
     pub id: String,
     /// the name of this process on this dyno
@@ -44,11 +47,11 @@ pub struct Dyno {
     /// dyno size (default: “standard-1X”)
     pub size: String,
     /// current status of process (either: crashed, down, idle, starting, or up)
-    pub state: String,
+    pub state: DynoState,
     /// type of process
     pub r#type: String, //type is a keyword in Rust
     /// when process last changed state
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// An app represents the program that you would like to deploy and run on Heroku.
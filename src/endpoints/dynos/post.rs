@@ -2,7 +2,9 @@
 
 use super::Dyno;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// Dyno Stop
@@ -32,15 +34,18 @@ use std::collections::HashMap;
 /// [response]: ../struct.Dyno.html
 pub struct DynoActionStop<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: Cow<'a, str>,
     /// dyno_id can be the dyno name or the dyno id
-    pub dyno_id: &'a str,
+    pub dyno_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DynoActionStop<'a> {
-    pub fn new(app_id: &'a str, dyno_id: &'a str) -> DynoActionStop<'a> {
-        DynoActionStop { app_id, dyno_id }
+    pub fn new(
+        app_id: impl Into<Cow<'a, str>>,
+        dyno_id: impl Into<Cow<'a, str>>,
+    ) -> DynoActionStop<'a> {
+        DynoActionStop { app_id: app_id.into(), dyno_id: dyno_id.into() }
     }
 }
 
@@ -95,18 +100,18 @@ impl<'a> HerokuEndpoint for DynoActionStop<'a> {
 #[derive(Serialize)]
 pub struct DynoCreate<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: DynoCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> DynoCreate<'a> {
-    pub fn new(app_id: &'a str, command: &'a str) -> DynoCreate<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>, command: impl Into<Cow<'a, str>>) -> DynoCreate<'a> {
         DynoCreate {
-            app_id,
+            app_id: app_id.into(),
             params: DynoCreateParams {
-                command: command,
+                command: command.into(),
                 attach: None,
                 env: None,
                 force_no_tty: None,
@@ -124,8 +129,16 @@ impl<'a> DynoCreate<'a> {
     }
 
     /// # env: custom environment to add to the dyno config vars
-    pub fn env(&mut self, env: HashMap<&'a str, &'a str>) -> &mut Self {
-        self.params.env = Some(env);
+    pub fn env(
+        &mut self,
+        env: impl IntoIterator<Item = (impl Into<Cow<'a, str>>, impl Into<Cow<'a, str>>)>,
+    ) -> &mut Self {
+        self.params.env = Some(
+            env
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect(),
+        );
         self
     }
 
@@ -138,8 +151,8 @@ impl<'a> DynoCreate<'a> {
     /// # size: dyno size
     ///
     /// `default`: “standard-1X”
-    pub fn size(&mut self, size: &'a str) -> &mut Self {
-        self.params.size = Some(size);
+    pub fn size(&mut self, size: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.size = Some(size.into());
         self
     }
 
@@ -150,22 +163,22 @@ impl<'a> DynoCreate<'a> {
     }
 
     /// # dyno_type: type of process
-    pub fn dyno_type(&mut self, dyno_type: &'a str) -> &mut Self {
-        self.params.r#type = Some(dyno_type);
+    pub fn dyno_type(&mut self, dyno_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.r#type = Some(dyno_type.into());
         self
     }
 
     pub fn build(&self) -> DynoCreate<'a> {
         DynoCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: DynoCreateParams {
-                command: self.params.command,
+                command: self.params.command.clone(),
                 attach: self.params.attach,
                 env: self.params.env.clone(),
                 force_no_tty: self.params.force_no_tty,
-                size: self.params.size,
+                size: self.params.size.clone(),
                 time_to_live: self.params.time_to_live,
-                r#type: self.params.r#type,
+                r#type: self.params.r#type.clone(),
             },
         }
     }
@@ -183,24 +196,24 @@ impl<'a> DynoCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct DynoCreateParams<'a> {
     /// command used to start process
-    pub command: &'a str,
+    pub command: Cow<'a, str>,
     /// whether to stream output or not
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attach: Option<bool>,
     /// custom environment to add to the dyno config vars
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub env: Option<HashMap<&'a str, &'a str>>,
+    pub env: Option<HashMap<Cow<'a, str>, Cow<'a, str>>>,
     /// force an attached on-off dyno to not run in a tty [Nullable]
     pub force_no_tty: Option<bool>,
     /// dyno size
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<&'a str>,
+    pub size: Option<Cow<'a, str>>,
     /// seconds until dyno expires
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_to_live: Option<i32>,
     /// type of process
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<&'a str>,
+    pub r#type: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Dyno, (), DynoCreateParams<'a>> for DynoCreate<'a> {
@@ -0,0 +1,62 @@
+//Anything related to DELETE requests for enterprise accounts and it's variations goes here.
+use super::EnterpriseAccountMember;
+
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
+
+/// Enterprise Account Member Delete
+///
+/// Delete a member from an enterprise account.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-member-delete)
+///
+/// # Example:
+///
+/// EnterpriseAccountMemberDelete takes two required parameters, account_id and member_id, and returns the deleted [`EnterpriseAccountMember`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&EnterpriseAccountMemberDelete::new("ACCOUNT_ID", "MEMBER_EMAIL"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccountMember.html
+pub struct EnterpriseAccountMemberDelete<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+    /// unique email address or identifier of the member
+    pub member_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> EnterpriseAccountMemberDelete<'a> {
+    pub fn new(
+        account_id: impl Into<Cow<'a, str>>,
+        member_id: impl Into<Cow<'a, str>>,
+    ) -> EnterpriseAccountMemberDelete<'a> {
+        EnterpriseAccountMemberDelete {
+            account_id: account_id.into(),
+            member_id: member_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<EnterpriseAccountMember> for EnterpriseAccountMemberDelete<'a> {
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+    fn path(&self) -> String {
+        format!(
+            "enterprise-accounts/{}/members/{}",
+            self.account_id, self.member_id
+        )
+    }
+}
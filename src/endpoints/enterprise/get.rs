@@ -0,0 +1,563 @@
+//Anything related to GET requests for enterprise accounts and it's variations goes here.
+use super::{
+    AuditTrailArchive, AuditTrailEvent, EnterpriseAccount, EnterpriseAccountDailyUsage,
+    EnterpriseAccountMember, EnterpriseAccountMonthlyUsage,
+};
+
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
+
+/// Enterprise Account List
+///
+/// List enterprise accounts in which you are a member.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-list)
+///
+/// # Example:
+///
+/// EnterpriseAccountList takes no required parameters and returns a list of [`EnterpriseAccounts`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&EnterpriseAccountList::new());
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccount.html
+pub struct EnterpriseAccountList {}
+
+#[cfg(feature = "builder")]
+impl EnterpriseAccountList {
+    pub fn new() -> EnterpriseAccountList {
+        EnterpriseAccountList {}
+    }
+}
+
+impl HerokuEndpoint<Vec<EnterpriseAccount>> for EnterpriseAccountList {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts")
+    }
+}
+
+/// Enterprise Account Info
+///
+/// Information about an enterprise account.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-info)
+///
+/// # Example:
+///
+/// EnterpriseAccountDetails takes one required parameter, account_id and returns the [`EnterpriseAccount`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&EnterpriseAccountDetails::new("ACCOUNT_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccount.html
+pub struct EnterpriseAccountDetails<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> EnterpriseAccountDetails<'a> {
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> EnterpriseAccountDetails<'a> {
+        EnterpriseAccountDetails {
+            account_id: account_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<EnterpriseAccount> for EnterpriseAccountDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts/{}", self.account_id)
+    }
+}
+
+/// Enterprise Account Member List
+///
+/// List members in an enterprise account.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-member-list)
+///
+/// # Example:
+///
+/// EnterpriseAccountMemberList takes one required parameter, account_id and returns a list of [`EnterpriseAccountMembers`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&EnterpriseAccountMemberList::new("ACCOUNT_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccountMember.html
+pub struct EnterpriseAccountMemberList<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> EnterpriseAccountMemberList<'a> {
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> EnterpriseAccountMemberList<'a> {
+        EnterpriseAccountMemberList {
+            account_id: account_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<EnterpriseAccountMember>> for EnterpriseAccountMemberList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts/{}/members", self.account_id)
+    }
+}
+
+/// Enterprise Account Daily Usage Info
+///
+/// Retrieves usage for an enterprise account for a range of days. Start and end dates can be specified as query parameters using the date format YYYY-MM-DD.
+/// The enterprise account identifier can be found from the enterprise account list command.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-daily-usage-info)
+///
+/// # Example:
+///
+/// EnterpriseAccountDailyUsageList takes two required parameters, account_id and start, and returns a list of [`EnterpriseAccountDailyUsage`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &EnterpriseAccountDailyUsageList::new("ACCOUNT_ID", "2020-01-01")
+///         .end("2020-01-31")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccountDailyUsage.html
+pub struct EnterpriseAccountDailyUsageList<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+    /// The query parameters to pass to the Heroku API
+    pub params: EnterpriseAccountDailyUsageParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> EnterpriseAccountDailyUsageList<'a> {
+    pub fn new(
+        account_id: impl Into<Cow<'a, str>>,
+        start: impl Into<Cow<'a, str>>,
+    ) -> EnterpriseAccountDailyUsageList<'a> {
+        EnterpriseAccountDailyUsageList {
+            account_id: account_id.into(),
+            params: EnterpriseAccountDailyUsageParams {
+                start: start.into(),
+                end: None,
+            },
+        }
+    }
+
+    /// # end: range end date, e.g. `2019-01-31`
+    pub fn end(&mut self, end: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.end = Some(end.into());
+        self
+    }
+
+    pub fn build(&self) -> EnterpriseAccountDailyUsageList<'a> {
+        EnterpriseAccountDailyUsageList {
+            account_id: self.account_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Query parameters of the daily usage, dates use the `YYYY-MM-DD` format.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-daily-usage-info-required-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct EnterpriseAccountDailyUsageParams<'a> {
+    /// range start date
+    pub start: Cow<'a, str>,
+    /// range end date
+    pub end: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<Vec<EnterpriseAccountDailyUsage>, EnterpriseAccountDailyUsageParams<'a>>
+    for EnterpriseAccountDailyUsageList<'a>
+{
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts/{}/usage/daily", self.account_id)
+    }
+    fn query(&self) -> Option<EnterpriseAccountDailyUsageParams<'a>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Enterprise Account Monthly Usage Info
+///
+/// Retrieves usage for an enterprise account for a range of months. Start and end dates can be specified as query parameters using the date format YYYY-MM.
+/// If no end date is specified, one month of usage is returned.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-monthly-usage-info)
+///
+/// # Example:
+///
+/// EnterpriseAccountMonthlyUsageList takes two required parameters, account_id and start, and returns a list of [`EnterpriseAccountMonthlyUsage`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &EnterpriseAccountMonthlyUsageList::new("ACCOUNT_ID", "2020-01")
+///         .end("2020-03")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccountMonthlyUsage.html
+pub struct EnterpriseAccountMonthlyUsageList<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+    /// The query parameters to pass to the Heroku API
+    pub params: EnterpriseAccountMonthlyUsageParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> EnterpriseAccountMonthlyUsageList<'a> {
+    pub fn new(
+        account_id: impl Into<Cow<'a, str>>,
+        start: impl Into<Cow<'a, str>>,
+    ) -> EnterpriseAccountMonthlyUsageList<'a> {
+        EnterpriseAccountMonthlyUsageList {
+            account_id: account_id.into(),
+            params: EnterpriseAccountMonthlyUsageParams {
+                start: start.into(),
+                end: None,
+            },
+        }
+    }
+
+    /// # end: range end month, e.g. `2019-03`
+    pub fn end(&mut self, end: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.end = Some(end.into());
+        self
+    }
+
+    pub fn build(&self) -> EnterpriseAccountMonthlyUsageList<'a> {
+        EnterpriseAccountMonthlyUsageList {
+            account_id: self.account_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Query parameters of the monthly usage, months use the `YYYY-MM` format.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-monthly-usage-info-required-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct EnterpriseAccountMonthlyUsageParams<'a> {
+    /// range start month
+    pub start: Cow<'a, str>,
+    /// range end month
+    pub end: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<Vec<EnterpriseAccountMonthlyUsage>, EnterpriseAccountMonthlyUsageParams<'a>>
+    for EnterpriseAccountMonthlyUsageList<'a>
+{
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts/{}/usage/monthly", self.account_id)
+    }
+    fn query(&self) -> Option<EnterpriseAccountMonthlyUsageParams<'a>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Audit Trail Event List
+///
+/// List existing events. Returns all events for one day, defaulting to current day.
+/// Order, actor, action, type and day can be specified as query parameters.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#audit-trail-event-list)
+///
+/// # Example:
+///
+/// AuditTrailEventList takes one required parameter, account_id and returns a list of [`AuditTrailEvents`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &AuditTrailEventList::new("ACCOUNT_ID")
+///         .day("2020-09-30")
+///         .event_type("app")
+///         .action("create")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.AuditTrailEvent.html
+pub struct AuditTrailEventList<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+    /// The query parameters to pass to the Heroku API
+    pub params: AuditTrailEventListParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> AuditTrailEventList<'a> {
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> AuditTrailEventList<'a> {
+        AuditTrailEventList {
+            account_id: account_id.into(),
+            params: AuditTrailEventListParams {
+                order: None,
+                actor: None,
+                action: None,
+                type_field: None,
+                day: None,
+            },
+        }
+    }
+
+    /// # order: order of the events, `asc` or `desc`
+    pub fn order(&mut self, order: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.order = Some(order.into());
+        self
+    }
+
+    /// # actor: email address of the user who caused the events
+    pub fn actor(&mut self, actor: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.actor = Some(actor.into());
+        self
+    }
+
+    /// # action: action of the events, e.g. `create`
+    pub fn action(&mut self, action: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.action = Some(action.into());
+        self
+    }
+
+    /// # type: type of the events, e.g. `app`
+    pub fn event_type(&mut self, event_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.type_field = Some(event_type.into());
+        self
+    }
+
+    /// # day: day of the events, e.g. `2020-09-30`
+    pub fn day(&mut self, day: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.day = Some(day.into());
+        self
+    }
+
+    pub fn build(&self) -> AuditTrailEventList<'a> {
+        AuditTrailEventList {
+            account_id: self.account_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Query parameters to filter the audit trail events.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct AuditTrailEventListParams<'a> {
+    /// order of the events, `asc` or `desc`
+    pub order: Option<Cow<'a, str>>,
+    /// email address of the user who caused the events
+    pub actor: Option<Cow<'a, str>>,
+    /// action of the events
+    pub action: Option<Cow<'a, str>>,
+    /// type of the events
+    #[serde(rename = "type")]
+    pub type_field: Option<Cow<'a, str>>,
+    /// day of the events, `YYYY-MM-DD`
+    pub day: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<Vec<AuditTrailEvent>, AuditTrailEventListParams<'a>>
+    for AuditTrailEventList<'a>
+{
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts/{}/events", self.account_id)
+    }
+    fn query(&self) -> Option<AuditTrailEventListParams<'a>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Audit Trail Archive List
+///
+/// List existing archives.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#audit-trail-archive-list)
+///
+/// # Example:
+///
+/// AuditTrailArchiveList takes one required parameter, account_id and returns a list of [`AuditTrailArchives`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&AuditTrailArchiveList::new("ACCOUNT_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.AuditTrailArchive.html
+pub struct AuditTrailArchiveList<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> AuditTrailArchiveList<'a> {
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> AuditTrailArchiveList<'a> {
+        AuditTrailArchiveList {
+            account_id: account_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<AuditTrailArchive>> for AuditTrailArchiveList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts/{}/archives", self.account_id)
+    }
+}
+
+/// Audit Trail Archive Info
+///
+/// Get archive for a single month.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#audit-trail-archive-info)
+///
+/// # Example:
+///
+/// AuditTrailArchiveDetails takes three required parameters, account_id, year and month, and returns the [`AuditTrailArchive`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&AuditTrailArchiveDetails::new("ACCOUNT_ID", 2020, 9));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.AuditTrailArchive.html
+pub struct AuditTrailArchiveDetails<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+    /// year of the archive
+    pub year: i64,
+    /// month of the archive, from 1 to 12
+    pub month: u32,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> AuditTrailArchiveDetails<'a> {
+    pub fn new(
+        account_id: impl Into<Cow<'a, str>>,
+        year: i64,
+        month: u32,
+    ) -> AuditTrailArchiveDetails<'a> {
+        AuditTrailArchiveDetails {
+            account_id: account_id.into(),
+            year,
+            month,
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<AuditTrailArchive> for AuditTrailArchiveDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!(
+            "enterprise-accounts/{}/archives/{}/{:02}",
+            self.account_id, self.year, self.month
+        )
+    }
+}
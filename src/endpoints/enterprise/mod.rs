@@ -0,0 +1,331 @@
+use crate::framework::response::ApiResult;
+
+pub mod delete;
+pub mod get;
+pub mod patch;
+pub mod post;
+
+pub use delete::EnterpriseAccountMemberDelete;
+pub use get::{
+    AuditTrailArchiveDetails, AuditTrailArchiveList, AuditTrailEventList,
+    AuditTrailEventListParams, EnterpriseAccountDailyUsageList, EnterpriseAccountDailyUsageParams,
+    EnterpriseAccountDetails, EnterpriseAccountList, EnterpriseAccountMemberList,
+    EnterpriseAccountMonthlyUsageList, EnterpriseAccountMonthlyUsageParams,
+};
+pub use patch::{
+    EnterpriseAccountMemberUpdate, EnterpriseAccountMemberUpdateParams, EnterpriseAccountUpdate,
+    EnterpriseAccountUpdateParams,
+};
+pub use post::{EnterpriseAccountMemberCreate, EnterpriseAccountMemberCreateParams};
+
+impl ApiResult for EnterpriseAccount {}
+impl ApiResult for Vec<EnterpriseAccount> {}
+
+impl ApiResult for EnterpriseAccountMember {}
+impl ApiResult for Vec<EnterpriseAccountMember> {}
+
+impl ApiResult for Vec<EnterpriseAccountDailyUsage> {}
+
+impl ApiResult for Vec<EnterpriseAccountMonthlyUsage> {}
+
+impl ApiResult for Vec<AuditTrailEvent> {}
+
+impl ApiResult for AuditTrailArchive {}
+impl ApiResult for Vec<AuditTrailArchive> {}
+
+pub use audit_trail_archive::AuditTrailArchive;
+pub use audit_trail_event::AuditTrailEvent;
+pub use enterprise_account::EnterpriseAccount;
+pub use enterprise_account_member::EnterpriseAccountMember;
+pub use enterprise_account_usage::{EnterpriseAccountDailyUsage, EnterpriseAccountMonthlyUsage};
+
+mod enterprise_account {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+
+    /// Enterprise Account
+    ///
+    /// Stability: development
+    ///
+    /// Enterprise accounts allow companies to manage their development teams and billing.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct EnterpriseAccount {
+        /// unique identifier of the enterprise account
+        pub id: String,
+        /// when the enterprise account was created
+        pub created_at: DateTime<Utc>,
+        /// Identity Provider associated with the Enterprise Account
+        pub identity_provider: Option<IdentityProvider>,
+        /// unique name of the enterprise account
+        pub name: String,
+        /// the current user's permissions for this enterprise account
+        pub permissions: Vec<String>,
+        /// whether the enterprise account is a trial or not
+        pub trial: bool,
+        /// when the enterprise account was updated
+        pub updated_at: DateTime<Utc>,
+    }
+
+    /// Identity Provider associated with the Enterprise Account
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct IdentityProvider {
+        /// unique identifier of this identity provider
+        pub id: String,
+        /// user-friendly unique identifier for this identity provider
+        pub name: String,
+        /// entity that owns this identity provider
+        pub owner: Owner,
+    }
+
+    /// entity that owns this identity provider
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Owner {
+        /// unique identifier of the owner
+        pub id: String,
+        /// name of the owner
+        pub name: String,
+        /// type of the owner
+        /// one of:"team" or "enterprise-account"
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+}
+
+mod enterprise_account_member {
+    /// Enterprise Account Member
+    ///
+    /// Stability: development
+    ///
+    /// Enterprise account members are users with access to an enterprise account.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-member)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct EnterpriseAccountMember {
+        /// unique identifier of the member
+        pub id: String,
+        /// enterprise account the member belongs to
+        pub enterprise_account: EnterpriseAccount,
+        /// enterprise account permissions
+        pub permissions: Vec<Permission>,
+        /// user information for the membership
+        pub user: User,
+        /// whether the Enterprise organization member has two factor authentication enabled
+        pub two_factor_authentication: bool,
+        /// Identity Provider information the member is federated with
+        pub identity_provider: Option<IdentityProvider>,
+    }
+
+    /// enterprise account the member belongs to
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct EnterpriseAccount {
+        /// unique identifier of the enterprise account
+        pub id: String,
+        /// unique name of the enterprise account
+        pub name: String,
+    }
+
+    /// permission in the enterprise account
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Permission {
+        /// description of the permission
+        pub description: Option<String>,
+        /// name of the permission
+        /// one of:"view" or "create" or "manage" or "billing"
+        pub name: String,
+    }
+
+    /// user information for the membership
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct User {
+        /// unique email address
+        pub email: String,
+        /// identifier of an account
+        pub id: String,
+    }
+
+    /// Identity Provider information the member is federated with
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct IdentityProvider {
+        /// unique identifier of this identity provider
+        pub id: String,
+        /// name of the identity provider
+        pub name: String,
+        /// whether the identity_provider information is redacted or not
+        pub redacted: bool,
+        /// entity that owns this identity provider
+        pub owner: Owner,
+    }
+
+    /// entity that owns this identity provider
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Owner {
+        /// unique identifier of the owner
+        pub id: String,
+        /// name of the owner
+        pub name: String,
+        /// type of the owner
+        /// one of:"team" or "enterprise-account"
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+}
+
+mod enterprise_account_usage {
+    use crate::endpoints::usage::{TeamUsage, Usage};
+
+    /// Enterprise Account Daily Usage
+    ///
+    /// Stability: development
+    ///
+    /// Usage for an enterprise account at a daily resolution.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-daily-usage)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct EnterpriseAccountDailyUsage {
+        /// enterprise account identifier
+        pub id: String,
+        /// name of the enterprise account
+        pub name: String,
+        /// date of the usage, e.g. `2019-01-01`
+        pub date: String,
+        /// usage by team
+        pub teams: Vec<TeamUsage>,
+        /// usage of the enterprise account
+        #[serde(flatten)]
+        pub usage: Usage,
+    }
+
+    /// Enterprise Account Monthly Usage
+    ///
+    /// Stability: development
+    ///
+    /// Usage for an enterprise account at a monthly resolution.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-monthly-usage)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct EnterpriseAccountMonthlyUsage {
+        /// enterprise account identifier
+        pub id: String,
+        /// name of the enterprise account
+        pub name: String,
+        /// year and month of the usage, e.g. `2019-01`
+        pub month: String,
+        /// usage by team
+        pub teams: Vec<TeamUsage>,
+        /// usage of the enterprise account
+        #[serde(flatten)]
+        pub usage: Usage,
+    }
+}
+
+mod audit_trail_event {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+
+    /// Audit Trail Event
+    ///
+    /// Stability: production
+    ///
+    /// Audit trail events represent actions taken in an enterprise account, and the teams and apps it owns.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#audit-trail-event)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct AuditTrailEvent {
+        /// unique identifier of event
+        pub id: String,
+        /// when event was created
+        pub created_at: DateTime<Utc>,
+        /// type of event, e.g. `app`
+        #[serde(rename = "type")]
+        pub type_field: String,
+        /// action for the event, e.g. `create`
+        pub action: String,
+        /// user who caused event
+        pub actor: Actor,
+        /// app upon which event took place
+        pub app: Option<App>,
+        /// owner of the app targeted by the event
+        pub owner: Option<Actor>,
+        /// enterprise account on which the event happened
+        pub enterprise_account: Option<EnterpriseAccount>,
+        /// team on which the event happened
+        pub team: Option<Team>,
+        /// information about where the action was triggered
+        pub request: Option<Request>,
+        /// data specific to the event
+        pub data: serde_json::Value,
+    }
+
+    /// user who caused event
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Actor {
+        /// unique identifier of the user
+        pub id: String,
+        /// unique email address of the user
+        pub email: String,
+    }
+
+    /// app upon which event took place
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct App {
+        /// unique identifier of app
+        pub id: String,
+        /// unique name of app
+        pub name: String,
+    }
+
+    /// enterprise account on which the event happened
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct EnterpriseAccount {
+        /// unique identifier of the enterprise account
+        pub id: String,
+        /// unique name of the enterprise account
+        pub name: String,
+    }
+
+    /// team on which the event happened
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Team {
+        /// unique identifier of the team
+        pub id: String,
+        /// unique name of the team
+        pub name: String,
+    }
+
+    /// information about where the action was triggered
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Request {
+        /// ip address where the request originated
+        pub ip_address: String,
+    }
+}
+
+mod audit_trail_archive {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+
+    /// Audit Trail Archive
+    ///
+    /// Stability: production
+    ///
+    /// An audit trail archive represents a monthly json zipped file containing events.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#audit-trail-archive)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct AuditTrailArchive {
+        /// when archive was created
+        pub created_at: DateTime<Utc>,
+        /// month of the archive, e.g. `10`
+        pub month: String,
+        /// year of the archive
+        pub year: i64,
+        /// url where to download the archive
+        pub url: String,
+        /// checksum for the archive
+        pub checksum: String,
+        /// size of the archive in bytes
+        pub size: i64,
+    }
+}
@@ -0,0 +1,172 @@
+//Anything related to PATCH requests for enterprise accounts and it's variations goes here.
+use super::{EnterpriseAccount, EnterpriseAccountMember};
+
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
+
+/// Enterprise Account Update
+///
+/// Update enterprise account properties.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-update)
+///
+/// # Example:
+///
+/// EnterpriseAccountUpdate takes one required parameter, account_id and returns the [`EnterpriseAccount`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &EnterpriseAccountUpdate::new("ACCOUNT_ID")
+///         .name("new-account-name")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccount.html
+pub struct EnterpriseAccountUpdate<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+    /// The parameters to pass to the Heroku API
+    pub params: EnterpriseAccountUpdateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> EnterpriseAccountUpdate<'a> {
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> EnterpriseAccountUpdate<'a> {
+        EnterpriseAccountUpdate {
+            account_id: account_id.into(),
+            params: EnterpriseAccountUpdateParams { name: None },
+        }
+    }
+
+    /// # name: unique name of the enterprise account
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
+        self
+    }
+
+    pub fn build(&self) -> EnterpriseAccountUpdate<'a> {
+        EnterpriseAccountUpdate {
+            account_id: self.account_id.clone(),
+            params: EnterpriseAccountUpdateParams {
+                name: self.params.name.clone(),
+            },
+        }
+    }
+}
+
+/// Update enterprise account properties.
+///
+/// [See Heroku documentation for more information about these optional parameters](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-update-optional-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct EnterpriseAccountUpdateParams<'a> {
+    /// unique name of the enterprise account
+    pub name: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<EnterpriseAccount, (), EnterpriseAccountUpdateParams<'a>>
+    for EnterpriseAccountUpdate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Patch
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts/{}", self.account_id)
+    }
+    fn body(&self) -> Option<EnterpriseAccountUpdateParams<'a>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Enterprise Account Member Update
+///
+/// Update a member in an enterprise account.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-member-update)
+///
+/// # Example:
+///
+/// EnterpriseAccountMemberUpdate takes three required parameters, account_id, member_id and permissions, and returns the [`EnterpriseAccountMember`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&EnterpriseAccountMemberUpdate::new(
+///     "ACCOUNT_ID",
+///     "MEMBER_EMAIL",
+///     vec!["view", "manage"],
+/// ));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccountMember.html
+pub struct EnterpriseAccountMemberUpdate<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+    /// unique email address or identifier of the member
+    pub member_id: Cow<'a, str>,
+    /// The parameters to pass to the Heroku API
+    pub params: EnterpriseAccountMemberUpdateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> EnterpriseAccountMemberUpdate<'a> {
+    pub fn new<P: Into<Cow<'a, str>>>(
+        account_id: impl Into<Cow<'a, str>>,
+        member_id: impl Into<Cow<'a, str>>,
+        permissions: Vec<P>,
+    ) -> EnterpriseAccountMemberUpdate<'a> {
+        EnterpriseAccountMemberUpdate {
+            account_id: account_id.into(),
+            member_id: member_id.into(),
+            params: EnterpriseAccountMemberUpdateParams {
+                permissions: permissions.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+/// Update enterprise account member with parameters
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-member-update-required-parameters)
+#[derive(Serialize, Clone, Debug)]
+pub struct EnterpriseAccountMemberUpdateParams<'a> {
+    /// permissions for enterprise account
+    /// any of:"view" or "create" or "manage" or "billing"
+    pub permissions: Vec<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<EnterpriseAccountMember, (), EnterpriseAccountMemberUpdateParams<'a>>
+    for EnterpriseAccountMemberUpdate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Patch
+    }
+    fn path(&self) -> String {
+        format!(
+            "enterprise-accounts/{}/members/{}",
+            self.account_id, self.member_id
+        )
+    }
+    fn body(&self) -> Option<EnterpriseAccountMemberUpdateParams<'a>> {
+        Some(self.params.clone())
+    }
+}
@@ -0,0 +1,102 @@
+//Anything related to POST requests for enterprise accounts and it's variations goes here.
+use super::EnterpriseAccountMember;
+
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
+
+/// Enterprise Account Member Create
+///
+/// Create a member in an enterprise account.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-member-create)
+///
+/// # Example:
+///
+/// EnterpriseAccountMemberCreate takes three required parameters, account_id, user and permissions, and returns the [`EnterpriseAccountMember`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &EnterpriseAccountMemberCreate::new("ACCOUNT_ID", "USER_EMAIL", vec!["view", "create"])
+///         .federated(true)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.EnterpriseAccountMember.html
+pub struct EnterpriseAccountMemberCreate<'a> {
+    /// unique identifier or name of the enterprise account
+    pub account_id: Cow<'a, str>,
+    /// parameters to pass to Heroku
+    pub params: EnterpriseAccountMemberCreateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> EnterpriseAccountMemberCreate<'a> {
+    /// Only required parameters passed
+    pub fn new<P: Into<Cow<'a, str>>>(
+        account_id: impl Into<Cow<'a, str>>,
+        user: impl Into<Cow<'a, str>>,
+        permissions: Vec<P>,
+    ) -> EnterpriseAccountMemberCreate<'a> {
+        EnterpriseAccountMemberCreate {
+            account_id: account_id.into(),
+            params: EnterpriseAccountMemberCreateParams {
+                user: user.into(),
+                permissions: permissions.into_iter().map(Into::into).collect(),
+                federated: None,
+            },
+        }
+    }
+
+    /// # federated: whether the user is federated and belongs to an Identity Provider
+    pub fn federated(&mut self, federated: bool) -> &mut Self {
+        self.params.federated = Some(federated);
+        self
+    }
+
+    pub fn build(&self) -> EnterpriseAccountMemberCreate<'a> {
+        EnterpriseAccountMemberCreate {
+            account_id: self.account_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Create enterprise account member with parameters
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#enterprise-account-member-create-required-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct EnterpriseAccountMemberCreateParams<'a> {
+    /// unique email address or identifier of an account
+    pub user: Cow<'a, str>,
+    /// permissions for enterprise account
+    /// any of:"view" or "create" or "manage" or "billing"
+    pub permissions: Vec<Cow<'a, str>>,
+    /// whether membership is being created as part of SSO JIT
+    pub federated: Option<bool>,
+}
+
+impl<'a> HerokuEndpoint<EnterpriseAccountMember, (), EnterpriseAccountMemberCreateParams<'a>>
+    for EnterpriseAccountMemberCreate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("enterprise-accounts/{}/members", self.account_id)
+    }
+    fn body(&self) -> Option<EnterpriseAccountMemberCreateParams<'a>> {
+        Some(self.params.clone())
+    }
+}
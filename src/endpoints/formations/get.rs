@@ -2,7 +2,9 @@
 
 use super::Formation;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Formation Info
 ///
@@ -31,17 +33,20 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Formation.html
 pub struct FormationDetails<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
-    /// formation_id can &'a str the formation id or type
-    pub formation_id: &'a str,
+    pub app_id: AppRef<'a>,
+    /// formation_id can Cow<'a, str> the formation id or type
+    pub formation_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> FormationDetails<'a> {
-    pub fn new(app_id: &'a str, formation_id: &'a str) -> FormationDetails<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        formation_id: impl Into<Cow<'a, str>>,
+    ) -> FormationDetails<'a> {
         FormationDetails {
-            app_id,
-            formation_id,
+            app_id: app_id.into(),
+            formation_id: formation_id.into(),
         }
     }
 }
@@ -81,13 +86,13 @@ impl<'a> HerokuEndpoint<Formation> for FormationDetails<'a> {
 /// [response]: ../struct.Formation.html
 pub struct FormationList<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> FormationList<'a> {
-    pub fn new(app_id: &'a str) -> FormationList<'a> {
-        FormationList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> FormationList<'a> {
+        FormationList { app_id: app_id.into() }
     }
 }
 
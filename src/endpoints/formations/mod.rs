@@ -1,4 +1,6 @@
 use crate::framework::response::ApiResult;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use serde::Deserialize;
 
 pub mod delete;
@@ -28,7 +30,7 @@ pub struct Formation {
     /// command to use to launch this process
     pub command: String,
     /// when the process type was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// unique identifier of this process type
     pub id: String,
     /// number of processes to maintain
@@ -38,7 +40,7 @@ pub struct Formation {
     /// type of process to maintain pattern: ^[-\w]{1,128}$
     pub r#type: String,
     /// when dyno type was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// An app represents the program that you would like to deploy and run on Heroku.
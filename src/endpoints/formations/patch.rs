@@ -2,7 +2,9 @@
 
 use super::Formation;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Formation Update
 ///
@@ -36,19 +38,22 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Formation.html
 pub struct FormationUpdate<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// formation_id can be the formation id or type
-    pub formation_id: &'a str,
+    pub formation_id: Cow<'a, str>,
     /// params are the parameters sent to the API to patch the Formation
     pub params: FormationUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> FormationUpdate<'a> {
-    pub fn new(app_id: &'a str, formation_id: &'a str) -> FormationUpdate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        formation_id: impl Into<Cow<'a, str>>,
+    ) -> FormationUpdate<'a> {
         FormationUpdate {
-            app_id,
-            formation_id,
+            app_id: app_id.into(),
+            formation_id: formation_id.into(),
             params: FormationUpdateParams {
                 quantity: None,
                 size: None,
@@ -59,8 +64,8 @@ impl<'a> FormationUpdate<'a> {
     /// # size: dyno size
     ///
     /// `default`: “standard-1X”
-    pub fn size(&mut self, size: &'a str) -> &mut Self {
-        self.params.size = Some(size);
+    pub fn size(&mut self, size: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.size = Some(size.into());
         self
     }
 
@@ -72,11 +77,11 @@ impl<'a> FormationUpdate<'a> {
 
     pub fn build(&self) -> FormationUpdate<'a> {
         FormationUpdate {
-            app_id: self.app_id,
-            formation_id: self.app_id,
+            app_id: self.app_id.clone(),
+            formation_id: self.formation_id.clone(),
             params: FormationUpdateParams {
                 quantity: self.params.quantity,
-                size: self.params.size,
+                size: self.params.size.clone(),
             },
         }
     }
@@ -91,7 +96,7 @@ pub struct FormationUpdateParams<'a> {
     /// number of processes to maintain
     pub quantity: Option<i32>,
     /// dyno size
-    pub size: Option<&'a str>,
+    pub size: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Formation, (), FormationUpdateParams<'a>> for FormationUpdate<'a> {
@@ -0,0 +1,345 @@
+//! Typed references to Heroku resources.
+//!
+//! Most endpoints address a resource either by its id or by its name. These types keep the references of different
+//! resources apart, so an add-on can't be passed where an app is expected, and render as percent-encoded path segments.
+//!
+//! Converting from a `&str` or a `String` is unchecked: a UUID becomes an `Id`, anything else a `Name`.
+//! Use `parse` to check the value against the Heroku naming rules first.
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use serde::ser::{Serialize, Serializer};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// characters escaped in a path segment
+const SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// A dot of a `.` or `..` name, whose `%` is escaped too.
+///
+/// Urls resolve `.`, `..` and their `%2E` encodings as dot-segments, so `apps/..` would reach another endpoint.
+/// The name rules reject them, this only applies to unchecked conversions, which then fail with a not found error.
+const DOT_ESCAPE: &str = "%252E";
+
+fn is_dot_segment(name: &str) -> bool {
+    name == "." || name == ".."
+}
+
+/// A reference that doesn't follow the Heroku rules for its resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidRef {
+    /// the kind of resource, e.g. "app"
+    pub kind: &'static str,
+    /// the rejected value
+    pub value: String,
+    /// the rule the value breaks
+    pub rule: &'static str,
+}
+
+impl fmt::Display for InvalidRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid {} `{}`: {}", self.kind, self.value, self.rule)
+    }
+}
+
+impl Error for InvalidRef {}
+
+macro_rules! named_ref {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $kind:expr, $rule:expr, $is_valid:expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name<'a> {
+            /// unique identifier
+            Id(Uuid),
+            /// unique name
+            Name(Cow<'a, str>),
+        }
+
+        impl<'a> $name<'a> {
+            /// Parse `value` as an id, or as a name following the Heroku naming rules.
+            pub fn parse(value: &'a str) -> Result<Self, InvalidRef> {
+                match Uuid::parse_str(value) {
+                    Ok(id) => Ok($name::Id(id)),
+                    Err(_) => $name::name(value),
+                }
+            }
+
+            /// A reference by name, checked against the Heroku naming rules.
+            pub fn name<N: Into<Cow<'a, str>>>(name: N) -> Result<Self, InvalidRef> {
+                let name = name.into();
+                let is_valid: fn(&str) -> bool = $is_valid;
+                if is_valid(&name) {
+                    Ok($name::Name(name))
+                } else {
+                    Err(InvalidRef {
+                        kind: $kind,
+                        value: name.into_owned(),
+                        rule: $rule,
+                    })
+                }
+            }
+
+            fn unchecked(value: Cow<'a, str>) -> Self {
+                match Uuid::parse_str(&value) {
+                    Ok(id) => $name::Id(id),
+                    Err(_) => $name::Name(value),
+                }
+            }
+
+            /// the id or the name, not encoded
+            pub fn as_str(&self) -> Cow<'_, str> {
+                match self {
+                    $name::Id(id) => Cow::Owned(id.to_string()),
+                    $name::Name(name) => Cow::Borrowed(name),
+                }
+            }
+
+            /// Copy the name if it is borrowed.
+            pub fn into_owned(self) -> $name<'static> {
+                match self {
+                    $name::Id(id) => $name::Id(id),
+                    $name::Name(name) => $name::Name(Cow::Owned(name.into_owned())),
+                }
+            }
+        }
+
+        impl From<Uuid> for $name<'_> {
+            fn from(id: Uuid) -> Self {
+                $name::Id(id)
+            }
+        }
+
+        impl<'a> From<&'a str> for $name<'a> {
+            fn from(value: &'a str) -> Self {
+                $name::unchecked(Cow::Borrowed(value))
+            }
+        }
+
+        impl<'a> From<&'a String> for $name<'a> {
+            fn from(value: &'a String) -> Self {
+                $name::unchecked(Cow::Borrowed(value))
+            }
+        }
+
+        impl From<String> for $name<'static> {
+            fn from(value: String) -> Self {
+                $name::unchecked(Cow::Owned(value))
+            }
+        }
+
+        impl FromStr for $name<'static> {
+            type Err = InvalidRef;
+
+            fn from_str(value: &str) -> Result<Self, InvalidRef> {
+                $name::parse(value).map($name::into_owned)
+            }
+        }
+
+        /// Displays as a percent-encoded path segment.
+        impl fmt::Display for $name<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self {
+                    $name::Id(id) => write!(f, "{}", id),
+                    $name::Name(name) if is_dot_segment(name) => {
+                        write!(f, "{}", DOT_ESCAPE.repeat(name.len()))
+                    }
+                    $name::Name(name) => write!(f, "{}", utf8_percent_encode(name, SEGMENT)),
+                }
+            }
+        }
+
+        impl Serialize for $name<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.as_str())
+            }
+        }
+    };
+}
+
+named_ref! {
+    /// An app, by id or by name.
+    AppRef,
+    "app",
+    "must be 3 to 30 lowercase letters, digits or dashes, starting with a letter and not ending with a dash",
+    |name| {
+        let bytes = name.as_bytes();
+        (3..=30).contains(&bytes.len())
+            && bytes[0].is_ascii_lowercase()
+            && bytes[bytes.len() - 1] != b'-'
+            && bytes.iter().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+    }
+}
+
+named_ref! {
+    /// An add-on, by id or by name.
+    AddonRef,
+    "add-on",
+    "must be letters, digits, dashes or underscores, starting with a letter",
+    |name| {
+        let bytes = name.as_bytes();
+        bytes.len() >= 2
+            && bytes[0].is_ascii_alphabetic()
+            && bytes.iter().all(|b| b.is_ascii_alphanumeric() || *b == b'-' || *b == b'_')
+    }
+}
+
+named_ref! {
+    /// A pipeline, by id or by name.
+    PipelineRef,
+    "pipeline",
+    "must be 3 to 30 lowercase letters, digits or dashes, starting with a letter",
+    |name| {
+        let bytes = name.as_bytes();
+        (3..=30).contains(&bytes.len())
+            && bytes[0].is_ascii_lowercase()
+            && bytes.iter().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+    }
+}
+
+named_ref! {
+    /// A team, by id or by name.
+    TeamRef,
+    "team",
+    "must be 1 to 30 lowercase letters, digits or dashes, not starting or ending with a dash",
+    |name| {
+        let bytes = name.as_bytes();
+        (1..=30).contains(&bytes.len())
+            && bytes[0] != b'-'
+            && bytes[bytes.len() - 1] != b'-'
+            && bytes.iter().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+    }
+}
+
+/// A release, by id or by version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReleaseRef {
+    /// unique identifier
+    Id(Uuid),
+    /// version of the release in its app
+    Version(u64),
+}
+
+impl From<Uuid> for ReleaseRef {
+    fn from(id: Uuid) -> Self {
+        ReleaseRef::Id(id)
+    }
+}
+
+impl From<u64> for ReleaseRef {
+    fn from(version: u64) -> Self {
+        ReleaseRef::Version(version)
+    }
+}
+
+/// Parses an id, or a version with or without its `v` prefix, e.g. `v12`.
+impl FromStr for ReleaseRef {
+    type Err = InvalidRef;
+
+    fn from_str(value: &str) -> Result<Self, InvalidRef> {
+        if let Ok(id) = Uuid::parse_str(value) {
+            return Ok(ReleaseRef::Id(id));
+        }
+        let version = value.strip_prefix('v').unwrap_or(value);
+        version
+            .parse()
+            .map(ReleaseRef::Version)
+            .map_err(|_| InvalidRef {
+                kind: "release",
+                value: value.to_string(),
+                rule: "must be a release id or a version number",
+            })
+    }
+}
+
+impl fmt::Display for ReleaseRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReleaseRef::Id(id) => write!(f, "{}", id),
+            ReleaseRef::Version(version) => write!(f, "{}", version),
+        }
+    }
+}
+
+impl Serialize for ReleaseRef {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_app() {
+        let id = "01234567-89ab-cdef-0123-456789abcdef";
+        assert_eq!(
+            AppRef::parse(id).unwrap(),
+            AppRef::Id(Uuid::parse_str(id).unwrap())
+        );
+        assert_eq!(
+            AppRef::parse("my-app-2").unwrap(),
+            AppRef::Name("my-app-2".into())
+        );
+        assert!(AppRef::parse("My_App").is_err());
+        assert!(AppRef::parse("my-app-").is_err());
+        assert!(AppRef::parse("ab").is_err());
+        assert!(PipelineRef::parse("my-pipeline").is_ok());
+        assert_eq!(
+            AddonRef::parse("postgresql curved").unwrap_err().to_string(),
+            "invalid add-on `postgresql curved`: must be letters, digits, dashes or underscores, starting with a letter"
+        );
+    }
+
+    #[test]
+    fn test_unchecked_names_are_encoded() {
+        let addon = AddonRef::from("DATABASE/../x y");
+        assert_eq!(addon.to_string(), "DATABASE%2F..%2Fx%20y");
+        assert_eq!(addon.as_str(), "DATABASE/../x y");
+        assert_eq!(
+            serde_json::to_string(&TeamRef::from("acme")).unwrap(),
+            "\"acme\""
+        );
+    }
+
+    #[test]
+    fn test_dot_segments_stay_in_their_segment() {
+        assert!(AppRef::parse("..").is_err());
+        assert!(TeamRef::parse(".").is_err());
+        assert!(AddonRef::name("..").is_err());
+
+        let app = AppRef::from("..");
+        assert_eq!(app.to_string(), "%252E%252E");
+        let url = url::Url::parse("https://api.heroku.com/")
+            .unwrap()
+            .join(&format!("apps/{}/releases", app))
+            .unwrap();
+        assert_eq!(url.path(), "/apps/%252E%252E/releases");
+        assert_eq!(TeamRef::from(".").to_string(), "%252E");
+        assert_eq!(AddonRef::from("...").to_string(), "...");
+    }
+
+    #[test]
+    fn test_release_ref() {
+        assert_eq!("v12".parse::<ReleaseRef>(), Ok(ReleaseRef::Version(12)));
+        assert_eq!("12".parse::<ReleaseRef>(), Ok(ReleaseRef::Version(12)));
+        assert!("latest".parse::<ReleaseRef>().is_err());
+        assert_eq!(ReleaseRef::from(3).to_string(), "3");
+    }
+}
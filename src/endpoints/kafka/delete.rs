@@ -0,0 +1,138 @@
+//Anything related to DELETE requests for Kafka and it's variations goes here.
+use super::KafkaConsumerGroupParams;
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Kafka Topic Delete
+///
+/// Delete a topic of a Kafka cluster, as `heroku kafka:topics:destroy` does. Its messages are lost.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+///
+/// # Example:
+///
+/// KafkaTopicDelete takes two required parameters, addon_id and topic_name, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KafkaTopicDelete::new("ADDON_ID", "orders"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct KafkaTopicDelete<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+    /// name of the topic
+    pub topic_name: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaTopicDelete<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        topic_name: impl Into<Cow<'a, str>>,
+    ) -> KafkaTopicDelete<'a> {
+        KafkaTopicDelete {
+            addon_id: addon_id.into(),
+            topic_name: topic_name.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value> for KafkaTopicDelete<'a> {
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+    fn path(&self) -> String {
+        format!(
+            "data/kafka/v0/clusters/{}/topics/{}",
+            self.addon_id, self.topic_name
+        )
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
+
+/// Kafka Consumer Group Delete
+///
+/// Delete a consumer group of a multi-tenant Kafka cluster, as `heroku kafka:consumer-groups:destroy` does.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/multi-tenant-kafka-on-heroku#consumer-groups)
+///
+/// # Example:
+///
+/// KafkaConsumerGroupDelete takes two required parameters, addon_id and name, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KafkaConsumerGroupDelete::new("ADDON_ID", "billing"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct KafkaConsumerGroupDelete<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: KafkaConsumerGroupDeleteParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaConsumerGroupDelete<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> KafkaConsumerGroupDelete<'a> {
+        KafkaConsumerGroupDelete {
+            addon_id: addon_id.into(),
+            params: KafkaConsumerGroupDeleteParams {
+                consumer_group: KafkaConsumerGroupParams { name: name.into() },
+            },
+        }
+    }
+}
+
+/// Delete a consumer group with parameters. The API names the group in the body, not the path.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/multi-tenant-kafka-on-heroku#consumer-groups)
+#[derive(Serialize, Clone, Debug)]
+pub struct KafkaConsumerGroupDeleteParams<'a> {
+    /// the consumer group to delete
+    pub consumer_group: KafkaConsumerGroupParams<'a>,
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), KafkaConsumerGroupDeleteParams<'a>>
+    for KafkaConsumerGroupDelete<'a>
+{
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+    fn path(&self) -> String {
+        format!("data/kafka/v0/clusters/{}/consumer_groups", self.addon_id)
+    }
+    fn body(&self) -> Option<KafkaConsumerGroupDeleteParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
@@ -0,0 +1,169 @@
+//Anything related to GET requests for Kafka and it's variations goes here.
+use super::{KafkaConsumerGroups, KafkaTopic, KafkaTopics};
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Kafka Topic List
+///
+/// List the topics of a Kafka cluster, as `heroku kafka:topics` does.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+///
+/// # Example:
+///
+/// KafkaTopicList takes one required parameter, addon_id, and returns the [`KafkaTopics`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KafkaTopicList::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.KafkaTopics.html
+pub struct KafkaTopicList<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaTopicList<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> KafkaTopicList<'a> {
+        KafkaTopicList {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<KafkaTopics> for KafkaTopicList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("data/kafka/v0/clusters/{}/topics", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
+
+/// Kafka Topic Info
+///
+/// Info for a topic of a Kafka cluster, as `heroku kafka:topics:info` shows.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+///
+/// # Example:
+///
+/// KafkaTopicDetails takes two required parameters, addon_id and topic_name, and returns the [`KafkaTopic`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KafkaTopicDetails::new("ADDON_ID", "orders"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.KafkaTopic.html
+pub struct KafkaTopicDetails<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+    /// name of the topic
+    pub topic_name: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaTopicDetails<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        topic_name: impl Into<Cow<'a, str>>,
+    ) -> KafkaTopicDetails<'a> {
+        KafkaTopicDetails {
+            addon_id: addon_id.into(),
+            topic_name: topic_name.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<KafkaTopic> for KafkaTopicDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!(
+            "data/kafka/v0/clusters/{}/topics/{}",
+            self.addon_id, self.topic_name
+        )
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
+
+/// Kafka Consumer Group List
+///
+/// List the consumer groups of a multi-tenant Kafka cluster.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/multi-tenant-kafka-on-heroku#consumer-groups)
+///
+/// # Example:
+///
+/// KafkaConsumerGroupList takes one required parameter, addon_id, and returns the [`KafkaConsumerGroups`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KafkaConsumerGroupList::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.KafkaConsumerGroups.html
+pub struct KafkaConsumerGroupList<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaConsumerGroupList<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> KafkaConsumerGroupList<'a> {
+        KafkaConsumerGroupList {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<KafkaConsumerGroups> for KafkaConsumerGroupList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("data/kafka/v0/clusters/{}/consumer_groups", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::framework::response::ApiResult;
+
+pub mod delete;
+pub mod get;
+pub mod post;
+pub mod put;
+
+pub use delete::{KafkaConsumerGroupDelete, KafkaConsumerGroupDeleteParams, KafkaTopicDelete};
+pub use get::{KafkaConsumerGroupList, KafkaTopicDetails, KafkaTopicList};
+pub use post::{
+    KafkaConsumerGroupCreate, KafkaConsumerGroupCreateParams, KafkaConsumerGroupParams,
+    KafkaCredentialRotate, KafkaTopicCreate, KafkaTopicCreateParams, KafkaTopicParams,
+};
+pub use put::{KafkaTopicUpdate, KafkaTopicUpdateParams};
+
+impl ApiResult for KafkaTopic {}
+impl ApiResult for KafkaTopics {}
+
+impl ApiResult for KafkaConsumerGroups {}
+
+pub use consumer_group::{KafkaConsumerGroup, KafkaConsumerGroups};
+pub use topic::{KafkaTopic, KafkaTopics};
+
+mod topic {
+    /// Kafka Topic
+    ///
+    /// A topic of an Apache Kafka on Heroku cluster.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct KafkaTopic {
+        /// name of the topic
+        pub name: String,
+        /// number of partitions of the topic
+        pub partitions: Option<i64>,
+        /// number of replicas of each partition
+        pub replication_factor: Option<i64>,
+        /// how long messages are kept, in milliseconds
+        pub retention_time_ms: Option<i64>,
+        /// whether the topic is compacted, keeping the latest message of each key
+        pub compaction: Option<bool>,
+        /// messages produced per second
+        pub messages_in_per_second: Option<f64>,
+        /// bytes produced per second
+        pub bytes_in_per_second: Option<f64>,
+        /// bytes consumed per second
+        pub bytes_out_per_second: Option<f64>,
+    }
+
+    /// Kafka Topics
+    ///
+    /// The topics of an Apache Kafka on Heroku cluster.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct KafkaTopics {
+        /// name of the attachment of the cluster, e.g. `KAFKA`
+        pub attachment_name: Option<String>,
+        /// topics of the cluster
+        pub topics: Vec<KafkaTopic>,
+    }
+}
+
+mod consumer_group {
+    /// Kafka Consumer Group
+    ///
+    /// A consumer group of an Apache Kafka on Heroku cluster. Multi-tenant plans need consumer groups to be created
+    /// before they are used.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/multi-tenant-kafka-on-heroku#consumer-groups)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct KafkaConsumerGroup {
+        /// name of the consumer group
+        pub name: String,
+    }
+
+    /// Kafka Consumer Groups
+    ///
+    /// The consumer groups of an Apache Kafka on Heroku cluster.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/multi-tenant-kafka-on-heroku#consumer-groups)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct KafkaConsumerGroups {
+        /// consumer groups of the cluster
+        pub consumer_groups: Vec<KafkaConsumerGroup>,
+    }
+}
@@ -0,0 +1,271 @@
+//Anything related to POST requests for Kafka and it's variations goes here.
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Kafka Topic Create
+///
+/// Create a topic on a Kafka cluster, as `heroku kafka:topics:create` does.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+///
+/// # Example:
+///
+/// KafkaTopicCreate takes two required parameters, addon_id and topic_name, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &KafkaTopicCreate::new("ADDON_ID", "orders")
+///         .partition_count(8)
+///         .retention_time_ms(86_400_000)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct KafkaTopicCreate<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: KafkaTopicCreateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaTopicCreate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        topic_name: impl Into<Cow<'a, str>>,
+    ) -> KafkaTopicCreate<'a> {
+        KafkaTopicCreate {
+            addon_id: addon_id.into(),
+            params: KafkaTopicCreateParams {
+                topic: KafkaTopicParams {
+                    name: topic_name.into(),
+                    partition_count: None,
+                    replication_factor: None,
+                    retention_time_ms: None,
+                    compaction: None,
+                },
+            },
+        }
+    }
+
+    /// # partition_count: number of partitions of the topic, defaults to the plan's default
+    pub fn partition_count(&mut self, partition_count: i64) -> &mut Self {
+        self.params.topic.partition_count = Some(partition_count);
+        self
+    }
+
+    /// # replication_factor: number of replicas of each partition
+    pub fn replication_factor(&mut self, replication_factor: i64) -> &mut Self {
+        self.params.topic.replication_factor = Some(replication_factor);
+        self
+    }
+
+    /// # retention_time_ms: how long messages are kept, in milliseconds
+    pub fn retention_time_ms(&mut self, retention_time_ms: i64) -> &mut Self {
+        self.params.topic.retention_time_ms = Some(retention_time_ms);
+        self
+    }
+
+    /// # compaction: keep only the latest message of each key
+    pub fn compaction(&mut self, compaction: bool) -> &mut Self {
+        self.params.topic.compaction = Some(compaction);
+        self
+    }
+
+    pub fn build(&self) -> KafkaTopicCreate<'a> {
+        KafkaTopicCreate {
+            addon_id: self.addon_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Create a new topic with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+#[derive(Serialize, Clone, Debug)]
+pub struct KafkaTopicCreateParams<'a> {
+    /// the topic to create
+    pub topic: KafkaTopicParams<'a>,
+}
+
+/// The settings of a topic, sent when creating or configuring it.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct KafkaTopicParams<'a> {
+    /// name of the topic
+    pub name: Cow<'a, str>,
+    /// number of partitions of the topic, only set on creation
+    pub partition_count: Option<i64>,
+    /// number of replicas of each partition
+    pub replication_factor: Option<i64>,
+    /// how long messages are kept, in milliseconds
+    pub retention_time_ms: Option<i64>,
+    /// whether the topic is compacted, keeping the latest message of each key
+    pub compaction: Option<bool>,
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), KafkaTopicCreateParams<'a>>
+    for KafkaTopicCreate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("data/kafka/v0/clusters/{}/topics", self.addon_id)
+    }
+    fn body(&self) -> Option<KafkaTopicCreateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
+
+/// Kafka Consumer Group Create
+///
+/// Create a consumer group on a multi-tenant Kafka cluster, as `heroku kafka:consumer-groups:create` does.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/multi-tenant-kafka-on-heroku#consumer-groups)
+///
+/// # Example:
+///
+/// KafkaConsumerGroupCreate takes two required parameters, addon_id and name, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KafkaConsumerGroupCreate::new("ADDON_ID", "billing"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct KafkaConsumerGroupCreate<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: KafkaConsumerGroupCreateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaConsumerGroupCreate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> KafkaConsumerGroupCreate<'a> {
+        KafkaConsumerGroupCreate {
+            addon_id: addon_id.into(),
+            params: KafkaConsumerGroupCreateParams {
+                consumer_group: KafkaConsumerGroupParams { name: name.into() },
+            },
+        }
+    }
+}
+
+/// Create a new consumer group with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/multi-tenant-kafka-on-heroku#consumer-groups)
+#[derive(Serialize, Clone, Debug)]
+pub struct KafkaConsumerGroupCreateParams<'a> {
+    /// the consumer group to create
+    pub consumer_group: KafkaConsumerGroupParams<'a>,
+}
+
+/// The consumer group a request is about.
+#[derive(Serialize, Clone, Debug)]
+pub struct KafkaConsumerGroupParams<'a> {
+    /// name of the consumer group
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), KafkaConsumerGroupCreateParams<'a>>
+    for KafkaConsumerGroupCreate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("data/kafka/v0/clusters/{}/consumer_groups", self.addon_id)
+    }
+    fn body(&self) -> Option<KafkaConsumerGroupCreateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
+
+/// Kafka Credential Rotate
+///
+/// Rotate the client certificates of a Kafka cluster, as `heroku kafka:credentials --reset` does. The config vars of
+/// the attached apps are updated once the new credentials are ready.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/kafka-on-heroku#rotating-credentials)
+///
+/// # Example:
+///
+/// KafkaCredentialRotate takes one required parameter, addon_id, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KafkaCredentialRotate::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct KafkaCredentialRotate<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaCredentialRotate<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> KafkaCredentialRotate<'a> {
+        KafkaCredentialRotate {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value> for KafkaCredentialRotate<'a> {
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!(
+            "data/kafka/v0/clusters/{}/rotate-credentials",
+            self.addon_id
+        )
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
@@ -0,0 +1,119 @@
+//Anything related to PUT requests for Kafka and it's variations goes here.
+use super::KafkaTopicParams;
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Kafka Topic Update
+///
+/// Configure an existing topic of a Kafka cluster, as `heroku kafka:topics:retention-time`,
+/// `heroku kafka:topics:compaction` and `heroku kafka:topics:replication-factor` do.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+///
+/// # Example:
+///
+/// KafkaTopicUpdate takes two required parameters, addon_id and topic_name, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &KafkaTopicUpdate::new("ADDON_ID", "orders")
+///         .retention_time_ms(3_600_000)
+///         .compaction(false)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct KafkaTopicUpdate<'a> {
+    /// unique identifier of the Kafka add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: KafkaTopicUpdateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KafkaTopicUpdate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        topic_name: impl Into<Cow<'a, str>>,
+    ) -> KafkaTopicUpdate<'a> {
+        KafkaTopicUpdate {
+            addon_id: addon_id.into(),
+            params: KafkaTopicUpdateParams {
+                topic: KafkaTopicParams {
+                    name: topic_name.into(),
+                    partition_count: None,
+                    replication_factor: None,
+                    retention_time_ms: None,
+                    compaction: None,
+                },
+            },
+        }
+    }
+
+    /// # replication_factor: number of replicas of each partition
+    pub fn replication_factor(&mut self, replication_factor: i64) -> &mut Self {
+        self.params.topic.replication_factor = Some(replication_factor);
+        self
+    }
+
+    /// # retention_time_ms: how long messages are kept, in milliseconds
+    pub fn retention_time_ms(&mut self, retention_time_ms: i64) -> &mut Self {
+        self.params.topic.retention_time_ms = Some(retention_time_ms);
+        self
+    }
+
+    /// # compaction: keep only the latest message of each key
+    pub fn compaction(&mut self, compaction: bool) -> &mut Self {
+        self.params.topic.compaction = Some(compaction);
+        self
+    }
+
+    pub fn build(&self) -> KafkaTopicUpdate<'a> {
+        KafkaTopicUpdate {
+            addon_id: self.addon_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Update a topic with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/kafka-on-heroku#managing-kafka-topics)
+#[derive(Serialize, Clone, Debug)]
+pub struct KafkaTopicUpdateParams<'a> {
+    /// the new settings of the topic, unset settings are left as they are
+    pub topic: KafkaTopicParams<'a>,
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), KafkaTopicUpdateParams<'a>>
+    for KafkaTopicUpdate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Put
+    }
+    fn path(&self) -> String {
+        format!(
+            "data/kafka/v0/clusters/{}/topics/{}",
+            self.addon_id, self.params.topic.name
+        )
+    }
+    fn body(&self) -> Option<KafkaTopicUpdateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Kafka)
+    }
+}
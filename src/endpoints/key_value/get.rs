@@ -0,0 +1,108 @@
+//Anything related to GET requests for Key-Value Store and it's variations goes here.
+use super::{KeyValueMaintenance, KeyValueStore};
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+
+/// Key-Value Store Info
+///
+/// Info for an existing Key-Value Store instance, as `heroku redis:info` shows.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-redis#viewing-instance-information)
+///
+/// # Example:
+///
+/// KeyValueDetails takes one required parameter, addon_id, and returns the [`KeyValueStore`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KeyValueDetails::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.KeyValueStore.html
+pub struct KeyValueDetails<'a> {
+    /// unique identifier of the Key-Value Store add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KeyValueDetails<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> KeyValueDetails<'a> {
+        KeyValueDetails {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<KeyValueStore> for KeyValueDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("redis/v0/databases/{}", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::KeyValue)
+    }
+}
+
+/// Key-Value Store Maintenance Info
+///
+/// Maintenance status of an existing Key-Value Store instance, as `heroku redis:maintenance` shows.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-redis-maintenance)
+///
+/// # Example:
+///
+/// KeyValueMaintenanceDetails takes one required parameter, addon_id, and returns the [`KeyValueMaintenance`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KeyValueMaintenanceDetails::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.KeyValueMaintenance.html
+pub struct KeyValueMaintenanceDetails<'a> {
+    /// unique identifier of the Key-Value Store add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KeyValueMaintenanceDetails<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> KeyValueMaintenanceDetails<'a> {
+        KeyValueMaintenanceDetails {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<KeyValueMaintenance> for KeyValueMaintenanceDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("redis/v0/databases/{}/maintenance", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::KeyValue)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::framework::response::ApiResult;
+
+pub mod get;
+pub mod patch;
+pub mod post;
+pub mod put;
+
+pub use get::{KeyValueDetails, KeyValueMaintenanceDetails};
+pub use patch::{KeyValueConfigUpdate, KeyValueConfigUpdateParams};
+pub use post::KeyValueMaintenanceRun;
+pub use put::{KeyValueMaintenanceWindowUpdate, KeyValueMaintenanceWindowUpdateParams};
+
+impl ApiResult for KeyValueStore {}
+
+impl ApiResult for KeyValueMaintenance {}
+
+impl ApiResult for KeyValueConfig {}
+
+pub use config::{KeyValueConfig, KeyValueConfigValue};
+pub use maintenance::KeyValueMaintenance;
+pub use store::{KeyValueInfo, KeyValueStore};
+
+mod store {
+    /// Key-Value Store
+    ///
+    /// A Heroku Key-Value Store instance, as shown by `heroku redis:info`.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-redis)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct KeyValueStore {
+        /// globally unique name of the add-on
+        pub name: Option<String>,
+        /// name of the plan, e.g. `premium-0`
+        pub plan: Option<String>,
+        /// url of the instance on the data API
+        pub resource_url: Option<String>,
+        /// whether clients must connect over TLS
+        pub prefer_native_tls: Option<bool>,
+        /// information about the instance, e.g. its version, maxmemory policy or timeout
+        pub info: Vec<KeyValueInfo>,
+    }
+
+    /// information about the instance
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct KeyValueInfo {
+        /// name of the information, e.g. `Version` or `Maxmemory`
+        pub name: String,
+        /// values of the information
+        pub values: Vec<serde_json::Value>,
+    }
+
+    impl KeyValueStore {
+        /// The values of the information `name`, e.g. `Version`, formatted as text.
+        pub fn info(&self, name: &str) -> Vec<String> {
+            self.info
+                .iter()
+                .filter(|info| info.name == name)
+                .flat_map(|info| info.values.iter())
+                .map(|value| match value {
+                    serde_json::Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                })
+                .collect()
+        }
+    }
+}
+
+mod maintenance {
+    /// Key-Value Store Maintenance
+    ///
+    /// The maintenance status of a Key-Value Store instance.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-redis-maintenance)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct KeyValueMaintenance {
+        /// status of the maintenance, e.g. `No maintenance required`
+        pub message: String,
+    }
+}
+
+mod config {
+    /// Key-Value Store Config
+    ///
+    /// The configurable settings of a Key-Value Store instance, as changed by `heroku redis:timeout` and
+    /// `heroku redis:maxmemory`. Only the settings that were sent are returned.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-redis#configuring-your-instance)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct KeyValueConfig {
+        /// seconds an idle connection is kept open, `0` keeps them open
+        pub timeout: Option<KeyValueConfigValue>,
+        /// how keys are evicted once the instance is full, e.g. `noeviction`
+        pub maxmemory_policy: Option<KeyValueConfigValue>,
+    }
+
+    /// value of a setting
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct KeyValueConfigValue {
+        /// current value of the setting
+        pub value: serde_json::Value,
+        /// description of the current value
+        pub desc: Option<String>,
+    }
+}
@@ -0,0 +1,106 @@
+//Anything related to PATCH requests for Key-Value Store and it's variations goes here.
+use super::KeyValueConfig;
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Key-Value Store Config Update
+///
+/// Change the idle connection timeout or the eviction policy of an existing Key-Value Store instance, as
+/// `heroku redis:timeout` and `heroku redis:maxmemory` do.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-redis#configuring-your-instance)
+///
+/// # Example:
+///
+/// KeyValueConfigUpdate takes one required parameter, addon_id, and returns the [`KeyValueConfig`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &KeyValueConfigUpdate::new("ADDON_ID")
+///         .timeout(300)
+///         .maxmemory_policy("allkeys-lru")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.KeyValueConfig.html
+pub struct KeyValueConfigUpdate<'a> {
+    /// unique identifier of the Key-Value Store add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: KeyValueConfigUpdateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KeyValueConfigUpdate<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> KeyValueConfigUpdate<'a> {
+        KeyValueConfigUpdate {
+            addon_id: addon_id.into(),
+            params: KeyValueConfigUpdateParams {
+                timeout: None,
+                maxmemory_policy: None,
+            },
+        }
+    }
+
+    /// # timeout: seconds an idle connection is kept open, `0` keeps them open
+    pub fn timeout(&mut self, timeout: u64) -> &mut Self {
+        self.params.timeout = Some(timeout);
+        self
+    }
+
+    /// # maxmemory_policy: how keys are evicted once the instance is full, one of `noeviction`, `allkeys-lru`, `volatile-lru`, `allkeys-random`, `volatile-random`, `volatile-ttl`, `allkeys-lfu` or `volatile-lfu`
+    pub fn maxmemory_policy(&mut self, maxmemory_policy: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.maxmemory_policy = Some(maxmemory_policy.into());
+        self
+    }
+
+    pub fn build(&self) -> KeyValueConfigUpdate<'a> {
+        KeyValueConfigUpdate {
+            addon_id: self.addon_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Update the config with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/heroku-redis#configuring-your-instance)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct KeyValueConfigUpdateParams<'a> {
+    /// seconds an idle connection is kept open, `0` keeps them open
+    pub timeout: Option<u64>,
+    /// how keys are evicted once the instance is full, e.g. `noeviction`
+    pub maxmemory_policy: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<KeyValueConfig, (), KeyValueConfigUpdateParams<'a>>
+    for KeyValueConfigUpdate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Patch
+    }
+    fn path(&self) -> String {
+        format!("redis/v0/databases/{}/config", self.addon_id)
+    }
+    fn body(&self) -> Option<KeyValueConfigUpdateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::KeyValue)
+    }
+}
@@ -0,0 +1,56 @@
+//Anything related to POST requests for Key-Value Store and it's variations goes here.
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+
+/// Key-Value Store Maintenance Run
+///
+/// Start the pending maintenance of an existing Key-Value Store instance, as `heroku redis:maintenance --run` does.
+/// The app should be in maintenance mode first.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-redis-maintenance)
+///
+/// # Example:
+///
+/// KeyValueMaintenanceRun takes one required parameter, addon_id, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KeyValueMaintenanceRun::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct KeyValueMaintenanceRun<'a> {
+    /// unique identifier of the Key-Value Store add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KeyValueMaintenanceRun<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> KeyValueMaintenanceRun<'a> {
+        KeyValueMaintenanceRun {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value> for KeyValueMaintenanceRun<'a> {
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("redis/v0/databases/{}/maintenance", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::KeyValue)
+    }
+}
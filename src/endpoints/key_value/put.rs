@@ -0,0 +1,78 @@
+//Anything related to PUT requests for Key-Value Store and it's variations goes here.
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Key-Value Store Maintenance Window Update
+///
+/// Set the weekly maintenance window of an existing Key-Value Store instance, as `heroku redis:maintenance --window` does.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-redis-maintenance#changing-the-maintenance-window)
+///
+/// # Example:
+///
+/// KeyValueMaintenanceWindowUpdate takes two required parameters, addon_id and description, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&KeyValueMaintenanceWindowUpdate::new("ADDON_ID", "Sunday 14:30"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct KeyValueMaintenanceWindowUpdate<'a> {
+    /// unique identifier of the Key-Value Store add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: KeyValueMaintenanceWindowUpdateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> KeyValueMaintenanceWindowUpdate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        description: impl Into<Cow<'a, str>>,
+    ) -> KeyValueMaintenanceWindowUpdate<'a> {
+        KeyValueMaintenanceWindowUpdate {
+            addon_id: addon_id.into(),
+            params: KeyValueMaintenanceWindowUpdateParams {
+                description: description.into(),
+            },
+        }
+    }
+}
+
+/// Update the maintenance window with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/heroku-redis-maintenance#changing-the-maintenance-window)
+#[derive(Serialize, Clone, Debug)]
+pub struct KeyValueMaintenanceWindowUpdateParams<'a> {
+    /// start of the weekly window, a day and a UTC time, e.g. `Sunday 14:30`
+    pub description: Cow<'a, str>,
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), KeyValueMaintenanceWindowUpdateParams<'a>>
+    for KeyValueMaintenanceWindowUpdate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Put
+    }
+    fn path(&self) -> String {
+        format!("redis/v0/databases/{}/maintenance_window", self.addon_id)
+    }
+    fn body(&self) -> Option<KeyValueMaintenanceWindowUpdateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::KeyValue)
+    }
+}
@@ -1,7 +1,9 @@
 //Anything related to DELETE requests for heroku logs and it's properties goes here.
 use super::LogDrain;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Log Drain Delete
 ///
@@ -30,15 +32,18 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.LogDrain.html
 pub struct LogDrainDelete<'a> {
     /// unique app identifier, either app name, or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique log drain identifier, either drain id, url or token
-    pub drain_id: &'a str,
+    pub drain_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> LogDrainDelete<'a> {
-    pub fn new(app_id: &'a str, drain_id: &'a str) -> LogDrainDelete<'a> {
-        LogDrainDelete { app_id, drain_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        drain_id: impl Into<Cow<'a, str>>,
+    ) -> LogDrainDelete<'a> {
+        LogDrainDelete { app_id: app_id.into(), drain_id: drain_id.into() }
     }
 }
 
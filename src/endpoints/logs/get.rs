@@ -1,7 +1,9 @@
 //Anything related to GET requests for heroku logs and it's properties goes here.
 use super::LogDrain;
 
+use crate::endpoints::ids::{AddonRef, AppRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Log Drain List
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.LogDrain.html
 pub struct LogDrainList<'a> {
     /// unique app identifier, either app name, or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> LogDrainList<'a> {
-    pub fn new(app_id: &'a str) -> LogDrainList {
-        LogDrainList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> LogDrainList<'a> {
+        LogDrainList { app_id: app_id.into() }
     }
 }
 
@@ -76,15 +78,18 @@ impl<'a> HerokuEndpoint<Vec<LogDrain>> for LogDrainList<'a> {
 /// [response]: ../struct.LogDrain.html
 pub struct LogDrainDetails<'a> {
     /// unique app identifier, either app name, or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique log drain identifier, either drain id, url or token
-    pub drain_id: &'a str,
+    pub drain_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> LogDrainDetails<'a> {
-    pub fn new(app_id: &'a str, drain_id: &'a str) -> LogDrainDetails<'a> {
-        LogDrainDetails { app_id, drain_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        drain_id: impl Into<Cow<'a, str>>,
+    ) -> LogDrainDetails<'a> {
+        LogDrainDetails { app_id: app_id.into(), drain_id: drain_id.into() }
     }
 }
 
@@ -124,13 +129,13 @@ impl<'a> HerokuEndpoint<LogDrain> for LogDrainDetails<'a> {
 /// [response]: ../struct.LogDrain.html
 pub struct LogDrainListByAddon<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> LogDrainListByAddon<'a> {
-    pub fn new(addon_id: &'a str) -> LogDrainListByAddon<'a> {
-        LogDrainListByAddon { addon_id }
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> LogDrainListByAddon<'a> {
+        LogDrainListByAddon { addon_id: addon_id.into() }
     }
 }
 
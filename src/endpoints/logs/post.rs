@@ -1,7 +1,9 @@
 //Anything related to POST requests for heroku logs and it's properties goes here.
 use super::{LogDrain, LogSession};
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Log Drain Create
 ///
@@ -31,17 +33,17 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.LogDrain.html
 pub struct LogDrainCreate<'a> {
     /// unique app identifier, either app name, or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: LogDrainCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> LogDrainCreate<'a> {
-    pub fn new(app_id: &'a str, url: &'a str) -> LogDrainCreate<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>, url: impl Into<Cow<'a, str>>) -> LogDrainCreate<'a> {
         LogDrainCreate {
-            app_id,
-            params: LogDrainCreateParams { url },
+            app_id: app_id.into(),
+            params: LogDrainCreateParams { url: url.into() },
         }
     }
 }
@@ -52,7 +54,7 @@ impl<'a> LogDrainCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct LogDrainCreateParams<'a> {
     /// url associated with the log drain
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<LogDrain, (), LogDrainCreateParams<'a>> for LogDrainCreate<'a> {
@@ -101,7 +103,7 @@ impl<'a> HerokuEndpoint<LogDrain, (), LogDrainCreateParams<'a>> for LogDrainCrea
 /// [response]: ../struct.LogSession.html
 pub struct LogSessionCreate<'a> {
     /// unique app identifier, either app name, or app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: LogSessionCreateParams<'a>,
 }
@@ -109,9 +111,9 @@ pub struct LogSessionCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> LogSessionCreate<'a> {
     /// Create a new log session with required parameters
-    pub fn new(app_id: &'a str) -> LogSessionCreate<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> LogSessionCreate<'a> {
         LogSessionCreate {
-            app_id,
+            app_id: app_id.into(),
             params: LogSessionCreateParams {
                 dyno: None,
                 lines: None,
@@ -122,8 +124,8 @@ impl<'a> LogSessionCreate<'a> {
     }
 
     /// # dyno: dyno to limit results to
-    pub fn dyno(&mut self, dyno: &'a str) -> &mut Self {
-        self.params.dyno = Some(dyno);
+    pub fn dyno(&mut self, dyno: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.dyno = Some(dyno.into());
         self
     }
     /// # lines: number of log lines to stream at once
@@ -132,8 +134,8 @@ impl<'a> LogSessionCreate<'a> {
         self
     }
     /// # source: log source to limit results to
-    pub fn source(&mut self, source: &'a str) -> &mut Self {
-        self.params.source = Some(source);
+    pub fn source(&mut self, source: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.source = Some(source.into());
         self
     }
     /// # tail: whether to stream ongoing logs
@@ -144,11 +146,11 @@ impl<'a> LogSessionCreate<'a> {
 
     pub fn build(&self) -> LogSessionCreate<'a> {
         LogSessionCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: LogSessionCreateParams {
-                dyno: self.params.dyno,
+                dyno: self.params.dyno.clone(),
                 lines: self.params.lines,
-                source: self.params.source,
+                source: self.params.source.clone(),
                 tail: self.params.tail,
             },
         }
@@ -161,11 +163,11 @@ impl<'a> LogSessionCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct LogSessionCreateParams<'a> {
     /// dyno to limit results to
-    pub dyno: Option<&'a str>,
+    pub dyno: Option<Cow<'a, str>>,
     /// number of log lines to stream at once
     pub lines: Option<i64>,
     /// log source to limit results to
-    pub source: Option<&'a str>,
+    pub source: Option<Cow<'a, str>>,
     /// whether to stream ongoing logswhether to stream ongoing logs
     pub tail: Option<bool>,
 }
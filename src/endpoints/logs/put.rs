@@ -1,7 +1,9 @@
 //Anything related to PUT requests for heroku logs and it's properties goes here.
 use super::LogDrain;
 
+use crate::endpoints::ids::AddonRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Log Drain Update
 ///
@@ -31,20 +33,24 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.LogDrain.html
 pub struct LogDrainUpdate<'a> {
     /// unique addon identifier
-    pub addon_id: &'a str,
+    pub addon_id: AddonRef<'a>,
     /// unique drain identifier, either drain id, url or token
-    pub drain_id: &'a str,
+    pub drain_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: LogDrainUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> LogDrainUpdate<'a> {
-    pub fn new(addon_id: &'a str, drain_id: &'a str, url: &'a str) -> LogDrainUpdate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        drain_id: impl Into<Cow<'a, str>>,
+        url: impl Into<Cow<'a, str>>,
+    ) -> LogDrainUpdate<'a> {
         LogDrainUpdate {
-            addon_id,
-            drain_id,
-            params: LogDrainUpdateParams { url },
+            addon_id: addon_id.into(),
+            drain_id: drain_id.into(),
+            params: LogDrainUpdateParams { url: url.into() },
         }
     }
 }
@@ -55,7 +61,7 @@ impl<'a> LogDrainUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct LogDrainUpdateParams<'a> {
     /// url associated with the log drain
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<LogDrain, (), LogDrainUpdateParams<'a>> for LogDrainUpdate<'a> {
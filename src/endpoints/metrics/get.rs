@@ -0,0 +1,619 @@
+//Anything related to GET requests for metrics and it's variations goes here.
+use super::{
+    CountValues, LatencyValues, LoadValues, MemoryValues, MetricSeries, MetricsResolution,
+};
+
+use crate::endpoints::ids::AppRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use chrono::offset::Utc;
+use chrono::DateTime;
+use std::borrow::Cow;
+
+/// Query parameters to select the process type, time range and resolution of metrics.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/metrics)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct MetricsParams<'a> {
+    /// process type, e.g. `web`, router metrics only exist for `web`
+    pub process_type: Option<Cow<'a, str>>,
+    /// start of the time range, defaults to an hour before `end_time`
+    pub start_time: Option<DateTime<Utc>>,
+    /// end of the time range, defaults to now
+    pub end_time: Option<DateTime<Utc>>,
+    /// time between two values
+    pub step: Option<MetricsResolution>,
+}
+
+/// Router Latency Metrics
+///
+/// Response times of the router for an app, as the Response Time chart of the Metrics tab shows.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/metrics#response-time)
+///
+/// # Example:
+///
+/// RouterLatencyMetrics takes one required parameter, app_id, and returns the [`MetricSeries`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &RouterLatencyMetrics::new("APP_ID")
+///         .process_type("web")
+///         .step(MetricsResolution::TenMinutes)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.MetricSeries.html
+pub struct RouterLatencyMetrics<'a> {
+    /// app_id can be the app name or the app id
+    pub app_id: AppRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: MetricsParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> RouterLatencyMetrics<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> RouterLatencyMetrics<'a> {
+        RouterLatencyMetrics {
+            app_id: app_id.into(),
+            params: MetricsParams {
+                process_type: None,
+                start_time: None,
+                end_time: None,
+                step: None,
+            },
+        }
+    }
+
+    /// # process_type: process type, e.g. `web`
+    pub fn process_type(&mut self, process_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.process_type = Some(process_type.into());
+        self
+    }
+
+    /// # start_time: start of the time range
+    pub fn start_time(&mut self, start_time: DateTime<Utc>) -> &mut Self {
+        self.params.start_time = Some(start_time);
+        self
+    }
+
+    /// # end_time: end of the time range
+    pub fn end_time(&mut self, end_time: DateTime<Utc>) -> &mut Self {
+        self.params.end_time = Some(end_time);
+        self
+    }
+
+    /// # step: time between two values
+    pub fn step(&mut self, step: MetricsResolution) -> &mut Self {
+        self.params.step = Some(step);
+        self
+    }
+
+    pub fn build(&self) -> RouterLatencyMetrics<'a> {
+        RouterLatencyMetrics {
+            app_id: self.app_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<MetricSeries<LatencyValues>, MetricsParams<'a>>
+    for RouterLatencyMetrics<'a>
+{
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("metrics/{}/router/latency", self.app_id)
+    }
+    fn query(&self) -> Option<MetricsParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Metrics)
+    }
+}
+
+/// Router Throughput Metrics
+///
+/// Requests served by the router for an app per step, by HTTP status, as the Throughput chart of the Metrics tab shows.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/metrics#throughput)
+///
+/// # Example:
+///
+/// RouterThroughputMetrics takes one required parameter, app_id, and returns the [`MetricSeries`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &RouterThroughputMetrics::new("APP_ID")
+///         .process_type("web")
+///         .step(MetricsResolution::TenMinutes)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.MetricSeries.html
+pub struct RouterThroughputMetrics<'a> {
+    /// app_id can be the app name or the app id
+    pub app_id: AppRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: MetricsParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> RouterThroughputMetrics<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> RouterThroughputMetrics<'a> {
+        RouterThroughputMetrics {
+            app_id: app_id.into(),
+            params: MetricsParams {
+                process_type: None,
+                start_time: None,
+                end_time: None,
+                step: None,
+            },
+        }
+    }
+
+    /// # process_type: process type, e.g. `web`
+    pub fn process_type(&mut self, process_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.process_type = Some(process_type.into());
+        self
+    }
+
+    /// # start_time: start of the time range
+    pub fn start_time(&mut self, start_time: DateTime<Utc>) -> &mut Self {
+        self.params.start_time = Some(start_time);
+        self
+    }
+
+    /// # end_time: end of the time range
+    pub fn end_time(&mut self, end_time: DateTime<Utc>) -> &mut Self {
+        self.params.end_time = Some(end_time);
+        self
+    }
+
+    /// # step: time between two values
+    pub fn step(&mut self, step: MetricsResolution) -> &mut Self {
+        self.params.step = Some(step);
+        self
+    }
+
+    pub fn build(&self) -> RouterThroughputMetrics<'a> {
+        RouterThroughputMetrics {
+            app_id: self.app_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<MetricSeries<CountValues>, MetricsParams<'a>>
+    for RouterThroughputMetrics<'a>
+{
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("metrics/{}/router/status", self.app_id)
+    }
+    fn query(&self) -> Option<MetricsParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Metrics)
+    }
+}
+
+/// Router Error Metrics
+///
+/// Router errors of an app per step, by error code, e.g. `H12`.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/metrics#events)
+///
+/// # Example:
+///
+/// RouterErrorMetrics takes one required parameter, app_id, and returns the [`MetricSeries`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &RouterErrorMetrics::new("APP_ID")
+///         .process_type("web")
+///         .step(MetricsResolution::TenMinutes)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.MetricSeries.html
+pub struct RouterErrorMetrics<'a> {
+    /// app_id can be the app name or the app id
+    pub app_id: AppRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: MetricsParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> RouterErrorMetrics<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> RouterErrorMetrics<'a> {
+        RouterErrorMetrics {
+            app_id: app_id.into(),
+            params: MetricsParams {
+                process_type: None,
+                start_time: None,
+                end_time: None,
+                step: None,
+            },
+        }
+    }
+
+    /// # process_type: process type, e.g. `web`
+    pub fn process_type(&mut self, process_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.process_type = Some(process_type.into());
+        self
+    }
+
+    /// # start_time: start of the time range
+    pub fn start_time(&mut self, start_time: DateTime<Utc>) -> &mut Self {
+        self.params.start_time = Some(start_time);
+        self
+    }
+
+    /// # end_time: end of the time range
+    pub fn end_time(&mut self, end_time: DateTime<Utc>) -> &mut Self {
+        self.params.end_time = Some(end_time);
+        self
+    }
+
+    /// # step: time between two values
+    pub fn step(&mut self, step: MetricsResolution) -> &mut Self {
+        self.params.step = Some(step);
+        self
+    }
+
+    pub fn build(&self) -> RouterErrorMetrics<'a> {
+        RouterErrorMetrics {
+            app_id: self.app_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<MetricSeries<CountValues>, MetricsParams<'a>> for RouterErrorMetrics<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("metrics/{}/router/errors", self.app_id)
+    }
+    fn query(&self) -> Option<MetricsParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Metrics)
+    }
+}
+
+/// Dyno Memory Metrics
+///
+/// Memory usage of the dynos of an app, as the Memory Usage chart of the Metrics tab shows.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/metrics#memory-usage)
+///
+/// # Example:
+///
+/// DynoMemoryMetrics takes one required parameter, app_id, and returns the [`MetricSeries`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &DynoMemoryMetrics::new("APP_ID")
+///         .process_type("web")
+///         .step(MetricsResolution::TenMinutes)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.MetricSeries.html
+pub struct DynoMemoryMetrics<'a> {
+    /// app_id can be the app name or the app id
+    pub app_id: AppRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: MetricsParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> DynoMemoryMetrics<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> DynoMemoryMetrics<'a> {
+        DynoMemoryMetrics {
+            app_id: app_id.into(),
+            params: MetricsParams {
+                process_type: None,
+                start_time: None,
+                end_time: None,
+                step: None,
+            },
+        }
+    }
+
+    /// # process_type: process type, e.g. `web`
+    pub fn process_type(&mut self, process_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.process_type = Some(process_type.into());
+        self
+    }
+
+    /// # start_time: start of the time range
+    pub fn start_time(&mut self, start_time: DateTime<Utc>) -> &mut Self {
+        self.params.start_time = Some(start_time);
+        self
+    }
+
+    /// # end_time: end of the time range
+    pub fn end_time(&mut self, end_time: DateTime<Utc>) -> &mut Self {
+        self.params.end_time = Some(end_time);
+        self
+    }
+
+    /// # step: time between two values
+    pub fn step(&mut self, step: MetricsResolution) -> &mut Self {
+        self.params.step = Some(step);
+        self
+    }
+
+    pub fn build(&self) -> DynoMemoryMetrics<'a> {
+        DynoMemoryMetrics {
+            app_id: self.app_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<MetricSeries<MemoryValues>, MetricsParams<'a>> for DynoMemoryMetrics<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("metrics/{}/dyno/memory", self.app_id)
+    }
+    fn query(&self) -> Option<MetricsParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Metrics)
+    }
+}
+
+/// Dyno Load Metrics
+///
+/// Load of the dynos of an app, as the Dyno Load chart of the Metrics tab shows.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/metrics#dyno-load)
+///
+/// # Example:
+///
+/// DynoLoadMetrics takes one required parameter, app_id, and returns the [`MetricSeries`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &DynoLoadMetrics::new("APP_ID")
+///         .process_type("web")
+///         .step(MetricsResolution::TenMinutes)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.MetricSeries.html
+pub struct DynoLoadMetrics<'a> {
+    /// app_id can be the app name or the app id
+    pub app_id: AppRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: MetricsParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> DynoLoadMetrics<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> DynoLoadMetrics<'a> {
+        DynoLoadMetrics {
+            app_id: app_id.into(),
+            params: MetricsParams {
+                process_type: None,
+                start_time: None,
+                end_time: None,
+                step: None,
+            },
+        }
+    }
+
+    /// # process_type: process type, e.g. `web`
+    pub fn process_type(&mut self, process_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.process_type = Some(process_type.into());
+        self
+    }
+
+    /// # start_time: start of the time range
+    pub fn start_time(&mut self, start_time: DateTime<Utc>) -> &mut Self {
+        self.params.start_time = Some(start_time);
+        self
+    }
+
+    /// # end_time: end of the time range
+    pub fn end_time(&mut self, end_time: DateTime<Utc>) -> &mut Self {
+        self.params.end_time = Some(end_time);
+        self
+    }
+
+    /// # step: time between two values
+    pub fn step(&mut self, step: MetricsResolution) -> &mut Self {
+        self.params.step = Some(step);
+        self
+    }
+
+    pub fn build(&self) -> DynoLoadMetrics<'a> {
+        DynoLoadMetrics {
+            app_id: self.app_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<MetricSeries<LoadValues>, MetricsParams<'a>> for DynoLoadMetrics<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("metrics/{}/dyno/load", self.app_id)
+    }
+    fn query(&self) -> Option<MetricsParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Metrics)
+    }
+}
+
+/// Dyno Error Metrics
+///
+/// Dyno errors of an app per step, by error code, e.g. `R14`.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/metrics#events)
+///
+/// # Example:
+///
+/// DynoErrorMetrics takes one required parameter, app_id, and returns the [`MetricSeries`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &DynoErrorMetrics::new("APP_ID")
+///         .process_type("web")
+///         .step(MetricsResolution::TenMinutes)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.MetricSeries.html
+pub struct DynoErrorMetrics<'a> {
+    /// app_id can be the app name or the app id
+    pub app_id: AppRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: MetricsParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> DynoErrorMetrics<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> DynoErrorMetrics<'a> {
+        DynoErrorMetrics {
+            app_id: app_id.into(),
+            params: MetricsParams {
+                process_type: None,
+                start_time: None,
+                end_time: None,
+                step: None,
+            },
+        }
+    }
+
+    /// # process_type: process type, e.g. `web`
+    pub fn process_type(&mut self, process_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.process_type = Some(process_type.into());
+        self
+    }
+
+    /// # start_time: start of the time range
+    pub fn start_time(&mut self, start_time: DateTime<Utc>) -> &mut Self {
+        self.params.start_time = Some(start_time);
+        self
+    }
+
+    /// # end_time: end of the time range
+    pub fn end_time(&mut self, end_time: DateTime<Utc>) -> &mut Self {
+        self.params.end_time = Some(end_time);
+        self
+    }
+
+    /// # step: time between two values
+    pub fn step(&mut self, step: MetricsResolution) -> &mut Self {
+        self.params.step = Some(step);
+        self
+    }
+
+    pub fn build(&self) -> DynoErrorMetrics<'a> {
+        DynoErrorMetrics {
+            app_id: self.app_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<MetricSeries<CountValues>, MetricsParams<'a>> for DynoErrorMetrics<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("metrics/{}/dyno/errors", self.app_id)
+    }
+    fn query(&self) -> Option<MetricsParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Metrics)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::framework::response::ApiResult;
+
+pub mod get;
+
+pub use get::{
+    DynoErrorMetrics, DynoLoadMetrics, DynoMemoryMetrics, MetricsParams, RouterErrorMetrics,
+    RouterLatencyMetrics, RouterThroughputMetrics,
+};
+
+impl ApiResult for MetricSeries<LatencyValues> {}
+impl ApiResult for MetricSeries<MemoryValues> {}
+impl ApiResult for MetricSeries<LoadValues> {}
+impl ApiResult for MetricSeries<CountValues> {}
+
+pub use series::{
+    CountValues, LatencyValues, LoadValues, MemoryValues, MetricSeries, MetricsResolution,
+};
+
+mod series {
+    use chrono::offset::Utc;
+    use chrono::{DateTime, Duration};
+    use std::collections::BTreeMap;
+
+    /// Metric Series
+    ///
+    /// Time series of an app or of one of its process types, one value per `step` from `start_time` to `end_time`.
+    /// Values are `None` where no data was reported, e.g. while the process type was scaled down.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/metrics)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct MetricSeries<T> {
+        /// when the first value was measured
+        pub start_time: DateTime<Utc>,
+        /// when the series ends
+        pub end_time: DateTime<Utc>,
+        /// time between two values
+        pub step: MetricsResolution,
+        /// the values, by metric
+        pub data: T,
+    }
+
+    impl<T> MetricSeries<T> {
+        /// When the value at `index` of a series was measured.
+        pub fn timestamp(&self, index: usize) -> DateTime<Utc> {
+            self.start_time + self.step.duration() * index as i32
+        }
+
+        /// The values of a series of `data`, each with when it was measured.
+        pub fn points(&self, values: &[Option<f64>]) -> Vec<(DateTime<Utc>, Option<f64>)> {
+            values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (self.timestamp(index), *value))
+                .collect()
+        }
+    }
+
+    /// Time between two values of a metric series.
+    #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MetricsResolution {
+        /// one value per minute, for ranges up to a day
+        #[serde(rename = "1m")]
+        OneMinute,
+        /// one value every five minutes
+        #[serde(rename = "5m")]
+        FiveMinutes,
+        /// one value every ten minutes, for ranges up to a week
+        #[serde(rename = "10m")]
+        TenMinutes,
+        /// one value per hour
+        #[serde(rename = "1h")]
+        OneHour,
+    }
+
+    impl MetricsResolution {
+        /// The time between two values.
+        pub fn duration(&self) -> Duration {
+            match self {
+                MetricsResolution::OneMinute => Duration::minutes(1),
+                MetricsResolution::FiveMinutes => Duration::minutes(5),
+                MetricsResolution::TenMinutes => Duration::minutes(10),
+                MetricsResolution::OneHour => Duration::hours(1),
+            }
+        }
+    }
+
+    /// router response times, in milliseconds
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct LatencyValues {
+        /// median response time
+        #[serde(rename = "latency.ms.p50", default)]
+        pub p50: Vec<Option<f64>>,
+        /// 95th percentile response time
+        #[serde(rename = "latency.ms.p95", default)]
+        pub p95: Vec<Option<f64>>,
+        /// 99th percentile response time
+        #[serde(rename = "latency.ms.p99", default)]
+        pub p99: Vec<Option<f64>>,
+        /// slowest response time
+        #[serde(rename = "latency.ms.max", default)]
+        pub max: Vec<Option<f64>>,
+    }
+
+    /// dyno memory usage, in megabytes
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct MemoryValues {
+        /// mean memory usage, resident and swap, across the dynos
+        #[serde(rename = "memory.total.mb.mean", default)]
+        pub total_mean: Vec<Option<f64>>,
+        /// highest memory usage, resident and swap, of a dyno
+        #[serde(rename = "memory.total.mb.max", default)]
+        pub total_max: Vec<Option<f64>>,
+        /// mean resident memory across the dynos
+        #[serde(rename = "memory.rss.mb.mean", default)]
+        pub rss_mean: Vec<Option<f64>>,
+        /// mean swap across the dynos
+        #[serde(rename = "memory.swap.mb.mean", default)]
+        pub swap_mean: Vec<Option<f64>>,
+        /// memory quota of the dyno size
+        #[serde(rename = "memory.quota.mb.max", default)]
+        pub quota: Vec<Option<f64>>,
+    }
+
+    /// dyno load, the number of CPU tasks running or waiting, averaged over a minute
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct LoadValues {
+        /// mean load across the dynos
+        #[serde(rename = "load.avg.1m.mean", default)]
+        pub mean: Vec<Option<f64>>,
+        /// highest load of a dyno
+        #[serde(rename = "load.avg.1m.max", default)]
+        pub max: Vec<Option<f64>>,
+    }
+
+    /// counts per step, by HTTP status for the throughput and by error code, e.g. `H12` or `R14`, for the errors
+    pub type CountValues = BTreeMap<String, Vec<Option<f64>>>;
+}
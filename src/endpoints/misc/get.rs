@@ -2,6 +2,7 @@
 use super::{Ratelimit, Region, Stack};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Region Info
 ///
@@ -31,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Region.html
 pub struct RegionDetails<'a> {
     /// region_id can be the region name or region id
-    pub region_id: &'a str,
+    pub region_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> RegionDetails<'a> {
-    pub fn new(region_id: &'a str) -> RegionDetails<'a> {
-        RegionDetails { region_id }
+    pub fn new(region_id: impl Into<Cow<'a, str>>) -> RegionDetails<'a> {
+        RegionDetails { region_id: region_id.into() }
     }
 }
 
@@ -207,13 +208,13 @@ impl HerokuEndpoint<Vec<Stack>> for StackList {
 /// [response]: ../struct.Stack.html
 pub struct StackDetails<'a> {
     /// stack_id can be the stack name or stack id
-    pub stack_id: &'a str,
+    pub stack_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> StackDetails<'a> {
-    pub fn new(stack_id: &'a str) -> StackDetails<'a> {
-        StackDetails { stack_id }
+    pub fn new(stack_id: impl Into<Cow<'a, str>>) -> StackDetails<'a> {
+        StackDetails { stack_id: stack_id.into() }
     }
 }
 
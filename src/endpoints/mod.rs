@@ -28,12 +28,26 @@ pub mod domains;
 /// dynos endpoints
 #[cfg(feature = "dynos")]
 pub mod dynos;
+/// enterprise account endpoints
+#[cfg(feature = "enterprise")]
+pub mod enterprise;
 /// formations endpoints
 #[cfg(feature = "formations")]
 pub mod formations;
+/// typed references to resources, used in the endpoint paths
+pub mod ids;
+/// Apache Kafka on Heroku data API endpoints, served on `api.data.heroku.com` and keyed by add-on id
+#[cfg(feature = "kafka")]
+pub mod kafka;
+/// Heroku Key-Value Store data API endpoints, served on `api.data.heroku.com` and keyed by add-on id
+#[cfg(feature = "key_value")]
+pub mod key_value;
 /// heroku logs endpoints
 #[cfg(feature = "logs")]
 pub mod logs;
+/// app metrics endpoints, served on `api.metrics.heroku.com`
+#[cfg(feature = "metrics")]
+pub mod metrics;
 /// mixed endpoints
 // used for those one off endpoints e.g. ratelimiting, region, stacks etc.
 #[cfg(feature = "misc")]
@@ -44,6 +58,9 @@ pub mod oauth;
 /// pipeline endpoints
 #[cfg(feature = "pipelines")]
 pub mod pipelines;
+/// Heroku Postgres data API endpoints, served on `postgres-api.heroku.com` and keyed by add-on id
+#[cfg(feature = "postgres")]
+pub mod postgres;
 /// release endpoints
 #[cfg(feature = "releases")]
 pub mod releases;
@@ -53,6 +70,8 @@ pub mod review;
 /// slug endpoints
 #[cfg(feature = "slugs")]
 pub mod slugs;
+/// typed statuses shared by the models
+pub mod status;
 /// spaces endpoints
 #[cfg(feature = "space")]
 pub mod space;
@@ -61,4 +80,6 @@ pub mod space;
 pub mod teams;
 /// testing endpoints
 #[cfg(feature = "testing")]
-pub mod testing;
\ No newline at end of file
+pub mod testing;
+/// usage amounts shared by the team and enterprise usage models
+pub mod usage;
\ No newline at end of file
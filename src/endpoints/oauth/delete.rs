@@ -2,6 +2,7 @@
 use super::{OAuth, OAuthClient, OAuthToken};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// OAuth Authorization Delete
 ///
@@ -30,13 +31,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.OAuth.html
 pub struct OAuthDelete<'a> {
     /// unique identifier of OAuth authorization
-    pub oauth_id: &'a str,
+    pub oauth_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthDelete<'a> {
-    pub fn new(oauth_id: &'a str) -> OAuthDelete<'a> {
-        OAuthDelete { oauth_id }
+    pub fn new(oauth_id: impl Into<Cow<'a, str>>) -> OAuthDelete<'a> {
+        OAuthDelete { oauth_id: oauth_id.into() }
     }
 }
 
@@ -76,13 +77,13 @@ impl<'a> HerokuEndpoint<OAuth> for OAuthDelete<'a> {
 /// [response]: ../struct.OAuthClient.html
 pub struct OAuthClientDelete<'a> {
     /// unique identifier of OAuth Client authorization
-    pub client_id: &'a str,
+    pub client_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthClientDelete<'a> {
-    pub fn new(client_id: &'a str) -> OAuthClientDelete<'a> {
-        OAuthClientDelete { client_id }
+    pub fn new(client_id: impl Into<Cow<'a, str>>) -> OAuthClientDelete<'a> {
+        OAuthClientDelete { client_id: client_id.into() }
     }
 }
 
@@ -122,13 +123,13 @@ impl<'a> HerokuEndpoint<OAuthClient, (), ()> for OAuthClientDelete<'a> {
 /// [response]: ../struct.OAuthToken.html
 pub struct OAuthTokenDelete<'a> {
     /// unique identifier of token
-    pub token_id: &'a str,
+    pub token_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthTokenDelete<'a> {
-    pub fn new(token_id: &'a str) -> OAuthTokenDelete<'a> {
-        OAuthTokenDelete { token_id }
+    pub fn new(token_id: impl Into<Cow<'a, str>>) -> OAuthTokenDelete<'a> {
+        OAuthTokenDelete { token_id: token_id.into() }
     }
 }
 
@@ -2,6 +2,7 @@
 use super::{OAuth, OAuthClient};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// OAuth Authorization Info
 ///
@@ -30,13 +31,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.OAuth.html
 pub struct OAuthDetails<'a> {
     /// oauth_id is the unique identifier.
-    pub oauth_id: &'a str,
+    pub oauth_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthDetails<'a> {
-    pub fn new(oauth_id: &'a str) -> OAuthDetails<'a> {
-        OAuthDetails { oauth_id }
+    pub fn new(oauth_id: impl Into<Cow<'a, str>>) -> OAuthDetails<'a> {
+        OAuthDetails { oauth_id: oauth_id.into() }
     }
 }
 
@@ -119,13 +120,13 @@ impl HerokuEndpoint<Vec<OAuth>> for OAuthList {
 /// [response]: ../struct.OAuthClient.html
 pub struct OAuthClientDetails<'a> {
     /// unique identifier of OAuth Client authorization
-    pub client_id: &'a str,
+    pub client_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthClientDetails<'a> {
-    pub fn new(client_id: &'a str) -> OAuthClientDetails<'a> {
-        OAuthClientDetails { client_id }
+    pub fn new(client_id: impl Into<Cow<'a, str>>) -> OAuthClientDetails<'a> {
+        OAuthClientDetails { client_id: client_id.into() }
     }
 }
 
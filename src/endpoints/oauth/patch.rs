@@ -2,6 +2,7 @@
 use super::OAuthClient;
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// OAuth Client Update
 ///
@@ -36,16 +37,16 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.OAuthClient.html
 pub struct OAuthClientUpdate<'a> {
     /// unique identifier of OAuth Client authorization
-    pub client_id: &'a str,
+    pub client_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: OAuthClientUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthClientUpdate<'a> {
-    pub fn new(client_id: &'a str) -> OAuthClientUpdate<'a> {
+    pub fn new(client_id: impl Into<Cow<'a, str>>) -> OAuthClientUpdate<'a> {
         OAuthClientUpdate {
-            client_id,
+            client_id: client_id.into(),
             params: OAuthClientUpdateParams {
                 name: None,
                 redirect_uri: None,
@@ -54,23 +55,23 @@ impl<'a> OAuthClientUpdate<'a> {
     }
 
     /// # name: OAuth client name
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     /// # redirect_uri: endpoint for redirection after authorization with OAuth client
-    pub fn redirect_uri(&mut self, redirect_uri: &'a str) -> &mut Self {
-        self.params.redirect_uri = Some(redirect_uri);
+    pub fn redirect_uri(&mut self, redirect_uri: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.redirect_uri = Some(redirect_uri.into());
         self
     }
 
     pub fn build(&self) -> OAuthClientUpdate<'a> {
         OAuthClientUpdate {
-            client_id: self.client_id,
+            client_id: self.client_id.clone(),
             params: OAuthClientUpdateParams {
-                name: self.params.name,
-                redirect_uri: self.params.redirect_uri,
+                name: self.params.name.clone(),
+                redirect_uri: self.params.redirect_uri.clone(),
             },
         }
     }
@@ -82,9 +83,9 @@ impl<'a> OAuthClientUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct OAuthClientUpdateParams<'a> {
     /// OAuth client name
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
     /// endpoint for redirection after authorization with OAuth client
-    pub redirect_uri: Option<&'a str>,
+    pub redirect_uri: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<OAuthClient, (), OAuthClientUpdateParams<'a>> for OAuthClientUpdate<'a> {
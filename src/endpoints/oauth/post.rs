@@ -2,6 +2,7 @@
 use super::{OAuth, OAuthClient, OAuthToken};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// OAuth Authorization Create
 ///
@@ -42,10 +43,10 @@ pub struct OAuthCreate<'a> {
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthCreate<'a> {
-    pub fn new(scope: Vec<&'a str>) -> OAuthCreate<'a> {
+    pub fn new(scope: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> OAuthCreate<'a> {
         OAuthCreate {
             params: OAuthCreateParams {
-                scope: scope,
+                scope: scope.into_iter().map(Into::into).collect(),
                 client: None,
                 description: None,
                 expires_in: None,
@@ -54,14 +55,14 @@ impl<'a> OAuthCreate<'a> {
     }
 
     /// # client: unique identifier of this OAuth client
-    pub fn client(&mut self, client: &'a str) -> &mut Self {
-        self.params.client = Some(client);
+    pub fn client(&mut self, client: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.client = Some(client.into());
         self
     }
 
     /// # description: human-friendly description of this OAuth authorization
-    pub fn description(&mut self, description: &'a str) -> &mut Self {
-        self.params.description = Some(description);
+    pub fn description(&mut self, description: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.description = Some(description.into());
         self
     }
 
@@ -75,8 +76,8 @@ impl<'a> OAuthCreate<'a> {
         OAuthCreate {
             params: OAuthCreateParams {
                 scope: self.params.scope.clone(),
-                client: self.params.client,
-                description: self.params.description,
+                client: self.params.client.clone(),
+                description: self.params.description.clone(),
                 expires_in: self.params.expires_in,
             },
         }
@@ -89,13 +90,13 @@ impl<'a> OAuthCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct OAuthCreateParams<'a> {
     /// The scope of access OAuth authorization allows
-    pub scope: Vec<&'a str>,
+    pub scope: Vec<Cow<'a, str>>,
     /// unique identifier of this OAuth client
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client: Option<&'a str>,
+    pub client: Option<Cow<'a, str>>,
     /// human-friendly description of this OAuth authorization
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
     /// seconds until OAuth token expires; may be null for tokens with indefinite lifetime [Nullable]
     pub expires_in: Option<u32>,
 }
@@ -140,13 +141,13 @@ impl<'a> HerokuEndpoint<OAuth, (), OAuthCreateParams<'a>> for OAuthCreate<'a> {
 /// [response]: ../struct.OAuth.html
 pub struct OAuthRegenerate<'a> {
     /// unique identifier of OAuth authorization
-    pub oauth_id: &'a str,
+    pub oauth_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthRegenerate<'a> {
-    pub fn new(oauth_id: &'a str) -> OAuthRegenerate<'a> {
-        OAuthRegenerate { oauth_id }
+    pub fn new(oauth_id: impl Into<Cow<'a, str>>) -> OAuthRegenerate<'a> {
+        OAuthRegenerate { oauth_id: oauth_id.into() }
     }
 }
 
@@ -196,9 +197,15 @@ pub struct OAuthClientCreate<'a> {
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthClientCreate<'a> {
-    pub fn new(name: &'a str, redirect_uri: &'a str) -> OAuthClientCreate<'a> {
+    pub fn new(
+        name: impl Into<Cow<'a, str>>,
+        redirect_uri: impl Into<Cow<'a, str>>,
+    ) -> OAuthClientCreate<'a> {
         OAuthClientCreate {
-            params: OAuthClientCreateParams { name, redirect_uri },
+            params: OAuthClientCreateParams {
+                name: name.into(),
+                redirect_uri: redirect_uri.into(),
+            },
         }
     }
 }
@@ -209,9 +216,9 @@ impl<'a> OAuthClientCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct OAuthClientCreateParams<'a> {
     /// OAuth client name
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// endpoint for redirection after authorization with OAuth client
-    pub redirect_uri: &'a str,
+    pub redirect_uri: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<OAuthClient, (), OAuthClientCreateParams<'a>> for OAuthClientCreate<'a> {
@@ -253,13 +260,13 @@ impl<'a> HerokuEndpoint<OAuthClient, (), OAuthClientCreateParams<'a>> for OAuthC
 /// [response]: ../struct.OAuthClient.html
 pub struct OAuthClientRotateCredentials<'a> {
     /// unique identifier of OAuth Client authorization
-    pub client_id: &'a str,
+    pub client_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OAuthClientRotateCredentials<'a> {
-    pub fn new(client_id: &'a str) -> OAuthClientRotateCredentials<'a> {
-        OAuthClientRotateCredentials { client_id }
+    pub fn new(client_id: impl Into<Cow<'a, str>>) -> OAuthClientRotateCredentials<'a> {
+        OAuthClientRotateCredentials { client_id: client_id.into() }
     }
 }
 
@@ -318,22 +325,22 @@ pub struct OAuthTokenCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> OAuthTokenCreate<'a> {
     pub fn new(
-        client_secret: &'a str,
-        grant_code: &'a str,
-        grant_type: &'a str,
-        refresh_token: &'a str,
+        client_secret: impl Into<Cow<'a, str>>,
+        grant_code: impl Into<Cow<'a, str>>,
+        grant_type: impl Into<Cow<'a, str>>,
+        refresh_token: impl Into<Cow<'a, str>>,
     ) -> OAuthTokenCreate<'a> {
         OAuthTokenCreate {
             params: OAuthTokenCreateParams {
                 client: Client {
-                    secret: client_secret,
+                    secret: client_secret.into(),
                 },
                 grant: Grant {
-                    code: grant_code,
-                    type_field: grant_type,
+                    code: grant_code.into(),
+                    type_field: grant_type.into(),
                 },
                 refresh_token: RefreshToken {
-                    token: refresh_token,
+                    token: refresh_token.into(),
                 },
             },
         }
@@ -358,7 +365,7 @@ pub struct OAuthTokenCreateParams<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct RefreshToken<'a> {
     /// contents of the token to be used for authorization
-    pub token: &'a str,
+    pub token: Cow<'a, str>,
 }
 
 // TODO(ben): Find a better solution than this
@@ -366,10 +373,10 @@ pub struct RefreshToken<'a> {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct Grant<'a> {
     /// grant code received from OAuth web application authorization
-    pub code: &'a str,
+    pub code: Cow<'a, str>,
     /// type of grant requested, one of authorization_code or refresh_token
     #[serde(rename = "type")]
-    pub type_field: &'a str,
+    pub type_field: Cow<'a, str>,
 }
 
 // TODO(ben): Find a better solution than this
@@ -377,7 +384,7 @@ pub struct Grant<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct Client<'a> {
     /// secret used to obtain OAuth authorizations under this client
-    pub secret: &'a str,
+    pub secret: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<OAuthToken, (), OAuthTokenCreateParams<'a>> for OAuthTokenCreate<'a> {
@@ -1,7 +1,9 @@
 //Anything related to DELETE requests for pipelines and it's properties goes here.
 use super::{Pipeline, PipelineCoupling};
 
+use crate::endpoints::ids::PipelineRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Pipeline Delete
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Pipeline.html
 pub struct PipelineDelete<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineDelete<'a> {
-    pub fn new(pipeline_id: &'a str) -> PipelineDelete<'a> {
-        PipelineDelete { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> PipelineDelete<'a> {
+        PipelineDelete { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -76,13 +78,13 @@ impl<'a> HerokuEndpoint<Pipeline> for PipelineDelete<'a> {
 /// [response]: ../struct.PipelineCoupling.html
 pub struct PipelineCouplingDelete<'a> {
     /// unique pipeline coupling identifier.
-    pub coupling_id: &'a str,
+    pub coupling_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineCouplingDelete<'a> {
-    pub fn new(coupling_id: &'a str) -> PipelineCouplingDelete<'a> {
-        PipelineCouplingDelete { coupling_id }
+    pub fn new(coupling_id: impl Into<Cow<'a, str>>) -> PipelineCouplingDelete<'a> {
+        PipelineCouplingDelete { coupling_id: coupling_id.into() }
     }
 }
 
@@ -4,7 +4,9 @@ use super::{
     PipelinePromotionTarget, PipelineRelease, PipelineStack,
 };
 
+use crate::endpoints::ids::{AppRef, PipelineRef, TeamRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Pipeline Info
 ///
@@ -33,13 +35,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Pipeline.html
 pub struct PipelineDetails<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineDetails<'a> {
-    pub fn new(pipeline_id: &'a str) -> PipelineDetails<'a> {
-        PipelineDetails { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> PipelineDetails<'a> {
+        PipelineDetails { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -122,13 +124,13 @@ impl HerokuEndpoint<Vec<Pipeline>> for PipelineList {
 /// [response]: ../struct.PipelineBuild.html
 pub struct PipelineLatestBuildsList<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineLatestBuildsList<'a> {
-    pub fn new(pipeline_id: &'a str) -> PipelineLatestBuildsList<'a> {
-        PipelineLatestBuildsList { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> PipelineLatestBuildsList<'a> {
+        PipelineLatestBuildsList { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -168,13 +170,13 @@ impl<'a> HerokuEndpoint<Vec<PipelineBuild>> for PipelineLatestBuildsList<'a> {
 /// [response]: ../struct.PipelineCoupling.html
 pub struct PipelineCouplingByPipelineList<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineCouplingByPipelineList<'a> {
-    pub fn new(pipeline_id: &'a str) -> PipelineCouplingByPipelineList<'a> {
-        PipelineCouplingByPipelineList { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> PipelineCouplingByPipelineList<'a> {
+        PipelineCouplingByPipelineList { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -257,13 +259,13 @@ impl HerokuEndpoint<Vec<PipelineCoupling>> for PipelineCouplingByUserList {
 /// [response]: ../struct.PipelineCoupling.html
 pub struct PipelineCouplingByTeamList<'a> {
     /// unique team identifier.
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineCouplingByTeamList<'a> {
-    pub fn new(team_id: &'a str) -> PipelineCouplingByTeamList<'a> {
-        PipelineCouplingByTeamList { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> PipelineCouplingByTeamList<'a> {
+        PipelineCouplingByTeamList { team_id: team_id.into() }
     }
 }
 
@@ -303,13 +305,13 @@ impl<'a> HerokuEndpoint<Vec<PipelineCoupling>> for PipelineCouplingByTeamList<'a
 /// [response]: ../struct.PipelineCoupling.html
 pub struct PipelineCouplingByAppDetails<'a> {
     /// unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineCouplingByAppDetails<'a> {
-    pub fn new(app_id: &'a str) -> PipelineCouplingByAppDetails<'a> {
-        PipelineCouplingByAppDetails { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> PipelineCouplingByAppDetails<'a> {
+        PipelineCouplingByAppDetails { app_id: app_id.into() }
     }
 }
 
@@ -392,13 +394,13 @@ impl HerokuEndpoint<Vec<PipelineCoupling>> for PipelineCouplingList {
 /// [response]: ../struct.PipelineCoupling.html
 pub struct PipelineCouplingDetails<'a> {
     /// unique pipeline coupling identifier.
-    pub coupling_id: &'a str,
+    pub coupling_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineCouplingDetails<'a> {
-    pub fn new(coupling_id: &'a str) -> PipelineCouplingDetails<'a> {
-        PipelineCouplingDetails { coupling_id }
+    pub fn new(coupling_id: impl Into<Cow<'a, str>>) -> PipelineCouplingDetails<'a> {
+        PipelineCouplingDetails { coupling_id: coupling_id.into() }
     }
 }
 
@@ -438,13 +440,13 @@ impl<'a> HerokuEndpoint<PipelineCoupling> for PipelineCouplingDetails<'a> {
 /// [response]: ../struct.PipelineDeployment.html
 pub struct PipelineDeploymentList<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineDeploymentList<'a> {
-    pub fn new(pipeline_id: &'a str) -> PipelineDeploymentList<'a> {
-        PipelineDeploymentList { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> PipelineDeploymentList<'a> {
+        PipelineDeploymentList { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -484,13 +486,13 @@ impl<'a> HerokuEndpoint<Vec<PipelineDeployment>> for PipelineDeploymentList<'a>
 /// [response]: ../struct.PipelinePromotion.html
 pub struct PipelinePromotionDetails<'a> {
     /// unique pipeline identifier.
-    pub promotion_id: &'a str,
+    pub promotion_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelinePromotionDetails<'a> {
-    pub fn new(promotion_id: &'a str) -> PipelinePromotionDetails<'a> {
-        PipelinePromotionDetails { promotion_id }
+    pub fn new(promotion_id: impl Into<Cow<'a, str>>) -> PipelinePromotionDetails<'a> {
+        PipelinePromotionDetails { promotion_id: promotion_id.into() }
     }
 }
 
@@ -530,13 +532,13 @@ impl<'a> HerokuEndpoint<PipelinePromotion> for PipelinePromotionDetails<'a> {
 /// [response]: ../struct.PipelinePromotionTarget.html
 pub struct PipelinePromotionTargetList<'a> {
     /// unique pipeline identifier.
-    pub promotion_id: &'a str,
+    pub promotion_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelinePromotionTargetList<'a> {
-    pub fn new(promotion_id: &'a str) -> PipelinePromotionTargetList<'a> {
-        PipelinePromotionTargetList { promotion_id }
+    pub fn new(promotion_id: impl Into<Cow<'a, str>>) -> PipelinePromotionTargetList<'a> {
+        PipelinePromotionTargetList { promotion_id: promotion_id.into() }
     }
 }
 
@@ -579,13 +581,13 @@ impl<'a> HerokuEndpoint<Vec<PipelinePromotionTarget>> for PipelinePromotionTarge
 /// [response]: ../struct.PipelineRelease.html
 pub struct PipelineLatestReleaseList<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineLatestReleaseList<'a> {
-    pub fn new(pipeline_id: &'a str) -> PipelineLatestReleaseList<'a> {
-        PipelineLatestReleaseList { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> PipelineLatestReleaseList<'a> {
+        PipelineLatestReleaseList { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -625,13 +627,13 @@ impl<'a> HerokuEndpoint<Vec<PipelineRelease>> for PipelineLatestReleaseList<'a>
 /// [response]: ../struct.PipelineStack.html
 pub struct PipelineStackDetails<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineStackDetails<'a> {
-    pub fn new(pipeline_id: &'a str) -> PipelineStackDetails<'a> {
-        PipelineStackDetails { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> PipelineStackDetails<'a> {
+        PipelineStackDetails { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -99,6 +99,7 @@ mod pipeline {
 mod pipeline_builds {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::BuildStatus;
 
     /// Pipeline Builds
     ///
@@ -129,7 +130,7 @@ mod pipeline_builds {
         /// Pipeline Stack
         pub stack: String,
         /// pipeline status
-        pub status: String,
+        pub status: BuildStatus,
         /// when the pipeline was last updated
         pub updated_at: DateTime<Utc>,
         /// user account
@@ -219,6 +220,7 @@ mod pipeline_couplings {
 mod pipeline_deployement {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::ReleaseStatus;
 
     /// Pipeline Deployment
     ///
@@ -237,7 +239,7 @@ mod pipeline_deployement {
         pub id: String,
         pub updated_at: DateTime<Utc>,
         pub slug: Slug,
-        pub status: String,
+        pub status: ReleaseStatus,
         pub user: User,
         pub version: i64,
         pub current: bool,
@@ -271,6 +273,7 @@ mod pipeline_deployement {
 mod pipeline_promotions {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::PromotionStatus;
 
     /// Pipeline Promotion
     ///
@@ -290,7 +293,7 @@ mod pipeline_promotions {
         /// source
         pub source: Source,
         /// status of promotion. one of:"pending" or "completed"
-        pub status: String,
+        pub status: PromotionStatus,
         /// when promotion was updated
         pub updated_at: Option<DateTime<Utc>>,
     }
@@ -319,6 +322,7 @@ mod pipeline_promotions {
 
 // pipeline promotions target submodule, anything for pipeline promotion targets goes here.
 mod pipeline_promotion_target {
+    use crate::endpoints::status::PromotionTargetStatus;
 
     /// Pipeline Promotion Target
     ///
@@ -341,7 +345,7 @@ mod pipeline_promotion_target {
         pub release: Release,
         /// status of promotion
         /// one of:"pending" or "succeeded" or "failed"
-        pub status: String,
+        pub status: PromotionTargetStatus,
     }
     #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
     pub struct App {
@@ -361,6 +365,7 @@ mod pipeline_promotion_target {
 mod pipeline_releases {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::ReleaseStatus;
 
     /// Pipeline Release
     ///
@@ -378,7 +383,7 @@ mod pipeline_releases {
         pub id: String,
         pub updated_at: Option<DateTime<Utc>>,
         pub slug: Option<Slug>,
-        pub status: String,
+        pub status: ReleaseStatus,
         pub user: User,
         pub version: i64,
         pub current: bool,
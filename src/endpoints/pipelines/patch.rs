@@ -1,7 +1,9 @@
 //Anything related to PATCH requests for pipelines and it's properties goes here.
 use super::{Pipeline, PipelineCoupling};
 
+use crate::endpoints::ids::PipelineRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Pipeline Update
 ///
@@ -30,16 +32,16 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Pipeline.html
 pub struct PipelineUpdate<'a> {
     /// unique pipeline identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: PipelineUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineUpdate<'a> {
-    pub fn new(pipeline_id: &'a str) -> PipelineUpdate<'a> {
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> PipelineUpdate<'a> {
         PipelineUpdate {
-            pipeline_id,
+            pipeline_id: pipeline_id.into(),
             params: PipelineUpdateParams { name: None },
         }
     }
@@ -47,16 +49,16 @@ impl<'a> PipelineUpdate<'a> {
     /// # name: name of pipeline
     /// 
     /// `pattern`: ^[a-z][a-z0-9-]{2,29}$ 
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     pub fn build(&self) -> PipelineUpdate<'a> {
         PipelineUpdate {
-            pipeline_id: self.pipeline_id,
+            pipeline_id: self.pipeline_id.clone(),
             params: PipelineUpdateParams {
-                name: self.params.name,
+                name: self.params.name.clone(),
             },
         }
     }
@@ -69,7 +71,7 @@ impl<'a> PipelineUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct PipelineUpdateParams<'a> {
     /// name of pipeline. pattern: ^[a-z][a-z0-9-]{2,29}$
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Pipeline, (), PipelineUpdateParams<'a>> for PipelineUpdate<'a> {
@@ -115,16 +117,16 @@ impl<'a> HerokuEndpoint<Pipeline, (), PipelineUpdateParams<'a>> for PipelineUpda
 /// [response]: ../struct.PipelineCoupling.html
 pub struct PipelineCouplingUpdate<'a> {
     /// unique pipeline coupling identifier.
-    pub coupling_id: &'a str,
+    pub coupling_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: PipelineCouplingUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineCouplingUpdate<'a> {
-    pub fn new(coupling_id: &'a str) -> PipelineCouplingUpdate {
+    pub fn new(coupling_id: impl Into<Cow<'a, str>>) -> PipelineCouplingUpdate<'a> {
         PipelineCouplingUpdate {
-            coupling_id,
+            coupling_id: coupling_id.into(),
             params: PipelineCouplingUpdateParams { stage: None },
         }
     }
@@ -132,16 +134,16 @@ impl<'a> PipelineCouplingUpdate<'a> {
     /// # stage: target pipeline stage
     /// 
     /// `one of` : "test" or "review" or "development" or "staging" or "production" 
-    pub fn stage(&mut self, stage: &'a str) -> &mut Self {
-        self.params.stage = Some(stage);
+    pub fn stage(&mut self, stage: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.stage = Some(stage.into());
         self
     }
 
     pub fn build(&self) -> PipelineCouplingUpdate<'a> {
         PipelineCouplingUpdate {
-            coupling_id: self.coupling_id,
+            coupling_id: self.coupling_id.clone(),
             params: PipelineCouplingUpdateParams {
-                stage: self.params.stage,
+                stage: self.params.stage.clone(),
             },
         }
     }
@@ -153,7 +155,7 @@ impl<'a> PipelineCouplingUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct PipelineCouplingUpdateParams<'a> {
     /// target pipeline stage. one of:"test" or "review" or "development" or "staging" or "production"
-    pub stage: Option<&'a str>,
+    pub stage: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<PipelineCoupling, (), PipelineCouplingUpdateParams<'a>>
@@ -2,6 +2,7 @@
 use super::{Pipeline, PipelineCoupling, PipelinePromotion, PipelineTransfer};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Pipeline Create
 ///
@@ -35,10 +36,10 @@ pub struct PipelineCreate<'a> {
 
 #[cfg(feature = "builder")]
 impl<'a> PipelineCreate<'a> {
-    pub fn new(pipeline_name: &'a str) -> PipelineCreate<'a> {
+    pub fn new(pipeline_name: impl Into<Cow<'a, str>>) -> PipelineCreate<'a> {
         PipelineCreate {
             params: PipelineCreateParams {
-                name: pipeline_name,
+                name: pipeline_name.into(),
             },
         }
     }
@@ -50,7 +51,7 @@ impl<'a> PipelineCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct PipelineCreateParams<'a> {
     /// name of pipeline. pattern: ^[a-z][a-z0-9-]{2,29}$
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<Pipeline, (), PipelineCreateParams<'a>> for PipelineCreate<'a> {
@@ -104,15 +105,15 @@ pub struct PipelineCouplingCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> PipelineCouplingCreate<'a> {
     pub fn new(
-        app_id: &'a str,
-        pipeline_id: &'a str,
-        pipeline_stage: &'a str,
+        app_id: impl Into<Cow<'a, str>>,
+        pipeline_id: impl Into<Cow<'a, str>>,
+        pipeline_stage: impl Into<Cow<'a, str>>,
     ) -> PipelineCouplingCreate<'a> {
         PipelineCouplingCreate {
             params: PipelineCouplingCreateParams {
-                app: app_id,
-                pipeline: pipeline_id,
-                stage: pipeline_stage,
+                app: app_id.into(),
+                pipeline: pipeline_id.into(),
+                stage: pipeline_stage.into(),
             },
         }
     }
@@ -124,11 +125,11 @@ impl<'a> PipelineCouplingCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct PipelineCouplingCreateParams<'a> {
     /// unique identifier or name of app
-    pub app: &'a str,
+    pub app: Cow<'a, str>,
     /// unique identifier of pipeline
-    pub pipeline: &'a str,
+    pub pipeline: Cow<'a, str>,
     /// target pipeline stage. one of:"test" or "review" or "development" or "staging" or "production"
-    pub stage: &'a str,
+    pub stage: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<PipelineCoupling, (), PipelineCouplingCreateParams<'a>>
@@ -186,18 +187,18 @@ pub struct PipelinePromotionCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> PipelinePromotionCreate<'a> {
     pub fn new(
-        pipeline_id: &'a str,
-        source_app_id: &'a str,
-        target_app_id: &'a str,
+        pipeline_id: impl Into<Cow<'a, str>>,
+        source_app_id: impl Into<Cow<'a, str>>,
+        target_app_id: impl Into<Cow<'a, str>>,
     ) -> PipelinePromotionCreate<'a> {
         PipelinePromotionCreate {
             params: PipelinePromotionCreateParams {
-                pipeline: PipelineParam { id: pipeline_id },
+                pipeline: PipelineParam { id: pipeline_id.into() },
                 source: SourceParam {
-                    app: AppParam { id: source_app_id },
+                    app: AppParam { id: source_app_id.into() },
                 },
                 targets: vec![TargetParam {
-                    app: AppParam { id: target_app_id },
+                    app: AppParam { id: target_app_id.into() },
                 }],
             },
         }
@@ -216,7 +217,7 @@ pub struct PipelinePromotionCreateParams<'a> {
 
 #[derive(Serialize, Clone, Debug)]
 pub struct PipelineParam<'a> {
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -226,7 +227,7 @@ pub struct SourceParam<'a> {
 
 #[derive(Serialize, Clone, Debug)]
 pub struct AppParam<'a> {
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -288,16 +289,16 @@ pub struct PipelineTransferCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> PipelineTransferCreate<'a> {
     pub fn new(
-        pipeline_id: &'a str,
-        new_owner_id: &'a str,
-        new_owner_type: &'a str,
+        pipeline_id: impl Into<Cow<'a, str>>,
+        new_owner_id: impl Into<Cow<'a, str>>,
+        new_owner_type: impl Into<Cow<'a, str>>,
     ) -> PipelineTransferCreate<'a> {
         PipelineTransferCreate {
             params: PipelineTransferCreateParams {
-                pipeline: PipelineParam { id: pipeline_id },
+                pipeline: PipelineParam { id: pipeline_id.into() },
                 new_owner: NewOwner {
-                    id: new_owner_id,
-                    type_field: new_owner_type,
+                    id: new_owner_id.into(),
+                    type_field: new_owner_type.into(),
                 },
             },
         }
@@ -316,11 +317,11 @@ pub struct PipelineTransferCreateParams<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct NewOwner<'a> {
     /// unique identifier of a pipeline owner
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
     /// type of pipeline owner
     /// pattern: `(^team$
     #[serde(rename = "type")]
-    pub type_field: &'a str,
+    pub type_field: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<PipelineTransfer, (), PipelineTransferCreateParams<'a>>
@@ -0,0 +1,124 @@
+//Anything related to DELETE requests for Heroku Postgres and it's variations goes here.
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Postgres Credential Delete
+///
+/// Delete a credential of an existing database. The `default` credential can't be deleted.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgresql-credentials#destroying-credentials)
+///
+/// # Example:
+///
+/// PostgresCredentialDelete takes two required parameters, addon_id and credential_name, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresCredentialDelete::new("ADDON_ID", "analyst"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct PostgresCredentialDelete<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// name of the credential
+    pub credential_name: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresCredentialDelete<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        credential_name: impl Into<Cow<'a, str>>,
+    ) -> PostgresCredentialDelete<'a> {
+        PostgresCredentialDelete {
+            addon_id: addon_id.into(),
+            credential_name: credential_name.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value> for PostgresCredentialDelete<'a> {
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+    fn path(&self) -> String {
+        format!(
+            "postgres/v0/databases/{}/credentials/{}",
+            self.addon_id, self.credential_name
+        )
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Backup Schedule Delete
+///
+/// Stop the daily backups of an existing database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-backups#stopping-scheduled-backups)
+///
+/// # Example:
+///
+/// PostgresBackupScheduleDelete takes two required parameters, addon_id and schedule_id, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresBackupScheduleDelete::new("ADDON_ID", "SCHEDULE_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct PostgresBackupScheduleDelete<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// unique identifier of the schedule
+    pub schedule_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresBackupScheduleDelete<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        schedule_id: impl Into<Cow<'a, str>>,
+    ) -> PostgresBackupScheduleDelete<'a> {
+        PostgresBackupScheduleDelete {
+            addon_id: addon_id.into(),
+            schedule_id: schedule_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value> for PostgresBackupScheduleDelete<'a> {
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+    fn path(&self) -> String {
+        format!(
+            "client/v11/databases/{}/transfer-schedules/{}",
+            self.addon_id, self.schedule_id
+        )
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
@@ -0,0 +1,431 @@
+//Anything related to GET requests for Heroku Postgres and it's variations goes here.
+use super::{
+    PostgresBackupSchedule, PostgresCredential, PostgresDatabase, PostgresMaintenance,
+    PostgresTransfer, PostgresWaitStatus,
+};
+
+use crate::endpoints::ids::{AddonRef, AppRef};
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Postgres Info
+///
+/// Info for an existing database, as shown by `heroku pg:info`.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgresql)
+///
+/// # Example:
+///
+/// PostgresDetails takes one required parameter, addon_id, and returns the [`PostgresDatabase`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresDetails::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresDatabase.html
+pub struct PostgresDetails<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresDetails<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresDetails<'a> {
+        PostgresDetails {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<PostgresDatabase> for PostgresDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Wait Status
+///
+/// Whether an existing database is available yet, as waited for by `heroku pg:wait`.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgresql)
+///
+/// # Example:
+///
+/// PostgresWaitStatusDetails takes one required parameter, addon_id, and returns the [`PostgresWaitStatus`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresWaitStatusDetails::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresWaitStatus.html
+pub struct PostgresWaitStatusDetails<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresWaitStatusDetails<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresWaitStatusDetails<'a> {
+        PostgresWaitStatusDetails {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<PostgresWaitStatus> for PostgresWaitStatusDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/wait_status", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Credential List
+///
+/// List the credentials of an existing database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgresql-credentials)
+///
+/// # Example:
+///
+/// PostgresCredentialList takes one required parameter, addon_id, and returns a list of [`PostgresCredentials`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresCredentialList::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresCredential.html
+pub struct PostgresCredentialList<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresCredentialList<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresCredentialList<'a> {
+        PostgresCredentialList {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<PostgresCredential>> for PostgresCredentialList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("postgres/v0/databases/{}/credentials", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Credential Info
+///
+/// Info for a credential of an existing database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgresql-credentials)
+///
+/// # Example:
+///
+/// PostgresCredentialDetails takes two required parameters, addon_id and credential_name, and returns the [`PostgresCredential`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresCredentialDetails::new("ADDON_ID", "default"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresCredential.html
+pub struct PostgresCredentialDetails<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// name of the credential, e.g. `default`
+    pub credential_name: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresCredentialDetails<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        credential_name: impl Into<Cow<'a, str>>,
+    ) -> PostgresCredentialDetails<'a> {
+        PostgresCredentialDetails {
+            addon_id: addon_id.into(),
+            credential_name: credential_name.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<PostgresCredential> for PostgresCredentialDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!(
+            "postgres/v0/databases/{}/credentials/{}",
+            self.addon_id, self.credential_name
+        )
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Backup List
+///
+/// List the backups, restores and copies of the databases of an app. Transfers are numbered per app, so this list is
+/// keyed by the app rather than by a database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-backups#viewing-backups)
+///
+/// # Example:
+///
+/// PostgresBackupList takes one required parameter, app_id, and returns a list of [`PostgresTransfers`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresBackupList::new("APP_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresTransfer.html
+pub struct PostgresBackupList<'a> {
+    /// unique app identifier, either app id or app name
+    pub app_id: AppRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresBackupList<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> PostgresBackupList<'a> {
+        PostgresBackupList {
+            app_id: app_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<PostgresTransfer>> for PostgresBackupList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("client/v11/apps/{}/transfers", self.app_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Backup Info
+///
+/// Info for a backup, restore or copy of the databases of an app.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-backups#viewing-backups)
+///
+/// # Example:
+///
+/// PostgresBackupDetails takes two required parameters, app_id and num, and returns the [`PostgresTransfer`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresBackupDetails::new("APP_ID", 12));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresTransfer.html
+pub struct PostgresBackupDetails<'a> {
+    /// unique app identifier, either app id or app name
+    pub app_id: AppRef<'a>,
+    /// number of the transfer, e.g. `12` for backup `b012`
+    pub num: i64,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresBackupDetails<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>, num: i64) -> PostgresBackupDetails<'a> {
+        PostgresBackupDetails {
+            app_id: app_id.into(),
+            num,
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<PostgresTransfer> for PostgresBackupDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("client/v11/apps/{}/transfers/{}", self.app_id, self.num)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Backup Schedule List
+///
+/// List the backup schedules of an existing database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-backups#scheduling-backups)
+///
+/// # Example:
+///
+/// PostgresBackupScheduleList takes one required parameter, addon_id, and returns a list of [`PostgresBackupSchedules`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresBackupScheduleList::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresBackupSchedule.html
+pub struct PostgresBackupScheduleList<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresBackupScheduleList<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresBackupScheduleList<'a> {
+        PostgresBackupScheduleList {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<PostgresBackupSchedule>> for PostgresBackupScheduleList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/transfer-schedules", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Maintenance Info
+///
+/// The maintenance status and window of an existing database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-maintenance)
+///
+/// # Example:
+///
+/// PostgresMaintenanceDetails takes one required parameter, addon_id, and returns the [`PostgresMaintenance`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresMaintenanceDetails::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresMaintenance.html
+pub struct PostgresMaintenanceDetails<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresMaintenanceDetails<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresMaintenanceDetails<'a> {
+        PostgresMaintenanceDetails {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<PostgresMaintenance> for PostgresMaintenanceDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/maintenance", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
@@ -0,0 +1,355 @@
+use crate::framework::response::ApiResult;
+
+pub mod delete;
+pub mod get;
+pub mod post;
+pub mod put;
+
+pub use delete::{PostgresBackupScheduleDelete, PostgresCredentialDelete};
+pub use get::{
+    PostgresBackupDetails, PostgresBackupList, PostgresBackupScheduleList,
+    PostgresCredentialDetails, PostgresCredentialList, PostgresDetails, PostgresMaintenanceDetails,
+    PostgresWaitStatusDetails,
+};
+pub use post::{
+    PostgresBackupCreate, PostgresBackupScheduleCreate, PostgresBackupScheduleCreateParams,
+    PostgresBackupUrlCreate, PostgresConnectionPoolingCreate,
+    PostgresConnectionPoolingCreateParams, PostgresCredentialCreate,
+    PostgresCredentialCreateParams, PostgresCredentialRotate, PostgresCredentialRotateAll,
+    PostgresCredentialRotateParams, PostgresMaintenanceRun,
+};
+pub use put::{
+    PostgresMaintenanceWindowUpdate, PostgresMaintenanceWindowUpdateParams, PostgresUnfollow,
+};
+
+impl ApiResult for PostgresDatabase {}
+
+impl ApiResult for PostgresWaitStatus {}
+
+impl ApiResult for PostgresCredential {}
+impl ApiResult for Vec<PostgresCredential> {}
+
+impl ApiResult for PostgresTransfer {}
+impl ApiResult for Vec<PostgresTransfer> {}
+
+impl ApiResult for PostgresBackupUrl {}
+
+impl ApiResult for PostgresBackupSchedule {}
+impl ApiResult for Vec<PostgresBackupSchedule> {}
+
+impl ApiResult for PostgresMaintenance {}
+
+impl ApiResult for PostgresConnectionPool {}
+
+pub use backup::{PostgresBackupSchedule, PostgresBackupUrl, PostgresTransfer};
+pub use connection_pooling::PostgresConnectionPool;
+pub use credential::{PostgresCredential, PostgresCredentialUser};
+pub use database::{PostgresDatabase, PostgresInfo, PostgresWaitStatus};
+pub use maintenance::PostgresMaintenance;
+
+/// The data API formats timestamps as `2020-01-01 12:00:00 +0000`, not RFC 3339.
+mod timestamp {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    const FORMAT: &str = "%Y-%m-%d %H:%M:%S %z";
+
+    pub fn serialize<S: Serializer>(
+        time: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&time.format(FORMAT))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let time = String::deserialize(deserializer)?;
+        DateTime::parse_from_str(&time, FORMAT)
+            .map(|time| time.with_timezone(&Utc))
+            .map_err(serde::de::Error::custom)
+    }
+
+    pub mod option {
+        use chrono::offset::Utc;
+        use chrono::DateTime;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            time: &Option<DateTime<Utc>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match time {
+                Some(time) => super::serialize(time, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<DateTime<Utc>>, D::Error> {
+            #[derive(Deserialize)]
+            struct Timestamp(#[serde(with = "super")] DateTime<Utc>);
+
+            let time = Option::<Timestamp>::deserialize(deserializer)?;
+            Ok(time.map(|Timestamp(time)| time))
+        }
+    }
+}
+
+mod database {
+    /// Postgres Database
+    ///
+    /// A Heroku Postgres database, as shown by `heroku pg:info`.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-postgresql)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct PostgresDatabase {
+        /// unique identifier of the add-on
+        pub addon_id: String,
+        /// globally unique name of the add-on
+        pub name: String,
+        /// name of the plan, e.g. `standard-0`
+        pub plan: String,
+        /// url of the database on the data API
+        pub resource_url: String,
+        /// name of the database this database follows, if it is a follower
+        pub following: Option<String>,
+        /// size of the database in bytes
+        pub num_bytes: Option<i64>,
+        /// number of tables of the database
+        pub num_tables: Option<i64>,
+        /// information about the database, e.g. its status, connections or followers
+        pub info: Vec<PostgresInfo>,
+    }
+
+    /// information about the database
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct PostgresInfo {
+        /// name of the information, e.g. `Status` or `Followers`
+        pub name: String,
+        /// values of the information
+        pub values: Vec<serde_json::Value>,
+        /// whether the values are add-on names to resolve to attachment names
+        pub resolve_db_name: Option<bool>,
+    }
+
+    impl PostgresDatabase {
+        /// The values of the information `name`, e.g. `Status`, formatted as text.
+        pub fn info(&self, name: &str) -> Vec<String> {
+            self.info
+                .iter()
+                .filter(|info| info.name == name)
+                .flat_map(|info| info.values.iter())
+                .map(|value| match value {
+                    serde_json::Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                })
+                .collect()
+        }
+
+        /// The names of the followers of the database.
+        pub fn followers(&self) -> Vec<String> {
+            self.info("Followers")
+        }
+    }
+
+    /// Postgres Wait Status
+    ///
+    /// Whether a database is still being provisioned, upgraded or caught up.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-postgresql)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PostgresWaitStatus {
+        /// status of the database, e.g. `Available`
+        pub message: String,
+        /// whether the database is not available yet
+        #[serde(rename = "waiting?")]
+        pub waiting: bool,
+        /// whether the database failed to become available
+        #[serde(rename = "error?")]
+        pub error: Option<bool>,
+    }
+}
+
+mod credential {
+    /// Postgres Credential
+    ///
+    /// A named set of database users, the default one is `default`.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-postgresql-credentials)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PostgresCredential {
+        /// unique identifier of the credential
+        pub uuid: String,
+        /// name of the credential
+        pub name: String,
+        /// state of the credential, e.g. `active` or `revoking`
+        pub state: String,
+        /// name of the database
+        pub database: String,
+        /// host of the database
+        pub host: String,
+        /// port of the database
+        pub port: i64,
+        /// database users of the credential, more than one while the credential is rotated
+        pub credentials: Vec<PostgresCredentialUser>,
+    }
+
+    /// database user of a credential
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PostgresCredentialUser {
+        /// name of the database user
+        pub user: String,
+        /// password of the database user
+        pub password: String,
+        /// state of the database user, e.g. `active` or `revoking`
+        pub state: String,
+        /// number of open connections of the database user
+        pub connections: Option<i64>,
+    }
+}
+
+mod backup {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+
+    /// Postgres Transfer
+    ///
+    /// A backup, restore or copy of a database. Backups are transfers to `gof3r`.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-postgres-backups)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PostgresTransfer {
+        /// unique identifier of the transfer
+        pub uuid: String,
+        /// number of the transfer in its app, e.g. `12` for backup `b012`
+        pub num: i64,
+        /// name of the source, e.g. the database add-on name
+        pub from_name: Option<String>,
+        /// type of the source, e.g. `pg_dump`
+        pub from_type: String,
+        /// name of the destination
+        pub to_name: Option<String>,
+        /// type of the destination, e.g. `gof3r` for backups
+        pub to_type: String,
+        /// size of the source in bytes
+        pub source_bytes: Option<i64>,
+        /// number of bytes transferred
+        pub processed_bytes: Option<i64>,
+        /// whether the transfer succeeded, `None` while it runs
+        pub succeeded: Option<bool>,
+        /// number of warnings raised by the transfer
+        pub warnings: Option<i64>,
+        /// when the transfer was created
+        #[serde(with = "super::timestamp")]
+        pub created_at: DateTime<Utc>,
+        /// when the transfer started
+        #[serde(default, with = "super::timestamp::option")]
+        pub started_at: Option<DateTime<Utc>>,
+        /// when the transfer finished
+        #[serde(default, with = "super::timestamp::option")]
+        pub finished_at: Option<DateTime<Utc>>,
+        /// when the transfer was canceled
+        #[serde(default, with = "super::timestamp::option")]
+        pub canceled_at: Option<DateTime<Utc>>,
+        /// when the backup was deleted
+        #[serde(default, with = "super::timestamp::option")]
+        pub deleted_at: Option<DateTime<Utc>>,
+        /// schedule that triggered the transfer
+        pub schedule: Option<Schedule>,
+    }
+
+    /// schedule that triggered a transfer
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Schedule {
+        /// unique identifier of the schedule
+        pub uuid: String,
+    }
+
+    impl PostgresTransfer {
+        /// Whether the transfer is a backup.
+        pub fn is_backup(&self) -> bool {
+            self.to_type == "gof3r"
+        }
+
+        /// Name of the transfer as shown by `heroku pg:backups`, e.g. `b012` for a backup or `r003` for a restore.
+        pub fn name(&self) -> String {
+            let prefix = match (self.from_type.as_str(), self.to_type.as_str()) {
+                ("pg_dump", "pg_restore") => "c",
+                ("pg_dump", _) if self.schedule.is_some() => "a",
+                (_, "pg_restore") => "r",
+                _ => "b",
+            };
+            format!("{}{:03}", prefix, self.num)
+        }
+    }
+
+    /// Postgres Backup Url
+    ///
+    /// A signed url to download a backup.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-postgres-backups#downloading-your-backups)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PostgresBackupUrl {
+        /// url to download the backup from
+        pub url: String,
+        /// when the url expires
+        #[serde(with = "super::timestamp")]
+        pub expires_at: DateTime<Utc>,
+    }
+
+    /// Postgres Backup Schedule
+    ///
+    /// A daily backup of a database.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-postgres-backups#scheduling-backups)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PostgresBackupSchedule {
+        /// unique identifier of the schedule
+        pub uuid: String,
+        /// name of the schedule, the attachment name of the database, e.g. `DATABASE_URL`
+        pub name: String,
+        /// hour of the day the backup is taken at, from 0 to 23
+        pub hour: i64,
+        /// time zone of the hour, e.g. `UTC` or `America/Los_Angeles`
+        pub timezone: String,
+        /// number of weekly backups kept
+        pub retain_weeks: Option<i64>,
+        /// number of monthly backups kept
+        pub retain_months: Option<i64>,
+    }
+}
+
+mod maintenance {
+    /// Postgres Maintenance
+    ///
+    /// The maintenance status and window of a database.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/heroku-postgres-maintenance)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PostgresMaintenance {
+        /// status of the maintenance, e.g. `No maintenance required`
+        pub message: String,
+        /// weekly maintenance window, e.g. `Sundays 14:30 to 18:30 UTC`
+        pub window: Option<String>,
+        /// when the pending maintenance is scheduled
+        pub scheduled_for: Option<String>,
+        /// whether maintenance is required
+        pub required: Option<bool>,
+    }
+}
+
+mod connection_pooling {
+    /// Postgres Connection Pool
+    ///
+    /// An attachment of a database through its connection pooler.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/postgres-connection-pooling)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PostgresConnectionPool {
+        /// name of the attachment, e.g. `DATABASE_CONNECTION_POOL`
+        pub name: String,
+    }
+}
@@ -0,0 +1,596 @@
+//Anything related to POST requests for Heroku Postgres and it's variations goes here.
+use super::{PostgresBackupSchedule, PostgresBackupUrl, PostgresConnectionPool, PostgresTransfer};
+
+use crate::endpoints::ids::{AddonRef, AppRef};
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Postgres Credential Create
+///
+/// Create a credential on an existing database. The database must be on a standard, premium, private or shield plan.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgresql-credentials#creating-a-new-credential)
+///
+/// # Example:
+///
+/// PostgresCredentialCreate takes two required parameters, addon_id and name, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresCredentialCreate::new("ADDON_ID", "analyst"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct PostgresCredentialCreate<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: PostgresCredentialCreateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresCredentialCreate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> PostgresCredentialCreate<'a> {
+        PostgresCredentialCreate {
+            addon_id: addon_id.into(),
+            params: PostgresCredentialCreateParams { name: name.into() },
+        }
+    }
+}
+
+/// Create a new credential with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/heroku-postgresql-credentials#creating-a-new-credential)
+#[derive(Serialize, Clone, Debug)]
+pub struct PostgresCredentialCreateParams<'a> {
+    /// name of the credential, lowercase letters, digits and underscores
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), PostgresCredentialCreateParams<'a>>
+    for PostgresCredentialCreate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("postgres/v0/databases/{}/credentials", self.addon_id)
+    }
+    fn body(&self) -> Option<PostgresCredentialCreateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Credential Rotate
+///
+/// Rotate the password of a credential of an existing database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgresql-credentials#rotating-credentials)
+///
+/// # Example:
+///
+/// PostgresCredentialRotate takes two required parameters, addon_id and credential_name, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &PostgresCredentialRotate::new("ADDON_ID", "default")
+///         .forced(true)
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct PostgresCredentialRotate<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// name of the credential, e.g. `default`
+    pub credential_name: Cow<'a, str>,
+    /// The parameters to pass to the Heroku API
+    pub params: PostgresCredentialRotateParams,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresCredentialRotate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        credential_name: impl Into<Cow<'a, str>>,
+    ) -> PostgresCredentialRotate<'a> {
+        PostgresCredentialRotate {
+            addon_id: addon_id.into(),
+            credential_name: credential_name.into(),
+            params: PostgresCredentialRotateParams { forced: None },
+        }
+    }
+
+    /// # forced: rotate even if the credential has open connections, they are terminated
+    pub fn forced(&mut self, forced: bool) -> &mut Self {
+        self.params.forced = Some(forced);
+        self
+    }
+
+    pub fn build(&self) -> PostgresCredentialRotate<'a> {
+        PostgresCredentialRotate {
+            addon_id: self.addon_id.clone(),
+            credential_name: self.credential_name.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Rotate credentials with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/heroku-postgresql-credentials#rotating-credentials)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct PostgresCredentialRotateParams {
+    /// rotate even if the credentials have open connections, they are terminated
+    pub forced: Option<bool>,
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), PostgresCredentialRotateParams>
+    for PostgresCredentialRotate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!(
+            "postgres/v0/databases/{}/credentials/{}/credentials_rotation",
+            self.addon_id, self.credential_name
+        )
+    }
+    fn body(&self) -> Option<PostgresCredentialRotateParams> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Credential Rotate All
+///
+/// Rotate the passwords of every credential of an existing database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgresql-credentials#rotating-credentials)
+///
+/// # Example:
+///
+/// PostgresCredentialRotateAll takes one required parameter, addon_id, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresCredentialRotateAll::new("ADDON_ID").build());
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct PostgresCredentialRotateAll<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: PostgresCredentialRotateParams,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresCredentialRotateAll<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresCredentialRotateAll<'a> {
+        PostgresCredentialRotateAll {
+            addon_id: addon_id.into(),
+            params: PostgresCredentialRotateParams { forced: None },
+        }
+    }
+
+    /// # forced: rotate even if the credentials have open connections, they are terminated
+    pub fn forced(&mut self, forced: bool) -> &mut Self {
+        self.params.forced = Some(forced);
+        self
+    }
+
+    pub fn build(&self) -> PostgresCredentialRotateAll<'a> {
+        PostgresCredentialRotateAll {
+            addon_id: self.addon_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), PostgresCredentialRotateParams>
+    for PostgresCredentialRotateAll<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!(
+            "postgres/v0/databases/{}/credentials_rotation",
+            self.addon_id
+        )
+    }
+    fn body(&self) -> Option<PostgresCredentialRotateParams> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Backup Capture
+///
+/// Capture a backup of an existing database, as `heroku pg:backups:capture` does.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-backups#manual-backups)
+///
+/// # Example:
+///
+/// PostgresBackupCreate takes one required parameter, addon_id, and returns the [`PostgresTransfer`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresBackupCreate::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresTransfer.html
+pub struct PostgresBackupCreate<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresBackupCreate<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresBackupCreate<'a> {
+        PostgresBackupCreate {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<PostgresTransfer> for PostgresBackupCreate<'a> {
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/backups", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Backup Url
+///
+/// Create a signed url to download a backup of the databases of an app, as `heroku pg:backups:url` does.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-backups#downloading-your-backups)
+///
+/// # Example:
+///
+/// PostgresBackupUrlCreate takes two required parameters, app_id and num, and returns the [`PostgresBackupUrl`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresBackupUrlCreate::new("APP_ID", 12));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresBackupUrl.html
+pub struct PostgresBackupUrlCreate<'a> {
+    /// unique app identifier, either app id or app name
+    pub app_id: AppRef<'a>,
+    /// number of the backup, e.g. `12` for backup `b012`
+    pub num: i64,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresBackupUrlCreate<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>, num: i64) -> PostgresBackupUrlCreate<'a> {
+        PostgresBackupUrlCreate {
+            app_id: app_id.into(),
+            num,
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<PostgresBackupUrl> for PostgresBackupUrlCreate<'a> {
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!(
+            "client/v11/apps/{}/transfers/{}/actions/public-url",
+            self.app_id, self.num
+        )
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Backup Schedule Create
+///
+/// Schedule a daily backup of an existing database.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-backups#scheduling-backups)
+///
+/// # Example:
+///
+/// PostgresBackupScheduleCreate takes three required parameters, addon_id, schedule_name and hour, and returns the [`PostgresBackupSchedule`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &PostgresBackupScheduleCreate::new("ADDON_ID", "DATABASE_URL", 2)
+///         .timezone("America/Los_Angeles")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresBackupSchedule.html
+pub struct PostgresBackupScheduleCreate<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: PostgresBackupScheduleCreateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresBackupScheduleCreate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        schedule_name: impl Into<Cow<'a, str>>,
+        hour: u32,
+    ) -> PostgresBackupScheduleCreate<'a> {
+        PostgresBackupScheduleCreate {
+            addon_id: addon_id.into(),
+            params: PostgresBackupScheduleCreateParams {
+                schedule_name: schedule_name.into(),
+                hour,
+                timezone: None,
+            },
+        }
+    }
+
+    /// # timezone: time zone of the hour, e.g. `America/Los_Angeles`, `UTC` by default
+    pub fn timezone(&mut self, timezone: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn build(&self) -> PostgresBackupScheduleCreate<'a> {
+        PostgresBackupScheduleCreate {
+            addon_id: self.addon_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Create a new backup schedule with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/heroku-postgres-backups#scheduling-backups)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct PostgresBackupScheduleCreateParams<'a> {
+    /// name of the schedule, the attachment name of the database, e.g. `DATABASE_URL`
+    pub schedule_name: Cow<'a, str>,
+    /// hour of the day to take the backup at, from 0 to 23
+    pub hour: u32,
+    /// time zone of the hour, `UTC` by default
+    pub timezone: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<PostgresBackupSchedule, (), PostgresBackupScheduleCreateParams<'a>>
+    for PostgresBackupScheduleCreate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/transfer-schedules", self.addon_id)
+    }
+    fn body(&self) -> Option<PostgresBackupScheduleCreateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Maintenance Run
+///
+/// Start the pending maintenance of an existing database now, instead of during its maintenance window.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-maintenance)
+///
+/// # Example:
+///
+/// PostgresMaintenanceRun takes one required parameter, addon_id, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresMaintenanceRun::new("ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct PostgresMaintenanceRun<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresMaintenanceRun<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresMaintenanceRun<'a> {
+        PostgresMaintenanceRun {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value> for PostgresMaintenanceRun<'a> {
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/maintenance", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Connection Pooling Attach
+///
+/// Attach an existing database to an app through its connection pooler, as `heroku pg:connection-pooling:attach` does.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/postgres-connection-pooling)
+///
+/// # Example:
+///
+/// PostgresConnectionPoolingCreate takes two required parameters, addon_id and app, and returns the [`PostgresConnectionPool`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &PostgresConnectionPoolingCreate::new("ADDON_ID", "APP_NAME")
+///         .credential("analyst")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PostgresConnectionPool.html
+pub struct PostgresConnectionPoolingCreate<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: PostgresConnectionPoolingCreateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresConnectionPoolingCreate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        app: impl Into<AppRef<'a>>,
+    ) -> PostgresConnectionPoolingCreate<'a> {
+        PostgresConnectionPoolingCreate {
+            addon_id: addon_id.into(),
+            params: PostgresConnectionPoolingCreateParams {
+                app: app.into(),
+                credential: None,
+            },
+        }
+    }
+
+    /// # credential: name of the credential the pooler connects with, `default` by default
+    pub fn credential(&mut self, credential: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.credential = Some(credential.into());
+        self
+    }
+
+    pub fn build(&self) -> PostgresConnectionPoolingCreate<'a> {
+        PostgresConnectionPoolingCreate {
+            addon_id: self.addon_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Attach a connection pool with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/postgres-connection-pooling)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct PostgresConnectionPoolingCreateParams<'a> {
+    /// app to attach the pool to, either app id or app name
+    pub app: AppRef<'a>,
+    /// name of the credential the pooler connects with
+    pub credential: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<PostgresConnectionPool, (), PostgresConnectionPoolingCreateParams<'a>>
+    for PostgresConnectionPoolingCreate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/connection-pooling", self.addon_id)
+    }
+    fn body(&self) -> Option<PostgresConnectionPoolingCreateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
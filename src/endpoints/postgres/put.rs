@@ -0,0 +1,129 @@
+//Anything related to PUT requests for Heroku Postgres and it's variations goes here.
+
+use crate::endpoints::ids::AddonRef;
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::DataApi;
+use std::borrow::Cow;
+
+/// Postgres Unfollow
+///
+/// Stop a follower database from following its leader, turning it into a writable database of its own. This can't be
+/// undone.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-follower-databases#database-upgrades-and-migrations-with-followers)
+///
+/// # Example:
+///
+/// PostgresUnfollow takes one required parameter, addon_id, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresUnfollow::new("FOLLOWER_ADDON_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct PostgresUnfollow<'a> {
+    /// unique identifier of the follower database add-on
+    pub addon_id: AddonRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresUnfollow<'a> {
+    pub fn new(addon_id: impl Into<AddonRef<'a>>) -> PostgresUnfollow<'a> {
+        PostgresUnfollow {
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value> for PostgresUnfollow<'a> {
+    fn method(&self) -> Method {
+        Method::Put
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/unfollow", self.addon_id)
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
+
+/// Postgres Maintenance Window Update
+///
+/// Set the weekly window during which maintenance of an existing database is run.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/heroku-postgres-maintenance#maintenance-windows)
+///
+/// # Example:
+///
+/// PostgresMaintenanceWindowUpdate takes two required parameters, addon_id and description, and returns the status message of the API.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PostgresMaintenanceWindowUpdate::new("ADDON_ID", "Sunday 14:30"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+pub struct PostgresMaintenanceWindowUpdate<'a> {
+    /// unique identifier of the database add-on
+    pub addon_id: AddonRef<'a>,
+    /// The parameters to pass to the Heroku API
+    pub params: PostgresMaintenanceWindowUpdateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PostgresMaintenanceWindowUpdate<'a> {
+    pub fn new(
+        addon_id: impl Into<AddonRef<'a>>,
+        description: impl Into<Cow<'a, str>>,
+    ) -> PostgresMaintenanceWindowUpdate<'a> {
+        PostgresMaintenanceWindowUpdate {
+            addon_id: addon_id.into(),
+            params: PostgresMaintenanceWindowUpdateParams {
+                description: description.into(),
+            },
+        }
+    }
+}
+
+/// Update the maintenance window with parameters.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/heroku-postgres-maintenance#maintenance-windows)
+#[derive(Serialize, Clone, Debug)]
+pub struct PostgresMaintenanceWindowUpdateParams<'a> {
+    /// start of the weekly window, a day and a UTC time, e.g. `Sunday 14:30`
+    pub description: Cow<'a, str>,
+}
+
+impl<'a> HerokuEndpoint<serde_json::Value, (), PostgresMaintenanceWindowUpdateParams<'a>>
+    for PostgresMaintenanceWindowUpdate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Put
+    }
+    fn path(&self) -> String {
+        format!("client/v11/databases/{}/maintenance_window", self.addon_id)
+    }
+    fn body(&self) -> Option<PostgresMaintenanceWindowUpdateParams<'a>> {
+        Some(self.params.clone())
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        Some(DataApi::Postgres)
+    }
+}
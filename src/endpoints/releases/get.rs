@@ -2,6 +2,7 @@
 
 use super::Release;
 
+use crate::endpoints::ids::{AppRef, ReleaseRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
 
 /// Release List
@@ -30,13 +31,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Release.html
 pub struct ReleaseList<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReleaseList<'a> {
-    pub fn new(app_id: &'a str) -> ReleaseList<'a> {
-        ReleaseList { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> ReleaseList<'a> {
+        ReleaseList { app_id: app_id.into() }
     }
 }
 
@@ -61,7 +62,7 @@ impl<'a> HerokuEndpoint<Vec<Release>> for ReleaseList<'a> {
 /// use heroku_rs::prelude::*;
 ///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
 ///
-/// let response = api_client.request(&ReleaseInfo::new("APP_ID", "RELEASE_ID"));
+/// let response = api_client.request(&ReleaseInfo::new("APP_ID", ReleaseRef::Version(12)));
 ///
 ///match response {
 ///     Ok(success) => println!("Success: {:#?}", success),
@@ -75,15 +76,18 @@ impl<'a> HerokuEndpoint<Vec<Release>> for ReleaseList<'a> {
 /// [response]: ../struct.Release.html
 pub struct ReleaseInfo<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// release_id can be the id or version
-    pub release_id: &'a str,
+    pub release_id: ReleaseRef,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReleaseInfo<'a> {
-    pub fn new(app_id: &'a str, release_id: &'a str) -> ReleaseInfo<'a> {
-        ReleaseInfo { app_id, release_id }
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        release_id: impl Into<ReleaseRef>,
+    ) -> ReleaseInfo<'a> {
+        ReleaseInfo { app_id: app_id.into(), release_id: release_id.into() }
     }
 }
 
@@ -1,4 +1,7 @@
+use crate::endpoints::status::ReleaseStatus;
 use crate::framework::response::ApiResult;
+use chrono::offset::Utc;
+use chrono::DateTime;
 use serde::Deserialize;
 
 pub mod get;
@@ -20,11 +23,11 @@ impl ApiResult for Vec<Release> {}
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct Release {
     /// add-on plans installed on the app for this release
-    pub addon_plan_names: Vec<i64>,
+    pub addon_plan_names: Vec<String>,
     /// An app represents the program that you would like to deploy and run on Heroku
     pub app: App,
     /// when release was created
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// indicates this release as being the current one for the app
     pub current: bool,
     /// description of changes in this release
@@ -36,9 +39,9 @@ pub struct Release {
     /// slug running this release
     pub slug: Option<Slug>,
     /// current status of the release - failed, pending, or succeeded
-    pub status: String,
+    pub status: ReleaseStatus,
     /// when release was updated
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// user account running release
     pub user: User,
     /// unique version assigned to the release
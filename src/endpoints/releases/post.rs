@@ -1,7 +1,9 @@
 //Anything related to creating apps and it's properties goes here.
 use super::Release;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Release Create
 ///
@@ -31,35 +33,35 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 #[derive(Serialize)]
 pub struct ReleaseCreate<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: ReleaseCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReleaseCreate<'a> {
-    pub fn new(app_id: &'a str, slug: &'a str) -> ReleaseCreate<'a> {
+    pub fn new(app_id: impl Into<AppRef<'a>>, slug: impl Into<Cow<'a, str>>) -> ReleaseCreate<'a> {
         ReleaseCreate {
-            app_id,
+            app_id: app_id.into(),
             params: ReleaseCreateParams {
-                slug: slug,
+                slug: slug.into(),
                 description: None,
             },
         }
     }
 
     /// # description: description of changes in this release
-    pub fn description(&mut self, description: &'a str) -> &mut Self {
-        self.params.description = Some(description);
+    pub fn description(&mut self, description: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.description = Some(description.into());
         self
     }
 
     pub fn build(&self) -> ReleaseCreate<'a> {
         ReleaseCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: ReleaseCreateParams {
-                slug: self.params.slug,
-                description: self.params.description,
+                slug: self.params.slug.clone(),
+                description: self.params.description.clone(),
             },
         }
     }
@@ -74,9 +76,9 @@ impl<'a> ReleaseCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct ReleaseCreateParams<'a> {
     /// unique identifier of slug
-    pub slug: &'a str,
+    pub slug: Cow<'a, str>,
     /// description of changes in release
-    pub description: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Release, (), ReleaseCreateParams<'a>> for ReleaseCreate<'a> {
@@ -119,18 +121,21 @@ impl<'a> HerokuEndpoint<Release, (), ReleaseCreateParams<'a>> for ReleaseCreate<
 #[derive(Serialize)]
 pub struct ReleaseRollback<'a> {
     /// app_id can be the app name or the app id
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: ReleaseRollbackParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReleaseRollback<'a> {
-    pub fn new(app_id: &'a str, release_id: &'a str) -> ReleaseRollback<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        release_id: impl Into<Cow<'a, str>>,
+    ) -> ReleaseRollback<'a> {
         ReleaseRollback {
-            app_id,
+            app_id: app_id.into(),
             params: ReleaseRollbackParams {
-                release: release_id,
+                release: release_id.into(),
             },
         }
     }
@@ -144,7 +149,7 @@ impl<'a> ReleaseRollback<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct ReleaseRollbackParams<'a> {
     /// unique identifier of release
-    pub release: &'a str,
+    pub release: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<Release, (), ReleaseRollbackParams<'a>> for ReleaseRollback<'a> {
@@ -1,7 +1,9 @@
 //Anything related to DELETE requests for review app and it's properties goes here.
 use super::{ReviewApp, ReviewAppConfig};
 
+use crate::endpoints::ids::PipelineRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Review App Delete
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.ReviewApp.html
 pub struct ReviewAppDelete<'a> {
     /// review_id is the unique identifier.
-    pub review_id: &'a str,
+    pub review_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppDelete<'a> {
-    pub fn new(review_id: &'a str) -> ReviewAppDelete<'a> {
-        ReviewAppDelete { review_id }
+    pub fn new(review_id: impl Into<Cow<'a, str>>) -> ReviewAppDelete<'a> {
+        ReviewAppDelete { review_id: review_id.into() }
     }
 }
 
@@ -76,13 +78,13 @@ impl<'a> HerokuEndpoint<ReviewApp> for ReviewAppDelete<'a> {
 /// [response]: ../struct.ReviewAppConfig.html
 pub struct ReviewAppConfigDelete<'a> {
     /// pipeline_id is the unique identifier.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppConfigDelete<'a> {
-    pub fn new(pipeline_id: &'a str) -> ReviewAppConfigDelete<'a> {
-        ReviewAppConfigDelete { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> ReviewAppConfigDelete<'a> {
+        ReviewAppConfigDelete { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -1,7 +1,9 @@
 //Anything related to GET requests for review app and it's properties goes here.
 use super::{ReviewApp, ReviewAppConfig};
 
+use crate::endpoints::ids::{AppRef, PipelineRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Get Review App
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.ReviewApp.html
 pub struct ReviewAppDetails<'a> {
     /// review_id is the unique identifier.
-    pub review_id: &'a str,
+    pub review_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppDetails<'a> {
-    pub fn new(review_id: &'a str) -> ReviewAppDetails<'a> {
-        ReviewAppDetails { review_id }
+    pub fn new(review_id: impl Into<Cow<'a, str>>) -> ReviewAppDetails<'a> {
+        ReviewAppDetails { review_id: review_id.into() }
     }
 }
 
@@ -76,13 +78,13 @@ impl<'a> HerokuEndpoint<ReviewApp> for ReviewAppDetails<'a> {
 /// [response]: ../struct.ReviewApp.html
 pub struct ReviewAppByAppDetails<'a> {
     /// app_id is the unique identifier, app name or app id.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppByAppDetails<'a> {
-    pub fn new(app_id: &'a str) -> ReviewAppByAppDetails<'a> {
-        ReviewAppByAppDetails { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> ReviewAppByAppDetails<'a> {
+        ReviewAppByAppDetails { app_id: app_id.into() }
     }
 }
 
@@ -122,13 +124,13 @@ impl<'a> HerokuEndpoint<ReviewApp> for ReviewAppByAppDetails<'a> {
 /// [response]: ../struct.ReviewApp.html
 pub struct ReviewAppByPipelineList<'a> {
     /// app_id is the unique identifier, app name or app id.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppByPipelineList<'a> {
-    pub fn new(pipeline_id: &'a str) -> ReviewAppByPipelineList<'a> {
-        ReviewAppByPipelineList { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> ReviewAppByPipelineList<'a> {
+        ReviewAppByPipelineList { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -168,13 +170,13 @@ impl<'a> HerokuEndpoint<Vec<ReviewApp>> for ReviewAppByPipelineList<'a> {
 /// [response]: ../struct.ReviewApp.html
 pub struct ReviewAppConfigDetails<'a> {
     /// app_id is the unique identifier, app name or app id.
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppConfigDetails<'a> {
-    pub fn new(pipeline_id: &'a str) -> ReviewAppConfigDetails<'a> {
-        ReviewAppConfigDetails { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> ReviewAppConfigDetails<'a> {
+        ReviewAppConfigDetails { pipeline_id: pipeline_id.into() }
     }
 }
 
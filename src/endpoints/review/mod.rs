@@ -28,6 +28,7 @@ pub use review_app_config::ReviewAppConfig;
 mod review_app {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::ReviewAppStatus;
     use serde_json::Value;
 
     /// Review App
@@ -52,8 +53,7 @@ mod review_app {
         /// the pipeline associated to the review app
         pub pipeline: Pipeline,
         /// current state of the review app
-        ///  one of:"pending" or "creating" or "created" or "deleting" or "deleted" or "errored"
-        pub status: String,
+        pub status: ReviewAppStatus,
         /// when review app was updated
         pub updated_at: DateTime<Utc>,
         /// The user who created the review app
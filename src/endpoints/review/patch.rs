@@ -1,7 +1,9 @@
 //Anything related to PATCH requests for review app and it's properties goes here.
 use super::ReviewAppConfig;
 
+use crate::endpoints::ids::PipelineRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Review App Configuration Update
 ///
@@ -38,16 +40,16 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.ReviewAppConfig.html
 pub struct ReviewAppConfigUpdate<'a> {
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: ReviewAppConfigUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppConfigUpdate<'a> {
-    pub fn new(pipeline_id: &'a str) -> ReviewAppConfigUpdate<'a> {
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> ReviewAppConfigUpdate<'a> {
         ReviewAppConfigUpdate {
-            pipeline_id,
+            pipeline_id: pipeline_id.into(),
             params: ReviewAppConfigUpdateParams {
                 automatic_review_apps: None,
                 destroy_stale_apps: None,
@@ -60,8 +62,8 @@ impl<'a> ReviewAppConfigUpdate<'a> {
     }
 
     /// # base_name: A unique prefix that will be used to create review app names
-    pub fn base_name(&mut self, base_name: &'a str) -> &mut Self {
-        self.params.base_name = Some(base_name);
+    pub fn base_name(&mut self, base_name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.base_name = Some(base_name.into());
         self
     }
 
@@ -80,17 +82,21 @@ impl<'a> ReviewAppConfigUpdate<'a> {
     /// ## type of deploy target
     /// 
     /// `pattern`:  pattern: `(^space$
-    pub fn deploy_target(&mut self, id: &'a str, t_type: &'a str) -> &mut Self {
+    pub fn deploy_target(
+        &mut self,
+        id: impl Into<Cow<'a, str>>,
+        t_type: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
         self.params.deploy_target = Some(DeployTarget {
-            id: id,
-            type_field: t_type,
+            id: id.into(),
+            type_field: t_type.into(),
         });
         self
     }
 
     /// # stale_days: If destroy_stale_apps is true, then apps will be destroyed after this many days
-    pub fn stale_days(&mut self, stale_days: &'a str) -> &mut Self {
-        self.params.stale_days = Some(stale_days);
+    pub fn stale_days(&mut self, stale_days: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.stale_days = Some(stale_days.into());
         self
     }
 
@@ -108,14 +114,14 @@ impl<'a> ReviewAppConfigUpdate<'a> {
 
     pub fn build(&self) -> ReviewAppConfigUpdate<'a> {
         ReviewAppConfigUpdate {
-            pipeline_id: self.pipeline_id,
+            pipeline_id: self.pipeline_id.clone(),
             params: ReviewAppConfigUpdateParams {
                 automatic_review_apps: self.params.automatic_review_apps,
                 destroy_stale_apps: self.params.destroy_stale_apps,
-                stale_days: self.params.stale_days,
+                stale_days: self.params.stale_days.clone(),
                 deploy_target: self.params.deploy_target.clone(),
                 wait_for_ci: self.params.wait_for_ci,
-                base_name: self.params.base_name,
+                base_name: self.params.base_name.clone(),
             },
         }
     }
@@ -134,25 +140,25 @@ pub struct ReviewAppConfigUpdateParams<'a> {
     pub destroy_stale_apps: Option<bool>,
     /// If destroy_stale_apps is true, then apps will be destroyed after this many days
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stale_days: Option<&'a str>,
+    pub stale_days: Option<Cow<'a, str>>,
     /// Provides a key/value pair to specify whether to use a common runtime or a private space. [Nullable]
     pub deploy_target: Option<DeployTarget<'a>>,
     /// If true, review apps will only be created when CI passes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wait_for_ci: Option<bool>,
     /// A unique prefix that will be used to create review app names. [Nullable]
-    pub base_name: Option<&'a str>,
+    pub base_name: Option<Cow<'a, str>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
 pub struct DeployTarget<'a> {
     /// unique identifier of deploy target
     ///  pattern: `(^[a-fA-F0-9]{8}-[a-fA-F0-9]{4}-[a-fA-F0-9]{4}-[a-fA-F0-9]{4}-[a-fA-F0-9]{12}$
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
     #[serde(rename = "type")]
     /// type of deploy target
     ///  pattern: `(^space$
-    pub type_field: &'a str,
+    pub type_field: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<ReviewAppConfig, (), ReviewAppConfigUpdateParams<'a>>
@@ -2,7 +2,9 @@
 use super::{ReviewApp, ReviewAppConfig};
 use std::collections::HashMap;
 
+use crate::endpoints::ids::PipelineRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Review App Create
 ///
@@ -53,17 +55,17 @@ pub struct ReviewAppCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppCreate<'a> {
     pub fn new(
-        branch: &'a str,
-        pipeline_id: &'a str,
-        source_blob_url: &'a str,
+        branch: impl Into<Cow<'a, str>>,
+        pipeline_id: impl Into<Cow<'a, str>>,
+        source_blob_url: impl Into<Cow<'a, str>>,
     ) -> ReviewAppCreate<'a> {
         ReviewAppCreate {
             params: ReviewAppCreateParams {
-                branch: branch,
+                branch: branch.into(),
                 pr_number: None,
-                pipeline: pipeline_id,
+                pipeline: pipeline_id.into(),
                 source_blob: SourceBlob {
-                    url: source_blob_url,
+                    url: source_blob_url.into(),
                     version: None,
                 },
                 enviroment: None,
@@ -73,20 +75,31 @@ impl<'a> ReviewAppCreate<'a> {
     }
 
     /// # source_blob_version: The version number (or SHA) of the code to build.
-    pub fn source_blob_version(&mut self, source_blob_version: &'a str) -> &mut Self {
-        self.params.source_blob.version = Some(source_blob_version);
+    pub fn source_blob_version(
+        &mut self,
+        source_blob_version: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        self.params.source_blob.version = Some(source_blob_version.into());
         self
     }
 
     /// # source_blob_url: URL where gzipped tar archive of source code for build was downloaded.
-    pub fn source_blob_url(&mut self, source_blob_url: &'a str) -> &mut Self {
-        self.params.source_blob.url = source_blob_url;
+    pub fn source_blob_url(&mut self, source_blob_url: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.source_blob.url = source_blob_url.into();
         self
     }
 
     /// # enviroment: A set of key value pairs which will be put into the environment of the spawned review app process.
-    pub fn enviroment(&mut self, enviroment: HashMap<&'a str, &'a str>) -> &mut Self {
-        self.params.enviroment = Some(enviroment);
+    pub fn enviroment(
+        &mut self,
+        enviroment: impl IntoIterator<Item = (impl Into<Cow<'a, str>>, impl Into<Cow<'a, str>>)>,
+    ) -> &mut Self {
+        self.params.enviroment = Some(
+            enviroment
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect(),
+        );
         self
     }
 
@@ -112,12 +125,12 @@ impl<'a> ReviewAppCreate<'a> {
     pub fn build(&self) -> ReviewAppCreate<'a> {
         ReviewAppCreate {
             params: ReviewAppCreateParams {
-                branch: self.params.branch,
+                branch: self.params.branch.clone(),
                 pr_number: self.params.pr_number,
-                pipeline: self.params.pipeline,
+                pipeline: self.params.pipeline.clone(),
                 source_blob: SourceBlob {
-                    url: self.params.source_blob.url,
-                    version: self.params.source_blob.version,
+                    url: self.params.source_blob.url.clone(),
+                    version: self.params.source_blob.version.clone(),
                 },
                 enviroment: self.params.enviroment.clone(),
                 fork_repo_id: self.params.fork_repo_id,
@@ -132,15 +145,15 @@ impl<'a> ReviewAppCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct ReviewAppCreateParams<'a> {
     /// the branch of the repository which the review app is based on
-    pub branch: &'a str,
+    pub branch: Cow<'a, str>,
     /// pull request number the review app is built for
     pub pr_number: Option<u32>,
     /// unique identifier of pipeline
-    pub pipeline: &'a str,
+    pub pipeline: Cow<'a, str>,
     /// source blob
     pub source_blob: SourceBlob<'a>,
     /// A set of key value pairs which will be put into the environment of the spawned review app process. [Nullable]
-    pub enviroment: Option<HashMap<&'a str, &'a str>>,
+    pub enviroment: Option<HashMap<Cow<'a, str>, Cow<'a, str>>>,
     /// repository id of the fork the branch resides in. [Nullable]
     pub fork_repo_id: Option<i64>,
 }
@@ -148,9 +161,9 @@ pub struct ReviewAppCreateParams<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct SourceBlob<'a> {
     /// URL where gzipped tar archive of source code for build was downloaded.
-    pub url: &'a str,
+    pub url: Cow<'a, str>,
     /// The version number (or SHA) of the code to build. [Nullable]
-    pub version: Option<&'a str>,
+    pub version: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<ReviewApp, (), ReviewAppCreateParams<'a>> for ReviewAppCreate<'a> {
@@ -206,18 +219,21 @@ impl<'a> HerokuEndpoint<ReviewApp, (), ReviewAppCreateParams<'a>> for ReviewAppC
 /// [response]: ../struct.ReviewAppConfig.html
 pub struct ReviewAppConfigEnable<'a> {
     /// unique pipeline identifier
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: ReviewAppConfigEnableParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> ReviewAppConfigEnable<'a> {
-    pub fn new(pipeline_id: &'a str, repo: &'a str) -> ReviewAppConfigEnable<'a> {
+    pub fn new(
+        pipeline_id: impl Into<PipelineRef<'a>>,
+        repo: impl Into<Cow<'a, str>>,
+    ) -> ReviewAppConfigEnable<'a> {
         ReviewAppConfigEnable {
-            pipeline_id,
+            pipeline_id: pipeline_id.into(),
             params: ReviewAppConfigEnableParams {
-                repo: repo,
+                repo: repo.into(),
                 automatic_review_apps: None,
                 destroy_stale_apps: None,
                 stale_days: None,
@@ -229,8 +245,8 @@ impl<'a> ReviewAppConfigEnable<'a> {
     }
 
     /// # base_name: A unique prefix that will be used to create review app names
-    pub fn base_name(&mut self, base_name: &'a str) -> &mut Self {
-        self.params.base_name = Some(base_name);
+    pub fn base_name(&mut self, base_name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.base_name = Some(base_name.into());
         self
     }
 
@@ -249,17 +265,21 @@ impl<'a> ReviewAppConfigEnable<'a> {
     /// ## type of deploy target
     /// 
     /// `pattern`:  pattern: `(^space$
-    pub fn deploy_target(&mut self, id: &'a str, t_type: &'a str) -> &mut Self {
+    pub fn deploy_target(
+        &mut self,
+        id: impl Into<Cow<'a, str>>,
+        t_type: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
         self.params.deploy_target = Some(DeployTarget {
-            id: id,
-            type_field: t_type,
+            id: id.into(),
+            type_field: t_type.into(),
         });
         self
     }
 
     /// # stale_days: If destroy_stale_apps is true, then apps will be destroyed after this many days
-    pub fn stale_days(&mut self, stale_days: &'a str) -> &mut Self {
-        self.params.stale_days = Some(stale_days);
+    pub fn stale_days(&mut self, stale_days: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.stale_days = Some(stale_days.into());
         self
     }
 
@@ -277,15 +297,15 @@ impl<'a> ReviewAppConfigEnable<'a> {
 
     pub fn build(&self) -> ReviewAppConfigEnable<'a> {
         ReviewAppConfigEnable {
-            pipeline_id: self.pipeline_id,
+            pipeline_id: self.pipeline_id.clone(),
             params: ReviewAppConfigEnableParams {
-                repo: self.params.repo,
+                repo: self.params.repo.clone(),
                 automatic_review_apps: self.params.automatic_review_apps,
                 destroy_stale_apps: self.params.destroy_stale_apps,
-                stale_days: self.params.stale_days,
+                stale_days: self.params.stale_days.clone(),
                 deploy_target: self.params.deploy_target.clone(),
                 wait_for_ci: self.params.wait_for_ci,
-                base_name: self.params.base_name,
+                base_name: self.params.base_name.clone(),
             },
         }
     }
@@ -297,7 +317,7 @@ impl<'a> ReviewAppConfigEnable<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct ReviewAppConfigEnableParams<'a> {
     /// The full_name of the repository that you want enable review-apps from.
-    pub repo: &'a str,
+    pub repo: Cow<'a, str>,
     /// If true, this will trigger the creation of review apps when pull-requests are opened in the repo.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub automatic_review_apps: Option<bool>,
@@ -306,25 +326,25 @@ pub struct ReviewAppConfigEnableParams<'a> {
     pub destroy_stale_apps: Option<bool>,
     /// If destroy_stale_apps is true, then apps will be destroyed after this many days
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stale_days: Option<&'a str>,
+    pub stale_days: Option<Cow<'a, str>>,
     /// Provides a key/value pair to specify whether to use a common runtime or a private space. [Nullable]
     pub deploy_target: Option<DeployTarget<'a>>,
     /// If true, review apps will only be created when CI passes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wait_for_ci: Option<bool>,
     /// A unique prefix that will be used to create review app names. [Nullable]
-    pub base_name: Option<&'a str>,
+    pub base_name: Option<Cow<'a, str>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
 pub struct DeployTarget<'a> {
     /// unique identifier of deploy target
     ///  pattern: `(^[a-fA-F0-9]{8}-[a-fA-F0-9]{4}-[a-fA-F0-9]{4}-[a-fA-F0-9]{4}-[a-fA-F0-9]{12}$
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
     #[serde(rename = "type")]
     /// type of deploy target
     ///  pattern: `(^space$
-    pub type_field: &'a str,
+    pub type_field: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<ReviewAppConfig, (), ReviewAppConfigEnableParams<'a>>
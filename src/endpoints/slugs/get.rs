@@ -1,7 +1,9 @@
 //Anything related to GET requests for slugs and it's variations goes here.
 use super::Slug;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Slug Info
 ///
@@ -30,15 +32,15 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Slug.html
 pub struct SlugDetails<'a> {
     /// unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// unique slug indentifier.
-    pub slug_id: &'a str,
+    pub slug_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SlugDetails<'a> {
-    pub fn new(app_id: &'a str, slug_id: &'a str) -> SlugDetails<'a> {
-        SlugDetails { app_id, slug_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>, slug_id: impl Into<Cow<'a, str>>) -> SlugDetails<'a> {
+        SlugDetails { app_id: app_id.into(), slug_id: slug_id.into() }
     }
 }
 
@@ -1,7 +1,9 @@
 //Anything related to POST requests for slugs and it's variations goes here.
 use super::Slug;
 
+use crate::endpoints::ids::AppRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// Create Slug
@@ -44,18 +46,24 @@ use std::collections::HashMap;
 /// [response]: ../struct.Slug.html
 pub struct SlugCreate<'a> {
     /// app_id is the unique app identifier.
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: SlugCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SlugCreate<'a> {
-    pub fn new(app_id: &'a str, process_types: HashMap<&'a str, &'a str>) -> SlugCreate<'a> {
+    pub fn new(
+        app_id: impl Into<AppRef<'a>>,
+        process_types: impl IntoIterator<Item = (impl Into<Cow<'a, str>>, impl Into<Cow<'a, str>>)>,
+    ) -> SlugCreate<'a> {
         SlugCreate {
-            app_id,
+            app_id: app_id.into(),
             params: SlugCreateParams {
-                process_types: process_types,
+                process_types: process_types
+                    .into_iter()
+                    .map(|(key, value)| (key.into(), value.into()))
+                    .collect(),
                 buildpack_provided_description: None,
                 checksum: None,
                 commit: None,
@@ -68,46 +76,46 @@ impl<'a> SlugCreate<'a> {
     /// # buildpack_provided_description: description from buildpack of slug
     pub fn buildpack_provided_description(
         &mut self,
-        buildpack_provided_description: &'a str,
+        buildpack_provided_description: impl Into<Cow<'a, str>>,
     ) -> &mut Self {
-        self.params.buildpack_provided_description = Some(buildpack_provided_description);
+        self.params.buildpack_provided_description = Some(buildpack_provided_description.into());
         self
     }
 
     /// # checksum: an optional checksum of the slug for verifying its integrity
-    pub fn checksum(&mut self, checksum: &'a str) -> &mut Self {
-        self.params.checksum = Some(checksum);
+    pub fn checksum(&mut self, checksum: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.checksum = Some(checksum.into());
         self
     }
 
     /// # commit: identification of the code with your version control system (eg: SHA of the git HEAD)
-    pub fn commit(&mut self, commit: &'a str) -> &mut Self {
-        self.params.commit = Some(commit);
+    pub fn commit(&mut self, commit: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.commit = Some(commit.into());
         self
     }
 
     /// # commit_description: an optional description of the provided commit
-    pub fn commit_description(&mut self, commit_description: &'a str) -> &mut Self {
-        self.params.commit_description = Some(commit_description);
+    pub fn commit_description(&mut self, commit_description: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.commit_description = Some(commit_description.into());
         self
     }
 
     /// # stack: unique name or identifier of stack
-    pub fn stack(&mut self, stack: &'a str) -> &mut Self {
-        self.params.stack = Some(stack);
+    pub fn stack(&mut self, stack: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.stack = Some(stack.into());
         self
     }
 
     pub fn build(&self) -> SlugCreate<'a> {
         SlugCreate {
-            app_id: self.app_id,
+            app_id: self.app_id.clone(),
             params: SlugCreateParams {
                 process_types: self.params.process_types.clone(),
-                buildpack_provided_description: self.params.buildpack_provided_description,
-                checksum: self.params.checksum,
-                commit: self.params.commit,
-                commit_description: self.params.commit_description,
-                stack: self.params.stack,
+                buildpack_provided_description: self.params.buildpack_provided_description.clone(),
+                checksum: self.params.checksum.clone(),
+                commit: self.params.commit.clone(),
+                commit_description: self.params.commit_description.clone(),
+                stack: self.params.stack.clone(),
             },
         }
     }
@@ -121,18 +129,18 @@ impl<'a> SlugCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct SlugCreateParams<'a> {
     /// hash mapping process type names to their respective command. [Nullable]
-    pub process_types: HashMap<&'a str, &'a str>,
+    pub process_types: HashMap<Cow<'a, str>, Cow<'a, str>>,
     /// human-friendly description from buildpack of slug. [Nullable]
-    pub buildpack_provided_description: Option<&'a str>,
+    pub buildpack_provided_description: Option<Cow<'a, str>>,
     //// an optional checksum of the slug for verifying its integrity. [Nullable]
-    pub checksum: Option<&'a str>,
+    pub checksum: Option<Cow<'a, str>>,
     /// identification of the code with your version control system (eg: SHA of the git HEAD). [Nullable]
-    pub commit: Option<&'a str>,
+    pub commit: Option<Cow<'a, str>>,
     /// an optional description of the provided commit. [Nullable]
-    pub commit_description: Option<&'a str>,
+    pub commit_description: Option<Cow<'a, str>>,
     /// unique name or identifier of stack
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stack: Option<&'a str>,
+    pub stack: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Slug, (), SlugCreateParams<'a>> for SlugCreate<'a> {
@@ -0,0 +1,180 @@
+//! CIDR checks for private space peering
+//!
+//! A peer VPC can only be routed to if none of its CIDR blocks overlap the CIDR of the private space, or the data CIDR
+//! used for the Heroku Data add-ons. AWS accepts such a peering anyway, so it is checked before accepting it.
+
+use super::Space;
+use std::fmt;
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+/// An IPv4 CIDR block, e.g. `10.0.0.0/16`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ipv4Cidr {
+    /// first address of the block
+    pub address: Ipv4Addr,
+    /// length of the prefix, from 0 to 32
+    pub prefix: u8,
+}
+
+impl Ipv4Cidr {
+    /// First address of the block, as a number.
+    pub fn first(&self) -> u32 {
+        u32::from(self.address) & self.mask()
+    }
+
+    /// Last address of the block, as a number.
+    pub fn last(&self) -> u32 {
+        self.first() | !self.mask()
+    }
+
+    /// Whether `address` is in the block.
+    pub fn contains(&self, address: Ipv4Addr) -> bool {
+        u32::from(address) & self.mask() == self.first()
+    }
+
+    /// Whether the two blocks share at least one address.
+    pub fn overlaps(&self, other: &Ipv4Cidr) -> bool {
+        self.first() <= other.last() && other.first() <= self.last()
+    }
+
+    fn mask(&self) -> u32 {
+        u32::MAX
+            .checked_shl(32 - u32::from(self.prefix))
+            .unwrap_or(0)
+    }
+}
+
+impl FromStr for Ipv4Cidr {
+    type Err = CidrError;
+
+    fn from_str(cidr: &str) -> Result<Self, Self::Err> {
+        let invalid = || CidrError::Invalid(cidr.to_string());
+        let mut parts = cidr.trim().splitn(2, '/');
+        let address: Ipv4Addr = parts
+            .next()
+            .and_then(|address| address.parse().ok())
+            .ok_or_else(invalid)?;
+        let prefix: u8 = parts
+            .next()
+            .and_then(|prefix| prefix.parse().ok())
+            .filter(|prefix| *prefix <= 32)
+            .ok_or_else(invalid)?;
+        Ok(Ipv4Cidr { address, prefix })
+    }
+}
+
+impl fmt::Display for Ipv4Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix)
+    }
+}
+
+/// Why peer CIDR blocks can't be accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CidrError {
+    /// the value is not an IPv4 CIDR block
+    Invalid(String),
+    /// a CIDR block of the peer overlaps a CIDR block of the space
+    Overlap {
+        /// the CIDR block of the peer
+        peer: Ipv4Cidr,
+        /// the CIDR block of the space, its `cidr` or its `data_cidr`
+        space: Ipv4Cidr,
+    },
+}
+
+impl std::error::Error for CidrError {}
+
+impl fmt::Display for CidrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CidrError::Invalid(cidr) => write!(f, "`{}` is not an IPv4 CIDR block", cidr),
+            CidrError::Overlap { peer, space } => write!(
+                f,
+                "the peer CIDR block {} overlaps the space CIDR block {}",
+                peer, space
+            ),
+        }
+    }
+}
+
+/// Check that every CIDR block of a peer is valid and doesn't overlap the `cidr` or `data_cidr` of `space`.
+///
+/// Returns the first invalid or overlapping block.
+pub fn check_peer_cidrs<S: AsRef<str>>(space: &Space, peer_cidrs: &[S]) -> Result<(), CidrError> {
+    let space_cidrs = [&space.cidr, &space.data_cidr]
+        .iter()
+        .filter(|cidr| !cidr.is_empty())
+        .map(|cidr| cidr.parse())
+        .collect::<Result<Vec<Ipv4Cidr>, _>>()?;
+
+    for peer in peer_cidrs {
+        let peer: Ipv4Cidr = peer.as_ref().parse()?;
+        if let Some(space) = space_cidrs.iter().find(|space| space.overlaps(&peer)) {
+            return Err(CidrError::Overlap {
+                peer,
+                space: *space,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn space() -> Space {
+        serde_json::from_value(json!({
+            "created_at": "2012-01-01T12:00:00Z",
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "name": "nasa",
+            "organization": {"name": "example"},
+            "team": {"id": "01234567-89ab-cdef-0123-456789abcdef", "name": "example"},
+            "region": {"id": "01234567-89ab-cdef-0123-456789abcdef", "name": "virginia"},
+            "shield": true,
+            "state": "allocated",
+            "updated_at": "2012-01-01T12:00:00Z",
+            "cidr": "10.0.0.0/16",
+            "data_cidr": "172.23.0.0/20"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let cidr: Ipv4Cidr = "10.1.0.0/16".parse().unwrap();
+        assert_eq!(cidr.to_string(), "10.1.0.0/16");
+        assert!(cidr.contains(Ipv4Addr::new(10, 1, 255, 1)));
+        assert!(!cidr.contains(Ipv4Addr::new(10, 2, 0, 0)));
+        assert_eq!("0.0.0.0/0".parse::<Ipv4Cidr>().unwrap().last(), u32::MAX);
+
+        for invalid in &["10.0.0.0", "10.0.0.0/33", "10.0.0/8", "fd00::/8"] {
+            assert_eq!(
+                invalid.parse::<Ipv4Cidr>(),
+                Err(CidrError::Invalid(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_peer_cidrs() {
+        let space = space();
+        assert_eq!(
+            check_peer_cidrs(&space, &["10.1.0.0/16", "192.168.0.0/24"]),
+            Ok(())
+        );
+
+        let overlap = check_peer_cidrs(&space, &["10.0.128.0/24"]).unwrap_err();
+        assert_eq!(
+            overlap.to_string(),
+            "the peer CIDR block 10.0.128.0/24 overlaps the space CIDR block 10.0.0.0/16"
+        );
+
+        // a larger peer block containing the data CIDR
+        assert!(check_peer_cidrs(&space, &["172.16.0.0/12"]).is_err());
+        assert!(check_peer_cidrs(&space, &["not-a-cidr"]).is_err());
+    }
+}
@@ -1,7 +1,8 @@
 //Anything related to DELETE requests for spaces goes here.
-use super::{Space, VPN};
+use super::{Peering, Space, VPN};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Space Delete
 ///
@@ -30,13 +31,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Space.html
 pub struct SpaceDelete<'a> {
     /// unique space identifier, either space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceDelete<'a> {
-    pub fn new(space_id: &'a str) -> SpaceDelete<'a> {
-        SpaceDelete { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> SpaceDelete<'a> {
+        SpaceDelete { space_id: space_id.into() }
     }
 }
 
@@ -76,15 +77,18 @@ impl<'a> HerokuEndpoint<Space> for SpaceDelete<'a> {
 /// [response]: ../struct.VPN.html
 pub struct VPNDelete<'a> {
     /// unique space identifier, either space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// unique vpn identifier, either vpn connection name or vpn connection id
-    pub vpn_id: &'a str,
+    pub vpn_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> VPNDelete<'a> {
-    pub fn new(space_id: &'a str, vpn_id: &'a str) -> VPNDelete<'a> {
-        VPNDelete { space_id, vpn_id }
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        vpn_id: impl Into<Cow<'a, str>>,
+    ) -> VPNDelete<'a> {
+        VPNDelete { space_id: space_id.into(), vpn_id: vpn_id.into() }
     }
 }
 
@@ -96,3 +100,54 @@ impl<'a> HerokuEndpoint<VPN> for VPNDelete<'a> {
         format!("spaces/{}/vpn-connections/{}", self.space_id, self.vpn_id)
     }
 }
+
+/// Peering Destroy
+///
+/// Destroy an active peering connection with a private space.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#peering-destroy)
+///
+/// # Example:
+///
+/// PeeringDelete takes two required parameters, space_id and pcx_id, and returns the deleted [`Peering`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PeeringDelete::new("SPACE_ID", "PCX_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+///
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.Peering.html
+pub struct PeeringDelete<'a> {
+    /// space_id can be the space name or space id
+    pub space_id: Cow<'a, str>,
+    /// The AWS VPC Peering Connection ID of the peering, e.g. `pcx-123456789012`
+    pub pcx_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PeeringDelete<'a> {
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        pcx_id: impl Into<Cow<'a, str>>,
+    ) -> PeeringDelete<'a> {
+        PeeringDelete { space_id: space_id.into(), pcx_id: pcx_id.into() }
+    }
+}
+
+impl<'a> HerokuEndpoint<Peering> for PeeringDelete<'a> {
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+    fn path(&self) -> String {
+        format!("spaces/{}/peerings/{}", self.space_id, self.pcx_id)
+    }
+}
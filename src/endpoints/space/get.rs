@@ -1,7 +1,11 @@
 //Anything related to GET requests for spaces goes here.
-use super::{InboundRuleset, OutboundRuleset, Space, SpaceAccess, SpaceNAT, VPN};
+use super::{
+    InboundRuleset, OutboundRuleset, Peering, PeeringInfo, Space, SpaceAccess, SpaceNAT, VPN,
+};
 
+use crate::endpoints::ids::TeamRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Space Info
 ///
@@ -30,13 +34,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Space.html
 pub struct SpaceDetails<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceDetails<'a> {
-    pub fn new(space_id: &'a str) -> SpaceDetails<'a> {
-        SpaceDetails { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> SpaceDetails<'a> {
+        SpaceDetails { space_id: space_id.into() }
     }
 }
 
@@ -92,6 +96,54 @@ impl HerokuEndpoint<Vec<Space>> for SpaceList {
     }
 }
 
+/// Team Space List
+///
+/// List spaces owned by the team
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#team-space-list)
+///
+/// # Example:
+///
+/// TeamSpaceList takes one required parameter, team_id, and returns a list of [`Spaces`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&TeamSpaceList::new("TEAM_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+///
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.Space.html
+pub struct TeamSpaceList<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> TeamSpaceList<'a> {
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamSpaceList<'a> {
+        TeamSpaceList {
+            team_id: team_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<Space>> for TeamSpaceList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("teams/{}/spaces", self.team_id)
+    }
+}
+
 /// Space Access Info
 ///
 /// List permissions for a given user on a given space.
@@ -119,17 +171,20 @@ impl HerokuEndpoint<Vec<Space>> for SpaceList {
 /// [response]: ../struct.SpaceAccess.html
 pub struct SpaceAccessDetails<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// account_id can be the account email or account id
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceAccessDetails<'a> {
-    pub fn new(space_id: &'a str, account_id: &'a str) -> SpaceAccessDetails<'a> {
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        account_id: impl Into<Cow<'a, str>>,
+    ) -> SpaceAccessDetails<'a> {
         SpaceAccessDetails {
-            space_id,
-            account_id,
+            space_id: space_id.into(),
+            account_id: account_id.into(),
         }
     }
 }
@@ -170,13 +225,13 @@ impl<'a> HerokuEndpoint<SpaceAccess> for SpaceAccessDetails<'a> {
 /// [response]: ../struct.SpaceAccess.html
 pub struct SpaceAccessList<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceAccessList<'a> {
-    pub fn new(space_id: &'a str) -> SpaceAccessList<'a> {
-        SpaceAccessList { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> SpaceAccessList<'a> {
+        SpaceAccessList { space_id: space_id.into() }
     }
 }
 
@@ -216,13 +271,13 @@ impl<'a> HerokuEndpoint<Vec<SpaceAccess>> for SpaceAccessList<'a> {
 /// [response]: ../struct.SpaceNAT.html
 pub struct SpaceNATDetails<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceNATDetails<'a> {
-    pub fn new(space_id: &'a str) -> SpaceNATDetails<'a> {
-        SpaceNATDetails { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> SpaceNATDetails<'a> {
+        SpaceNATDetails { space_id: space_id.into() }
     }
 }
 
@@ -262,13 +317,13 @@ impl<'a> HerokuEndpoint<SpaceNAT> for SpaceNATDetails<'a> {
 /// [response]: ../struct.InboundRuleset.html
 pub struct InboundRulesetCurrent<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> InboundRulesetCurrent<'a> {
-    pub fn new(space_id: &'a str) -> InboundRulesetCurrent<'a> {
-        InboundRulesetCurrent { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> InboundRulesetCurrent<'a> {
+        InboundRulesetCurrent { space_id: space_id.into() }
     }
 }
 
@@ -308,17 +363,20 @@ impl<'a> HerokuEndpoint<InboundRuleset> for InboundRulesetCurrent<'a> {
 /// [response]: ../struct.InboundRuleset.html
 pub struct InboundRulesetDetails<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// unique inbound ruleset identifier
-    pub ruleset_id: &'a str,
+    pub ruleset_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> InboundRulesetDetails<'a> {
-    pub fn new(space_id: &'a str, ruleset_id: &'a str) -> InboundRulesetDetails<'a> {
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        ruleset_id: impl Into<Cow<'a, str>>,
+    ) -> InboundRulesetDetails<'a> {
         InboundRulesetDetails {
-            space_id,
-            ruleset_id,
+            space_id: space_id.into(),
+            ruleset_id: ruleset_id.into(),
         }
     }
 }
@@ -362,13 +420,13 @@ impl<'a> HerokuEndpoint<InboundRuleset> for InboundRulesetDetails<'a> {
 /// [response]: ../struct.InboundRuleset.html
 pub struct InboundRulesetList<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> InboundRulesetList<'a> {
-    pub fn new(space_id: &'a str) -> InboundRulesetList<'a> {
-        InboundRulesetList { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> InboundRulesetList<'a> {
+        InboundRulesetList { space_id: space_id.into() }
     }
 }
 
@@ -408,13 +466,13 @@ impl<'a> HerokuEndpoint<Vec<InboundRuleset>> for InboundRulesetList<'a> {
 /// [response]: ../struct.OutboundRuleset.html
 pub struct OutboundRulesetCurrent<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OutboundRulesetCurrent<'a> {
-    pub fn new(space_id: &'a str) -> OutboundRulesetCurrent<'a> {
-        OutboundRulesetCurrent { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> OutboundRulesetCurrent<'a> {
+        OutboundRulesetCurrent { space_id: space_id.into() }
     }
 }
 
@@ -454,17 +512,20 @@ impl<'a> HerokuEndpoint<OutboundRuleset> for OutboundRulesetCurrent<'a> {
 /// [response]: ../struct.OutboundRuleset.html
 pub struct OutboundRulesetDetails<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// outbound ruleset unique identifier
-    pub ruleset_id: &'a str,
+    pub ruleset_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OutboundRulesetDetails<'a> {
-    pub fn new(space_id: &'a str, ruleset_id: &'a str) -> OutboundRulesetDetails<'a> {
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        ruleset_id: impl Into<Cow<'a, str>>,
+    ) -> OutboundRulesetDetails<'a> {
         OutboundRulesetDetails {
-            space_id,
-            ruleset_id,
+            space_id: space_id.into(),
+            ruleset_id: ruleset_id.into(),
         }
     }
 }
@@ -508,13 +569,13 @@ impl<'a> HerokuEndpoint<OutboundRuleset> for OutboundRulesetDetails<'a> {
 /// [response]: ../struct.OutboundRuleset.html
 pub struct OutboundRulesetList<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OutboundRulesetList<'a> {
-    pub fn new(space_id: &'a str) -> OutboundRulesetList<'a> {
-        OutboundRulesetList { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> OutboundRulesetList<'a> {
+        OutboundRulesetList { space_id: space_id.into() }
     }
 }
 
@@ -554,13 +615,13 @@ impl<'a> HerokuEndpoint<Vec<OutboundRuleset>> for OutboundRulesetList<'a> {
 /// [response]: ../struct.VPN.html
 pub struct VPNList<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> VPNList<'a> {
-    pub fn new(space_id: &'a str) -> VPNList<'a> {
-        VPNList { space_id }
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> VPNList<'a> {
+        VPNList { space_id: space_id.into() }
     }
 }
 
@@ -600,15 +661,18 @@ impl<'a> HerokuEndpoint<Vec<VPN>> for VPNList<'a> {
 /// [response]: ../struct.VPN.html
 pub struct VPNDetails<'a> {
     /// space_id can be the space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// unique vpn identifier, either vpn connection name or vpn connection id
-    pub vpn_id: &'a str,
+    pub vpn_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> VPNDetails<'a> {
-    pub fn new(space_id: &'a str, vpn_id: &'a str) -> VPNDetails<'a> {
-        VPNDetails { space_id, vpn_id }
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        vpn_id: impl Into<Cow<'a, str>>,
+    ) -> VPNDetails<'a> {
+        VPNDetails { space_id: space_id.into(), vpn_id: vpn_id.into() }
     }
 }
 
@@ -620,3 +684,146 @@ impl<'a> HerokuEndpoint<VPN> for VPNDetails<'a> {
         format!("spaces/{}/vpn-connections/{}", self.space_id, self.vpn_id)
     }
 }
+
+/// Peering List
+///
+/// List peering connections of a private space VPC.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#peering-list)
+///
+/// # Example:
+///
+/// PeeringList takes one required parameter, space_id, and returns a list of [`Peerings`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PeeringList::new("SPACE_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+///
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.Peering.html
+pub struct PeeringList<'a> {
+    /// space_id can be the space name or space id
+    pub space_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PeeringList<'a> {
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> PeeringList<'a> {
+        PeeringList { space_id: space_id.into() }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<Peering>> for PeeringList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("spaces/{}/peerings", self.space_id)
+    }
+}
+
+/// Peering Info
+///
+/// Fetch information for existing peering connection
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#peering-info)
+///
+/// # Example:
+///
+/// PeeringDetails takes two required parameters, space_id and pcx_id, and returns the [`Peering`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PeeringDetails::new("SPACE_ID", "PCX_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+///
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.Peering.html
+pub struct PeeringDetails<'a> {
+    /// space_id can be the space name or space id
+    pub space_id: Cow<'a, str>,
+    /// The AWS VPC Peering Connection ID of the peering, e.g. `pcx-123456789012`
+    pub pcx_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PeeringDetails<'a> {
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        pcx_id: impl Into<Cow<'a, str>>,
+    ) -> PeeringDetails<'a> {
+        PeeringDetails { space_id: space_id.into(), pcx_id: pcx_id.into() }
+    }
+}
+
+impl<'a> HerokuEndpoint<Peering> for PeeringDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("spaces/{}/peerings/{}", self.space_id, self.pcx_id)
+    }
+}
+
+/// Peering Info Info
+///
+/// Provides the necessary information to establish an AWS VPC Peering Connection.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#peering-info-info)
+///
+/// # Example:
+///
+/// PeeringInfoDetails takes one required parameter, space_id, and returns the [`PeeringInfo`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PeeringInfoDetails::new("SPACE_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+///
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PeeringInfo.html
+pub struct PeeringInfoDetails<'a> {
+    /// space_id can be the space name or space id
+    pub space_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PeeringInfoDetails<'a> {
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> PeeringInfoDetails<'a> {
+        PeeringInfoDetails { space_id: space_id.into() }
+    }
+}
+
+impl<'a> HerokuEndpoint<PeeringInfo> for PeeringInfoDetails<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("spaces/{}/peering-info", self.space_id)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::framework::response::ApiResult;
 
+pub mod cidr;
 pub mod delete;
 pub mod get;
 pub mod patch;
@@ -8,20 +9,21 @@ pub mod put;
 
 pub use get::{
     InboundRulesetCurrent, InboundRulesetDetails, InboundRulesetList, OutboundRulesetCurrent,
-    OutboundRulesetDetails, OutboundRulesetList, SpaceAccessDetails, SpaceAccessList, SpaceDetails,
-    SpaceList, SpaceNATDetails, VPNDetails, VPNList,
+    OutboundRulesetDetails, OutboundRulesetList, PeeringDetails, PeeringInfoDetails, PeeringList,
+    SpaceAccessDetails, SpaceAccessList, SpaceDetails, SpaceList, SpaceNATDetails, TeamSpaceList,
+    VPNDetails, VPNList,
 };
 pub use patch::{SpaceAccessUpdate, SpaceAccessUpdateParams, SpaceUpdate, SpaceUpdateParams};
 pub use post::{
-    SpaceCreate, SpaceCreateParams, SpaceTransferCreate, SpaceTransferCreateParams, VPNCreate,
-    VPNCreateParams,
+    PeeringAccept, SpaceCreate, SpaceCreateParams, SpaceTransferCreate, SpaceTransferCreateParams,
+    VPNCreate, VPNCreateParams,
 };
 pub use put::{
     InboundRulesetCreate, InboundRulesetCreateParams, OutboundRulesetCreate,
     OutboundRulesetCreateParams,
 };
 
-pub use delete::{SpaceDelete, VPNDelete};
+pub use delete::{PeeringDelete, SpaceDelete, VPNDelete};
 
 impl ApiResult for Space {}
 impl ApiResult for Vec<Space> {}
@@ -44,8 +46,14 @@ impl ApiResult for Vec<OutboundRuleset> {}
 impl ApiResult for VPN {}
 impl ApiResult for Vec<VPN> {}
 
+impl ApiResult for Peering {}
+impl ApiResult for Vec<Peering> {}
+
+impl ApiResult for PeeringInfo {}
+
 pub use inbound_ruleset::InboundRuleset;
 pub use outbound_ruleset::OutboundRuleset;
+pub use peering::{Peering, PeeringInfo};
 pub use space_access::SpaceAccess;
 pub use space_nat::SpaceNAT;
 pub use space_transfer::SpaceTransfer;
@@ -55,6 +63,7 @@ pub use vpn::VPN;
 mod spaces {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::SpaceState;
 
     /// Space
     ///
@@ -81,8 +90,7 @@ mod spaces {
         /// true if this space has shield enabled
         pub shield: bool,
         /// availability of this space
-        ///  one of:"allocating" or "allocated" or "deleting"
-        pub state: String,
+        pub state: SpaceState,
         /// when space was updated
         pub updated_at: DateTime<Utc>,
         /// The RFC-1918 CIDR the Private Space will use. It must be a /16 in 10.0.0.0/8, 172.16.0.0/12 or 192.168.0.0/16
@@ -167,6 +175,7 @@ mod space_access {
 mod space_nat {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::SpaceNatState;
 
     /// Space Network Address Translation
     ///
@@ -182,8 +191,7 @@ mod space_nat {
         /// potential IPs from which outbound network traffic will originate
         pub sources: Vec<String>,
         /// availability of network address translation for a space
-        ///  one of:"disabled" or "updating" or "enabled"
-        pub state: String,
+        pub state: SpaceNatState,
         /// when network address translation for a space was updated
         pub updated_at: DateTime<Utc>,
     }
@@ -192,6 +200,7 @@ mod space_nat {
 mod space_transfer {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::SpaceState;
 
     /// Space Transfer
     ///
@@ -209,7 +218,7 @@ mod space_transfer {
         pub team: Team,
         pub region: Region,
         pub shield: bool,
-        pub state: String,
+        pub state: SpaceState,
         pub updated_at: DateTime<Utc>,
         pub cidr: String,
         pub data_cidr: String,
@@ -311,6 +320,10 @@ mod outbound_ruleset {
 }
 
 mod vpn {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+    use crate::endpoints::status::{VpnStatus, VpnTunnelStatus};
+
     /// Private Spaces VPN
     ///
     /// Stability: production
@@ -335,18 +348,76 @@ mod vpn {
         /// IKE Version
         pub ike_version: i64,
         /// Status of the VPN
-        ///  one of:"pending" or "provisioning" or "active" or "deprovisioning" or "failed"
-        pub status: String,
+        pub status: VpnStatus,
         /// Details of the status
         pub status_message: String,
     }
     #[derive(Deserialize, Serialize, Debug, Clone)]
     pub struct Tunnel {
-        pub last_status_change: String,
+        pub last_status_change: DateTime<Utc>,
         pub ip: String,
         pub customer_ip: String,
         pub pre_shared_key: String,
-        pub status: String,
+        pub status: VpnTunnelStatus,
         pub status_message: String,
     }
 }
+
+mod peering {
+    use crate::endpoints::status::{PeeringStatus, PeeringType};
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+
+    /// Peering
+    ///
+    /// Stability: prototype
+    ///
+    /// Peering provides a way to peer your Private Space VPC to another AWS VPC.
+    ///
+    /// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#peering)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Peering {
+        /// The AWS VPC Peering Connection ID of the peering.
+        pub pcx_id: String,
+        /// The type of peering connection.
+        #[serde(rename = "type")]
+        pub type_field: PeeringType,
+        /// The status of the peering connection.
+        pub status: PeeringStatus,
+        /// The AWS VPC ID of the peer.
+        pub aws_vpc_id: String,
+        /// The AWS region of the peer connection.
+        pub aws_region: String,
+        /// The AWS account ID of your Private Space.
+        pub aws_account_id: String,
+        /// The CIDR blocks of the peer.
+        pub cidr_blocks: Vec<String>,
+        /// When a peering connection will expire.
+        pub expires: Option<DateTime<Utc>>,
+    }
+
+    /// Peering Info
+    ///
+    /// Stability: prototype
+    ///
+    /// [Peering Info](https://devcenter.heroku.com/articles/private-space-peering) gives you the information necessary to peer an AWS VPC to a Private Space.
+    ///
+    /// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#peering-info)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PeeringInfo {
+        /// The AWS account ID of your Private Space.
+        pub aws_account_id: String,
+        /// region name used by provider
+        pub aws_region: String,
+        /// The AWS VPC ID of the peer.
+        pub vpc_id: String,
+        /// The CIDR range of the Private Space VPC
+        pub vpc_cidr: String,
+        /// The CIDR ranges that should be routed to the Private Space VPC.
+        pub dyno_cidr_blocks: Vec<String>,
+        /// The CIDR ranges that you must not conflict with.
+        pub unavailable_cidr_blocks: Vec<String>,
+        /// The CIDR ranges that should be routed to the Private Space VPC.
+        pub space_cidr_blocks: Vec<String>,
+    }
+}
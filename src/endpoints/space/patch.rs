@@ -2,6 +2,7 @@
 use super::{Space, SpaceAccess};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Space Update
 ///
@@ -34,16 +35,16 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Space.html
 pub struct SpaceUpdate<'a> {
     /// unique space identifier, either space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: SpaceUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceUpdate<'a> {
-    pub fn new(space_id: &'a str) -> SpaceUpdate<'a> {
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> SpaceUpdate<'a> {
         SpaceUpdate {
-            space_id,
+            space_id: space_id.into(),
             params: SpaceUpdateParams { name: None },
         }
     }
@@ -51,16 +52,16 @@ impl<'a> SpaceUpdate<'a> {
     /// # name: unique name of space
     /// 
     /// `pattern`:  pattern: `^[a-z0-9](?:[a-z0-9]
-    pub fn name(&mut self, _name: &'a str) -> &mut Self {
-        self.params.name = Some(_name);
+    pub fn name(&mut self, _name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(_name.into());
         self
     }
 
     pub fn build(&self) -> SpaceUpdate<'a> {
         SpaceUpdate {
-            space_id: self.space_id,
+            space_id: self.space_id.clone(),
             params: SpaceUpdateParams {
-                name: self.params.name,
+                name: self.params.name.clone(),
             },
         }
     }
@@ -74,7 +75,7 @@ impl<'a> SpaceUpdate<'a> {
 pub struct SpaceUpdateParams<'a> {
     /// unique name of space
     ///  pattern: `^[a-z0-9](?:[a-z0-9]
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Space, (), SpaceUpdateParams<'a>> for SpaceUpdate<'a> {
@@ -117,21 +118,25 @@ impl<'a> HerokuEndpoint<Space, (), SpaceUpdateParams<'a>> for SpaceUpdate<'a> {
 /// [response]: ../struct.SpaceAccess.html
 pub struct SpaceAccessUpdate<'a> {
     /// unique space identifier, either space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// unique account identifier, either account email or account if
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: SpaceAccessUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceAccessUpdate<'a> {
-    pub fn new(space_id: &'a str, account_id: &'a str, _name: &'a str) -> SpaceAccessUpdate<'a> {
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        account_id: impl Into<Cow<'a, str>>,
+        _name: impl Into<Cow<'a, str>>,
+    ) -> SpaceAccessUpdate<'a> {
         SpaceAccessUpdate {
-            space_id,
-            account_id,
+            space_id: space_id.into(),
+            account_id: account_id.into(),
             params: SpaceAccessUpdateParams {
-                permissions: vec![Permissions { name: _name }],
+                permissions: vec![Permissions { name: _name.into() }],
             },
         }
     }
@@ -149,7 +154,7 @@ pub struct SpaceAccessUpdateParams<'a> {
 pub struct Permissions<'a> {
     /// unique name of space
     ///  pattern: `^[a-z0-9](?:[a-z0-9]
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<SpaceAccess, (), SpaceAccessUpdateParams<'a>> for SpaceAccessUpdate<'a> {
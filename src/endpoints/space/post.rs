@@ -1,7 +1,8 @@
 //Anything related to POST requests for spaces goes here.
-use super::{Space, SpaceTransfer, VPN};
+use super::{Peering, Space, SpaceTransfer, VPN};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Space Create
 ///
@@ -41,11 +42,11 @@ pub struct SpaceCreate<'a> {
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceCreate<'a> {
-    pub fn new(name: &'a str, team: &'a str) -> SpaceCreate<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>, team: impl Into<Cow<'a, str>>) -> SpaceCreate<'a> {
         SpaceCreate {
             params: SpaceCreateParams {
-                name: name,
-                team: team,
+                name: name.into(),
+                team: team.into(),
                 cidr: None,
                 data_cidr: None,
                 region: None,
@@ -55,8 +56,8 @@ impl<'a> SpaceCreate<'a> {
     }
 
     /// # region: unique identifier or name of region
-    pub fn region(&mut self, regions: &'a str) -> &mut Self {
-        self.params.region = Some(regions);
+    pub fn region(&mut self, regions: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.region = Some(regions.into());
         self
     }
     /// # cidr: The RFC-1918 CIDR the Private Space will use. It must be a /16 in 10.0.0.0/8, 172.16.0.0/12 or 192.168.0.0/16
@@ -64,13 +65,13 @@ impl<'a> SpaceCreate<'a> {
     /// `default`: "10.0.0.0/16"
     ///
     /// `pattern`: `^((?:10
-    pub fn cidr(&mut self, _cidr: &'a str) -> &mut Self {
-        self.params.cidr = Some(_cidr);
+    pub fn cidr(&mut self, _cidr: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.cidr = Some(_cidr.into());
         self
     }
     /// # data_cidr: The RFC-1918 CIDR that the Private Space will use for the Heroku-managed peering connection that’s automatically created when using Heroku Data add-ons. It must be between a /16 and a /20
-    pub fn data_cidr(&mut self, _data_cidr: &'a str) -> &mut Self {
-        self.params.data_cidr = Some(_data_cidr);
+    pub fn data_cidr(&mut self, _data_cidr: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.data_cidr = Some(_data_cidr.into());
         self
     }
     /// # shield: true if this space has shield enabled
@@ -81,11 +82,11 @@ impl<'a> SpaceCreate<'a> {
     pub fn build(&self) -> SpaceCreate<'a> {
         SpaceCreate {
             params: SpaceCreateParams {
-                name: self.params.name,
-                team: self.params.team,
-                cidr: self.params.cidr,
-                data_cidr: self.params.data_cidr,
-                region: self.params.region,
+                name: self.params.name.clone(),
+                team: self.params.team.clone(),
+                cidr: self.params.cidr.clone(),
+                data_cidr: self.params.data_cidr.clone(),
+                region: self.params.region.clone(),
                 shield: self.params.shield,
             },
         }
@@ -100,18 +101,18 @@ impl<'a> SpaceCreate<'a> {
 pub struct SpaceCreateParams<'a> {
     /// unique name of space
     ///  pattern: `^[a-z0-9](?:[a-z0-9]
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// unique name of team
-    pub team: &'a str,
+    pub team: Cow<'a, str>,
     /// The RFC-1918 CIDR the Private Space will use. It must be a /16 in 10.0.0.0/8, 172.16.0.0/12 or 192.168.0.0/16
     ///  default: "10.0.0.0/16"
     ///  pattern: `^((?:10
-    pub cidr: Option<&'a str>,
+    pub cidr: Option<Cow<'a, str>>,
     /// The RFC-1918 CIDR that the Private Space will use for the Heroku-managed peering connection that’s automatically created when using Heroku Data add-ons.
     /// It must be between a /16 and a /20
-    pub data_cidr: Option<&'a str>,
+    pub data_cidr: Option<Cow<'a, str>>,
     /// unique identifier or name of region
-    pub region: Option<&'a str>,
+    pub region: Option<Cow<'a, str>>,
     /// true if this space has shield enabled
     pub shield: Option<bool>,
 }
@@ -158,17 +159,20 @@ impl<'a> HerokuEndpoint<Space, (), SpaceCreateParams<'a>> for SpaceCreate<'a> {
 /// [response]: ../struct.SpaceTransfer.html
 pub struct SpaceTransferCreate<'a> {
     /// unique space identifier, either space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: SpaceTransferCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> SpaceTransferCreate<'a> {
-    pub fn new(space_id: &'a str, new_owner: &'a str) -> SpaceTransferCreate<'a> {
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        new_owner: impl Into<Cow<'a, str>>,
+    ) -> SpaceTransferCreate<'a> {
         SpaceTransferCreate {
-            space_id,
-            params: SpaceTransferCreateParams { new_owner },
+            space_id: space_id.into(),
+            params: SpaceTransferCreateParams { new_owner: new_owner.into() },
         }
     }
 }
@@ -180,7 +184,7 @@ impl<'a> SpaceTransferCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct SpaceTransferCreateParams<'a> {
     /// unique name of team
-    pub new_owner: &'a str,
+    pub new_owner: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<SpaceTransfer, (), SpaceTransferCreateParams<'a>>
@@ -225,7 +229,7 @@ impl<'a> HerokuEndpoint<SpaceTransfer, (), SpaceTransferCreateParams<'a>>
 /// [response]: ../struct.VPN.html
 pub struct VPNCreate<'a> {
     /// unique space identifier, either space name or space id
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: VPNCreateParams<'a>,
 }
@@ -233,17 +237,17 @@ pub struct VPNCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> VPNCreate<'a> {
     pub fn new(
-        space_id: &'a str,
-        name: &'a str,
-        public_ip: &'a str,
-        routable_cidrs: Vec<&'a str>,
+        space_id: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+        public_ip: impl Into<Cow<'a, str>>,
+        routable_cidrs: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
     ) -> VPNCreate<'a> {
         VPNCreate {
-            space_id,
+            space_id: space_id.into(),
             params: VPNCreateParams {
-                name,
-                public_ip,
-                routable_cidrs,
+                name: name.into(),
+                public_ip: public_ip.into(),
+                routable_cidrs: routable_cidrs.into_iter().map(Into::into).collect(),
             },
         }
     }
@@ -256,11 +260,11 @@ impl<'a> VPNCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct VPNCreateParams<'a> {
     /// VPN Name
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Public IP of VPN customer gateway
-    pub public_ip: &'a str,
+    pub public_ip: Cow<'a, str>,
     /// A list of Routable CIDRs of VPN
-    pub routable_cidrs: Vec<&'a str>,
+    pub routable_cidrs: Vec<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<VPN, (), VPNCreateParams<'a>> for VPNCreate<'a> {
@@ -274,3 +278,77 @@ impl<'a> HerokuEndpoint<VPN, (), VPNCreateParams<'a>> for VPNCreate<'a> {
         Some(self.params.clone())
     }
 }
+
+/// Peering Accept
+///
+/// Accept a pending peering connection with a private space.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#peering-accept)
+///
+/// # Example:
+///
+/// PeeringAccept takes two required parameters, space_id and pcx_id, and returns the [`Peering`][response].
+///
+/// [`PeeringAccept::checked`][checked] only accepts the peering if its CIDR blocks don't overlap the ones of the space.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&PeeringAccept::new("SPACE_ID", "PCX_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+///
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.Peering.html
+/// [checked]: struct.PeeringAccept.html#method.checked
+pub struct PeeringAccept<'a> {
+    /// space_id can be the space name or space id
+    pub space_id: Cow<'a, str>,
+    /// The AWS VPC Peering Connection ID of the peering, e.g. `pcx-123456789012`
+    pub pcx_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> PeeringAccept<'a> {
+    pub fn new(
+        space_id: impl Into<Cow<'a, str>>,
+        pcx_id: impl Into<Cow<'a, str>>,
+    ) -> PeeringAccept<'a> {
+        PeeringAccept { space_id: space_id.into(), pcx_id: pcx_id.into() }
+    }
+
+    /// Accept `peering` only if its CIDR blocks are valid and don't overlap the `cidr` and `data_cidr` of `space`.
+    ///
+    /// ```rust
+    /// use heroku_rs::prelude::*;
+    /// use heroku_rs::endpoints::space::{Peering, Space};
+    ///# fn accept(api_client: &HttpApiClient, space: &Space, peering: &Peering) -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// let accept = PeeringAccept::checked(space, peering)?;
+    /// api_client.request(&accept)?;
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn checked(
+        space: &'a Space,
+        peering: &'a Peering,
+    ) -> Result<PeeringAccept<'a>, super::cidr::CidrError> {
+        super::cidr::check_peer_cidrs(space, &peering.cidr_blocks)?;
+        Ok(PeeringAccept::new(space.id.as_str(), peering.pcx_id.as_str()))
+    }
+}
+
+impl<'a> HerokuEndpoint<Peering> for PeeringAccept<'a> {
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("spaces/{}/peerings/{}/actions/accept", self.space_id, self.pcx_id)
+    }
+}
@@ -2,6 +2,7 @@
 use super::{InboundRuleset, OutboundRuleset};
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Inbound Ruleset Create
 ///
@@ -32,29 +33,33 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.InboundRuleset.html
 pub struct InboundRulesetCreate<'a> {
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: InboundRulesetCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> InboundRulesetCreate<'a> {
-    pub fn new(space_id: &'a str) -> InboundRulesetCreate<'a> {
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> InboundRulesetCreate<'a> {
         InboundRulesetCreate {
-            space_id,
+            space_id: space_id.into(),
             params: InboundRulesetCreateParams { rules: None },
         }
     }
 
     /// # name: array of rules
-    pub fn rule(&mut self, action: &'a str, source: &'a str) -> &mut Self {
-        self.params.rules = Some(vec![(Rule { action, source })]);
+    pub fn rule(
+        &mut self,
+        action: impl Into<Cow<'a, str>>,
+        source: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        self.params.rules = Some(vec![(Rule { action: action.into(), source: source.into() })]);
         self
     }
 
     pub fn build(&self) -> InboundRulesetCreate<'a> {
         InboundRulesetCreate {
-            space_id: self.space_id,
+            space_id: self.space_id.clone(),
             params: InboundRulesetCreateParams {
                 rules: self.params.rules.clone(),
             },
@@ -73,8 +78,8 @@ pub struct InboundRulesetCreateParams<'a> {
 }
 #[derive(Serialize, Clone, Debug)]
 pub struct Rule<'a> {
-    pub action: &'a str,
-    pub source: &'a str,
+    pub action: Cow<'a, str>,
+    pub source: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<InboundRuleset, (), InboundRulesetCreateParams<'a>>
@@ -125,31 +130,31 @@ impl<'a> HerokuEndpoint<InboundRuleset, (), InboundRulesetCreateParams<'a>>
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.OutboundRuleset.html
 pub struct OutboundRulesetCreate<'a> {
-    pub space_id: &'a str,
+    pub space_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: OutboundRulesetCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> OutboundRulesetCreate<'a> {
-    pub fn new(space_id: &'a str) -> OutboundRulesetCreate<'a> {
+    pub fn new(space_id: impl Into<Cow<'a, str>>) -> OutboundRulesetCreate<'a> {
         OutboundRulesetCreate {
-            space_id,
+            space_id: space_id.into(),
             params: OutboundRulesetCreateParams { rules: None },
         }
     }
 
     pub fn rule(
         &mut self,
-        target: &'a str,
-        protocol: &'a str,
+        target: impl Into<Cow<'a, str>>,
+        protocol: impl Into<Cow<'a, str>>,
         from_port: i64,
         to_port: i64,
     ) -> &mut Self {
         self.params.rules = Some(vec![
             (OutboundRule {
-                target,
-                protocol,
+                target: target.into(),
+                protocol: protocol.into(),
                 from_port,
                 to_port,
             }),
@@ -159,7 +164,7 @@ impl<'a> OutboundRulesetCreate<'a> {
 
     pub fn build(&self) -> OutboundRulesetCreate<'a> {
         OutboundRulesetCreate {
-            space_id: self.space_id,
+            space_id: self.space_id.clone(),
             params: OutboundRulesetCreateParams {
                 rules: self.params.rules.clone(),
             },
@@ -178,8 +183,8 @@ pub struct OutboundRulesetCreateParams<'a> {
 }
 #[derive(Serialize, Clone, Debug)]
 pub struct OutboundRule<'a> {
-    pub target: &'a str,
-    pub protocol: &'a str,
+    pub target: Cow<'a, str>,
+    pub protocol: Cow<'a, str>,
     pub from_port: i64,
     pub to_port: i64,
 }
@@ -191,7 +196,7 @@ impl<'a> HerokuEndpoint<OutboundRuleset, (), OutboundRulesetCreateParams<'a>>
         Method::Put
     }
     fn path(&self) -> String {
-        format!("spaces/{}/inbound-ruleset", self.space_id)
+        format!("spaces/{}/outbound-ruleset", self.space_id)
     }
     fn body(&self) -> Option<OutboundRulesetCreateParams<'a>> {
         Some(self.params.clone())
@@ -0,0 +1,400 @@
+//! Typed statuses and states shared by the endpoint models.
+//!
+//! Every enum (de)serializes from the string Heroku sends, values that are not known by this version of the crate
+//! end up in the `Unknown` variant instead of failing the whole response.
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+macro_rules! status_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// a value this version of the crate doesn't know about
+            Unknown(String),
+        }
+
+        impl $name {
+            /// the value as sent by Heroku
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    other => $name::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
+        }
+    };
+}
+
+status_enum! {
+    /// Status of a build.
+    BuildStatus {
+        /// the build is running
+        Pending => "pending",
+        /// the build produced a slug
+        Succeeded => "succeeded",
+        /// the build failed
+        Failed => "failed",
+    }
+}
+
+status_enum! {
+    /// Status of a release.
+    ReleaseStatus {
+        /// the release phase is running
+        Pending => "pending",
+        /// the release is live
+        Succeeded => "succeeded",
+        /// the release phase failed
+        Failed => "failed",
+    }
+}
+
+status_enum! {
+    /// State of a dyno.
+    DynoState {
+        /// the dyno is booting
+        Starting => "starting",
+        /// the dyno is running
+        Up => "up",
+        /// the dyno exited unexpectedly
+        Crashed => "crashed",
+        /// the dyno is sleeping
+        Idle => "idle",
+        /// the dyno is stopped
+        Down => "down",
+    }
+}
+
+status_enum! {
+    /// Status of a test run or test node.
+    TestRunStatus {
+        /// the test run is queued
+        Pending => "pending",
+        /// the test run was cancelled
+        Cancelled => "cancelled",
+        /// the test environment is being created
+        Creating => "creating",
+        /// the app is being built
+        Building => "building",
+        /// the tests are running
+        Running => "running",
+        /// the tests passed
+        Succeeded => "succeeded",
+        /// the tests failed
+        Failed => "failed",
+        /// the test run could not complete
+        Errored => "errored",
+        /// the test run is kept open for debugging
+        Debugging => "debugging",
+    }
+}
+
+status_enum! {
+    /// Status of a pipeline promotion.
+    PromotionStatus {
+        /// the promotion is in progress
+        Pending => "pending",
+        /// every target was processed
+        Completed => "completed",
+    }
+}
+
+status_enum! {
+    /// Status of a single target of a pipeline promotion.
+    PromotionTargetStatus {
+        /// the target is being promoted to
+        Pending => "pending",
+        /// the release was created on the target
+        Succeeded => "succeeded",
+        /// the promotion to the target failed
+        Failed => "failed",
+    }
+}
+
+status_enum! {
+    /// State of an add-on in its lifecycle.
+    AddonState {
+        /// the add-on is being provisioned
+        Provisioning => "provisioning",
+        /// the add-on is ready
+        Provisioned => "provisioned",
+        /// the add-on was removed
+        Deprovisioned => "deprovisioned",
+    }
+}
+
+status_enum! {
+    /// Release status of an add-on service.
+    AddonServiceState {
+        /// alpha, invite only
+        Alpha => "alpha",
+        /// beta, available to everyone
+        Beta => "beta",
+        /// generally available
+        Ga => "ga",
+        /// no longer available
+        Shutdown => "shutdown",
+    }
+}
+
+status_enum! {
+    /// Release status of an add-on plan.
+    AddonPlanState {
+        /// available to everyone
+        Public => "public",
+        /// only available to invited users
+        Private => "private",
+        /// no longer available
+        Shutdown => "shutdown",
+    }
+}
+
+status_enum! {
+    /// Status of an app setup.
+    AppSetupStatus {
+        /// the setup is running
+        Pending => "pending",
+        /// the app was set up
+        Succeeded => "succeeded",
+        /// the setup failed
+        Failed => "failed",
+    }
+}
+
+status_enum! {
+    /// Status of the CNAME record of a domain.
+    DomainStatus {
+        /// the record is being checked
+        Pending => "pending",
+        /// the record points to Heroku
+        Succeeded => "succeeded",
+        /// the record doesn't point to Heroku
+        Failed => "failed",
+    }
+}
+
+status_enum! {
+    /// Status of the Automated Certificate Management of a domain.
+    AcmStatus {
+        /// the certificate was issued
+        CertIssued => "cert issued",
+        /// the certificate request is waiting for the DNS to be verified
+        Pending => "pending",
+        /// the DNS points to Heroku
+        DnsVerified => "dns-verified",
+        /// the certificate request is queued
+        Queued => "queued",
+        /// the certificate is being issued
+        Waiting => "waiting",
+        /// the certificate could not be issued, Heroku keeps trying
+        Failing => "failing",
+        /// the certificate could not be issued
+        Failed => "failed",
+    }
+}
+
+status_enum! {
+    /// Status of a webhook delivery or of one of its attempts.
+    WebhookDeliveryStatus {
+        /// the delivery was not attempted yet
+        Pending => "pending",
+        /// the delivery is scheduled
+        Scheduled => "scheduled",
+        /// the delivery failed and will be retried
+        Retrying => "retrying",
+        /// the delivery failed
+        Failed => "failed",
+        /// the delivery was received
+        Succeeded => "succeeded",
+    }
+}
+
+status_enum! {
+    /// Status of a private space peering connection.
+    PeeringStatus {
+        /// the peering request is being sent
+        InitiatingRequest => "initiating-request",
+        /// the peering request waits to be accepted
+        PendingAcceptance => "pending-acceptance",
+        /// the peering connection is being set up
+        Provisioning => "provisioning",
+        /// the peering connection is up
+        Active => "active",
+        /// the peering connection could not be set up
+        Failed => "failed",
+        /// the peering request was not accepted in time
+        Expired => "expired",
+        /// the peering request was rejected
+        Rejected => "rejected",
+        /// the peering connection was deleted
+        Deleted => "deleted",
+    }
+}
+
+status_enum! {
+    /// Type of a private space peering connection.
+    PeeringType {
+        /// a peering Heroku manages, e.g. for Heroku Data add-ons
+        HerokuManaged => "heroku-managed",
+        /// a peering with a VPC of the customer
+        CustomerManaged => "customer-managed",
+        /// a peering with a Heroku Postgres database
+        HerokuPostgres => "heroku-postgres",
+        /// a peering with a Heroku Redis database
+        HerokuRedis => "heroku-redis",
+        /// a peering with a Heroku Kafka cluster
+        HerokuKafka => "heroku-kafka",
+    }
+}
+
+status_enum! {
+    /// Status of a review app.
+    ReviewAppStatus {
+        /// the review app is queued
+        Pending => "pending",
+        /// the review app is being created
+        Creating => "creating",
+        /// the review app is running
+        Created => "created",
+        /// the review app is being deleted
+        Deleting => "deleting",
+        /// the review app was deleted
+        Deleted => "deleted",
+        /// the creation failed, see `error_status` and `message`
+        Errored => "errored",
+    }
+}
+
+status_enum! {
+    /// Availability of a private space.
+    SpaceState {
+        /// the space is being created
+        Allocating => "allocating",
+        /// the space is ready
+        Allocated => "allocated",
+        /// the space is being deleted
+        Deleting => "deleting",
+    }
+}
+
+status_enum! {
+    /// Availability of the network address translation of a private space.
+    SpaceNatState {
+        /// outbound traffic doesn't come from stable IPs
+        Disabled => "disabled",
+        /// the sources are being changed
+        Updating => "updating",
+        /// outbound traffic comes from the listed sources
+        Enabled => "enabled",
+    }
+}
+
+status_enum! {
+    /// Status of a private space VPN connection.
+    VpnStatus {
+        /// the connection is queued
+        Pending => "pending",
+        /// the connection is being set up
+        Provisioning => "provisioning",
+        /// the connection is ready
+        Active => "active",
+        /// the connection is being removed
+        Deprovisioning => "deprovisioning",
+        /// the connection could not be set up
+        Failed => "failed",
+    }
+}
+
+status_enum! {
+    /// Status of a tunnel of a private space VPN connection.
+    VpnTunnelStatus {
+        /// the tunnel is up
+        Up => "UP",
+        /// the tunnel is down
+        Down => "DOWN",
+    }
+}
+
+status_enum! {
+    /// Release state of an account, app or team feature.
+    FeatureState {
+        /// generally available
+        Public => "public",
+        /// available to anyone who enables it, may change
+        Beta => "beta",
+        /// experimental, may change or go away
+        Alpha => "alpha",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_value() {
+        let state: DynoState = serde_json::from_str("\"crashed\"").unwrap();
+        assert_eq!(state, DynoState::Crashed);
+        assert_eq!(serde_json::to_string(&state).unwrap(), "\"crashed\"");
+    }
+
+    #[test]
+    fn test_unknown_value_round_trip() {
+        let status: BuildStatus = serde_json::from_str("\"expired\"").unwrap();
+        assert_eq!(status, BuildStatus::Unknown("expired".to_string()));
+        assert_eq!(status.to_string(), "expired");
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"expired\"");
+    }
+
+    #[test]
+    fn test_value_is_case_sensitive() {
+        assert_eq!(VpnTunnelStatus::from("UP"), VpnTunnelStatus::Up);
+        assert_eq!(
+            VpnTunnelStatus::from("up"),
+            VpnTunnelStatus::Unknown("up".to_string())
+        );
+    }
+
+    #[test]
+    fn test_value_with_space() {
+        assert_eq!(AcmStatus::from("cert issued"), AcmStatus::CertIssued);
+        assert_eq!(AcmStatus::CertIssued.as_str(), "cert issued");
+    }
+}
@@ -1,7 +1,11 @@
 //Anything related to GET requests for Teams and it's variations goes here.
-use super::{Team, TeamInvitation, TeamMember};
+use super::{
+    Team, TeamIdentityProvider, TeamInvitation, TeamMember, WhitelistedAddonService,
+};
 
+use crate::endpoints::ids::TeamRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Team Delete
 ///
@@ -30,13 +34,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Team.html
 pub struct TeamDelete<'a> {
     /// unique team identifier.
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamDelete<'a> {
-    pub fn new(team_id: &'a str) -> TeamDelete {
-        TeamDelete { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamDelete<'a> {
+        TeamDelete { team_id: team_id.into() }
     }
 }
 
@@ -76,17 +80,20 @@ impl<'a> HerokuEndpoint<Team> for TeamDelete<'a> {
 /// [response]: ../struct.TeamInvitation.html
 pub struct TeamInvitationRevoke<'a> {
     /// unique team identifier.
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// unique invitation identifier
-    pub invitation_id: &'a str,
+    pub invitation_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamInvitationRevoke<'a> {
-    pub fn new(team_id: &'a str, invitation_id: &'a str) -> TeamInvitationRevoke<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        invitation_id: impl Into<Cow<'a, str>>,
+    ) -> TeamInvitationRevoke<'a> {
         TeamInvitationRevoke {
-            team_id,
-            invitation_id,
+            team_id: team_id.into(),
+            invitation_id: invitation_id.into(),
         }
     }
 }
@@ -127,15 +134,18 @@ impl<'a> HerokuEndpoint<TeamInvitation> for TeamInvitationRevoke<'a> {
 /// [response]: ../struct.TeamMember.html
 pub struct TeamMemberDelete<'a> {
     /// unique team identifier.
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// unique member identifier
-    pub member_id: &'a str,
+    pub member_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamMemberDelete<'a> {
-    pub fn new(team_id: &'a str, member_id: &'a str) -> TeamMemberDelete<'a> {
-        TeamMemberDelete { team_id, member_id }
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        member_id: impl Into<Cow<'a, str>>,
+    ) -> TeamMemberDelete<'a> {
+        TeamMemberDelete { team_id: team_id.into(), member_id: member_id.into() }
     }
 }
 
@@ -147,3 +157,117 @@ impl<'a> HerokuEndpoint<TeamMember> for TeamMemberDelete<'a> {
         format!("teams/{}/members/{}", self.team_id, self.member_id)
     }
 }
+
+/// Team Identity Provider Delete
+///
+/// Delete a team's Identity Provider
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#identity-provider-delete-by-team)
+///
+/// # Example:
+///
+/// TeamIdentityProviderDelete takes two required parameters, team_id and identity_provider_id, and returns the deleted [`TeamIdentityProvider`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&TeamIdentityProviderDelete::new("TEAM_NAME", "PROVIDER_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.TeamIdentityProvider.html
+pub struct TeamIdentityProviderDelete<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+    /// unique identifier of the identity provider
+    pub identity_provider_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> TeamIdentityProviderDelete<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        identity_provider_id: impl Into<Cow<'a, str>>,
+    ) -> TeamIdentityProviderDelete<'a> {
+        TeamIdentityProviderDelete {
+            team_id: team_id.into(),
+            identity_provider_id: identity_provider_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<TeamIdentityProvider> for TeamIdentityProviderDelete<'a> {
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+    fn path(&self) -> String {
+        format!(
+            "teams/{}/identity-providers/{}",
+            self.team_id, self.identity_provider_id
+        )
+    }
+}
+
+/// Whitelisted Add-on Service Delete By Team
+///
+/// Remove a whitelisted entity
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#whitelisted-entity-delete-by-team)
+///
+/// # Example:
+///
+/// WhitelistedAddonServiceDelete takes two required parameters, team_id and whitelisted_id, and returns the removed [`WhitelistedAddonService`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&WhitelistedAddonServiceDelete::new("TEAM_ID", "heroku-postgresql"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.WhitelistedAddonService.html
+pub struct WhitelistedAddonServiceDelete<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+    /// unique identifier of the whitelisting entity, or name of the whitelisted add-on service
+    pub whitelisted_id: Cow<'a, str>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> WhitelistedAddonServiceDelete<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        whitelisted_id: impl Into<Cow<'a, str>>,
+    ) -> WhitelistedAddonServiceDelete<'a> {
+        WhitelistedAddonServiceDelete {
+            team_id: team_id.into(),
+            whitelisted_id: whitelisted_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<WhitelistedAddonService> for WhitelistedAddonServiceDelete<'a> {
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+    fn path(&self) -> String {
+        format!(
+            "teams/{}/whitelisted-addon-services/{}",
+            self.team_id, self.whitelisted_id
+        )
+    }
+}
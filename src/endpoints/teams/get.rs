@@ -1,10 +1,13 @@
 //Anything related to GET requests for Teams and it's variations goes here.
 use super::{
-    Team, TeamApp, TeamAppPermission, TeamFeature, TeamInvitation, TeamInvoice, TeamMember,
-    TeamPreferences,
+    PermissionEntity, Team, TeamApp, TeamAppPermission, TeamDailyUsage, TeamFeature,
+    TeamIdentityProvider, TeamInvitation, TeamInvoice, TeamMember, TeamMonthlyUsage,
+    TeamPreferences, WhitelistedAddonService,
 };
 
+use crate::endpoints::ids::{AppRef, TeamRef};
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Team Info
 ///
@@ -33,13 +36,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Team.html
 pub struct TeamDetails<'a> {
     /// unique team identifier.
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamDetails<'a> {
-    pub fn new(team_id: &'a str) -> TeamDetails {
-        TeamDetails { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamDetails<'a> {
+        TeamDetails { team_id: team_id.into() }
     }
 }
 
@@ -121,13 +124,13 @@ impl HerokuEndpoint<Vec<Team>> for TeamList {
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.Team.html
 pub struct TeamListByEA<'a> {
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamListByEA<'a> {
-    pub fn new(account_id: &'a str) -> TeamListByEA {
-        TeamListByEA { account_id }
+    pub fn new(account_id: impl Into<Cow<'a, str>>) -> TeamListByEA<'a> {
+        TeamListByEA { account_id: account_id.into() }
     }
 }
 
@@ -166,13 +169,13 @@ impl<'a> HerokuEndpoint<Vec<Team>> for TeamListByEA<'a> {
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.TeamApp.html
 pub struct TeamAppDetails<'a> {
-    pub app_id: &'a str,
+    pub app_id: AppRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamAppDetails<'a> {
-    pub fn new(app_id: &'a str) -> TeamAppDetails {
-        TeamAppDetails { app_id }
+    pub fn new(app_id: impl Into<AppRef<'a>>) -> TeamAppDetails<'a> {
+        TeamAppDetails { app_id: app_id.into() }
     }
 }
 
@@ -211,13 +214,13 @@ impl<'a> HerokuEndpoint<TeamApp> for TeamAppDetails<'a> {
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.TeamApp.html
 pub struct TeamAppList<'a> {
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamAppList<'a> {
-    pub fn new(team_id: &'a str) -> TeamAppList {
-        TeamAppList { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamAppList<'a> {
+        TeamAppList { team_id: team_id.into() }
     }
 }
 
@@ -273,6 +276,54 @@ impl HerokuEndpoint<Vec<TeamAppPermission>> for TeamAppPermissionList {
     }
 }
 
+/// Team Permission List
+///
+/// List permission entities for a team.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#permission-entity-list)
+///
+/// # Example:
+///
+/// TeamPermissionList takes one required parameter, team_id, and returns a list of [`PermissionEntities`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&TeamPermissionList::new("TEAM_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.PermissionEntity.html
+pub struct TeamPermissionList<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> TeamPermissionList<'a> {
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamPermissionList<'a> {
+        TeamPermissionList {
+            team_id: team_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<PermissionEntity>> for TeamPermissionList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("teams/{}/permissions", self.team_id)
+    }
+}
+
 /// Team Feature List
 ///
 /// List existing team features.
@@ -300,13 +351,13 @@ impl HerokuEndpoint<Vec<TeamAppPermission>> for TeamAppPermissionList {
 /// [response]: ../struct.TeamFeature.html
 pub struct TeamFeatureList<'a> {
     /// unique team identifier, either name or id
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamFeatureList<'a> {
-    pub fn new(team_id: &'a str) -> TeamFeatureList {
-        TeamFeatureList { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamFeatureList<'a> {
+        TeamFeatureList { team_id: team_id.into() }
     }
 }
 
@@ -346,17 +397,20 @@ impl<'a> HerokuEndpoint<Vec<TeamFeature>> for TeamFeatureList<'a> {
 /// [response]: ../struct.TeamFeature.html
 pub struct TeamFeatureDetails<'a> {
     /// unique team identifier, either name or id
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// unique feature identifier, either name or id
-    pub feature_id: &'a str,
+    pub feature_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamFeatureDetails<'a> {
-    pub fn new(team_id: &'a str, feature_id: &'a str) -> TeamFeatureDetails<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        feature_id: impl Into<Cow<'a, str>>,
+    ) -> TeamFeatureDetails<'a> {
         TeamFeatureDetails {
-            team_id,
-            feature_id,
+            team_id: team_id.into(),
+            feature_id: feature_id.into(),
         }
     }
 }
@@ -397,13 +451,13 @@ impl<'a> HerokuEndpoint<TeamFeature> for TeamFeatureDetails<'a> {
 /// [response]: ../struct.TeamInvitation.html
 pub struct TeamInvitationList<'a> {
     /// unique team identifier, either name or id
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamInvitationList<'a> {
-    pub fn new(team_id: &'a str) -> TeamInvitationList {
-        TeamInvitationList { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamInvitationList<'a> {
+        TeamInvitationList { team_id: team_id.into() }
     }
 }
 
@@ -443,13 +497,13 @@ impl<'a> HerokuEndpoint<Vec<TeamInvitation>> for TeamInvitationList<'a> {
 /// [response]: ../struct.TeamInvitation.html
 pub struct TeamInvitationDetails<'a> {
     /// unique token identifier
-    pub token_id: &'a str,
+    pub token_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamInvitationDetails<'a> {
-    pub fn new(token_id: &'a str) -> TeamInvitationDetails {
-        TeamInvitationDetails { token_id }
+    pub fn new(token_id: impl Into<Cow<'a, str>>) -> TeamInvitationDetails<'a> {
+        TeamInvitationDetails { token_id: token_id.into() }
     }
 }
 
@@ -489,13 +543,13 @@ impl<'a> HerokuEndpoint<TeamInvitation> for TeamInvitationDetails<'a> {
 /// [response]: ../struct.TeamInvoice.html
 pub struct TeamInvoiceList<'a> {
     /// unique team identifier, either name or id
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamInvoiceList<'a> {
-    pub fn new(team_id: &'a str) -> TeamInvoiceList {
-        TeamInvoiceList { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamInvoiceList<'a> {
+        TeamInvoiceList { team_id: team_id.into() }
     }
 }
 
@@ -535,17 +589,20 @@ impl<'a> HerokuEndpoint<Vec<TeamInvoice>> for TeamInvoiceList<'a> {
 /// [response]: ../struct.TeamInvoice.html
 pub struct TeamInvoiceDetails<'a> {
     /// unique token identifier
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// invoice number
-    pub invoice_id: &'a str,
+    pub invoice_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamInvoiceDetails<'a> {
-    pub fn new(team_id: &'a str, invoice_id: &'a str) -> TeamInvoiceDetails<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        invoice_id: impl Into<Cow<'a, str>>,
+    ) -> TeamInvoiceDetails<'a> {
         TeamInvoiceDetails {
-            team_id,
-            invoice_id,
+            team_id: team_id.into(),
+            invoice_id: invoice_id.into(),
         }
     }
 }
@@ -586,13 +643,13 @@ impl<'a> HerokuEndpoint<TeamInvoice> for TeamInvoiceDetails<'a> {
 /// [response]: ../struct.TeamMember.html
 pub struct TeamMemberList<'a> {
     /// unique team identifier, either name or id
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamMemberList<'a> {
-    pub fn new(team_id: &'a str) -> TeamMemberList {
-        TeamMemberList { team_id }
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamMemberList<'a> {
+        TeamMemberList { team_id: team_id.into() }
     }
 }
 
@@ -632,15 +689,18 @@ impl<'a> HerokuEndpoint<Vec<TeamMember>> for TeamMemberList<'a> {
 /// [response]: ../struct.TeamApp.html
 pub struct TeamMemberAppsList<'a> {
     /// unique team identifier, either name or id
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// unique team member identifier, either email or id
-    pub member_id: &'a str,
+    pub member_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamMemberAppsList<'a> {
-    pub fn new(team_id: &'a str, member_id: &'a str) -> TeamMemberAppsList<'a> {
-        TeamMemberAppsList { team_id, member_id }
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        member_id: impl Into<Cow<'a, str>>,
+    ) -> TeamMemberAppsList<'a> {
+        TeamMemberAppsList { team_id: team_id.into(), member_id: member_id.into() }
     }
 }
 
@@ -680,13 +740,13 @@ impl<'a> HerokuEndpoint<Vec<TeamApp>> for TeamMemberAppsList<'a> {
 /// [response]: ../struct.TeamPreferences.html
 pub struct TeamPreferenceList<'a> {
     /// unique  identifier.
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamPreferenceList<'a> {
-    pub fn new(id: &'a str) -> TeamPreferenceList {
-        TeamPreferenceList { id }
+    pub fn new(id: impl Into<Cow<'a, str>>) -> TeamPreferenceList<'a> {
+        TeamPreferenceList { id: id.into() }
     }
 }
 
@@ -698,3 +758,269 @@ impl<'a> HerokuEndpoint<TeamPreferences> for TeamPreferenceList<'a> {
         format!("teams/{}/preferences", self.id)
     }
 }
+
+/// Team Daily Usage Info
+///
+/// Retrieves usage for an enterprise team for a range of days. Start and end dates can be specified as query parameters using the date format YYYY-MM-DD.
+/// The team identifier can be found from the team list endpoint.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#team-daily-usage-info)
+///
+/// # Example:
+///
+/// TeamDailyUsageList takes two required parameters, team_id and start, and returns a list of [`TeamDailyUsage`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &TeamDailyUsageList::new("TEAM_ID", "2020-01-01")
+///         .end("2020-01-31")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.TeamDailyUsage.html
+pub struct TeamDailyUsageList<'a> {
+    /// unique team identifier.
+    pub team_id: TeamRef<'a>,
+    /// The query parameters to pass to the Heroku API
+    pub params: TeamDailyUsageParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> TeamDailyUsageList<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        start: impl Into<Cow<'a, str>>,
+    ) -> TeamDailyUsageList<'a> {
+        TeamDailyUsageList {
+            team_id: team_id.into(),
+            params: TeamDailyUsageParams { start: start.into(), end: None },
+        }
+    }
+
+    /// # end: range end date, e.g. `2019-01-31`
+    pub fn end(&mut self, end: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.end = Some(end.into());
+        self
+    }
+
+    pub fn build(&self) -> TeamDailyUsageList<'a> {
+        TeamDailyUsageList { team_id: self.team_id.clone(), params: self.params.clone() }
+    }
+}
+
+/// Query parameters of the daily usage, dates use the `YYYY-MM-DD` format.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#team-daily-usage-info-required-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct TeamDailyUsageParams<'a> {
+    /// range start date
+    pub start: Cow<'a, str>,
+    /// range end date
+    pub end: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<Vec<TeamDailyUsage>, TeamDailyUsageParams<'a>> for TeamDailyUsageList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("teams/{}/usage/daily", self.team_id)
+    }
+    fn query(&self) -> Option<TeamDailyUsageParams<'a>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Team Monthly Usage Info
+///
+/// Retrieves usage for an enterprise team for a range of months. Start and end dates can be specified as query parameters using the date format YYYY-MM.
+/// If no end date is specified, one month of usage is returned.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#team-monthly-usage-info)
+///
+/// # Example:
+///
+/// TeamMonthlyUsageList takes two required parameters, team_id and start, and returns a list of [`TeamMonthlyUsage`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &TeamMonthlyUsageList::new("TEAM_ID", "2020-01")
+///         .end("2020-03")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.TeamMonthlyUsage.html
+pub struct TeamMonthlyUsageList<'a> {
+    /// unique team identifier.
+    pub team_id: TeamRef<'a>,
+    /// The query parameters to pass to the Heroku API
+    pub params: TeamMonthlyUsageParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> TeamMonthlyUsageList<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        start: impl Into<Cow<'a, str>>,
+    ) -> TeamMonthlyUsageList<'a> {
+        TeamMonthlyUsageList {
+            team_id: team_id.into(),
+            params: TeamMonthlyUsageParams { start: start.into(), end: None },
+        }
+    }
+
+    /// # end: range end month, e.g. `2019-03`
+    pub fn end(&mut self, end: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.end = Some(end.into());
+        self
+    }
+
+    pub fn build(&self) -> TeamMonthlyUsageList<'a> {
+        TeamMonthlyUsageList { team_id: self.team_id.clone(), params: self.params.clone() }
+    }
+}
+
+/// Query parameters of the monthly usage, months use the `YYYY-MM` format.
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#team-monthly-usage-info-required-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct TeamMonthlyUsageParams<'a> {
+    /// range start month
+    pub start: Cow<'a, str>,
+    /// range end month
+    pub end: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<Vec<TeamMonthlyUsage>, TeamMonthlyUsageParams<'a>>
+    for TeamMonthlyUsageList<'a>
+{
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("teams/{}/usage/monthly", self.team_id)
+    }
+    fn query(&self) -> Option<TeamMonthlyUsageParams<'a>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Team Identity Provider List
+///
+/// Get a list of a team's Identity Providers
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#identity-provider-list-by-team)
+///
+/// # Example:
+///
+/// TeamIdentityProviderList takes one required parameter, team_id, and returns a list of [`TeamIdentityProviders`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&TeamIdentityProviderList::new("TEAM_NAME"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.TeamIdentityProvider.html
+pub struct TeamIdentityProviderList<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> TeamIdentityProviderList<'a> {
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamIdentityProviderList<'a> {
+        TeamIdentityProviderList {
+            team_id: team_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<TeamIdentityProvider>> for TeamIdentityProviderList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("teams/{}/identity-providers", self.team_id)
+    }
+}
+
+/// Whitelisted Add-on Service List By Team
+///
+/// List all whitelisted Add-on Services for a Team
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#whitelisted-entity-list-by-team)
+///
+/// # Example:
+///
+/// WhitelistedAddonServiceList takes one required parameter, team_id, and returns a list of [`WhitelistedAddonServices`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(&WhitelistedAddonServiceList::new("TEAM_ID"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.WhitelistedAddonService.html
+pub struct WhitelistedAddonServiceList<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> WhitelistedAddonServiceList<'a> {
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> WhitelistedAddonServiceList<'a> {
+        WhitelistedAddonServiceList {
+            team_id: team_id.into(),
+        }
+    }
+}
+
+impl<'a> HerokuEndpoint<Vec<WhitelistedAddonService>> for WhitelistedAddonServiceList<'a> {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        format!("teams/{}/whitelisted-addon-services", self.team_id)
+    }
+}
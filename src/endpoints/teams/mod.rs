@@ -6,21 +6,28 @@ pub mod patch;
 pub mod post;
 pub mod put;
 
-pub use delete::{TeamDelete, TeamInvitationRevoke, TeamMemberDelete};
+pub use delete::{
+    TeamDelete, TeamIdentityProviderDelete, TeamInvitationRevoke, TeamMemberDelete,
+    WhitelistedAddonServiceDelete,
+};
 pub use get::{
-    TeamAppDetails, TeamAppList, TeamAppPermissionList, TeamDetails, TeamFeatureDetails,
-    TeamFeatureList, TeamInvitationDetails, TeamInvitationList, TeamInvoiceDetails,
-    TeamInvoiceList, TeamList, TeamListByEA, TeamMemberAppsList, TeamMemberList,
-    TeamPreferenceList,
+    TeamAppDetails, TeamAppList, TeamAppPermissionList, TeamDailyUsageList, TeamDailyUsageParams,
+    TeamDetails, TeamFeatureDetails, TeamFeatureList, TeamIdentityProviderList,
+    TeamInvitationDetails, TeamInvitationList, TeamInvoiceDetails, TeamInvoiceList, TeamList,
+    TeamListByEA, TeamMemberAppsList, TeamMemberList, TeamMonthlyUsageList,
+    TeamMonthlyUsageParams, TeamPreferenceList, TeamPermissionList, WhitelistedAddonServiceList,
 };
 pub use patch::{
     TeamAppTransfer, TeamAppTransferParams, TeamAppUpdateLocked, TeamAppUpdateLockedParams,
-    TeamMemberUpdate, TeamMemberUpdateParams, TeamPreferenceUpdate, TeamPreferenceUpdateParams,
-    TeamUpdate, TeamUpdateParams,
+    TeamIdentityProviderUpdate, TeamIdentityProviderUpdateParams, TeamMemberUpdate,
+    TeamMemberUpdateParams, TeamPreferenceUpdate, TeamPreferenceUpdateParams, TeamUpdate,
+    TeamUpdateParams,
 };
 pub use post::{
-    TeamAppCreate, TeamAppCreateParams, TeamCreate, TeamCreateByEA, TeamCreateByEAParams,
-    TeamCreateParams, TeamInvitationAccept, TeamMemberCreate, TeamMemberCreateParams,
+    AppFilter, AppFilterIn, AppFilterParams, TeamAppCreate, TeamAppCreateParams, TeamCreate,
+    TeamCreateByEA, TeamCreateByEAParams, TeamCreateParams, TeamIdentityProviderCreate,
+    TeamIdentityProviderCreateParams, TeamInvitationAccept, TeamMemberCreate,
+    TeamMemberCreateParams, WhitelistedAddonServiceCreate, WhitelistedAddonServiceCreateParams,
 };
 pub use put::{
     TeamInvitationCreate, TeamInvitationCreateParams, TeamMemberCreateorUpdate,
@@ -35,6 +42,11 @@ impl ApiResult for Vec<TeamApp> {}
 
 impl ApiResult for Vec<TeamAppPermission> {}
 
+impl ApiResult for Vec<PermissionEntity> {}
+
+impl ApiResult for TeamIdentityProvider {}
+impl ApiResult for Vec<TeamIdentityProvider> {}
+
 impl ApiResult for TeamFeature {}
 impl ApiResult for Vec<TeamFeature> {}
 
@@ -50,14 +62,24 @@ impl ApiResult for Vec<TeamMember> {}
 impl ApiResult for TeamPreferences {}
 impl ApiResult for Vec<TeamPreferences> {}
 
+impl ApiResult for Vec<TeamDailyUsage> {}
+
+impl ApiResult for Vec<TeamMonthlyUsage> {}
+
+impl ApiResult for WhitelistedAddonService {}
+impl ApiResult for Vec<WhitelistedAddonService> {}
+
 pub use team::Team;
 pub use team_app::TeamApp;
 pub use team_feature::TeamFeature;
+pub use team_identity_provider::TeamIdentityProvider;
 pub use team_invitation::TeamInvitation;
 pub use team_invoice::TeamInvoice;
 pub use team_member::TeamMember;
-pub use team_permission::TeamAppPermission;
+pub use team_permission::{PermissionEntity, TeamAppPermission};
 pub use team_preferences::TeamPreferences;
+pub use team_usage::{TeamDailyUsage, TeamMonthlyUsage};
+pub use whitelisted_addon_service::WhitelistedAddonService;
 
 mod team {
     use chrono::offset::Utc;
@@ -237,11 +259,46 @@ mod team_permission {
         /// A description of what the app permission allows.
         pub description: String,
     }
+
+    /// Permission Entity
+    ///
+    /// Stability: development
+    ///
+    /// An owned entity including users' permissions.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#permission-entity)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct PermissionEntity {
+        /// ID of the entity.
+        pub id: String,
+        /// Name of the entity.
+        pub name: String,
+        /// unique identifier of team
+        pub team_id: String,
+        /// The type of object the entity is referring to.
+        /// one of:"app" or "space"
+        #[serde(rename = "type")]
+        pub type_field: String,
+        /// Users that have access to the entity.
+        pub users: Vec<User>,
+    }
+
+    /// A user that has access to the entity.
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct User {
+        /// unique email address
+        pub email: String,
+        /// identifier of an account
+        pub id: String,
+        /// permissions of the user on the entity, e.g. `view` or `deploy`
+        pub permissions: Vec<String>,
+    }
 }
 
 mod team_feature {
     use chrono::offset::Utc;
     use chrono::DateTime;
+    use crate::endpoints::status::FeatureState;
 
     /// Team Feature
     ///
@@ -265,7 +322,7 @@ mod team_feature {
         /// unique name of team feature
         pub name: String,
         /// state of team feature
-        pub state: String,
+        pub state: FeatureState,
         /// when team feature was updated
         pub updated_at: DateTime<Utc>,
         /// user readable feature name
@@ -275,6 +332,60 @@ mod team_feature {
     }
 }
 
+mod team_identity_provider {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+
+    /// Identity Provider
+    ///
+    /// Stability: production
+    ///
+    /// Identity Providers represent the SAML configuration of teams or an Enterprise account
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#identity-provider)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct TeamIdentityProvider {
+        /// raw contents of the public certificate (eg: .crt or .pem file)
+        pub certificate: String,
+        /// when provider record was created
+        pub created_at: DateTime<Utc>,
+        /// URL identifier provided by the identity provider
+        pub entity_id: String,
+        /// unique identifier of this identity provider
+        pub id: String,
+        /// single log out URL for this identity provider
+        pub slo_target_url: Option<String>,
+        /// single sign on URL for this identity provider
+        pub sso_target_url: String,
+        /// team associated with this identity provider
+        pub organization: Option<Organization>,
+        /// time the provider record was updated
+        pub updated_at: DateTime<Utc>,
+        /// entity that owns this identity provider
+        pub owner: Owner,
+    }
+
+    /// team associated with this identity provider
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Organization {
+        /// unique name of team
+        pub name: String,
+    }
+
+    /// entity that owns this identity provider
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Owner {
+        /// unique identifier of the owner
+        pub id: String,
+        /// name of the owner
+        pub name: String,
+        /// type of the owner
+        /// one of:"team" or "enterprise-account"
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+}
+
 mod team_invitation {
     use chrono::offset::Utc;
     use chrono::DateTime;
@@ -465,3 +576,95 @@ mod team_preferences {
         pub whitelisting_enabled: Option<bool>,
     }
 }
+
+mod team_usage {
+    use crate::endpoints::usage::{AppUsage, Usage};
+
+    /// Team Daily Usage
+    ///
+    /// Stability: development
+    ///
+    /// Usage for an enterprise team at a daily resolution.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#team-daily-usage)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct TeamDailyUsage {
+        /// team identifier
+        pub id: String,
+        /// name of the team
+        pub name: String,
+        /// date of the usage, e.g. `2019-01-01`
+        pub date: String,
+        /// usage of the apps of the team
+        pub apps: Vec<AppUsage>,
+        /// usage of the team
+        #[serde(flatten)]
+        pub usage: Usage,
+    }
+
+    /// Team Monthly Usage
+    ///
+    /// Stability: development
+    ///
+    /// Usage for an enterprise team at a monthly resolution.
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#team-monthly-usage)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    pub struct TeamMonthlyUsage {
+        /// team identifier
+        pub id: String,
+        /// name of the team
+        pub name: String,
+        /// year and month of the usage, e.g. `2019-01`
+        pub month: String,
+        /// usage of the apps of the team
+        pub apps: Vec<AppUsage>,
+        /// usage of the team
+        #[serde(flatten)]
+        pub usage: Usage,
+    }
+}
+
+mod whitelisted_addon_service {
+    use chrono::offset::Utc;
+    use chrono::DateTime;
+
+    /// Whitelisted Entity
+    ///
+    /// Stability: production
+    ///
+    /// Entities that have been whitelisted to be used by a Team
+    ///
+    /// [For more information please refer to the Heroku documentation](https://devcenter.heroku.com/articles/platform-api-reference#whitelisted-entity)
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct WhitelistedAddonService {
+        /// when the add-on service was whitelisted
+        pub added_at: DateTime<Utc>,
+        /// the user which whitelisted the Add-on Service
+        pub added_by: AddedBy,
+        /// the Add-on Service whitelisted for use
+        pub addon_service: AddonService,
+        /// unique identifier for this whitelisting entity
+        pub id: String,
+    }
+
+    /// the user which whitelisted the Add-on Service
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct AddedBy {
+        /// unique email address
+        pub email: Option<String>,
+        /// identifier of an account
+        pub id: Option<String>,
+    }
+
+    /// the Add-on Service whitelisted for use
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct AddonService {
+        /// unique identifier of this add-on-service
+        pub id: String,
+        /// unique name of this add-on-service
+        pub name: String,
+        /// human-readable name of the add-on service provider
+        pub human_name: String,
+    }
+}
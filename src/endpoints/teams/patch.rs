@@ -1,7 +1,9 @@
 //Anything related to PATCH requests for Teams and it's variations goes here.
-use super::{Team, TeamApp, TeamMember, TeamPreferences};
+use super::{Team, TeamApp, TeamIdentityProvider, TeamMember, TeamPreferences};
 
+use crate::endpoints::ids::TeamRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Team Update
 ///
@@ -35,16 +37,16 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.Team.html
 pub struct TeamUpdate<'a> {
     /// team_id is the unique team identifier.
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: TeamUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamUpdate<'a> {
-    pub fn new(team_id: &'a str) -> TeamUpdate<'a> {
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> TeamUpdate<'a> {
         TeamUpdate {
-            team_id,
+            team_id: team_id.into(),
             params: TeamUpdateParams {
                 default: None,
                 name: None,
@@ -59,17 +61,17 @@ impl<'a> TeamUpdate<'a> {
     }
 
     /// # name: unique name of team
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     pub fn build(&self) -> TeamUpdate<'a> {
         TeamUpdate {
-            team_id: self.team_id,
+            team_id: self.team_id.clone(),
             params: TeamUpdateParams {
                 default: self.params.default,
-                name: self.params.name,
+                name: self.params.name.clone(),
             },
         }
     }
@@ -84,7 +86,7 @@ pub struct TeamUpdateParams<'a> {
     /// whether to use this team when none is specified
     pub default: Option<bool>,
     /// unique name of team
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Team, (), TeamUpdateParams<'a>> for TeamUpdate<'a> {
@@ -127,16 +129,16 @@ impl<'a> HerokuEndpoint<Team, (), TeamUpdateParams<'a>> for TeamUpdate<'a> {
 /// [response]: ../struct.Team.html
 pub struct TeamAppUpdateLocked<'a> {
     /// team_id is the unique team identifier.
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: TeamAppUpdateLockedParams,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamAppUpdateLocked<'a> {
-    pub fn new(team_id: &'a str, locked: bool) -> TeamAppUpdateLocked<'a> {
+    pub fn new(team_id: impl Into<TeamRef<'a>>, locked: bool) -> TeamAppUpdateLocked<'a> {
         TeamAppUpdateLocked {
-            team_id,
+            team_id: team_id.into(),
             params: TeamAppUpdateLockedParams { locked },
         }
     }
@@ -192,17 +194,20 @@ impl<'a> HerokuEndpoint<Team, (), TeamAppUpdateLockedParams> for TeamAppUpdateLo
 /// [response]: ../struct.TeamApp.html
 pub struct TeamAppTransfer<'a> {
     /// team_id is the unique team identifier.
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// The parameters to pass to the Heroku API
     pub params: TeamAppTransferParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamAppTransfer<'a> {
-    pub fn new(team_id: &'a str, owner_id: &'a str) -> TeamAppTransfer<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        owner_id: impl Into<Cow<'a, str>>,
+    ) -> TeamAppTransfer<'a> {
         TeamAppTransfer {
-            team_id: team_id,
-            params: TeamAppTransferParams { owner: owner_id },
+            team_id: team_id.into(),
+            params: TeamAppTransferParams { owner: owner_id.into() },
         }
     }
 }
@@ -220,7 +225,7 @@ impl<'a> TeamAppTransfer<'a> {
 pub struct TeamAppTransferParams<'a> {
     /// unique email address, identifier of an account or implicit reference to currently authorized user
     /// or unique name of team
-    pub owner: &'a str,
+    pub owner: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<TeamApp, (), TeamAppTransferParams<'a>> for TeamAppTransfer<'a> {
@@ -262,7 +267,7 @@ impl<'a> HerokuEndpoint<TeamApp, (), TeamAppTransferParams<'a>> for TeamAppTrans
 /// [response]: ../struct.TeamMember.html
 pub struct TeamMemberUpdate<'a> {
     /// unique team identifier
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// parameters to pass to Heroku
     pub params: TeamMemberUpdateParams<'a>,
 }
@@ -270,12 +275,16 @@ pub struct TeamMemberUpdate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> TeamMemberUpdate<'a> {
     /// Only required parameters passed
-    pub fn new(team_id: &'a str, email: &'a str, role: &'a str) -> TeamMemberUpdate<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        email: impl Into<Cow<'a, str>>,
+        role: impl Into<Cow<'a, str>>,
+    ) -> TeamMemberUpdate<'a> {
         TeamMemberUpdate {
-            team_id,
+            team_id: team_id.into(),
             params: TeamMemberUpdateParams {
-                email: email,
-                role: role,
+                email: email.into(),
+                role: role.into(),
                 federated: None,
             },
         }
@@ -289,10 +298,10 @@ impl<'a> TeamMemberUpdate<'a> {
 
     pub fn build(&self) -> TeamMemberUpdate<'a> {
         TeamMemberUpdate {
-            team_id: self.team_id,
+            team_id: self.team_id.clone(),
             params: TeamMemberUpdateParams {
-                email: self.params.email,
-                role: self.params.role,
+                email: self.params.email.clone(),
+                role: self.params.role.clone(),
                 federated: self.params.federated,
             },
         }
@@ -306,11 +315,11 @@ impl<'a> TeamMemberUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TeamMemberUpdateParams<'a> {
     /// unique email address
-    pub email: &'a str,
+    pub email: Cow<'a, str>,
     /// Even though marked with `Option`, this parameter is NOT optional.
     /// role in the team
     /// one of:"admin" or "collaborator" or "member" or "owner" or null
-    pub role: &'a str,
+    pub role: Cow<'a, str>,
     /// whether the user is federated and belongs to an Identity Provider
     pub federated: Option<bool>,
 }
@@ -354,16 +363,16 @@ impl<'a> HerokuEndpoint<TeamMember, (), TeamMemberUpdateParams<'a>> for TeamMemb
 /// [response]: ../struct.TeamPreferences.html
 pub struct TeamPreferenceUpdate<'a> {
     /// unique identifier
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
     /// parameters to pass to Heroku
     pub params: TeamPreferenceUpdateParams,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamPreferenceUpdate<'a> {
-    pub fn new(id: &'a str) -> TeamPreferenceUpdate<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>) -> TeamPreferenceUpdate<'a> {
         TeamPreferenceUpdate {
-            id,
+            id: id.into(),
             params: TeamPreferenceUpdateParams {
                 whitelisting_enabled: None,
             },
@@ -378,7 +387,7 @@ impl<'a> TeamPreferenceUpdate<'a> {
 
     pub fn build(&self) -> TeamPreferenceUpdate<'a> {
         TeamPreferenceUpdate {
-            id: self.id,
+            id: self.id.clone(),
             params: TeamPreferenceUpdateParams {
                 whitelisting_enabled: self.params.whitelisting_enabled,
             },
@@ -407,3 +416,126 @@ impl<'a> HerokuEndpoint<TeamPreferences, (), TeamPreferenceUpdateParams> for Tea
         Some(self.params.clone())
     }
 }
+
+/// Team Identity Provider Update
+///
+/// Update a team's Identity Provider
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#identity-provider-update-by-team)
+///
+/// # Example:
+///
+/// TeamIdentityProviderUpdate takes two required parameters, team_id and identity_provider_id, and returns the updated [`TeamIdentityProvider`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &TeamIdentityProviderUpdate::new("TEAM_NAME", "PROVIDER_ID")
+///         .certificate("-----BEGIN CERTIFICATE----- ...")
+///         .slo_target_url("https://idp.example.com/slo")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.TeamIdentityProvider.html
+pub struct TeamIdentityProviderUpdate<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+    /// unique identifier of the identity provider
+    pub identity_provider_id: Cow<'a, str>,
+    /// parameters to pass to Heroku
+    pub params: TeamIdentityProviderUpdateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> TeamIdentityProviderUpdate<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        identity_provider_id: impl Into<Cow<'a, str>>,
+    ) -> TeamIdentityProviderUpdate<'a> {
+        TeamIdentityProviderUpdate {
+            team_id: team_id.into(),
+            identity_provider_id: identity_provider_id.into(),
+            params: TeamIdentityProviderUpdateParams {
+                certificate: None,
+                entity_id: None,
+                slo_target_url: None,
+                sso_target_url: None,
+            },
+        }
+    }
+
+    /// # certificate: raw contents of the public certificate (eg: .crt or .pem file)
+    pub fn certificate(&mut self, certificate: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.certificate = Some(certificate.into());
+        self
+    }
+
+    /// # entity_id: URL identifier provided by the identity provider
+    pub fn entity_id(&mut self, entity_id: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.entity_id = Some(entity_id.into());
+        self
+    }
+
+    /// # slo_target_url: single log out URL for this identity provider
+    pub fn slo_target_url(&mut self, slo_target_url: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.slo_target_url = Some(slo_target_url.into());
+        self
+    }
+
+    /// # sso_target_url: single sign on URL for this identity provider
+    pub fn sso_target_url(&mut self, sso_target_url: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.sso_target_url = Some(sso_target_url.into());
+        self
+    }
+
+    pub fn build(&self) -> TeamIdentityProviderUpdate<'a> {
+        TeamIdentityProviderUpdate {
+            team_id: self.team_id.clone(),
+            identity_provider_id: self.identity_provider_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Update team identity provider with parameters
+///
+/// [See Heroku documentation for more information about these optional parameters](https://devcenter.heroku.com/articles/platform-api-reference#identity-provider-update-by-team-optional-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct TeamIdentityProviderUpdateParams<'a> {
+    /// raw contents of the public certificate (eg: .crt or .pem file)
+    pub certificate: Option<Cow<'a, str>>,
+    /// URL identifier provided by the identity provider
+    pub entity_id: Option<Cow<'a, str>>,
+    /// single log out URL for this identity provider
+    pub slo_target_url: Option<Cow<'a, str>>,
+    /// single sign on URL for this identity provider
+    pub sso_target_url: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<TeamIdentityProvider, (), TeamIdentityProviderUpdateParams<'a>>
+    for TeamIdentityProviderUpdate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Patch
+    }
+    fn path(&self) -> String {
+        format!(
+            "teams/{}/identity-providers/{}",
+            self.team_id, self.identity_provider_id
+        )
+    }
+    fn body(&self) -> Option<TeamIdentityProviderUpdateParams<'a>> {
+        Some(self.params.clone())
+    }
+}
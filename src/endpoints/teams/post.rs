@@ -1,7 +1,11 @@
 //Anything related to POST requests for Teams and it's variations goes here.
-use super::{Team, TeamApp, TeamInvitation, TeamMember};
+use super::{
+    Team, TeamApp, TeamIdentityProvider, TeamInvitation, TeamMember, WhitelistedAddonService,
+};
 
+use crate::endpoints::ids::TeamRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Team Create
 ///
@@ -54,10 +58,10 @@ pub struct TeamCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> TeamCreate<'a> {
     // `new` method has only the required parameters
-    pub fn new(name: &'a str) -> TeamCreate {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> TeamCreate<'a> {
         TeamCreate {
             params: TeamCreateParams {
-                name: name,
+                name: name.into(),
                 address_1: None,
                 address_2: None,
                 card_number: None,
@@ -78,113 +82,113 @@ impl<'a> TeamCreate<'a> {
     }
 
     /// # address_1: street address line 1
-    pub fn address_1(&mut self, address_1: &'a str) -> &mut Self {
-        self.params.address_1 = Some(address_1);
+    pub fn address_1(&mut self, address_1: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.address_1 = Some(address_1.into());
         self
     }
 
     /// # address_2: street address line 2
-    pub fn address_2(&mut self, address_2: &'a str) -> &mut Self {
-        self.params.address_2 = Some(address_2);
+    pub fn address_2(&mut self, address_2: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.address_2 = Some(address_2.into());
         self
     }
 
     /// # card_number: encrypted card number of payment method
-    pub fn card_number(&mut self, card_number: &'a str) -> &mut Self {
-        self.params.card_number = Some(card_number);
+    pub fn card_number(&mut self, card_number: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.card_number = Some(card_number.into());
         self
     }
 
     /// # city: city
-    pub fn city(&mut self, city: &'a str) -> &mut Self {
-        self.params.city = Some(city);
+    pub fn city(&mut self, city: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.city = Some(city.into());
         self
     }
 
     /// # country: country
-    pub fn country(&mut self, country: &'a str) -> &mut Self {
-        self.params.country = Some(country);
+    pub fn country(&mut self, country: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.country = Some(country.into());
         self
     }
 
     /// # cvv: card verification value
-    pub fn cvv(&mut self, cvv: &'a str) -> &mut Self {
-        self.params.cvv = Some(cvv);
+    pub fn cvv(&mut self, cvv: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.cvv = Some(cvv.into());
         self
     }
 
     /// # expiration_month: expiration month
-    pub fn expiration_month(&mut self, expiration_month: &'a str) -> &mut Self {
-        self.params.expiration_month = Some(expiration_month);
+    pub fn expiration_month(&mut self, expiration_month: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.expiration_month = Some(expiration_month.into());
         self
     }
 
     /// # expiration_year: expiration year
-    pub fn expiration_year(&mut self, expiration_year: &'a str) -> &mut Self {
-        self.params.expiration_year = Some(expiration_year);
+    pub fn expiration_year(&mut self, expiration_year: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.expiration_year = Some(expiration_year.into());
         self
     }
 
     /// # first_name: the first name for payment method
-    pub fn first_name(&mut self, first_name: &'a str) -> &mut Self {
-        self.params.first_name = Some(first_name);
+    pub fn first_name(&mut self, first_name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.first_name = Some(first_name.into());
         self
     }
 
     /// # last_name: the last name for payment method
-    pub fn last_name(&mut self, last_name: &'a str) -> &mut Self {
-        self.params.last_name = Some(last_name);
+    pub fn last_name(&mut self, last_name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.last_name = Some(last_name.into());
         self
     }
 
     /// # other: metadata
-    pub fn other(&mut self, other: &'a str) -> &mut Self {
-        self.params.other = Some(other);
+    pub fn other(&mut self, other: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.other = Some(other.into());
         self
     }
 
     /// # postal_code: postal code
-    pub fn postal_code(&mut self, postal_code: &'a str) -> &mut Self {
-        self.params.postal_code = Some(postal_code);
+    pub fn postal_code(&mut self, postal_code: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.postal_code = Some(postal_code.into());
         self
     }
     /// # state:state
-    pub fn state(&mut self, state: &'a str) -> &mut Self {
-        self.params.state = Some(state);
+    pub fn state(&mut self, state: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.state = Some(state.into());
         self
     }
 
     /// # nonce: Nonce generated by Braintree hosted fields form
-    pub fn nonce(&mut self, nonce: &'a str) -> &mut Self {
-        self.params.nonce = Some(nonce);
+    pub fn nonce(&mut self, nonce: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.nonce = Some(nonce.into());
         self
     }
 
     /// # device_data: Device data string generated by the client
-    pub fn device_data(&mut self, device_data: &'a str) -> &mut Self {
-        self.params.device_data = Some(device_data);
+    pub fn device_data(&mut self, device_data: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.device_data = Some(device_data.into());
         self
     }
 
     pub fn build(&self) -> TeamCreate<'a> {
         TeamCreate {
             params: TeamCreateParams {
-                name: self.params.name,
-                address_1: self.params.address_1,
-                address_2: self.params.address_2,
-                card_number: self.params.card_number,
-                city: self.params.city,
-                country: self.params.country,
-                cvv: self.params.cvv,
-                expiration_month: self.params.expiration_month,
-                expiration_year: self.params.expiration_year,
-                first_name: self.params.first_name,
-                last_name: self.params.last_name,
-                other: self.params.other,
-                postal_code: self.params.postal_code,
-                state: self.params.state,
-                nonce: self.params.nonce,
-                device_data: self.params.device_data,
+                name: self.params.name.clone(),
+                address_1: self.params.address_1.clone(),
+                address_2: self.params.address_2.clone(),
+                card_number: self.params.card_number.clone(),
+                city: self.params.city.clone(),
+                country: self.params.country.clone(),
+                cvv: self.params.cvv.clone(),
+                expiration_month: self.params.expiration_month.clone(),
+                expiration_year: self.params.expiration_year.clone(),
+                first_name: self.params.first_name.clone(),
+                last_name: self.params.last_name.clone(),
+                other: self.params.other.clone(),
+                postal_code: self.params.postal_code.clone(),
+                state: self.params.state.clone(),
+                nonce: self.params.nonce.clone(),
+                device_data: self.params.device_data.clone(),
             },
         }
     }
@@ -199,37 +203,37 @@ impl<'a> TeamCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TeamCreateParams<'a> {
     /// unique name of team
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// street address line 1
-    pub address_1: Option<&'a str>,
+    pub address_1: Option<Cow<'a, str>>,
     /// street address line 2
-    pub address_2: Option<&'a str>,
+    pub address_2: Option<Cow<'a, str>>,
     /// encrypted card number of payment method
-    pub card_number: Option<&'a str>,
+    pub card_number: Option<Cow<'a, str>>,
     /// city
-    pub city: Option<&'a str>,
+    pub city: Option<Cow<'a, str>>,
     /// country
-    pub country: Option<&'a str>,
+    pub country: Option<Cow<'a, str>>,
     /// card verification value
-    pub cvv: Option<&'a str>,
+    pub cvv: Option<Cow<'a, str>>,
     /// expiration month
-    pub expiration_month: Option<&'a str>,
+    pub expiration_month: Option<Cow<'a, str>>,
     /// expiration year
-    pub expiration_year: Option<&'a str>,
+    pub expiration_year: Option<Cow<'a, str>>,
     /// the first name for payment method
-    pub first_name: Option<&'a str>,
+    pub first_name: Option<Cow<'a, str>>,
     /// the last name for payment method
-    pub last_name: Option<&'a str>,
+    pub last_name: Option<Cow<'a, str>>,
     /// metadata
-    pub other: Option<&'a str>,
+    pub other: Option<Cow<'a, str>>,
     /// postal code
-    pub postal_code: Option<&'a str>,
+    pub postal_code: Option<Cow<'a, str>>,
     /// state
-    pub state: Option<&'a str>,
+    pub state: Option<Cow<'a, str>>,
     /// Nonce generated by Braintree hosted fields form
-    pub nonce: Option<&'a str>,
+    pub nonce: Option<Cow<'a, str>>,
     /// Device data string generated by the client
-    pub device_data: Option<&'a str>,
+    pub device_data: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<Team, (), TeamCreateParams<'a>> for TeamCreate<'a> {
@@ -272,17 +276,20 @@ impl<'a> HerokuEndpoint<Team, (), TeamCreateParams<'a>> for TeamCreate<'a> {
 /// [response]: ../struct.Team.html
 pub struct TeamCreateByEA<'a> {
     /// unique account identifier
-    pub account_id: &'a str,
+    pub account_id: Cow<'a, str>,
     /// parameters to pass to Heroku
     pub params: TeamCreateByEAParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamCreateByEA<'a> {
-    pub fn new(account_id: &'a str, name: &'a str) -> TeamCreateByEA<'a> {
+    pub fn new(
+        account_id: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> TeamCreateByEA<'a> {
         TeamCreateByEA {
-            account_id: account_id,
-            params: TeamCreateByEAParams { name },
+            account_id: account_id.into(),
+            params: TeamCreateByEAParams { name: name.into() },
         }
     }
 }
@@ -293,7 +300,7 @@ impl<'a> TeamCreateByEA<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TeamCreateByEAParams<'a> {
     /// unique name of team
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<Team, (), TeamCreateByEAParams<'a>> for TeamCreateByEA<'a> {
@@ -374,14 +381,14 @@ impl<'a> TeamAppCreate<'a> {
     /// # name: name of app
     ///
     /// `pattern`:  pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.params.name = Some(name);
+    pub fn name(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.name = Some(name.into());
         self
     }
 
     /// # team: unique name of team
-    pub fn team(&mut self, team: &'a str) -> &mut Self {
-        self.params.team = Some(team);
+    pub fn team(&mut self, team: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.team = Some(team.into());
         self
     }
 
@@ -392,22 +399,22 @@ impl<'a> TeamAppCreate<'a> {
     }
 
     /// # region: name of region
-    pub fn region(&mut self, region: &'a str) -> &mut Self {
-        self.params.region = Some(region);
+    pub fn region(&mut self, region: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.region = Some(region.into());
         self
     }
 
     /// # space: unique name of space
     ///
     /// `pattern`: pattern: `^[a-z0-9](?:[a-z0-9]
-    pub fn space(&mut self, space: &'a str) -> &mut Self {
-        self.params.space = Some(space);
+    pub fn space(&mut self, space: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.space = Some(space.into());
         self
     }
 
     /// # stack: unique name
-    pub fn stack(&mut self, stack: &'a str) -> &mut Self {
-        self.params.stack = Some(stack);
+    pub fn stack(&mut self, stack: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.stack = Some(stack.into());
         self
     }
 
@@ -421,12 +428,12 @@ impl<'a> TeamAppCreate<'a> {
         TeamAppCreate {
             params: TeamAppCreateParams {
                 locked: self.params.locked,
-                name: self.params.name,
-                team: self.params.team,
+                name: self.params.name.clone(),
+                team: self.params.team.clone(),
                 personal: self.params.personal,
-                region: self.params.region,
-                space: self.params.space,
-                stack: self.params.stack,
+                region: self.params.region.clone(),
+                space: self.params.space.clone(),
+                stack: self.params.stack.clone(),
                 internal_routing: self.params.internal_routing,
             },
         }
@@ -445,18 +452,18 @@ pub struct TeamAppCreateParams<'a> {
     pub locked: Option<bool>,
     /// name of app
     /// pattern: ^[a-z][a-z0-9-]{1,28}[a-z0-9]$
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
     /// unique name of team
-    pub team: Option<&'a str>,
+    pub team: Option<Cow<'a, str>>,
     /// force creation of the app in the user account even if a default team is set.
     pub personal: Option<bool>,
     /// name of region
-    pub region: Option<&'a str>,
+    pub region: Option<Cow<'a, str>>,
     /// unique name of space
     ///  pattern: `^[a-z0-9](?:[a-z0-9]
-    pub space: Option<&'a str>,
+    pub space: Option<Cow<'a, str>>,
     /// unique name
-    pub stack: Option<&'a str>,
+    pub stack: Option<Cow<'a, str>>,
     /// describes whether a Private Spaces app is externally routable or not
     pub internal_routing: Option<bool>,
 }
@@ -501,13 +508,13 @@ impl<'a> HerokuEndpoint<TeamApp, (), TeamAppCreateParams<'a>> for TeamAppCreate<
 /// [response]: ../struct.TeamInvitation.html
 pub struct TeamInvitationAccept<'a> {
     /// unique token identifier
-    pub token_id: &'a str,
+    pub token_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamInvitationAccept<'a> {
-    pub fn new(token_id: &'a str) -> TeamInvitationAccept<'a> {
-        TeamInvitationAccept { token_id }
+    pub fn new(token_id: impl Into<Cow<'a, str>>) -> TeamInvitationAccept<'a> {
+        TeamInvitationAccept { token_id: token_id.into() }
     }
 }
 
@@ -547,7 +554,7 @@ impl<'a> HerokuEndpoint<TeamInvitation> for TeamInvitationAccept<'a> {
 /// [response]: ../struct.TeamMember.html
 pub struct TeamMemberCreate<'a> {
     /// unique team identifier
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// parameters to pass to Heroku
     pub params: TeamMemberCreateParams<'a>,
 }
@@ -555,12 +562,16 @@ pub struct TeamMemberCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> TeamMemberCreate<'a> {
     /// Only required parameters passed
-    pub fn new(team_id: &'a str, email: &'a str, role: &'a str) -> TeamMemberCreate<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        email: impl Into<Cow<'a, str>>,
+        role: impl Into<Cow<'a, str>>,
+    ) -> TeamMemberCreate<'a> {
         TeamMemberCreate {
-            team_id,
+            team_id: team_id.into(),
             params: TeamMemberCreateParams {
-                email: email,
-                role: role,
+                email: email.into(),
+                role: role.into(),
                 federated: None,
             },
         }
@@ -574,10 +585,10 @@ impl<'a> TeamMemberCreate<'a> {
 
     pub fn build(&self) -> TeamMemberCreate<'a> {
         TeamMemberCreate {
-            team_id: self.team_id,
+            team_id: self.team_id.clone(),
             params: TeamMemberCreateParams {
-                email: self.params.email,
-                role: self.params.role,
+                email: self.params.email.clone(),
+                role: self.params.role.clone(),
                 federated: self.params.federated,
             },
         }
@@ -591,11 +602,11 @@ impl<'a> TeamMemberCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TeamMemberCreateParams<'a> {
     /// unique email address
-    pub email: &'a str,
+    pub email: Cow<'a, str>,
     /// Even though marked with `Option`, this parameter is NOT optional.
     /// role in the team
     /// one of:"admin" or "collaborator" or "member" or "owner" or null
-    pub role: &'a str,
+    pub role: Cow<'a, str>,
     /// whether the user is federated and belongs to an Identity Provider
     pub federated: Option<bool>,
 }
@@ -611,3 +622,294 @@ impl<'a> HerokuEndpoint<TeamMember, (), TeamMemberCreateParams<'a>> for TeamMemb
         Some(self.params.clone())
     }
 }
+
+/// Team Identity Provider Create
+///
+/// Create an Identity Provider for a team
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#identity-provider-create-by-team)
+///
+/// # Example:
+///
+/// TeamIdentityProviderCreate takes four required parameters, team_id, certificate, entity_id and sso_target_url, and returns the created [`TeamIdentityProvider`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &TeamIdentityProviderCreate::new(
+///         "TEAM_NAME",
+///         "-----BEGIN CERTIFICATE----- ...",
+///         "https://idp.example.com/metadata",
+///         "https://idp.example.com/sso",
+///     )
+///     .slo_target_url("https://idp.example.com/slo")
+///     .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.TeamIdentityProvider.html
+pub struct TeamIdentityProviderCreate<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+    /// parameters to pass to Heroku
+    pub params: TeamIdentityProviderCreateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> TeamIdentityProviderCreate<'a> {
+    /// Only required parameters passed
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        certificate: impl Into<Cow<'a, str>>,
+        entity_id: impl Into<Cow<'a, str>>,
+        sso_target_url: impl Into<Cow<'a, str>>,
+    ) -> TeamIdentityProviderCreate<'a> {
+        TeamIdentityProviderCreate {
+            team_id: team_id.into(),
+            params: TeamIdentityProviderCreateParams {
+                certificate: certificate.into(),
+                entity_id: entity_id.into(),
+                slo_target_url: None,
+                sso_target_url: sso_target_url.into(),
+            },
+        }
+    }
+
+    /// # slo_target_url: single log out URL for this identity provider
+    pub fn slo_target_url(&mut self, slo_target_url: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.slo_target_url = Some(slo_target_url.into());
+        self
+    }
+
+    pub fn build(&self) -> TeamIdentityProviderCreate<'a> {
+        TeamIdentityProviderCreate {
+            team_id: self.team_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Create team identity provider with parameters
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#identity-provider-create-by-team-required-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct TeamIdentityProviderCreateParams<'a> {
+    /// raw contents of the public certificate (eg: .crt or .pem file)
+    pub certificate: Cow<'a, str>,
+    /// URL identifier provided by the identity provider
+    pub entity_id: Cow<'a, str>,
+    /// single log out URL for this identity provider
+    pub slo_target_url: Option<Cow<'a, str>>,
+    /// single sign on URL for this identity provider
+    pub sso_target_url: Cow<'a, str>,
+}
+
+impl<'a> HerokuEndpoint<TeamIdentityProvider, (), TeamIdentityProviderCreateParams<'a>>
+    for TeamIdentityProviderCreate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("teams/{}/identity-providers", self.team_id)
+    }
+    fn body(&self) -> Option<TeamIdentityProviderCreateParams<'a>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Whitelisted Add-on Service Create By Team
+///
+/// Whitelist an Add-on Service
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#whitelisted-entity-create-by-team)
+///
+/// # Example:
+///
+/// WhitelistedAddonServiceCreate takes one required parameter, team_id, and returns the list of [`WhitelistedAddonServices`][response].
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &WhitelistedAddonServiceCreate::new("TEAM_ID")
+///         .addon_service("heroku-postgresql")
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.WhitelistedAddonService.html
+pub struct WhitelistedAddonServiceCreate<'a> {
+    /// unique team identifier, either name or id
+    pub team_id: TeamRef<'a>,
+    /// parameters to pass to Heroku
+    pub params: WhitelistedAddonServiceCreateParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> WhitelistedAddonServiceCreate<'a> {
+    pub fn new(team_id: impl Into<TeamRef<'a>>) -> WhitelistedAddonServiceCreate<'a> {
+        WhitelistedAddonServiceCreate {
+            team_id: team_id.into(),
+            params: WhitelistedAddonServiceCreateParams {
+                addon_service: None,
+            },
+        }
+    }
+
+    /// # addon_service: name of the Add-on to whitelist
+    pub fn addon_service(&mut self, addon_service: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.addon_service = Some(addon_service.into());
+        self
+    }
+
+    pub fn build(&self) -> WhitelistedAddonServiceCreate<'a> {
+        WhitelistedAddonServiceCreate {
+            team_id: self.team_id.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Whitelist an add-on service with parameters
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#whitelisted-entity-create-by-team-optional-parameters)
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct WhitelistedAddonServiceCreateParams<'a> {
+    /// name of the Add-on to whitelist
+    pub addon_service: Option<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<Vec<WhitelistedAddonService>, (), WhitelistedAddonServiceCreateParams<'a>>
+    for WhitelistedAddonServiceCreate<'a>
+{
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("teams/{}/whitelisted-addon-services", self.team_id)
+    }
+    fn body(&self) -> Option<WhitelistedAddonServiceCreateParams<'a>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Filter Apps
+///
+/// Request an apps list filtered by app id.
+///
+/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#filters-apps)
+///
+/// # Example:
+///
+/// AppFilter takes no required parameters, and returns the list of [`TeamApps`][response] matching the ids added to the filter.
+/// Apps that don't exist or that the user can't access are left out of the response.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let response = api_client.request(
+///     &AppFilter::new()
+///         .app_id("01234567-89ab-cdef-0123-456789abcdef")
+///         .app_ids(vec!["APP_ID_1", "APP_ID_2"])
+///         .build(),
+/// );
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+//
+/// ```
+/// See how to create the Heroku [`api_client`][httpApiClientConfig].
+///
+/// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
+/// [response]: ../struct.TeamApp.html
+pub struct AppFilter<'a> {
+    /// parameters to pass to Heroku
+    pub params: AppFilterParams<'a>,
+}
+
+#[cfg(feature = "builder")]
+impl<'a> AppFilter<'a> {
+    /// An empty filter, add app ids with `app_id` or `app_ids`
+    pub fn new() -> AppFilter<'a> {
+        AppFilter {
+            params: AppFilterParams {
+                in_filter: AppFilterIn { id: Vec::new() },
+            },
+        }
+    }
+
+    /// # app_id: unique identifier of an app to include in the list
+    pub fn app_id(&mut self, app_id: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.in_filter.id.push(app_id.into());
+        self
+    }
+
+    /// # app_ids: unique identifiers of apps to include in the list
+    pub fn app_ids(
+        &mut self,
+        app_ids: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        self.params
+            .in_filter
+            .id
+            .extend(app_ids.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn build(&self) -> AppFilter<'a> {
+        AppFilter {
+            params: self.params.clone(),
+        }
+    }
+}
+
+/// Filter apps with parameters
+///
+/// [See Heroku documentation for more information about these paramters](https://devcenter.heroku.com/articles/platform-api-reference#filters-apps-required-parameters)
+#[derive(Serialize, Clone, Debug)]
+pub struct AppFilterParams<'a> {
+    /// the attribute values apps must have
+    #[serde(rename = "in")]
+    pub in_filter: AppFilterIn<'a>,
+}
+
+/// The attribute values apps must have
+#[derive(Serialize, Clone, Debug)]
+pub struct AppFilterIn<'a> {
+    /// unique identifiers of the apps
+    pub id: Vec<Cow<'a, str>>,
+}
+
+impl<'a> HerokuEndpoint<Vec<TeamApp>, (), AppFilterParams<'a>> for AppFilter<'a> {
+    fn method(&self) -> Method {
+        Method::Post
+    }
+    fn path(&self) -> String {
+        format!("filters/apps")
+    }
+    fn body(&self) -> Option<AppFilterParams<'a>> {
+        Some(self.params.clone())
+    }
+}
@@ -1,7 +1,9 @@
 //Anything related to PUT requests for Teams and it's variations goes here.
 use super::{TeamInvitation, TeamMember};
 
+use crate::endpoints::ids::TeamRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Team Invitation Create
 ///
@@ -34,18 +36,21 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.TeamInvitation.html
 pub struct TeamInvitationCreate<'a> {
     /// unique team identifier
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// parameters to pass to Heroku
     pub params: TeamInvitationCreateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TeamInvitationCreate<'a> {
-    pub fn new(team_id: &'a str, email: &'a str) -> TeamInvitationCreate<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        email: impl Into<Cow<'a, str>>,
+    ) -> TeamInvitationCreate<'a> {
         TeamInvitationCreate {
-            team_id,
+            team_id: team_id.into(),
             params: TeamInvitationCreateParams {
-                email: email,
+                email: email.into(),
                 role: None,
             },
         }
@@ -54,17 +59,17 @@ impl<'a> TeamInvitationCreate<'a> {
     /// # role: role in the team
     /// 
     /// `one of`: "admin" or "collaborator" or "member" or "owner" or null 
-    pub fn role(&mut self, role: &'a str) -> &mut Self {
-        self.params.role = Some(role);
+    pub fn role(&mut self, role: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.role = Some(role.into());
         self
     }
 
     pub fn build(&self) -> TeamInvitationCreate<'a> {
         TeamInvitationCreate {
-            team_id: self.team_id,
+            team_id: self.team_id.clone(),
             params: TeamInvitationCreateParams {
-                email: self.params.email,
-                role: self.params.role,
+                email: self.params.email.clone(),
+                role: self.params.role.clone(),
             },
         }
     }
@@ -78,11 +83,11 @@ impl<'a> TeamInvitationCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TeamInvitationCreateParams<'a> {
     /// unique email address
-    pub email: &'a str,
+    pub email: Cow<'a, str>,
     /// Even though marked with `Option`, this parameter is NOT optional.
     /// role in the team
     /// one of:"admin" or "collaborator" or "member" or "owner" or null. [Nullable]
-    pub role: Option<&'a str>,
+    pub role: Option<Cow<'a, str>>,
 }
 
 impl<'a> HerokuEndpoint<TeamInvitation, (), TeamInvitationCreateParams<'a>>
@@ -130,7 +135,7 @@ impl<'a> HerokuEndpoint<TeamInvitation, (), TeamInvitationCreateParams<'a>>
 /// [response]: ../struct.TeamMember.html
 pub struct TeamMemberCreateorUpdate<'a> {
     /// unique team identifier
-    pub team_id: &'a str,
+    pub team_id: TeamRef<'a>,
     /// parameters to pass to Heroku
     pub params: TeamMemberCreateorUpdateParams<'a>,
 }
@@ -138,12 +143,16 @@ pub struct TeamMemberCreateorUpdate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> TeamMemberCreateorUpdate<'a> {
     /// Only required parameters passed
-    pub fn new(team_id: &'a str, email: &'a str, role: &'a str) -> TeamMemberCreateorUpdate<'a> {
+    pub fn new(
+        team_id: impl Into<TeamRef<'a>>,
+        email: impl Into<Cow<'a, str>>,
+        role: impl Into<Cow<'a, str>>,
+    ) -> TeamMemberCreateorUpdate<'a> {
         TeamMemberCreateorUpdate {
-            team_id,
+            team_id: team_id.into(),
             params: TeamMemberCreateorUpdateParams {
-                email: email,
-                role: role,
+                email: email.into(),
+                role: role.into(),
                 federated: None,
             },
         }
@@ -157,10 +166,10 @@ impl<'a> TeamMemberCreateorUpdate<'a> {
 
     pub fn build(&self) -> TeamMemberCreateorUpdate<'a> {
         TeamMemberCreateorUpdate {
-            team_id: self.team_id,
+            team_id: self.team_id.clone(),
             params: TeamMemberCreateorUpdateParams {
-                email: self.params.email,
-                role: self.params.role,
+                email: self.params.email.clone(),
+                role: self.params.role.clone(),
                 federated: self.params.federated,
             },
         }
@@ -174,11 +183,11 @@ impl<'a> TeamMemberCreateorUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TeamMemberCreateorUpdateParams<'a> {
     /// unique email address
-    pub email: &'a str,
+    pub email: Cow<'a, str>,
     /// Even though marked with `Option`, this parameter is NOT optional.
     /// role in the team
     /// one of:"admin" or "collaborator" or "member" or "owner" or null
-    pub role: &'a str,
+    pub role: Cow<'a, str>,
     /// whether the user is federated and belongs to an Identity Provider
     pub federated: Option<bool>,
 }
@@ -1,7 +1,9 @@
 //Anything related to GET requests for Heroku tests goes here.
 use super::{TestCase, TestNode, TestRun};
 
+use crate::endpoints::ids::PipelineRef;
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Test Case List
 ///
@@ -30,13 +32,13 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [response]: ../struct.TestCase.html
 pub struct TestCaseList<'a> {
     /// run_id is the test run unique identifier
-    pub run_id: &'a str,
+    pub run_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TestCaseList<'a> {
-    pub fn new(run_id: &'a str) -> TestCaseList<'a> {
-        TestCaseList { run_id }
+    pub fn new(run_id: impl Into<Cow<'a, str>>) -> TestCaseList<'a> {
+        TestCaseList { run_id: run_id.into() }
     }
 }
 
@@ -76,13 +78,13 @@ impl<'a> HerokuEndpoint<Vec<TestCase>> for TestCaseList<'a> {
 /// [response]: ../struct.TestNode.html
 pub struct TestNodeList<'a> {
     /// run_id is the test run unique identifier
-    pub run_id: &'a str,
+    pub run_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TestNodeList<'a> {
-    pub fn new(run_id: &'a str) -> TestNodeList<'a> {
-        TestNodeList { run_id }
+    pub fn new(run_id: impl Into<Cow<'a, str>>) -> TestNodeList<'a> {
+        TestNodeList { run_id: run_id.into() }
     }
 }
 
@@ -122,13 +124,13 @@ impl<'a> HerokuEndpoint<Vec<TestNode>> for TestNodeList<'a> {
 /// [response]: ../struct.TestRun.html
 pub struct TestRunDetails<'a> {
     /// run_id is the test run unique identifier
-    pub run_id: &'a str,
+    pub run_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TestRunDetails<'a> {
-    pub fn new(run_id: &'a str) -> TestRunDetails<'a> {
-        TestRunDetails { run_id }
+    pub fn new(run_id: impl Into<Cow<'a, str>>) -> TestRunDetails<'a> {
+        TestRunDetails { run_id: run_id.into() }
     }
 }
 
@@ -168,13 +170,13 @@ impl<'a> HerokuEndpoint<TestRun> for TestRunDetails<'a> {
 /// [response]: ../struct.TestRun.html
 pub struct TestRunList<'a> {
     /// pipeline_id is the test run pipeline identifier
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TestRunList<'a> {
-    pub fn new(pipeline_id: &'a str) -> TestRunList<'a> {
-        TestRunList { pipeline_id }
+    pub fn new(pipeline_id: impl Into<PipelineRef<'a>>) -> TestRunList<'a> {
+        TestRunList { pipeline_id: pipeline_id.into() }
     }
 }
 
@@ -214,17 +216,20 @@ impl<'a> HerokuEndpoint<Vec<TestRun>> for TestRunList<'a> {
 /// [response]: ../struct.TestRun.html
 pub struct TestRunDetailsByPipeline<'a> {
     /// pipeline_id is the test run pipeline identifier
-    pub pipeline_id: &'a str,
+    pub pipeline_id: PipelineRef<'a>,
     /// run_id is the test run unique identifier
-    pub run_id: &'a str,
+    pub run_id: Cow<'a, str>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TestRunDetailsByPipeline<'a> {
-    pub fn new(pipeline_id: &'a str, run_id: &'a str) -> TestRunDetailsByPipeline<'a> {
+    pub fn new(
+        pipeline_id: impl Into<PipelineRef<'a>>,
+        run_id: impl Into<Cow<'a, str>>,
+    ) -> TestRunDetailsByPipeline<'a> {
         TestRunDetailsByPipeline {
-            pipeline_id,
-            run_id,
+            pipeline_id: pipeline_id.into(),
+            run_id: run_id.into(),
         }
     }
 }
@@ -71,6 +71,7 @@ mod test_case {
 }
 
 mod test_node {
+    use crate::endpoints::status::TestRunStatus;
     use chrono::offset::Utc;
     use chrono::DateTime;
 
@@ -105,7 +106,7 @@ mod test_node {
         pub setup_stream_url: String,
         /// current state of the test run
         ///  one of:"pending" or "cancelled" or "creating" or "building" or "running" or "succeeded" or "failed" or "errored" or "debugging"
-        pub status: String,
+        pub status: TestRunStatus,
         /// when test node was updated
         pub updated_at: DateTime<Utc>,
         /// test run
@@ -131,6 +132,7 @@ mod test_node {
 }
 
 mod test_run {
+    use crate::endpoints::status::TestRunStatus;
     use chrono::offset::Utc;
     use chrono::DateTime;
     use serde_json::Value;
@@ -174,7 +176,7 @@ mod test_run {
         pub pipeline: Pipeline,
         /// current state of the test run
         ///  one of:"pending" or "cancelled" or "creating" or "building" or "running" or "succeeded" or "failed" or "errored" or "debugging"
-        pub status: String,
+        pub status: TestRunStatus,
         /// The download location for the source code to be tested
         pub source_blob_url: String,
         /// when test-run was updated
@@ -223,7 +225,7 @@ mod test_run {
         /// SMS number of account
         pub sms_number: Option<String>,
         /// when account was suspended
-        pub suspended_at: Option<String>,
+        pub suspended_at: Option<DateTime<Utc>>,
         /// when account became delinquent
         pub delinquent_at: Option<DateTime<Utc>>,
         /// whether two-factor auth is enabled on the account
@@ -2,6 +2,7 @@
 use super::TestRun;
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Test Run Update
 ///
@@ -17,7 +18,7 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 ///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
 ///
 /// let status = "cancelled";
-/// let message = None;
+/// let message: Option<&str> = None;
 /// let response = api_client.request(&TestRunUpdate::new("RUN_ID", message, status));
 ///
 ///match response {
@@ -31,17 +32,24 @@ use crate::framework::endpoint::{HerokuEndpoint, Method};
 /// [httpApiClientConfig]: ../../../framework/struct.HttpApiClient.html
 /// [response]: ../struct.TestRun.html
 pub struct TestRunUpdate<'a> {
-    pub run_id: &'a str,
+    pub run_id: Cow<'a, str>,
     /// The parameters to pass to the Heroku API
     pub params: TestRunUpdateParams<'a>,
 }
 
 #[cfg(feature = "builder")]
 impl<'a> TestRunUpdate<'a> {
-    pub fn new(run_id: &'a str, message: Option<&'a str>, status: &'a str) -> TestRunUpdate<'a> {
+    pub fn new(
+        run_id: impl Into<Cow<'a, str>>,
+        message: Option<impl Into<Cow<'a, str>>>,
+        status: impl Into<Cow<'a, str>>,
+    ) -> TestRunUpdate<'a> {
         TestRunUpdate {
-            run_id,
-            params: TestRunUpdateParams { message, status },
+            run_id: run_id.into(),
+            params: TestRunUpdateParams {
+                message: message.map(Into::into),
+                status: status.into(),
+            },
         }
     }
 }
@@ -52,15 +60,15 @@ impl<'a> TestRunUpdate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TestRunUpdateParams<'a> {
     /// human friendly message indicating reason for an error. [Nullable]
-    pub message: Option<&'a str>,
+    pub message: Option<Cow<'a, str>>,
     /// current state of the test run
     ///  one of:"pending" or "cancelled" or "creating" or "building" or "running" or "succeeded" or "failed" or "errored" or "debugging"
-    pub status: &'a str,
+    pub status: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<TestRun, (), TestRunUpdateParams<'a>> for TestRunUpdate<'a> {
     fn method(&self) -> Method {
-        Method::Post
+        Method::Patch
     }
     fn path(&self) -> String {
         format!("test-runs/{}", self.run_id)
@@ -2,6 +2,7 @@
 use super::TestRun;
 
 use crate::framework::endpoint::{HerokuEndpoint, Method};
+use std::borrow::Cow;
 
 /// Test Run Create
 ///
@@ -47,21 +48,21 @@ pub struct TestRunCreate<'a> {
 #[cfg(feature = "builder")]
 impl<'a> TestRunCreate<'a> {
     pub fn new(
-        commit_branch: &'a str,
-        commit_message: &'a str,
-        commit_sha: &'a str,
-        pipeline: &'a str,
-        source_blob_url: &'a str,
+        commit_branch: impl Into<Cow<'a, str>>,
+        commit_message: impl Into<Cow<'a, str>>,
+        commit_sha: impl Into<Cow<'a, str>>,
+        pipeline: impl Into<Cow<'a, str>>,
+        source_blob_url: impl Into<Cow<'a, str>>,
     ) -> TestRunCreate<'a> {
         TestRunCreate {
             params: TestRunCreateParams {
-                commit_branch: commit_branch,
-                commit_message: commit_message,
-                commit_sha: commit_sha,
+                commit_branch: commit_branch.into(),
+                commit_message: commit_message.into(),
+                commit_sha: commit_sha.into(),
                 debug: None,
                 organization: None,
-                pipeline: pipeline,
-                source_blob_url: source_blob_url,
+                pipeline: pipeline.into(),
+                source_blob_url: source_blob_url.into(),
             },
         }
     }
@@ -73,21 +74,21 @@ impl<'a> TestRunCreate<'a> {
     }
 
     /// # organization: unique name or identifier of team
-    pub fn organization(&mut self, organization: &'a str) -> &mut Self {
-        self.params.organization = Some(organization);
+    pub fn organization(&mut self, organization: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.params.organization = Some(organization.into());
         self
     }
 
     pub fn build(&self) -> TestRunCreate<'a> {
         TestRunCreate {
             params: TestRunCreateParams {
-                commit_branch: self.params.commit_branch,
-                commit_message: self.params.commit_message,
-                commit_sha: self.params.commit_sha,
+                commit_branch: self.params.commit_branch.clone(),
+                commit_message: self.params.commit_message.clone(),
+                commit_sha: self.params.commit_sha.clone(),
                 debug: self.params.debug,
-                organization: self.params.organization,
-                pipeline: self.params.pipeline,
-                source_blob_url: self.params.source_blob_url,
+                organization: self.params.organization.clone(),
+                pipeline: self.params.pipeline.clone(),
+                source_blob_url: self.params.source_blob_url.clone(),
             },
         }
     }
@@ -100,19 +101,19 @@ impl<'a> TestRunCreate<'a> {
 #[derive(Serialize, Clone, Debug)]
 pub struct TestRunCreateParams<'a> {
     /// the branch of the repository that the test run concerns
-    pub commit_branch: &'a str,
+    pub commit_branch: Cow<'a, str>,
     /// the message for the commit under test
-    pub commit_message: &'a str,
+    pub commit_message: Cow<'a, str>,
     /// the SHA hash of the commit under test
-    pub commit_sha: &'a str,
+    pub commit_sha: Cow<'a, str>,
     /// whether the test run was started for interactive debugging
     pub debug: Option<bool>,
     /// unique name or identifier of team
-    pub organization: Option<&'a str>,
+    pub organization: Option<Cow<'a, str>>,
     /// unique identifier or name of pipeline
-    pub pipeline: &'a str,
+    pub pipeline: Cow<'a, str>,
     /// The download location for the source code to be tested
-    pub source_blob_url: &'a str,
+    pub source_blob_url: Cow<'a, str>,
 }
 
 impl<'a> HerokuEndpoint<TestRun, (), TestRunCreateParams<'a>> for TestRunCreate<'a> {
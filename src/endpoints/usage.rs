@@ -0,0 +1,88 @@
+//! Usage amounts shared by the team and enterprise account usage models.
+//!
+//! Heroku reports usage with the same set of amounts at every level: for an enterprise account, for each of its teams
+//! and for each of their apps. [`Usage`][usage] holds them and can be added up, see the
+//! [`usage_report`][usage_report] tool to roll them up by app, month or team.
+//!
+//! [usage]: struct.Usage.html
+//! [usage_report]: ../../tools/usage_report/index.html
+
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+/// The usage amounts of an app, a team or an enterprise account.
+///
+/// Amounts that are not reported at a level, e.g. `space` for an app, are 0.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Usage {
+    /// dyno units used
+    pub dynos: f64,
+    /// total add-on credits used
+    pub addons: f64,
+    /// total add-on credits used for first party add-ons
+    pub data: f64,
+    /// total add-on credits used for third party add-ons
+    pub partner: f64,
+    /// space credits used
+    #[serde(default)]
+    pub space: f64,
+    /// max connect rows synced, only reported monthly
+    #[serde(default)]
+    pub connect: f64,
+}
+
+impl Add for Usage {
+    type Output = Usage;
+
+    fn add(mut self, other: Usage) -> Usage {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for Usage {
+    fn add_assign(&mut self, other: Usage) {
+        self.dynos += other.dynos;
+        self.addons += other.addons;
+        self.data += other.data;
+        self.partner += other.partner;
+        self.space += other.space;
+        self.connect += other.connect;
+    }
+}
+
+impl Sum for Usage {
+    fn sum<I: Iterator<Item = Usage>>(iter: I) -> Usage {
+        iter.fold(Usage::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Usage> for Usage {
+    fn sum<I: Iterator<Item = &'a Usage>>(iter: I) -> Usage {
+        iter.copied().sum()
+    }
+}
+
+/// Usage of an app.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AppUsage {
+    /// unique name of app
+    pub app_name: String,
+    /// usage of the app
+    #[serde(flatten)]
+    pub usage: Usage,
+}
+
+/// Usage of a team, as reported inside an enterprise account usage.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct TeamUsage {
+    /// team identifier
+    pub id: String,
+    /// name of the team
+    pub name: String,
+    /// usage of the apps of the team
+    pub apps: Vec<AppUsage>,
+    /// usage of the team
+    #[serde(flatten)]
+    pub usage: Usage,
+}
@@ -1,7 +1,8 @@
 //! This module contains the synchronous (blocking) API client.
 use crate::framework::{
-    endpoint::HerokuEndpoint,
-    response::{ApiResponse, ApiResult},
+    endpoint::{HerokuEndpoint, Ranged},
+    envelope::RequestEnvelope,
+    response::{match_response, ApiResponse, ApiResult},
 };
 use serde::Serialize;
 
@@ -30,4 +31,55 @@ pub trait HerokuApiClient {
         ResultType: ApiResult,
         QueryType: Serialize,
         BodyType: Serialize;
+
+    /// Send a request captured in a [`RequestEnvelope`], parsing the response as `ResultType`.
+    ///
+    /// Use `serde_json::Value` as `ResultType` when the endpoint that built the envelope isn't known.
+    ///
+    /// [`RequestEnvelope`]: ../envelope/struct.RequestEnvelope.html
+    fn execute<ResultType>(&self, envelope: &RequestEnvelope) -> ApiResponse<ResultType>
+    where
+        ResultType: ApiResult,
+    {
+        self.request(envelope)
+    }
+
+    /// Send a list request and follow the `Next-Range` header of each partial response, returning every page.
+    ///
+    /// Heroku returns at most 1000 items per request, 200 by default, and answers with `206 Partial Content` and a
+    /// `Next-Range` header while more remain. The `Range` of `endpoint`, if any, sets the order and size of the pages.
+    ///
+    /// [See Heroku documentation for more information about ranges](https://devcenter.heroku.com/articles/platform-api-reference#ranges)
+    fn request_all<ItemType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<Vec<ItemType>, QueryType, BodyType>,
+    ) -> ApiResponse<Vec<ItemType>>
+    where
+        Vec<ItemType>: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let mut items = Vec::new();
+        let mut range = endpoint.range();
+        loop {
+            let response = match &range {
+                Some(range) => self.request_raw(&Ranged::new(endpoint, range.as_str()))?,
+                None => self.request_raw(endpoint)?,
+            };
+            let next_range = if response.status() == reqwest::StatusCode::PARTIAL_CONTENT {
+                response
+                    .headers()
+                    .get("Next-Range")
+                    .and_then(|next_range| next_range.to_str().ok())
+                    .map(String::from)
+            } else {
+                None
+            };
+            items.extend(match_response::<Vec<ItemType>>(response)?);
+            match next_range {
+                Some(next_range) => range = Some(next_range),
+                None => return Ok(items),
+            }
+        }
+    }
 }
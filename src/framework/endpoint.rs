@@ -1,10 +1,12 @@
 use crate::framework::response::{ApiResult, Empty};
-use crate::framework::ApiEnvironment;
+use crate::framework::{ApiEnvironment, DataApi};
 use serde::Serialize;
+use std::fmt;
 use url::Url;
 
 /// HTTP methods used on this crate.
-#[derive(Copy, Clone)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum Method {
     Get,
     Post,
@@ -13,6 +15,19 @@ pub enum Method {
     Patch,
 }
 
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let method = match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+            Method::Patch => "PATCH",
+        };
+        write!(f, "{}", method)
+    }
+}
+
 /// Heroku Endpoint trait by default has a empty struct and void query types and body types
 ///
 /// This trait is responsible for the majority of the functionality of this crate.
@@ -30,8 +45,17 @@ where
     fn body(&self) -> Option<BodyType> {
         None
     }
+    /// The data or metrics API serving the endpoint, `None` for the platform API.
+    fn data_api(&self) -> Option<DataApi> {
+        None
+    }
     fn url(&self, environment: &ApiEnvironment) -> Url {
-        Url::from(environment).join(&self.path()).unwrap()
+        match self.data_api() {
+            Some(api) => environment.data_url(api),
+            None => Url::from(environment),
+        }
+        .join(&self.path())
+        .unwrap()
     }
     fn content_type(&self) -> &str {
         "application/json"
@@ -42,4 +66,85 @@ where
     fn agent(&self) -> &str {
         "heroku_rs"
     }
+    /// The `Range` header of list endpoints, e.g. `version ..; order=desc, max=10`, none by default.
+    fn range(&self) -> Option<String> {
+        None
+    }
+}
+
+/// An endpoint sent with a `Range` header, to sort a list or change the size of its pages.
+///
+/// [See Heroku documentation for more information about ranges](https://devcenter.heroku.com/articles/platform-api-reference#ranges)
+///
+/// # Example:
+///
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::framework::endpoint::Ranged;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let list = ReleaseList::new("APP_ID");
+/// let response = api_client.request(&Ranged::new(&list, "version ..; order=desc, max=10"));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+/// ```
+pub struct Ranged<'e, ResultType, QueryType, BodyType> {
+    /// the endpoint to send
+    pub endpoint: &'e dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    /// the `Range` header, e.g. `id ..; max=1000`
+    pub range: String,
+}
+
+impl<'e, ResultType, QueryType, BodyType> Ranged<'e, ResultType, QueryType, BodyType> {
+    pub fn new(
+        endpoint: &'e dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+        range: impl Into<String>,
+    ) -> Self {
+        Ranged {
+            endpoint,
+            range: range.into(),
+        }
+    }
+}
+
+impl<'e, ResultType, QueryType, BodyType> HerokuEndpoint<ResultType, QueryType, BodyType>
+    for Ranged<'e, ResultType, QueryType, BodyType>
+where
+    ResultType: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+    fn path(&self) -> String {
+        self.endpoint.path()
+    }
+    fn query(&self) -> Option<QueryType> {
+        self.endpoint.query()
+    }
+    fn body(&self) -> Option<BodyType> {
+        self.endpoint.body()
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        self.endpoint.data_api()
+    }
+    fn url(&self, environment: &ApiEnvironment) -> Url {
+        self.endpoint.url(environment)
+    }
+    fn content_type(&self) -> &str {
+        self.endpoint.content_type()
+    }
+    fn version(&self) -> &str {
+        self.endpoint.version()
+    }
+    fn agent(&self) -> &str {
+        self.endpoint.agent()
+    }
+    fn range(&self) -> Option<String> {
+        Some(self.range.clone())
+    }
 }
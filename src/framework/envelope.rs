@@ -0,0 +1,138 @@
+//! Serializable request envelopes.
+//!
+//! A [`RequestEnvelope`] captures everything needed to send an endpoint request later: the endpoint name, the
+//! method, the API serving it, the path, the query and the body. Envelopes can be stored, reviewed and then run with
+//! [`HerokuApiClient::execute`], e.g. to approve an `AppDelete` before it is sent.
+//!
+//! [`HerokuApiClient::execute`]: ../apiclient/trait.HerokuApiClient.html#method.execute
+
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::response::ApiResult;
+use crate::framework::DataApi;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+/// A request to the Heroku API, detached from the endpoint that built it.
+///
+/// # Example:
+///
+/// ```rust
+/// use heroku_rs::prelude::*;
+///
+/// let proposed = RequestEnvelope::new(&AppDelete::new("my-app")).unwrap();
+/// let stored = serde_json::to_string(&proposed).unwrap();
+///
+/// // later, once the request has been approved
+/// let approved: RequestEnvelope = serde_json::from_str(&stored).unwrap();
+/// assert_eq!(approved.to_string(), "AppDelete: DELETE apps/my-app");
+///
+///#    let api_client = HttpApiClient::create(&"API_KEY").unwrap();
+/// let response = api_client.execute::<serde_json::Value>(&approved);
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RequestEnvelope {
+    /// name of the endpoint that built the request, e.g. `AppDelete`
+    pub endpoint: String,
+    /// HTTP method
+    pub method: Method,
+    /// data or metrics API serving the request, the platform API if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<DataApi>,
+    /// path relative to the API root, e.g. `apps/my-app`
+    pub path: String,
+    /// query parameters, if any
+    pub query: Option<Value>,
+    /// request body, if any
+    pub body: Option<Value>,
+    /// content type of the body
+    #[serde(default = "default_content_type")]
+    pub content_type: String,
+    /// accepted API version
+    #[serde(default = "default_version")]
+    pub version: String,
+    /// `Range` header of list requests, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<String>,
+}
+
+fn default_content_type() -> String {
+    String::from("application/json")
+}
+
+fn default_version() -> String {
+    String::from("application/vnd.heroku+json; version=3")
+}
+
+impl RequestEnvelope {
+    /// Capture the request `endpoint` would send.
+    ///
+    /// Fails if the query or the body can't be serialized to JSON.
+    pub fn new<E, ResultType, QueryType, BodyType>(
+        endpoint: &E,
+    ) -> serde_json::Result<RequestEnvelope>
+    where
+        E: HerokuEndpoint<ResultType, QueryType, BodyType>,
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        Ok(RequestEnvelope {
+            endpoint: endpoint_name::<E>(),
+            method: endpoint.method(),
+            api: endpoint.data_api(),
+            path: endpoint.path(),
+            query: endpoint.query().map(serde_json::to_value).transpose()?,
+            body: endpoint.body().map(serde_json::to_value).transpose()?,
+            content_type: endpoint.content_type().to_string(),
+            version: endpoint.version().to_string(),
+            range: endpoint.range(),
+        })
+    }
+}
+
+/// The type name without its module path or generics, e.g. `AppDelete`.
+fn endpoint_name<E>() -> String {
+    let name = std::any::type_name::<E>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+/// Displays the endpoint, the method and the path, e.g. `AppDelete: DELETE apps/my-app`.
+impl fmt::Display for RequestEnvelope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} {}", self.endpoint, self.method, self.path)
+    }
+}
+
+impl<ResultType: ApiResult> HerokuEndpoint<ResultType, Value, Value> for RequestEnvelope {
+    fn method(&self) -> Method {
+        self.method
+    }
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+    fn query(&self) -> Option<Value> {
+        self.query.clone()
+    }
+    fn data_api(&self) -> Option<DataApi> {
+        self.api
+    }
+    fn body(&self) -> Option<Value> {
+        self.body.clone()
+    }
+    fn content_type(&self) -> &str {
+        &self.content_type
+    }
+    fn version(&self) -> &str {
+        &self.version
+    }
+    fn range(&self) -> Option<String> {
+        self.range.clone()
+    }
+}
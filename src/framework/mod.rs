@@ -3,6 +3,7 @@
 pub mod apiclient;
 pub mod auth;
 pub mod endpoint;
+pub mod envelope;
 mod reqwest_utils;
 pub mod response;
 
@@ -27,6 +28,41 @@ impl<'a> From<&'a ApiEnvironment> for url::Url {
     }
 }
 
+/// The Heroku data and metrics APIs, served on their own host next to the platform API.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DataApi {
+    /// Heroku Postgres, on `postgres-api.heroku.com`
+    Postgres,
+    /// Apache Kafka on Heroku, on `api.data.heroku.com`
+    Kafka,
+    /// Heroku Key-Value Store, formerly Heroku Redis, on `api.data.heroku.com`
+    KeyValue,
+    /// app metrics, on `api.metrics.heroku.com`
+    Metrics,
+}
+
+impl ApiEnvironment {
+    /// The base url of the data API `api`.
+    ///
+    /// A custom environment serves the data APIs on its own url, like the platform API.
+    pub fn data_url(&self, api: DataApi) -> url::Url {
+        match (self, api) {
+            (ApiEnvironment::Production, DataApi::Postgres) => {
+                url::Url::parse("https://postgres-api.heroku.com/").unwrap()
+            }
+            (ApiEnvironment::Production, DataApi::Kafka)
+            | (ApiEnvironment::Production, DataApi::KeyValue) => {
+                url::Url::parse("https://api.data.heroku.com/").unwrap()
+            }
+            (ApiEnvironment::Production, DataApi::Metrics) => {
+                url::Url::parse("https://api.metrics.heroku.com/").unwrap()
+            }
+            (ApiEnvironment::Custom(url), _) => url.clone(),
+        }
+    }
+}
+
 /// The client used to make requests to Heroku.
 ///
 /// This struct contains the synchronous client.
@@ -163,6 +199,9 @@ impl<'a> HerokuApiClient for HttpApiClient {
 
         request = request.header(reqwest::header::ACCEPT, endpoint.version());
         request = request.header(reqwest::header::USER_AGENT, endpoint.agent());
+        if let Some(range) = endpoint.range() {
+            request = request.header(reqwest::header::RANGE, range);
+        }
         request = request.auth(&self.credentials);
 
         let response = request.send()?;
@@ -196,6 +235,9 @@ impl<'a> HerokuApiClient for HttpApiClient {
 
         request = request.header(reqwest::header::ACCEPT, endpoint.version());
         request = request.header(reqwest::header::USER_AGENT, endpoint.agent());
+        if let Some(range) = endpoint.range() {
+            request = request.header(reqwest::header::RANGE, range);
+        }
         request = request.auth(&self.credentials);
 
         let response = request.send()?;
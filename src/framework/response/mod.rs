@@ -29,6 +29,8 @@ pub fn match_response<T: ApiResult>(api_response: reqwest::blocking::Response) -
 impl ApiResult for Empty {}
 impl ApiResult for () {}
 impl ApiResult for Vec<Empty> {}
+impl ApiResult for serde_json::Value {}
+impl ApiResult for Vec<serde_json::Value> {}
 
 pub trait ApiResult: DeserializeOwned + Debug {}
 
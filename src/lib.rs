@@ -203,6 +203,7 @@ pub mod macros;
 
 pub mod endpoints;
 pub mod framework;
+pub mod tools;
 
 /// A module meant to be glob imported when using heroku_rs.
 ///
@@ -233,16 +234,26 @@ pub mod prelude {
     pub use crate::endpoints::domains::*;
     #[cfg(feature = "dynos")]
     pub use crate::endpoints::dynos::*;
+    #[cfg(feature = "enterprise")]
+    pub use crate::endpoints::enterprise::*;
     #[cfg(feature = "formations")]
     pub use crate::endpoints::formations::*;
+    #[cfg(feature = "kafka")]
+    pub use crate::endpoints::kafka::*;
+    #[cfg(feature = "key_value")]
+    pub use crate::endpoints::key_value::*;
     #[cfg(feature = "logs")]
     pub use crate::endpoints::logs::*;
+    #[cfg(feature = "metrics")]
+    pub use crate::endpoints::metrics::*;
     #[cfg(feature = "misc")]
     pub use crate::endpoints::misc::*;
     #[cfg(feature = "oauth")]
     pub use crate::endpoints::oauth::*;
     #[cfg(feature = "pipelines")]
     pub use crate::endpoints::pipelines::*;
+    #[cfg(feature = "postgres")]
+    pub use crate::endpoints::postgres::*;
     #[cfg(feature = "releases")]
     pub use crate::endpoints::releases::*;
     #[cfg(feature = "review")]
@@ -256,12 +267,18 @@ pub mod prelude {
     #[cfg(feature = "testing")]
     pub use crate::endpoints::testing::*;
     #[doc(no_inline)]
+    pub use crate::endpoints::ids::*;
+    #[doc(no_inline)]
+    pub use crate::endpoints::status::*;
+    #[doc(no_inline)]
+    pub use crate::endpoints::usage::*;
+    #[doc(no_inline)]
     pub use crate::endpoints::*;
     #[doc(no_inline)]
     pub use crate::framework::endpoint::Method;
     #[doc(no_inline)]
     pub use crate::framework::{
-        apiclient::HerokuApiClient, auth::Credentials, ApiEnvironment, HttpApiClient,
-        HttpApiClientConfig,
+        apiclient::HerokuApiClient, auth::Credentials, envelope::RequestEnvelope, ApiEnvironment,
+        DataApi, HttpApiClient, HttpApiClientConfig,
     };
 }
@@ -0,0 +1,409 @@
+//! Add-on catalog
+//!
+//! A searchable snapshot of the add-on marketplace: every add-on service with its plans and the regions it can be
+//! provisioned in. Useful to answer questions like "which Redis plans under $50 a month can run in a private space in
+//! Frankfurt?" without clicking through the Elements marketplace.
+//!
+//! The catalog is fetched once with [`AddonCatalog::fetch`][fetch], which lists the add-on services, their region
+//! capabilities and the plans of every service still available, then searched with [`CatalogQuery`][query].
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::addon_catalog::{AddonCatalog, CatalogQuery};
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let catalog = AddonCatalog::fetch(&api_client)?;
+//! let query = CatalogQuery::new()
+//!     .text("redis")
+//!     .region("eu")
+//!     .private_space(true)
+//!     .max_price_cents(5000)
+//!     .clone();
+//!
+//! for result in catalog.search(&query) {
+//!     for plan in result.plans {
+//!         println!("{}: ${:.2}/{}", plan.name, plan.price.cents as f64 / 100.0, plan.price.unit);
+//!     }
+//! }
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [fetch]: struct.AddonCatalog.html#method.fetch
+//! [query]: struct.CatalogQuery.html
+
+use crate::endpoints::addons::{
+    AddonPlan, AddonRegionCapability, AddonService, AddonServiceList, PlanList,
+    RegionCapabilityList,
+};
+use crate::endpoints::status::{AddonPlanState, AddonServiceState};
+use crate::framework::{apiclient::HerokuApiClient, response::ApiResponse};
+
+/// A region an add-on service can be provisioned in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionSupport {
+    /// unique name of the region, e.g. `eu`
+    pub region: String,
+    /// whether the add-on can be installed to a private space of the region
+    pub supports_private_networking: bool,
+}
+
+/// An add-on service of the catalog.
+#[derive(Debug, Clone)]
+pub struct CatalogEntry {
+    /// the add-on service
+    pub service: AddonService,
+    /// plans of the service, cheapest first
+    pub plans: Vec<AddonPlan>,
+    /// regions the service can be provisioned in
+    pub regions: Vec<RegionSupport>,
+}
+
+impl CatalogEntry {
+    /// Whether the service can be provisioned in `region`, in a private space if `private_space` is set.
+    ///
+    /// Without a region, whether it can be provisioned in any region.
+    fn available_in(&self, region: Option<&str>, private_space: bool) -> bool {
+        self.regions.iter().any(|support| {
+            region.is_none_or(|region| support.region == region)
+                && (!private_space || support.supports_private_networking)
+        })
+    }
+
+    fn text_matches(&self, text: &str) -> bool {
+        contains(&self.service.name, text) || contains(&self.service.human_name, text)
+    }
+}
+
+/// A search of the catalog. Every filter left unset matches everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CatalogQuery<'a> {
+    /// case insensitive text in the name of the service or in the name or description of a plan
+    pub text: Option<&'a str>,
+    /// region the service must be available in, e.g. `eu`
+    pub region: Option<&'a str>,
+    /// only services and plans that can be installed in a private space
+    pub private_space: bool,
+    /// most a plan can cost, in cents per unit of the plan. Plans priced by contract are left out.
+    pub max_price_cents: Option<i64>,
+}
+
+impl<'a> CatalogQuery<'a> {
+    /// A query matching every available plan.
+    pub fn new() -> CatalogQuery<'a> {
+        CatalogQuery::default()
+    }
+
+    /// Only match services or plans mentioning `text`.
+    pub fn text(&mut self, text: &'a str) -> &mut Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Only match services available in `region`.
+    pub fn region(&mut self, region: &'a str) -> &mut Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Only match services and plans that can be installed in a private space.
+    pub fn private_space(&mut self, private_space: bool) -> &mut Self {
+        self.private_space = private_space;
+        self
+    }
+
+    /// Only match plans costing at most `cents` per unit.
+    pub fn max_price_cents(&mut self, cents: i64) -> &mut Self {
+        self.max_price_cents = Some(cents);
+        self
+    }
+
+    fn plan_matches(&self, plan: &AddonPlan) -> bool {
+        plan.state != AddonPlanState::Shutdown
+            && (!self.private_space || plan.installable_inside_private_space)
+            && self
+                .max_price_cents
+                .is_none_or(|max| !plan.price.contract && plan.price.cents <= max)
+    }
+}
+
+/// A service matching a [`CatalogQuery`][query], with its matching plans.
+///
+/// [query]: struct.CatalogQuery.html
+#[derive(Debug, Clone)]
+pub struct CatalogMatch<'a> {
+    /// the add-on service
+    pub service: &'a AddonService,
+    /// the plans of the service matching the query, cheapest first
+    pub plans: Vec<&'a AddonPlan>,
+}
+
+/// Every add-on service with its plans and regions, sorted by service name.
+#[derive(Debug, Clone, Default)]
+pub struct AddonCatalog {
+    /// the add-on services
+    pub entries: Vec<CatalogEntry>,
+}
+
+impl AddonCatalog {
+    /// Build a catalog out of services, the region capabilities of the services and their plans.
+    ///
+    /// Services that are shut down are left out.
+    pub fn new(
+        services: Vec<AddonService>,
+        capabilities: &[AddonRegionCapability],
+        plans: Vec<AddonPlan>,
+    ) -> AddonCatalog {
+        let mut entries: Vec<CatalogEntry> = services
+            .into_iter()
+            .filter(|service| service.state != AddonServiceState::Shutdown)
+            .map(|service| CatalogEntry {
+                regions: capabilities
+                    .iter()
+                    .filter(|capability| capability.addon_service.id == service.id)
+                    .map(|capability| RegionSupport {
+                        region: capability.region.name.clone(),
+                        supports_private_networking: capability.supports_private_networking,
+                    })
+                    .collect(),
+                plans: Vec::new(),
+                service,
+            })
+            .collect();
+
+        for plan in plans {
+            if let Some(entry) = entries
+                .iter_mut()
+                .find(|entry| entry.service.id == plan.addon_service.id)
+            {
+                entry.plans.push(plan);
+            }
+        }
+        for entry in &mut entries {
+            entry.plans.sort_by_key(|plan| plan.price.cents);
+        }
+        entries.sort_by(|a, b| a.service.name.cmp(&b.service.name));
+        AddonCatalog { entries }
+    }
+
+    /// Fetch the catalog: the add-on services, their region capabilities, then the plans of every service.
+    pub fn fetch<C: HerokuApiClient>(api_client: &C) -> ApiResponse<AddonCatalog> {
+        let services = api_client.request(&AddonServiceList {})?;
+        let capabilities = api_client.request(&RegionCapabilityList {})?;
+
+        let mut plans = Vec::new();
+        for service in &services {
+            if service.state != AddonServiceState::Shutdown {
+                plans.extend(api_client.request(&PlanList {
+                    service_id: service.id.as_str().into(),
+                })?);
+            }
+        }
+        Ok(AddonCatalog::new(services, &capabilities, plans))
+    }
+
+    /// The entry of a service, by name.
+    pub fn service(&self, name: &str) -> Option<&CatalogEntry> {
+        self.entries.iter().find(|entry| entry.service.name == name)
+    }
+
+    /// The services with at least one plan matching `query`.
+    pub fn search(&self, query: &CatalogQuery) -> Vec<CatalogMatch<'_>> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.available_in(query.region, query.private_space))
+            .filter_map(|entry| {
+                let service_matches = query.text.is_none_or(|text| entry.text_matches(text));
+                let plans: Vec<&AddonPlan> = entry
+                    .plans
+                    .iter()
+                    .filter(|plan| query.plan_matches(plan))
+                    .filter(|plan| {
+                        service_matches
+                            || query.text.is_none_or(|text| {
+                                contains(&plan.name, text)
+                                    || contains(&plan.human_name, text)
+                                    || contains(&plan.description, text)
+                            })
+                    })
+                    .collect();
+                if plans.is_empty() {
+                    None
+                } else {
+                    Some(CatalogMatch {
+                        service: &entry.service,
+                        plans,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+/// Case insensitive `haystack.contains(needle)`.
+fn contains(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    const CREATED_AT: &str = "2012-01-01T12:00:00Z";
+
+    fn service(id: &str, name: &str, human_name: &str, state: &str) -> Value {
+        json!({
+            "cli_plugin_name": null,
+            "created_at": CREATED_AT,
+            "human_name": human_name,
+            "id": id,
+            "name": name,
+            "state": state,
+            "supports_multiple_installations": true,
+            "supports_sharing": true,
+            "updated_at": CREATED_AT
+        })
+    }
+
+    fn plan(service: &str, name: &str, cents: i64, private_space: bool) -> AddonPlan {
+        serde_json::from_value(json!({
+            "addon_service": {"id": service, "name": service},
+            "created_at": CREATED_AT,
+            "compliance": null,
+            "default": false,
+            "description": format!("{} {}", service, name),
+            "human_name": name,
+            "id": format!("{}:{}", service, name),
+            "installable_inside_private_space": private_space,
+            "installable_outside_private_space": true,
+            "name": name,
+            "price": {"cents": cents, "contract": false, "unit": "month"},
+            "space_default": false,
+            "state": "public",
+            "updated_at": CREATED_AT,
+            "visible": true
+        }))
+        .unwrap()
+    }
+
+    fn capability(
+        service: &Value,
+        region: &str,
+        private_networking: bool,
+    ) -> AddonRegionCapability {
+        serde_json::from_value(json!({
+            "id": format!("{}-{}", service["id"], region),
+            "supports_private_networking": private_networking,
+            "addon_service": service,
+            "region": {
+                "country": "Ireland",
+                "created_at": CREATED_AT,
+                "description": region,
+                "id": region,
+                "locale": region,
+                "name": region,
+                "private_capable": true,
+                "provider": {"name": "amazon-web-services", "region": "eu-west-1"},
+                "updated_at": CREATED_AT
+            }
+        }))
+        .unwrap()
+    }
+
+    fn catalog() -> AddonCatalog {
+        let redis = service("redis", "heroku-redis", "Heroku Redis", "ga");
+        let papertrail = service("papertrail", "papertrail", "Papertrail", "ga");
+        let legacy = service("legacy", "legacy-redis", "Legacy Redis", "shutdown");
+        let capabilities = vec![
+            capability(&redis, "eu", true),
+            capability(&redis, "us", true),
+            capability(&papertrail, "us", false),
+            capability(&legacy, "eu", true),
+        ];
+        let mut premium = plan("redis", "premium-0", 1500, true);
+        premium.description = String::from("High availability");
+        let plans = vec![
+            premium,
+            plan("redis", "hobby-dev", 0, false),
+            plan("papertrail", "choklad", 0, false),
+            plan("papertrail", "fixa", 700, false),
+            plan("legacy", "basic", 100, true),
+        ];
+        let services = vec![redis, papertrail, legacy]
+            .into_iter()
+            .map(|service| serde_json::from_value(service).unwrap())
+            .collect();
+        AddonCatalog::new(services, &capabilities, plans)
+    }
+
+    fn names(matches: &[CatalogMatch]) -> Vec<String> {
+        matches
+            .iter()
+            .flat_map(|result| {
+                result
+                    .plans
+                    .iter()
+                    .map(move |plan| format!("{}:{}", result.service.name, plan.name))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn catalog_skips_shut_down_services_and_sorts_plans() {
+        let catalog = catalog();
+        assert_eq!(catalog.entries.len(), 2);
+        assert!(catalog.service("legacy-redis").is_none());
+
+        let redis = catalog.service("heroku-redis").unwrap();
+        assert_eq!(redis.plans[0].name, "hobby-dev");
+        assert_eq!(redis.regions.len(), 2);
+        assert_eq!(names(&catalog.search(&CatalogQuery::new())).len(), 4);
+    }
+
+    #[test]
+    fn search_filters_by_region_and_private_space() {
+        let catalog = catalog();
+        assert_eq!(
+            names(&catalog.search(CatalogQuery::new().region("eu"))),
+            vec!["heroku-redis:hobby-dev", "heroku-redis:premium-0"]
+        );
+        assert_eq!(
+            names(&catalog.search(CatalogQuery::new().private_space(true))),
+            vec!["heroku-redis:premium-0"]
+        );
+        assert!(catalog
+            .search(
+                CatalogQuery::new()
+                    .region("us")
+                    .text("papertrail")
+                    .private_space(true)
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn search_filters_by_price_and_text() {
+        let catalog = catalog();
+        assert_eq!(
+            names(&catalog.search(CatalogQuery::new().max_price_cents(1000))),
+            vec![
+                "heroku-redis:hobby-dev",
+                "papertrail:choklad",
+                "papertrail:fixa"
+            ]
+        );
+        assert_eq!(
+            names(&catalog.search(CatalogQuery::new().text("REDIS").max_price_cents(0))),
+            vec!["heroku-redis:hobby-dev"]
+        );
+        // matches the description of a plan only
+        assert_eq!(
+            names(&catalog.search(CatalogQuery::new().text("availability"))),
+            vec!["heroku-redis:premium-0"]
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Team add-on policy
+//!
+//! Teams can restrict the add-on services their members may install to an allow-list, the whitelisted add-on
+//! services, enforced when the `whitelisting-enabled` team preference is set. Enforcement only applies to new
+//! installations: add-ons provisioned before a service was removed from the list, or before enforcement was turned on,
+//! keep running.
+//!
+//! [`check_team`][check_team] lists every add-on of the team apps and reports the ones whose service is not on the
+//! allow-list, whether enforcement is on or not, so they can be reviewed or replaced.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::addon_policy;
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let report = addon_policy::check_team(&api_client, "TEAM_NAME")?;
+//!
+//! if !report.is_compliant() {
+//!     print!("{}", report);
+//! }
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [check_team]: fn.check_team.html
+
+use crate::endpoints::addons::{Addon, AddonListByTeam};
+use crate::endpoints::teams::{
+    TeamPreferenceList, WhitelistedAddonService, WhitelistedAddonServiceList,
+};
+use crate::framework::{apiclient::HerokuApiClient, response::ApiResponse};
+use std::fmt;
+
+/// The add-on services a team allows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AddonPolicy {
+    /// names of the whitelisted add-on services
+    pub allowed_services: Vec<String>,
+    /// whether the team enforces the allow-list on new installations
+    pub enforced: bool,
+}
+
+impl AddonPolicy {
+    /// The policy of a team, out of its whitelisted add-on services and its `whitelisting-enabled` preference.
+    pub fn new(whitelist: &[WhitelistedAddonService], whitelisting_enabled: bool) -> AddonPolicy {
+        AddonPolicy {
+            allowed_services: whitelist
+                .iter()
+                .map(|entity| entity.addon_service.name.clone())
+                .collect(),
+            enforced: whitelisting_enabled,
+        }
+    }
+
+    /// Whether the add-on service `name` is on the allow-list.
+    pub fn allows(&self, name: &str) -> bool {
+        self.allowed_services.iter().any(|allowed| allowed == name)
+    }
+
+    /// The add-ons whose service is not on the allow-list.
+    pub fn violations(&self, addons: &[Addon]) -> Vec<PolicyViolation> {
+        addons
+            .iter()
+            .filter(|addon| !self.allows(&addon.addon_service.name))
+            .map(|addon| PolicyViolation {
+                addon: addon.name.clone(),
+                app: addon.app.name.clone(),
+                service: addon.addon_service.name.clone(),
+                plan: addon.plan.name.clone(),
+            })
+            .collect()
+    }
+}
+
+/// An add-on of a non-approved service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyViolation {
+    /// globally unique name of the add-on
+    pub addon: String,
+    /// name of the app owning the add-on
+    pub app: String,
+    /// name of the add-on service, missing from the allow-list
+    pub service: String,
+    /// name of the plan, e.g. `heroku-postgresql:standard-0`
+    pub plan: String,
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) on {}: {} is not approved",
+            self.addon, self.plan, self.app, self.service
+        )
+    }
+}
+
+/// Non-approved add-ons of a team.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyReport {
+    /// name or id of the team
+    pub team: String,
+    /// the policy the add-ons were checked against
+    pub policy: AddonPolicy,
+    /// number of add-ons checked
+    pub checked: usize,
+    /// the add-ons of non-approved services, by app then add-on name
+    pub violations: Vec<PolicyViolation>,
+}
+
+impl PolicyReport {
+    /// Check `addons` of `team` against `policy`.
+    pub fn new(team: &str, policy: AddonPolicy, addons: &[Addon]) -> PolicyReport {
+        let mut violations = policy.violations(addons);
+        violations.sort_by(|a, b| (&a.app, &a.addon).cmp(&(&b.app, &b.addon)));
+        PolicyReport {
+            team: team.to_string(),
+            policy,
+            checked: addons.len(),
+            violations,
+        }
+    }
+
+    /// Whether every add-on of the team is approved.
+    pub fn is_compliant(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for PolicyReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "team {}: {} of {} add-ons not approved, whitelisting {}",
+            self.team,
+            self.violations.len(),
+            self.checked,
+            if self.policy.enforced {
+                "enforced"
+            } else {
+                "not enforced"
+            }
+        )?;
+        for violation in &self.violations {
+            writeln!(f, "  {}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check every add-on of the apps of `team` against the team allow-list.
+///
+/// Requests the team preferences, then every page of its whitelisted add-on services and of its add-ons.
+pub fn check_team<C: HerokuApiClient>(api_client: &C, team: &str) -> ApiResponse<PolicyReport> {
+    let preferences = api_client.request(&TeamPreferenceList { id: team.into() })?;
+    let whitelist = api_client.request_all(&WhitelistedAddonServiceList {
+        team_id: team.into(),
+    })?;
+    let addons = api_client.request_all(&AddonListByTeam {
+        team_id: team.into(),
+    })?;
+
+    let policy = AddonPolicy::new(
+        &whitelist,
+        preferences.whitelisting_enabled.unwrap_or(false),
+    );
+    Ok(PolicyReport::new(team, policy, &addons))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn addon(app: &str, service: &str, plan: &str) -> Addon {
+        serde_json::from_value(json!({
+            "actions": [],
+            "addon_service": {"id": format!("{}-id", service), "name": service},
+            "billing_entity": {"id": "app-id", "name": app, "type": "app"},
+            "app": {"id": format!("{}-id", app), "name": app},
+            "billed_price": null,
+            "config_vars": [],
+            "created_at": "2012-01-01T12:00:00Z",
+            "id": format!("{}-{}", app, service),
+            "name": format!("{}-{}", service, app),
+            "plan": {"id": "plan-id", "name": format!("{}:{}", service, plan)},
+            "provider_id": "abcd1234",
+            "state": "provisioned",
+            "updated_at": "2012-01-01T12:00:00Z",
+            "web_url": null
+        }))
+        .unwrap()
+    }
+
+    fn whitelisted(service: &str) -> WhitelistedAddonService {
+        serde_json::from_value(json!({
+            "added_at": "2012-01-01T12:00:00Z",
+            "added_by": {"email": "security@example.com", "id": "user-id"},
+            "addon_service": {"id": format!("{}-id", service), "name": service, "human_name": service},
+            "id": "entity-id"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn flags_addons_missing_from_the_allow_list() {
+        let policy = AddonPolicy::new(&[whitelisted("heroku-postgresql")], true);
+        let addons = vec![
+            addon("web", "heroku-postgresql", "standard-0"),
+            addon("web", "papertrail", "choklad"),
+            addon("api", "sendgrid", "starter"),
+        ];
+        let report = PolicyReport::new("acme", policy, &addons);
+
+        assert!(!report.is_compliant());
+        assert_eq!(report.checked, 3);
+        assert_eq!(
+            report
+                .violations
+                .iter()
+                .map(|violation| violation.addon.as_str())
+                .collect::<Vec<_>>(),
+            vec!["sendgrid-api", "papertrail-web"]
+        );
+        assert_eq!(
+            report.to_string(),
+            "team acme: 2 of 3 add-ons not approved, whitelisting enforced\n  \
+             sendgrid-api (sendgrid:starter) on api: sendgrid is not approved\n  \
+             papertrail-web (papertrail:choklad) on web: papertrail is not approved\n"
+        );
+    }
+
+    #[test]
+    fn empty_allow_list_approves_nothing() {
+        let report = PolicyReport::new(
+            "acme",
+            AddonPolicy::default(),
+            &[addon("web", "heroku-redis", "hobby-dev")],
+        );
+        assert_eq!(report.violations.len(), 1);
+        assert!(report.to_string().contains("whitelisting not enforced"));
+        assert!(PolicyReport::new("acme", AddonPolicy::default(), &[]).is_compliant());
+    }
+}
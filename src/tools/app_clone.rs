@@ -0,0 +1,623 @@
+//! App cloning
+//!
+//! Copies an app into a new app, optionally in another region, team, private space or account.
+//!
+//! The clone creates the app, then copies the buildpacks, add-ons, config vars, the current slug and the formation, in that order.
+//! Each of them can be left out with [`CloneOptions::exclude`][exclude].
+//! Add-ons whose plan can't be provisioned in the target region are skipped and listed in the [`CloneReport`][report].
+//!
+//! To clone into another account, pass a client authenticated with that account as the target client.
+//! Releasing the source slug requires the target account to have access to the source app.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::app_clone::{self, CloneOptions, Resource};
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let mut options = CloneOptions::new("my-app", "my-app-staging-42");
+//! options.region = Some("eu");
+//! options.exclude(Resource::Formation);
+//!
+//! let report = app_clone::clone_app(&api_client, &api_client, &options)?;
+//! for addon in &report.unprovisionable_addons {
+//!     println!("skipped {}: {}", addon.plan, addon.reason);
+//! }
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [exclude]: struct.CloneOptions.html#method.exclude
+//! [report]: struct.CloneReport.html
+
+use crate::endpoints::addons::post::Attachment;
+use crate::endpoints::addons::{
+    Addon, AddonCreate, AddonCreateParams, AddonListByApp, AddonRegionCapability,
+    AttachmentListByApp, RegionCapabilityList,
+};
+use crate::endpoints::apps::{App, AppCreate, AppCreateParams, AppDetails};
+use crate::endpoints::builds::{
+    BuildPackInstallationList, BuildpackInstallationUpdate, BuildpackInstallationUpdateParams,
+    Update,
+};
+use crate::endpoints::config_vars::{AppConfigVarDetails, AppConfigVarUpdate};
+use crate::endpoints::formations::{FormationList, FormationUpdate, FormationUpdateParams};
+use crate::endpoints::releases::{Release, ReleaseCreate, ReleaseCreateParams, ReleaseList};
+use crate::endpoints::space::SpaceDetails;
+use crate::endpoints::teams::{TeamAppCreate, TeamAppCreateParams};
+use crate::framework::apiclient::HerokuApiClient;
+use crate::framework::endpoint::Ranged;
+use crate::framework::response::HerokuApiFailure;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A part of the app that can be left out of the clone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    /// buildpack installations
+    Buildpacks,
+    /// add-ons owned by the source app
+    Addons,
+    /// config vars, except the ones set by add-ons
+    ConfigVars,
+    /// the slug of the current release
+    Slug,
+    /// quantity and size of every process type, needs the slug
+    Formation,
+}
+
+impl Resource {
+    /// every resource, in the order they are cloned
+    pub fn all() -> Vec<Resource> {
+        vec![
+            Resource::Buildpacks,
+            Resource::Addons,
+            Resource::ConfigVars,
+            Resource::Slug,
+            Resource::Formation,
+        ]
+    }
+}
+
+/// What to clone and where.
+#[derive(Debug, Clone)]
+pub struct CloneOptions<'a> {
+    /// name or id of the app to clone
+    pub source: &'a str,
+    /// name of the new app
+    pub target: &'a str,
+    /// region of the new app, defaults to the region of the source app or of the space
+    pub region: Option<&'a str>,
+    /// team owning the new app, the app is personal when not set
+    pub team: Option<&'a str>,
+    /// private space of the new app, requires a team
+    pub space: Option<&'a str>,
+    /// stack of the new app, defaults to the stack of the source app
+    pub stack: Option<&'a str>,
+    /// config vars that are not copied
+    pub exclude_config_vars: Vec<&'a str>,
+    /// the resources to clone, all of them by default
+    pub resources: Vec<Resource>,
+}
+
+impl<'a> CloneOptions<'a> {
+    /// Clone every resource of `source` into a new personal app named `target`.
+    pub fn new(source: &'a str, target: &'a str) -> CloneOptions<'a> {
+        CloneOptions {
+            source,
+            target,
+            region: None,
+            team: None,
+            space: None,
+            stack: None,
+            exclude_config_vars: Vec::new(),
+            resources: Resource::all(),
+        }
+    }
+
+    /// Leave a resource out of the clone.
+    pub fn exclude(&mut self, resource: Resource) -> &mut Self {
+        self.resources.retain(|r| *r != resource);
+        self
+    }
+
+    /// Add a resource back to the clone.
+    pub fn include(&mut self, resource: Resource) -> &mut Self {
+        if !self.resources.contains(&resource) {
+            self.resources.push(resource);
+        }
+        self
+    }
+
+    fn includes(&self, resource: Resource) -> bool {
+        self.resources.contains(&resource)
+    }
+}
+
+/// An add-on of the source app that was not provisioned on the clone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnprovisionableAddon {
+    /// name of the add-on on the source app
+    pub addon: String,
+    /// add-on service and plan, e.g. `heroku-postgresql:standard-0`
+    pub plan: String,
+    /// why the add-on was skipped
+    pub reason: String,
+}
+
+/// What was cloned.
+#[derive(Debug, Clone)]
+pub struct CloneReport {
+    /// id of the new app
+    pub app_id: String,
+    /// name of the new app
+    pub app_name: String,
+    /// add-ons provisioned on the new app
+    pub addons: Vec<Addon>,
+    /// add-ons that were skipped
+    pub unprovisionable_addons: Vec<UnprovisionableAddon>,
+    /// names of the add-ons attached from other apps, they are not shared with the clone
+    pub skipped_attachments: Vec<String>,
+    /// number of config vars copied
+    pub config_vars: usize,
+    /// the release of the source slug on the new app
+    pub release: Option<Release>,
+}
+
+/// CloneError
+///
+/// Returned when the options can't be cloned, or when a step of the clone failed.
+#[derive(Debug)]
+pub enum CloneError {
+    /// A private space was given without the team owning it, nothing was requested
+    SpaceWithoutTeam,
+    /// A request failed. The new app is not deleted, `app_id` is set once it was created so it can be cleaned up.
+    Request {
+        /// what the clone was doing
+        step: String,
+        /// id of the new app, if it was created
+        app_id: Option<String>,
+        /// the failed request
+        error: HerokuApiFailure,
+    },
+}
+
+impl std::error::Error for CloneError {}
+
+impl fmt::Display for CloneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CloneError::SpaceWithoutTeam => {
+                write!(f, "a private space requires a team, set `team` as well")
+            }
+            CloneError::Request { step, error, .. } => {
+                write!(f, "clone failed while {}: {}", step, error)
+            }
+        }
+    }
+}
+
+/// The config vars of `config` to copy: those set by an add-on, as listed in `addon_config_vars`, are left out
+/// since the add-ons of the clone set their own, and so are the `excluded` ones.
+fn cloned_config_vars(
+    config: HashMap<String, Option<String>>,
+    addon_config_vars: &[String],
+    excluded: &[&str],
+) -> HashMap<String, String> {
+    config
+        .into_iter()
+        .filter(|(key, _)| {
+            !excluded.contains(&key.as_str()) && !addon_config_vars.iter().any(|var| var == key)
+        })
+        .filter_map(|(key, value)| value.map(|value| (key, value)))
+        .collect()
+}
+
+/// Find the add-ons of `addons` that can't be provisioned in `region`.
+///
+/// In a private space, the add-on service must also support private networking.
+pub fn unprovisionable_addons(
+    addons: &[Addon],
+    capabilities: &[AddonRegionCapability],
+    region: &str,
+    private_space: bool,
+) -> Vec<UnprovisionableAddon> {
+    addons
+        .iter()
+        .filter_map(|addon| {
+            let capability = capabilities.iter().find(|capability| {
+                capability.addon_service.name == addon.addon_service.name
+                    && capability.region.name == region
+            });
+            let reason = match capability {
+                None => format!(
+                    "{} is not available in {}",
+                    addon.addon_service.name, region
+                ),
+                Some(capability) if private_space && !capability.supports_private_networking => {
+                    format!(
+                        "{} can't be installed in a private space",
+                        addon.addon_service.name
+                    )
+                }
+                Some(_) => return None,
+            };
+            Some(UnprovisionableAddon {
+                addon: addon.name.clone(),
+                plan: addon.plan.name.clone(),
+                reason,
+            })
+        })
+        .collect()
+}
+
+/// Clone an app, reading from `source_client` and creating the new app with `target_client`.
+///
+/// Fails before sending any request when a private space is given without a team.
+pub fn clone_app<S: HerokuApiClient, T: HerokuApiClient>(
+    source_client: &S,
+    target_client: &T,
+    options: &CloneOptions,
+) -> Result<CloneReport, CloneError> {
+    if options.space.is_some() && options.team.is_none() {
+        return Err(CloneError::SpaceWithoutTeam);
+    }
+
+    let mut app_id: Option<String> = None;
+    let fail = |step: &str, app_id: &Option<String>| {
+        let step = step.to_string();
+        let app_id = app_id.clone();
+        move |error: HerokuApiFailure| CloneError::Request {
+            step,
+            app_id,
+            error,
+        }
+    };
+
+    let source: App = source_client
+        .request(&AppDetails {
+            app_id: options.source.into(),
+        })
+        .map_err(fail("reading the source app", &app_id))?;
+    let source_id = source.id.as_str();
+
+    let region = match (options.region, options.space) {
+        (Some(region), _) => region.to_string(),
+        (None, Some(space)) => {
+            target_client
+                .request(&SpaceDetails {
+                    space_id: space.into(),
+                })
+                .map_err(fail("reading the space", &app_id))?
+                .region
+                .name
+        }
+        (None, None) => source.region.name.clone(),
+    };
+    let stack = options.stack.unwrap_or(&source.build_stack.name);
+
+    // add-ons are checked before anything is created
+    let mut owned_addons = Vec::new();
+    let mut skipped_attachments = Vec::new();
+    let mut unprovisionable = Vec::new();
+    let mut attachment_names = HashMap::new();
+    let mut addon_config_vars = Vec::new();
+    if options.includes(Resource::Addons) || options.includes(Resource::ConfigVars) {
+        let addons = source_client
+            .request(&AddonListByApp {
+                app_id: source_id.into(),
+            })
+            .map_err(fail("listing add-ons", &app_id))?;
+        let attachments = source_client
+            .request(&AttachmentListByApp {
+                app_id: source_id.into(),
+            })
+            .map_err(fail("listing add-on attachments", &app_id))?;
+        for attachment in &attachments {
+            attachment_names.insert(attachment.addon.id.clone(), attachment.name.clone());
+        }
+        for addon in addons {
+            addon_config_vars.extend(addon.config_vars.iter().cloned());
+            if addon.app.id == source.id {
+                owned_addons.push(addon);
+            } else {
+                skipped_attachments.push(addon.name);
+            }
+        }
+    }
+    if options.includes(Resource::Addons) && !owned_addons.is_empty() {
+        let capabilities = source_client
+            .request(&RegionCapabilityList {})
+            .map_err(fail("listing add-on region capabilities", &app_id))?;
+        unprovisionable = unprovisionable_addons(
+            &owned_addons,
+            &capabilities,
+            &region,
+            options.space.is_some(),
+        );
+    }
+
+    let (created_id, created_name) = match options.team {
+        Some(team) => target_client
+            .request(&TeamAppCreate {
+                params: TeamAppCreateParams {
+                    locked: None,
+                    name: Some(options.target.into()),
+                    team: Some(team.into()),
+                    personal: None,
+                    region: Some(region.as_str().into()),
+                    space: options.space.map(Cow::from),
+                    stack: Some(stack.into()),
+                    internal_routing: None,
+                },
+            })
+            .map(|app| (app.id, app.name)),
+        None => target_client
+            .request(&AppCreate {
+                params: AppCreateParams {
+                    name: Some(options.target.into()),
+                    region: Some(region.as_str().into()),
+                    stack: Some(stack.into()),
+                },
+            })
+            .map(|app| (app.id, app.name)),
+    }
+    .map_err(fail("creating the app", &app_id))?;
+    app_id = Some(created_id.clone());
+    let target_id = created_id.as_str();
+
+    let mut report = CloneReport {
+        app_id: created_id.clone(),
+        app_name: created_name,
+        addons: Vec::new(),
+        unprovisionable_addons: Vec::new(),
+        skipped_attachments,
+        config_vars: 0,
+        release: None,
+    };
+
+    if options.includes(Resource::Buildpacks) {
+        let mut buildpacks = source_client
+            .request(&BuildPackInstallationList {
+                app_id: source_id.into(),
+            })
+            .map_err(fail("listing buildpacks", &app_id))?;
+        buildpacks.sort_by_key(|b| b.ordinal);
+        if !buildpacks.is_empty() {
+            target_client
+                .request(&BuildpackInstallationUpdate {
+                    app_id: target_id.into(),
+                    params: BuildpackInstallationUpdateParams {
+                        updates: buildpacks
+                            .into_iter()
+                            .map(|installation| Update {
+                                buildpack: installation.buildpack.url,
+                            })
+                            .collect(),
+                    },
+                })
+                .map_err(fail("setting buildpacks", &app_id))?;
+        }
+    }
+
+    if options.includes(Resource::Addons) {
+        for addon in &owned_addons {
+            if unprovisionable.iter().any(|u| u.addon == addon.name) {
+                continue;
+            }
+            let created = target_client
+                .request(&AddonCreate {
+                    app_id: target_id.into(),
+                    params: AddonCreateParams {
+                        attachment: attachment_names.get(&addon.id).map(|name| Attachment {
+                            name: Some(name.into()),
+                        }),
+                        config: None,
+                        confirm: None,
+                        plan: addon.plan.name.as_str().into(),
+                        name: None,
+                    },
+                })
+                .map_err(fail(
+                    &format!("creating add-on {}", addon.plan.name),
+                    &app_id,
+                ))?;
+            report.addons.push(created);
+        }
+        report.unprovisionable_addons = unprovisionable;
+    }
+
+    if options.includes(Resource::ConfigVars) {
+        let config = source_client
+            .request(&AppConfigVarDetails {
+                app_id: source_id.into(),
+            })
+            .map_err(fail("reading config vars", &app_id))?;
+        let params = cloned_config_vars(config, &addon_config_vars, &options.exclude_config_vars);
+        report.config_vars = params.len();
+        if !params.is_empty() {
+            target_client
+                .request(&AppConfigVarUpdate {
+                    app_id: target_id.into(),
+                    params,
+                })
+                .map_err(fail("setting config vars", &app_id))?;
+        }
+    }
+
+    if options.includes(Resource::Slug) {
+        // newest first: the current release is usually the latest one, but a failed
+        // release phase leaves newer releases that are not current
+        let releases = source_client
+            .request(&Ranged::new(
+                &ReleaseList {
+                    app_id: source_id.into(),
+                },
+                "version ..; order=desc, max=25",
+            ))
+            .map_err(fail("listing releases", &app_id))?;
+        let slug = releases
+            .iter()
+            .find(|release| release.current)
+            .and_then(|release| release.slug.as_ref());
+        if let Some(slug) = slug {
+            let description = format!("Cloned from {}", source.name);
+            let release = target_client
+                .request(&ReleaseCreate {
+                    app_id: target_id.into(),
+                    params: ReleaseCreateParams {
+                        slug: slug.id.as_str().into(),
+                        description: Some(description.as_str().into()),
+                    },
+                })
+                .map_err(fail("releasing the slug", &app_id))?;
+            report.release = Some(release);
+        }
+    }
+
+    // the process types only exist once the slug is released
+    if options.includes(Resource::Formation) && report.release.is_some() {
+        let formation = source_client
+            .request(&FormationList {
+                app_id: source_id.into(),
+            })
+            .map_err(fail("listing the formation", &app_id))?;
+        for process in &formation {
+            target_client
+                .request(&FormationUpdate {
+                    app_id: target_id.into(),
+                    formation_id: process.r#type.as_str().into(),
+                    params: FormationUpdateParams {
+                        quantity: Some(process.quantity),
+                        size: Some(process.size.as_str().into()),
+                    },
+                })
+                .map_err(fail(&format!("scaling {}", process.r#type), &app_id))?;
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addon(name: &str, service: &str) -> Addon {
+        serde_json::from_value(serde_json::json!({
+            "actions": [],
+            "addon_service": { "id": format!("{}-id", service), "name": service },
+            "billing_entity": { "id": "app-id", "name": "my-app", "type": "app" },
+            "app": { "id": "app-id", "name": "my-app" },
+            "billed_price": null,
+            "config_vars": [],
+            "created_at": "2020-01-01T00:00:00Z",
+            "id": format!("{}-id", name),
+            "name": name,
+            "plan": { "id": "plan-id", "name": format!("{}:basic", service) },
+            "provider_id": "provider-id",
+            "state": "provisioned",
+            "updated_at": "2020-01-01T00:00:00Z",
+            "web_url": null,
+        }))
+        .unwrap()
+    }
+
+    fn capability(service: &str, region: &str, private: bool) -> AddonRegionCapability {
+        serde_json::from_value(serde_json::json!({
+            "id": "capability-id",
+            "supports_private_networking": private,
+            "addon_service": {
+                "cli_plugin_name": null,
+                "created_at": "2020-01-01T00:00:00Z",
+                "human_name": service,
+                "id": format!("{}-id", service),
+                "name": service,
+                "state": "ga",
+                "supports_multiple_installations": true,
+                "supports_sharing": true,
+                "updated_at": "2020-01-01T00:00:00Z",
+            },
+            "region": {
+                "country": "Ireland",
+                "created_at": "2020-01-01T00:00:00Z",
+                "description": "Europe",
+                "id": "region-id",
+                "locale": "Dublin",
+                "name": region,
+                "private_capable": false,
+                "provider": { "name": "amazon-web-services", "region": "eu-west-1" },
+                "updated_at": "2020-01-01T00:00:00Z",
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_unprovisionable_addons() {
+        let addons = vec![addon("pg", "heroku-postgresql"), addon("mail", "mailer")];
+        let capabilities = vec![
+            capability("heroku-postgresql", "eu", true),
+            capability("mailer", "us", false),
+        ];
+
+        let skipped = unprovisionable_addons(&addons, &capabilities, "eu", false);
+        assert_eq!(
+            skipped,
+            vec![UnprovisionableAddon {
+                addon: "mail".to_string(),
+                plan: "mailer:basic".to_string(),
+                reason: "mailer is not available in eu".to_string(),
+            }]
+        );
+
+        let skipped = unprovisionable_addons(&addons, &capabilities, "us", true);
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(
+            skipped[1].reason,
+            "mailer can't be installed in a private space"
+        );
+    }
+
+    #[test]
+    fn test_cloned_config_vars_skip_only_addon_vars() {
+        let config: HashMap<String, Option<String>> = [
+            ("DATABASE_URL", Some("postgres://localhost/db")),
+            ("DATABASE_POOL_SIZE", Some("10")),
+            ("SECRET_KEY", Some("secret")),
+            ("UNSET", None),
+        ]
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.map(String::from)))
+        .collect();
+
+        let cloned = cloned_config_vars(config, &["DATABASE_URL".to_string()], &["SECRET_KEY"]);
+        assert_eq!(cloned.len(), 1);
+        assert_eq!(cloned["DATABASE_POOL_SIZE"], "10");
+    }
+
+    #[test]
+    fn test_space_without_team_is_rejected() {
+        let api_client = crate::framework::HttpApiClient::create("API_KEY").unwrap();
+        let mut options = CloneOptions::new("my-app", "my-copy");
+        options.space = Some("my-space");
+
+        let error = clone_app(&api_client, &api_client, &options).unwrap_err();
+        assert!(matches!(error, CloneError::SpaceWithoutTeam));
+    }
+
+    #[test]
+    fn test_options_exclude_include() {
+        let mut options = CloneOptions::new("my-app", "my-copy");
+        options.exclude(Resource::Slug).exclude(Resource::Formation);
+        assert!(!options.includes(Resource::Slug));
+        assert!(options.includes(Resource::Addons));
+
+        options.include(Resource::Slug);
+        assert!(options.includes(Resource::Slug));
+        assert_eq!(options.resources.len(), 4);
+    }
+}
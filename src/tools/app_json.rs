@@ -0,0 +1,578 @@
+//! app.json manifest
+//!
+//! Typed model of the [app.json][appjson] manifest file used by app setups, review apps and Heroku CI.
+//!
+//! An [`AppManifest`][manifest] can be validated locally and turned into [`AppSetupCreateParams`][params],
+//! reporting any required config vars the user did not provide before the request is made.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::app_json::AppManifest;
+//! use std::collections::HashMap;
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let manifest = AppManifest::from_file("app.json")?;
+//!
+//! let mut inputs = HashMap::new();
+//! inputs.insert(String::from("STRIPE_KEY"), String::from("sk_test_123"));
+//!
+//! let params = manifest.setup_params("https://example.com/source.tgz", &inputs)?;
+//! let response = api_client.request(&AppSetupCreate { params });
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [appjson]: https://devcenter.heroku.com/articles/app-json-schema
+//! [manifest]: struct.AppManifest.html
+//! [params]: ../../endpoints/apps/post/struct.AppSetupCreateParams.html
+
+use crate::endpoints::apps::post::{Overrides, SetupApp, SourceBlob};
+use crate::endpoints::apps::AppSetupCreateParams;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// ManifestError
+///
+/// Returned when a manifest can't be read, is invalid, or is missing required inputs.
+#[derive(Debug)]
+pub enum ManifestError {
+    /// The manifest file could not be read
+    Io(std::io::Error),
+    /// The manifest is not valid JSON or doesn't match the app.json schema
+    Parse(serde_json::Error),
+    /// The manifest parsed, but has invalid values
+    Invalid(Vec<String>),
+    /// Required config vars without a value, generator or user input
+    MissingEnv(Vec<String>),
+}
+
+impl Error for ManifestError {}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ManifestError::Io(err) => write!(f, "{}", err),
+            ManifestError::Parse(err) => write!(f, "invalid app.json: {}", err),
+            ManifestError::Invalid(problems) => {
+                write!(f, "invalid app.json: {}", problems.join(", "))
+            }
+            ManifestError::MissingEnv(keys) => {
+                write!(f, "missing required config vars: {}", keys.join(", "))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for ManifestError {
+    fn from(error: std::io::Error) -> Self {
+        ManifestError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for ManifestError {
+    fn from(error: serde_json::Error) -> Self {
+        ManifestError::Parse(error)
+    }
+}
+
+/// App Manifest
+///
+/// The content of an `app.json` file.
+///
+/// [See Heroku documentation for more information about this file](https://devcenter.heroku.com/articles/app-json-schema)
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct AppManifest {
+    /// a clean and simple name to identify the template
+    pub name: Option<String>,
+    /// a brief summary of the app
+    pub description: Option<String>,
+    /// an array of strings describing the app
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// the project's website
+    pub website: Option<String>,
+    /// the location of the application's source code
+    pub repository: Option<String>,
+    /// the URL of the application's logo image
+    pub logo: Option<String>,
+    /// a URL specifying where to redirect the user once their new app is deployed
+    pub success_url: Option<String>,
+    /// scripts to run at specific points in the app lifecycle, e.g. `postdeploy` and `pr-predestroy`
+    #[serde(default)]
+    pub scripts: BTreeMap<String, String>,
+    /// config vars the app needs
+    #[serde(default)]
+    pub env: BTreeMap<String, ManifestEnv>,
+    /// dyno process types, with their quantity and size
+    #[serde(default)]
+    pub formation: BTreeMap<String, ManifestFormation>,
+    /// add-ons to provision on the app
+    #[serde(default)]
+    pub addons: Vec<ManifestAddon>,
+    /// buildpacks to install on the app, in order
+    #[serde(default)]
+    pub buildpacks: Vec<ManifestBuildpack>,
+    /// overrides for specific environments, e.g. `test` for Heroku CI
+    pub environments: Option<ManifestEnvironments>,
+    /// the Heroku stack on which the app will run
+    pub stack: Option<String>,
+}
+
+/// A config var declared in the manifest.
+///
+/// The shorthand `"KEY": "value"` form deserializes to a config var with that value.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "ManifestEnvRepr")]
+pub struct ManifestEnv {
+    /// a human-friendly description of the config var
+    pub description: Option<String>,
+    /// default value of the config var
+    pub value: Option<String>,
+    /// whether a value is required, defaults to true
+    pub required: bool,
+    /// generator for the value, only `secret` is supported by Heroku
+    pub generator: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestEnvRepr {
+    Value(String),
+    Detailed {
+        description: Option<String>,
+        value: Option<String>,
+        required: Option<bool>,
+        generator: Option<String>,
+    },
+}
+
+impl From<ManifestEnvRepr> for ManifestEnv {
+    fn from(repr: ManifestEnvRepr) -> Self {
+        match repr {
+            ManifestEnvRepr::Value(value) => ManifestEnv {
+                description: None,
+                value: Some(value),
+                required: true,
+                generator: None,
+            },
+            ManifestEnvRepr::Detailed {
+                description,
+                value,
+                required,
+                generator,
+            } => ManifestEnv {
+                description,
+                value,
+                required: required.unwrap_or(true),
+                generator,
+            },
+        }
+    }
+}
+
+/// A process type declared in the manifest formation.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ManifestFormation {
+    /// number of dynos to run
+    pub quantity: Option<i32>,
+    /// dyno size, e.g. `standard-1x`
+    pub size: Option<String>,
+}
+
+/// An add-on declared in the manifest.
+///
+/// The shorthand `"heroku-postgresql:mini"` form deserializes to an add-on with that plan.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(from = "ManifestAddonRepr")]
+pub struct ManifestAddon {
+    /// add-on service and optional plan, e.g. `heroku-redis:mini`
+    pub plan: String,
+    /// name of the attachment
+    #[serde(rename = "as")]
+    pub attachment_name: Option<String>,
+    /// add-on specific provisioning options
+    pub options: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestAddonRepr {
+    Plan(String),
+    Detailed {
+        plan: String,
+        #[serde(rename = "as")]
+        attachment_name: Option<String>,
+        options: Option<serde_json::Value>,
+    },
+}
+
+impl From<ManifestAddonRepr> for ManifestAddon {
+    fn from(repr: ManifestAddonRepr) -> Self {
+        match repr {
+            ManifestAddonRepr::Plan(plan) => ManifestAddon {
+                plan,
+                attachment_name: None,
+                options: None,
+            },
+            ManifestAddonRepr::Detailed {
+                plan,
+                attachment_name,
+                options,
+            } => ManifestAddon {
+                plan,
+                attachment_name,
+                options,
+            },
+        }
+    }
+}
+
+impl ManifestAddon {
+    /// the add-on service, e.g. `heroku-redis` for `heroku-redis:mini`
+    pub fn service(&self) -> &str {
+        self.plan.split(':').next().unwrap_or(&self.plan)
+    }
+}
+
+/// A buildpack declared in the manifest.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ManifestBuildpack {
+    /// URL or registry name of the buildpack
+    pub url: String,
+}
+
+/// Environment specific overrides of the manifest.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ManifestEnvironments {
+    /// used by Heroku CI test runs
+    pub test: Option<ManifestEnvironment>,
+    /// used by review apps
+    pub review: Option<ManifestEnvironment>,
+}
+
+/// The settings of a single environment, these replace the top level ones when set.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ManifestEnvironment {
+    /// scripts for this environment, e.g. `test-setup` and `test`
+    #[serde(default)]
+    pub scripts: BTreeMap<String, String>,
+    /// config vars for this environment
+    pub env: Option<BTreeMap<String, ManifestEnv>>,
+    /// formation for this environment
+    pub formation: Option<BTreeMap<String, ManifestFormation>>,
+    /// add-ons for this environment
+    pub addons: Option<Vec<ManifestAddon>>,
+    /// buildpacks for this environment
+    pub buildpacks: Option<Vec<ManifestBuildpack>>,
+}
+
+impl AppManifest {
+    /// Parse a manifest from a JSON string.
+    pub fn parse(json: &str) -> Result<AppManifest, ManifestError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Read and parse a manifest from disk.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<AppManifest, ManifestError> {
+        let content = fs::read_to_string(path)?;
+        AppManifest::parse(&content)
+    }
+
+    /// Validate the manifest values that Heroku would otherwise reject when the setup runs.
+    pub fn validate(&self) -> Result<(), ManifestError> {
+        let mut problems = Vec::new();
+
+        validate_env(&self.env, "env", &mut problems);
+        validate_formation(&self.formation, "formation", &mut problems);
+        validate_addons(&self.addons, "addons", &mut problems);
+        validate_buildpacks(&self.buildpacks, "buildpacks", &mut problems);
+
+        if let Some(stack) = &self.stack {
+            if stack.trim().is_empty() {
+                problems.push(String::from("stack can't be empty"));
+            }
+        }
+
+        if let Some(environments) = &self.environments {
+            let named = [
+                ("test", &environments.test),
+                ("review", &environments.review),
+            ];
+            for (name, environment) in named.iter() {
+                let environment = match environment {
+                    Some(environment) => environment,
+                    None => continue,
+                };
+                if let Some(env) = &environment.env {
+                    validate_env(env, &format!("environments.{}.env", name), &mut problems);
+                }
+                if let Some(formation) = &environment.formation {
+                    let context = format!("environments.{}.formation", name);
+                    validate_formation(formation, &context, &mut problems);
+                }
+                if let Some(addons) = &environment.addons {
+                    let context = format!("environments.{}.addons", name);
+                    validate_addons(addons, &context, &mut problems);
+                }
+                if let Some(buildpacks) = &environment.buildpacks {
+                    let context = format!("environments.{}.buildpacks", name);
+                    validate_buildpacks(buildpacks, &context, &mut problems);
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ManifestError::Invalid(problems))
+        }
+    }
+
+    /// Required config vars that have no default value, no generator and are missing from `inputs`, sorted by name.
+    pub fn missing_env(&self, inputs: &HashMap<String, String>) -> Vec<String> {
+        self.env
+            .iter()
+            .filter(|(key, env)| {
+                env.required
+                    && env.value.is_none()
+                    && env.generator.is_none()
+                    && !inputs.contains_key(key.as_str())
+            })
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Build the parameters of an `AppSetupCreate` from the manifest and the user `inputs`.
+    ///
+    /// The manifest is validated first, and every required config var must be resolvable, otherwise nothing is built.
+    /// The inputs are sent as env overrides and the manifest stack, if any, is used as the app stack.
+    pub fn setup_params<'a>(
+        &'a self,
+        source_url: &'a str,
+        inputs: &'a HashMap<String, String>,
+    ) -> Result<AppSetupCreateParams<'a>, ManifestError> {
+        self.validate()?;
+
+        let missing = self.missing_env(inputs);
+        if !missing.is_empty() {
+            return Err(ManifestError::MissingEnv(missing));
+        }
+
+        let env: HashMap<Cow<'a, str>, Cow<'a, str>> = inputs
+            .iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+
+        Ok(AppSetupCreateParams {
+            app: SetupApp {
+                locked: None,
+                name: None,
+                organization: None,
+                personal: None,
+                region: None,
+                space: None,
+                stack: self.stack.as_deref().map(Cow::from),
+            },
+            source_blob: SourceBlob {
+                checksum: None,
+                url: source_url.into(),
+                version: None,
+            },
+            overrides: Overrides {
+                buildpacks: None,
+                env: if env.is_empty() { None } else { Some(env) },
+            },
+        })
+    }
+}
+
+fn validate_env(env: &BTreeMap<String, ManifestEnv>, context: &str, problems: &mut Vec<String>) {
+    for (key, var) in env {
+        let valid_name = key
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            problems.push(format!(
+                "{}.{} is not a valid config var name",
+                context, key
+            ));
+        }
+        if let Some(generator) = &var.generator {
+            if generator != "secret" {
+                problems.push(format!(
+                    "{}.{} has unknown generator `{}`, expected `secret`",
+                    context, key, generator
+                ));
+            }
+            if var.value.is_some() {
+                problems.push(format!(
+                    "{}.{} can't have both a value and a generator",
+                    context, key
+                ));
+            }
+        }
+    }
+}
+
+fn validate_formation(
+    formation: &BTreeMap<String, ManifestFormation>,
+    context: &str,
+    problems: &mut Vec<String>,
+) {
+    for (process, settings) in formation {
+        if process.is_empty()
+            || !process
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            problems.push(format!(
+                "{}.{} is not a valid process type",
+                context, process
+            ));
+        }
+        if let Some(quantity) = settings.quantity {
+            if quantity < 0 {
+                problems.push(format!(
+                    "{}.{}.quantity can't be negative",
+                    context, process
+                ));
+            }
+        }
+        if let Some(size) = &settings.size {
+            if size.trim().is_empty() {
+                problems.push(format!("{}.{}.size can't be empty", context, process));
+            }
+        }
+    }
+}
+
+fn validate_addons(addons: &[ManifestAddon], context: &str, problems: &mut Vec<String>) {
+    for (index, addon) in addons.iter().enumerate() {
+        let mut parts = addon.plan.split(':');
+        let service = parts.next().unwrap_or("");
+        let plan = parts.next();
+        if service.is_empty() || plan == Some("") || parts.next().is_some() {
+            problems.push(format!(
+                "{}[{}] `{}` is not a valid add-on plan, expected `service` or `service:plan`",
+                context, index, addon.plan
+            ));
+        }
+        if let Some(name) = &addon.attachment_name {
+            let valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid {
+                problems.push(format!(
+                    "{}[{}] `{}` is not a valid attachment name",
+                    context, index, name
+                ));
+            }
+        }
+    }
+}
+
+fn validate_buildpacks(
+    buildpacks: &[ManifestBuildpack],
+    context: &str,
+    problems: &mut Vec<String>,
+) {
+    for (index, buildpack) in buildpacks.iter().enumerate() {
+        if buildpack.url.trim().is_empty() {
+            problems.push(format!("{}[{}].url can't be empty", context, index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"{
+        "name": "Small Sharp Tool",
+        "scripts": { "postdeploy": "bundle exec rake bootstrap" },
+        "env": {
+            "SECRET_TOKEN": { "description": "A secret key", "generator": "secret" },
+            "WEB_CONCURRENCY": { "value": "5", "required": false },
+            "STRIPE_KEY": { "description": "Stripe API key" },
+            "LANG": "en_US.UTF-8"
+        },
+        "formation": { "web": { "quantity": 1, "size": "standard-1x" } },
+        "addons": ["heroku-redis", { "plan": "heroku-postgresql:mini", "as": "DATABASE" }],
+        "buildpacks": [{ "url": "heroku/ruby" }],
+        "environments": {
+            "test": {
+                "scripts": { "test": "bundle exec rake test" },
+                "addons": ["heroku-postgresql:in-dyno"]
+            }
+        },
+        "stack": "heroku-22"
+    }"#;
+
+    #[test]
+    fn parses_shorthand_and_detailed_forms() {
+        let manifest = AppManifest::parse(MANIFEST).unwrap();
+        assert_eq!(manifest.env["LANG"].value.as_deref(), Some("en_US.UTF-8"));
+        assert!(manifest.env["STRIPE_KEY"].required);
+        assert!(!manifest.env["WEB_CONCURRENCY"].required);
+        assert_eq!(manifest.addons[0].plan, "heroku-redis");
+        assert_eq!(manifest.addons[1].service(), "heroku-postgresql");
+        assert_eq!(
+            manifest.addons[1].attachment_name.as_deref(),
+            Some("DATABASE")
+        );
+        assert_eq!(manifest.formation["web"].quantity, Some(1));
+        let test = manifest.environments.unwrap().test.unwrap();
+        assert_eq!(test.scripts["test"], "bundle exec rake test");
+        assert!(test.env.is_none());
+    }
+
+    #[test]
+    fn reports_missing_required_env() {
+        let manifest = AppManifest::parse(MANIFEST).unwrap();
+        assert_eq!(manifest.missing_env(&HashMap::new()), vec!["STRIPE_KEY"]);
+
+        match manifest.setup_params("https://example.com/source.tgz", &HashMap::new()) {
+            Err(ManifestError::MissingEnv(keys)) => assert_eq!(keys, vec!["STRIPE_KEY"]),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let mut inputs = HashMap::new();
+        inputs.insert(String::from("STRIPE_KEY"), String::from("sk_test"));
+        let params = manifest
+            .setup_params("https://example.com/source.tgz", &inputs)
+            .unwrap();
+        assert_eq!(params.app.stack, Some("heroku-22".into()));
+        assert_eq!(params.overrides.env.unwrap()["STRIPE_KEY"], "sk_test");
+    }
+
+    #[test]
+    fn validation_collects_problems() {
+        let manifest = AppManifest::parse(
+            r#"{
+                "env": { "BAD-NAME": "x", "TOKEN": { "generator": "uuid" } },
+                "formation": { "web": { "quantity": -1 } },
+                "addons": ["heroku-redis:", { "plan": "papertrail", "as": "1LOGS" }],
+                "environments": { "test": { "buildpacks": [{ "url": "" }] } }
+            }"#,
+        )
+        .unwrap();
+        match manifest.validate() {
+            Err(ManifestError::Invalid(problems)) => assert_eq!(problems.len(), 6),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
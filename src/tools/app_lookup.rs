@@ -0,0 +1,142 @@
+//! Bulk app lookups
+//!
+//! Fetching the details of many apps with [`AppDetails`][details] costs one request per app, which adds up quickly
+//! against the API rate limit when a dashboard or an audit script walks hundreds of apps. [`fetch_apps`][fetch_apps]
+//! gets all of them with a single [`AppFilter`][filter] request instead.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::app_lookup;
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let app_ids = vec!["01234567-89ab-cdef-0123-456789abcdef", "12345678-9abc-def0-1234-56789abcdef0"];
+//! let lookup = app_lookup::fetch_apps(&api_client, &app_ids)?;
+//!
+//! for app in &lookup.apps {
+//!     println!("{}: {}", app.name, app.web_url);
+//! }
+//! for app_id in &lookup.missing {
+//!     println!("{} not found", app_id);
+//! }
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [details]: ../../endpoints/apps/get/struct.AppDetails.html
+//! [filter]: ../../endpoints/teams/post/struct.AppFilter.html
+//! [fetch_apps]: fn.fetch_apps.html
+
+use crate::endpoints::teams::{AppFilter, AppFilterIn, AppFilterParams, TeamApp};
+use crate::framework::{apiclient::HerokuApiClient, response::ApiResponse};
+
+/// Apps fetched by id.
+#[derive(Debug, Clone, Default)]
+pub struct AppLookup {
+    /// the apps found, in the order their ids were requested
+    pub apps: Vec<TeamApp>,
+    /// the requested ids that don't exist or can't be accessed
+    pub missing: Vec<String>,
+}
+
+impl AppLookup {
+    /// Match the `apps` returned by the filter with the requested `app_ids`.
+    ///
+    /// Duplicate ids are only looked up once.
+    pub fn new<S: AsRef<str>>(app_ids: &[S], mut apps: Vec<TeamApp>) -> AppLookup {
+        let mut lookup = AppLookup::default();
+        for app_id in app_ids {
+            let app_id = app_id.as_ref();
+            if lookup.get(app_id).is_some() || lookup.missing.iter().any(|id| id == app_id) {
+                continue;
+            }
+            match apps.iter().position(|app| app.id == app_id) {
+                Some(index) => lookup.apps.push(apps.swap_remove(index)),
+                None => lookup.missing.push(app_id.to_string()),
+            }
+        }
+        lookup
+    }
+
+    /// The app with id `app_id`, if it was found.
+    pub fn get(&self, app_id: &str) -> Option<&TeamApp> {
+        self.apps.iter().find(|app| app.id == app_id)
+    }
+}
+
+/// Fetch the apps with the given ids in a single request.
+///
+/// No request is made without ids.
+pub fn fetch_apps<C: HerokuApiClient, S: AsRef<str>>(
+    api_client: &C,
+    app_ids: &[S],
+) -> ApiResponse<AppLookup> {
+    if app_ids.is_empty() {
+        return Ok(AppLookup::default());
+    }
+
+    let filter = AppFilter {
+        params: AppFilterParams {
+            in_filter: AppFilterIn {
+                id: app_ids.iter().map(|id| id.as_ref().into()).collect(),
+            },
+        },
+    };
+    let apps = api_client.request(&filter)?;
+    Ok(AppLookup::new(app_ids, apps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn app(id: &str) -> TeamApp {
+        serde_json::from_value(json!({
+            "archived_at": null,
+            "buildpack_provided_description": null,
+            "build_stack": {"id": "stack-id", "name": "heroku-20"},
+            "created_at": "2012-01-01T12:00:00Z",
+            "git_url": format!("https://git.heroku.com/{}.git", id),
+            "id": id,
+            "internal_routing": null,
+            "joined": false,
+            "locked": false,
+            "maintenance": false,
+            "name": format!("app-{}", id),
+            "team": null,
+            "owner": null,
+            "region": {"id": "region-id", "name": "us"},
+            "released_at": null,
+            "repo_size": null,
+            "slug_size": null,
+            "space": null,
+            "stack": {"id": "stack-id", "name": "heroku-20"},
+            "updated_at": "2012-01-01T12:00:00Z",
+            "web_url": format!("https://app-{}.herokuapp.com/", id)
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn apps_keep_the_requested_order() {
+        let lookup = AppLookup::new(
+            &["c", "a", "missing", "a", "b"],
+            vec![app("a"), app("b"), app("c")],
+        );
+
+        assert_eq!(
+            lookup
+                .apps
+                .iter()
+                .map(|app| app.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["c", "a", "b"]
+        );
+        assert_eq!(lookup.missing, vec!["missing"]);
+        assert_eq!(lookup.get("b").unwrap().name, "app-b");
+        assert!(lookup.get("missing").is_none());
+    }
+}
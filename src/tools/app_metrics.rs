@@ -0,0 +1,262 @@
+//! App metrics
+//!
+//! The metrics endpoints return one kind of series for one process type per request. [`fetch`][fetch] lists the
+//! formation of an app and requests the memory, load and error series of every process type, plus the router
+//! latency, throughput and error series for `web`, the only process type the router sends requests to.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::app_metrics;
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let end_time = chrono::Utc::now();
+//! let start_time = end_time - chrono::Duration::hours(24);
+//! let metrics = app_metrics::fetch(
+//!     &api_client,
+//!     "APP_NAME",
+//!     start_time,
+//!     end_time,
+//!     MetricsResolution::TenMinutes,
+//! )?;
+//!
+//! for process in &metrics.processes {
+//!     println!(
+//!         "{}: peak memory {:?} MB, peak load {:?}",
+//!         process.process_type,
+//!         process.peak_memory(),
+//!         process.peak_load()
+//!     );
+//! }
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [fetch]: fn.fetch.html
+
+use crate::endpoints::formations::{Formation, FormationList};
+use crate::endpoints::metrics::{
+    CountValues, DynoErrorMetrics, DynoLoadMetrics, DynoMemoryMetrics, LatencyValues, LoadValues,
+    MemoryValues, MetricSeries, MetricsParams, MetricsResolution, RouterErrorMetrics,
+    RouterLatencyMetrics, RouterThroughputMetrics,
+};
+use crate::framework::{apiclient::HerokuApiClient, response::ApiResponse};
+use chrono::offset::Utc;
+use chrono::DateTime;
+use std::collections::BTreeMap;
+
+/// The metrics of an app, by process type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppMetrics {
+    pub processes: Vec<ProcessMetrics>,
+}
+
+impl AppMetrics {
+    /// The metrics of `process_type`, e.g. `web`.
+    pub fn process(&self, process_type: &str) -> Option<&ProcessMetrics> {
+        self.processes
+            .iter()
+            .find(|process| process.process_type == process_type)
+    }
+}
+
+/// The metrics of a process type, and its current formation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessMetrics {
+    /// process type, e.g. `web`
+    pub process_type: String,
+    /// number of dynos currently running the process type
+    pub quantity: i32,
+    /// current dyno size, e.g. `standard-1X`
+    pub size: String,
+    pub memory: MetricSeries<MemoryValues>,
+    pub load: MetricSeries<LoadValues>,
+    /// dyno errors by code, e.g. `R14`
+    pub errors: MetricSeries<CountValues>,
+    /// router metrics, only for `web`
+    pub router: Option<RouterMetrics>,
+}
+
+/// The router metrics of the `web` process type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouterMetrics {
+    pub latency: MetricSeries<LatencyValues>,
+    /// requests by HTTP status
+    pub throughput: MetricSeries<CountValues>,
+    /// router errors by code, e.g. `H12`
+    pub errors: MetricSeries<CountValues>,
+}
+
+impl ProcessMetrics {
+    /// The highest memory usage of a dyno over the range, in megabytes.
+    pub fn peak_memory(&self) -> Option<f64> {
+        peak(&self.memory.data.total_max)
+    }
+
+    /// The highest load of a dyno over the range.
+    pub fn peak_load(&self) -> Option<f64> {
+        peak(&self.load.data.max)
+    }
+
+    /// The number of dyno and router errors over the range, by code.
+    pub fn error_counts(&self) -> BTreeMap<String, f64> {
+        let mut counts = BTreeMap::new();
+        let router_errors = self.router.iter().map(|router| &router.errors.data);
+        for errors in std::iter::once(&self.errors.data).chain(router_errors) {
+            for (code, values) in errors {
+                *counts.entry(code.clone()).or_insert(0.0) += values.iter().flatten().sum::<f64>();
+            }
+        }
+        counts
+    }
+}
+
+fn peak(values: &[Option<f64>]) -> Option<f64> {
+    values
+        .iter()
+        .flatten()
+        .copied()
+        .fold(None, |peak, value| match peak {
+            Some(peak) if peak >= value => Some(peak),
+            _ => Some(value),
+        })
+}
+
+/// The metrics of every process type of `app` from `start_time` to `end_time`, one value per `step`.
+///
+/// Requests the formation of the app, then three series per process type and three more for `web`.
+pub fn fetch<C: HerokuApiClient>(
+    api_client: &C,
+    app: &str,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    step: MetricsResolution,
+) -> ApiResponse<AppMetrics> {
+    let formations = api_client.request(&FormationList { app_id: app.into() })?;
+
+    let mut processes = Vec::new();
+    for formation in formations {
+        processes.push(fetch_process(
+            api_client, app, formation, start_time, end_time, step,
+        )?);
+    }
+    Ok(AppMetrics { processes })
+}
+
+fn fetch_process<C: HerokuApiClient>(
+    api_client: &C,
+    app: &str,
+    formation: Formation,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    step: MetricsResolution,
+) -> ApiResponse<ProcessMetrics> {
+    let params = MetricsParams {
+        process_type: Some(formation.r#type.clone().into()),
+        start_time: Some(start_time),
+        end_time: Some(end_time),
+        step: Some(step),
+    };
+
+    let memory = api_client.request(&DynoMemoryMetrics {
+        app_id: app.into(),
+        params: params.clone(),
+    })?;
+    let load = api_client.request(&DynoLoadMetrics {
+        app_id: app.into(),
+        params: params.clone(),
+    })?;
+    let errors = api_client.request(&DynoErrorMetrics {
+        app_id: app.into(),
+        params: params.clone(),
+    })?;
+
+    let router = if formation.r#type == "web" {
+        Some(RouterMetrics {
+            latency: api_client.request(&RouterLatencyMetrics {
+                app_id: app.into(),
+                params: params.clone(),
+            })?,
+            throughput: api_client.request(&RouterThroughputMetrics {
+                app_id: app.into(),
+                params: params.clone(),
+            })?,
+            errors: api_client.request(&RouterErrorMetrics {
+                app_id: app.into(),
+                params,
+            })?,
+        })
+    } else {
+        None
+    };
+
+    Ok(ProcessMetrics {
+        process_type: formation.r#type,
+        quantity: formation.quantity,
+        size: formation.size,
+        memory,
+        load,
+        errors,
+        router,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn series<T: serde::de::DeserializeOwned>(data: serde_json::Value) -> MetricSeries<T> {
+        serde_json::from_value(json!({
+            "start_time": "2020-01-01T00:00:00Z",
+            "end_time": "2020-01-01T00:30:00Z",
+            "step": "10m",
+            "data": data
+        }))
+        .unwrap()
+    }
+
+    fn worker() -> ProcessMetrics {
+        ProcessMetrics {
+            process_type: String::from("worker"),
+            quantity: 2,
+            size: String::from("standard-1X"),
+            memory: series(json!({"memory.total.mb.max": [210.5, null, 480.25]})),
+            load: series(json!({"load.avg.1m.max": [0.5, 1.25, null]})),
+            errors: series(json!({"R14": [null, 2, 1]})),
+            router: None,
+        }
+    }
+
+    #[test]
+    fn peaks_skip_missing_values() {
+        let worker = worker();
+        assert_eq!(worker.peak_memory(), Some(480.25));
+        assert_eq!(worker.peak_load(), Some(1.25));
+        assert_eq!(peak(&[None, None]), None);
+    }
+
+    #[test]
+    fn error_counts_add_router_errors() {
+        let mut web = worker();
+        web.router = Some(RouterMetrics {
+            latency: series(json!({"latency.ms.p50": [12.0, 15.0, 11.0]})),
+            throughput: series(json!({"200": [100, 120, 90]})),
+            errors: series(json!({"H12": [1, null, 0], "R14": [1]})),
+        });
+
+        let counts = web.error_counts();
+        assert_eq!(counts["R14"], 4.0);
+        assert_eq!(counts["H12"], 1.0);
+    }
+
+    #[test]
+    fn points_are_spaced_by_the_step() {
+        let worker = worker();
+        let points = worker.memory.points(&worker.memory.data.total_max);
+        assert_eq!(points[2].0.to_rfc3339(), "2020-01-01T00:20:00+00:00");
+        assert_eq!(points[1].1, None);
+    }
+}
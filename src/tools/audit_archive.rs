@@ -0,0 +1,437 @@
+//! Enterprise audit trail archives
+//!
+//! Every month Heroku publishes the audit trail events of an enterprise account as a gzipped JSON
+//! [`AuditTrailArchive`][archive]. [`fetch`][fetch] downloads the archive of a month, verifies its checksum,
+//! decompresses it and parses the events into an [`AuditLog`][log], which can be queried by date range and actor to
+//! show who changed what.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::audit_archive;
+//! use chrono::{TimeZone, Utc};
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let log = audit_archive::fetch(&api_client, "ACCOUNT_ID", 2020, 3)?;
+//!
+//! let start = Utc.with_ymd_and_hms(2020, 3, 1, 0, 0, 0).unwrap();
+//! let end = Utc.with_ymd_and_hms(2020, 3, 8, 0, 0, 0).unwrap();
+//! for record in &log.between(start, end).records {
+//!     println!("{}", record);
+//! }
+//! print!("{}", log.by_actor("someone@example.com").to_csv());
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [archive]: ../../endpoints/enterprise/struct.AuditTrailArchive.html
+//! [fetch]: fn.fetch.html
+//! [log]: struct.AuditLog.html
+
+use crate::endpoints::enterprise::{AuditTrailArchive, AuditTrailArchiveDetails, AuditTrailEvent};
+use crate::framework::apiclient::HerokuApiClient;
+use crate::framework::response::HerokuApiFailure;
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::io::{self, Read};
+
+/// Errors that can happen while downloading or reading an audit trail archive.
+#[derive(Debug)]
+pub enum ArchiveError {
+    /// a request to Heroku failed
+    Api(HerokuApiFailure),
+    /// the archive could not be downloaded
+    Download(reqwest::Error),
+    /// the archive could not be decompressed
+    Io(io::Error),
+    /// the archive doesn't match the checksum published with it
+    Checksum {
+        /// checksum published with the archive
+        expected: String,
+        /// SHA-256 of the downloaded archive
+        actual: String,
+    },
+    /// the archive doesn't contain audit trail events
+    Json(serde_json::Error),
+}
+
+impl std::error::Error for ArchiveError {}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArchiveError::Api(e) => write!(f, "{}", e),
+            ArchiveError::Download(e) => write!(f, "archive download failed: {}", e),
+            ArchiveError::Io(e) => write!(f, "invalid archive: {}", e),
+            ArchiveError::Checksum { expected, actual } => write!(
+                f,
+                "archive checksum mismatch, expected {} but got {}",
+                expected, actual
+            ),
+            ArchiveError::Json(e) => write!(f, "invalid audit trail events: {}", e),
+        }
+    }
+}
+
+impl From<HerokuApiFailure> for ArchiveError {
+    fn from(error: HerokuApiFailure) -> Self {
+        ArchiveError::Api(error)
+    }
+}
+
+impl From<reqwest::Error> for ArchiveError {
+    fn from(error: reqwest::Error) -> Self {
+        ArchiveError::Download(error)
+    }
+}
+
+impl From<io::Error> for ArchiveError {
+    fn from(error: io::Error) -> Self {
+        ArchiveError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for ArchiveError {
+    fn from(error: serde_json::Error) -> Self {
+        ArchiveError::Json(error)
+    }
+}
+
+/// What an audit trail event acted on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resource {
+    /// type of the resource, e.g. `app`
+    pub type_field: String,
+    /// name of the app, team or enterprise account the event happened on
+    pub name: Option<String>,
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} {}", self.type_field, name),
+            None => write!(f, "{}", self.type_field),
+        }
+    }
+}
+
+/// An audit trail event: who did what, on which resource and when.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditRecord {
+    /// when the event happened
+    pub created_at: DateTime<Utc>,
+    /// email address of the user who caused the event
+    pub actor: String,
+    /// action of the event, e.g. `create`
+    pub action: String,
+    /// what the event acted on
+    pub resource: Resource,
+    /// the event as published in the archive
+    pub event: AuditTrailEvent,
+}
+
+impl From<AuditTrailEvent> for AuditRecord {
+    fn from(event: AuditTrailEvent) -> Self {
+        let name = event
+            .app
+            .as_ref()
+            .map(|app| app.name.clone())
+            .or_else(|| event.team.as_ref().map(|team| team.name.clone()))
+            .or_else(|| {
+                event
+                    .enterprise_account
+                    .as_ref()
+                    .map(|account| account.name.clone())
+            });
+        AuditRecord {
+            created_at: event.created_at,
+            actor: event.actor.email.clone(),
+            action: event.action.clone(),
+            resource: Resource {
+                type_field: event.type_field.clone(),
+                name,
+            },
+            event,
+        }
+    }
+}
+
+impl fmt::Display for AuditRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.created_at.to_rfc3339(),
+            self.actor,
+            self.action,
+            self.resource
+        )
+    }
+}
+
+/// Audit trail events, oldest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditLog {
+    /// the events of the log
+    pub records: Vec<AuditRecord>,
+}
+
+impl AuditLog {
+    /// A log out of `events`, sorted by date.
+    pub fn new(events: Vec<AuditTrailEvent>) -> AuditLog {
+        let mut records: Vec<AuditRecord> = events.into_iter().map(AuditRecord::from).collect();
+        records.sort_by_key(|record| record.created_at);
+        AuditLog { records }
+    }
+
+    /// Parse the decompressed content of an archive.
+    ///
+    /// Events can be either a JSON array or one JSON object per line.
+    pub fn parse(json: &[u8]) -> Result<AuditLog, ArchiveError> {
+        let is_array = json
+            .iter()
+            .find(|byte| !byte.is_ascii_whitespace())
+            .is_some_and(|byte| *byte == b'[');
+        let events = if is_array {
+            serde_json::from_slice(json)?
+        } else {
+            serde_json::Deserializer::from_slice(json)
+                .into_iter::<AuditTrailEvent>()
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        Ok(AuditLog::new(events))
+    }
+
+    /// Verify, decompress and parse the downloaded content of `archive`.
+    pub fn from_archive(
+        archive: &AuditTrailArchive,
+        bytes: &[u8],
+    ) -> Result<AuditLog, ArchiveError> {
+        verify_checksum(bytes, &archive.checksum)?;
+        AuditLog::parse(&decompress(bytes)?)
+    }
+
+    /// The events that happened from `start` included to `end` excluded.
+    pub fn between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> AuditLog {
+        self.filter(|record| record.created_at >= start && record.created_at < end)
+    }
+
+    /// The events caused by the user with email address `actor`.
+    pub fn by_actor(&self, actor: &str) -> AuditLog {
+        self.filter(|record| record.actor.eq_ignore_ascii_case(actor))
+    }
+
+    fn filter<P: Fn(&AuditRecord) -> bool>(&self, predicate: P) -> AuditLog {
+        AuditLog {
+            records: self
+                .records
+                .iter()
+                .filter(|record| predicate(record))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// The log as CSV, with one `created_at,actor,action,type,resource` line per event.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("created_at,actor,action,type,resource\n");
+        for record in &self.records {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                record.created_at.to_rfc3339(),
+                record.actor,
+                record.action,
+                record.resource.type_field,
+                record.resource.name.as_deref().unwrap_or("")
+            ));
+        }
+        csv
+    }
+}
+
+/// Check `bytes` against the hex encoded SHA-256 `checksum` published with an archive.
+///
+/// The checksum may be prefixed with `sha256:`.
+pub fn verify_checksum(bytes: &[u8], checksum: &str) -> Result<(), ArchiveError> {
+    let expected = checksum.trim();
+    let expected = expected.strip_prefix("sha256:").unwrap_or(expected);
+    let actual: String = Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    if actual.eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        Err(ArchiveError::Checksum {
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+}
+
+/// Decompress a gzipped archive.
+pub fn decompress(bytes: &[u8]) -> io::Result<Vec<u8>> {
+    let mut json = Vec::new();
+    GzDecoder::new(bytes).read_to_end(&mut json)?;
+    Ok(json)
+}
+
+/// Download `archive` and read its events.
+///
+/// The archive url is signed and doesn't need the API key.
+pub fn download(archive: &AuditTrailArchive) -> Result<AuditLog, ArchiveError> {
+    let bytes = reqwest::blocking::get(&archive.url)?
+        .error_for_status()?
+        .bytes()?;
+    AuditLog::from_archive(archive, &bytes)
+}
+
+/// Download the archive of the enterprise account `account_id` for `month` of `year` and read its events.
+pub fn fetch<C: HerokuApiClient>(
+    api_client: &C,
+    account_id: &str,
+    year: i64,
+    month: u32,
+) -> Result<AuditLog, ArchiveError> {
+    let archive = api_client.request(&AuditTrailArchiveDetails {
+        account_id: account_id.into(),
+        year,
+        month,
+    })?;
+    download(&archive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use serde_json::{json, Value};
+    use std::io::Write;
+
+    fn event(id: &str, created_at: &str, actor: &str, action: &str, app: Option<&str>) -> Value {
+        json!({
+            "id": id,
+            "created_at": created_at,
+            "type": if app.is_some() { "app" } else { "team_member" },
+            "action": action,
+            "actor": {"id": format!("{}-id", actor), "email": actor},
+            "app": app.map(|name| json!({"id": format!("{}-id", name), "name": name})),
+            "owner": null,
+            "enterprise_account": {"id": "ea-id", "name": "acme"},
+            "team": {"id": "team-id", "name": "acme-web"},
+            "request": {"ip_address": "192.0.2.1"},
+            "data": {}
+        })
+    }
+
+    fn events() -> Vec<Value> {
+        vec![
+            event(
+                "2",
+                "2020-03-05T10:00:00Z",
+                "bob@example.com",
+                "update",
+                Some("web"),
+            ),
+            event(
+                "1",
+                "2020-03-01T09:00:00Z",
+                "alice@example.com",
+                "create",
+                Some("web"),
+            ),
+            event(
+                "3",
+                "2020-03-20T12:00:00Z",
+                "alice@example.com",
+                "add",
+                None,
+            ),
+        ]
+    }
+
+    fn archive(bytes: &[u8]) -> AuditTrailArchive {
+        let checksum: String = Sha256::digest(bytes)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        serde_json::from_value(json!({
+            "created_at": "2020-04-01T00:00:00Z",
+            "month": "03",
+            "year": 2020,
+            "url": "https://example.com/archive.json.gz",
+            "checksum": checksum,
+            "size": bytes.len()
+        }))
+        .unwrap()
+    }
+
+    fn gzip(json: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(json).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn reads_a_verified_archive() {
+        let bytes = gzip(&serde_json::to_vec(&events()).unwrap());
+        let log = AuditLog::from_archive(&archive(&bytes), &bytes).unwrap();
+
+        assert_eq!(
+            log.records
+                .iter()
+                .map(|record| record.event.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["1", "2", "3"]
+        );
+        assert_eq!(
+            log.records[0].to_string(),
+            "2020-03-01T09:00:00+00:00 alice@example.com create app web"
+        );
+        assert_eq!(log.records[2].resource.to_string(), "team_member acme-web");
+    }
+
+    #[test]
+    fn rejects_a_tampered_archive() {
+        let bytes = gzip(&serde_json::to_vec(&events()).unwrap());
+        let mut tampered = bytes.clone();
+        tampered.push(0);
+
+        match AuditLog::from_archive(&archive(&bytes), &tampered) {
+            Err(ArchiveError::Checksum { .. }) => (),
+            other => panic!("expected a checksum mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_one_event_per_line() {
+        let lines: Vec<String> = events().iter().map(|event| event.to_string()).collect();
+        let log = AuditLog::parse(lines.join("\n").as_bytes()).unwrap();
+        assert_eq!(log.records.len(), 3);
+
+        assert!(AuditLog::parse(b"[{\"id\": \"1\"}]").is_err());
+    }
+
+    #[test]
+    fn queries_by_date_range_and_actor() {
+        let log = AuditLog::parse(&serde_json::to_vec(&events()).unwrap()).unwrap();
+        let start = Utc.with_ymd_and_hms(2020, 3, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 3, 8, 0, 0, 0).unwrap();
+
+        assert_eq!(log.between(start, end).records.len(), 2);
+        assert_eq!(
+            log.between(start, end)
+                .by_actor("Alice@example.com")
+                .to_csv(),
+            "created_at,actor,action,type,resource\n\
+             2020-03-01T09:00:00+00:00,alice@example.com,create,app,web\n"
+        );
+        assert!(log.by_actor("carol@example.com").records.is_empty());
+    }
+}
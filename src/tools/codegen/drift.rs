@@ -0,0 +1,280 @@
+//! Drift between the schema, the hand-written endpoints and `docs/ENDPOINTS.md`
+//!
+//! Routes are compared by method and path, with every path parameter replaced by `{}`, so `apps/{}/builds` in an
+//! endpoint matches `/apps/{app_id_or_name}/builds` in the docs and the schema.
+
+use super::schema::{Property, PropertyType, Resource, Schema};
+use crate::framework::endpoint::Method;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A method and a normalized path.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Route {
+    /// HTTP method, in upper case
+    pub method: String,
+    /// path without the leading slash, with `{}` for every parameter
+    pub path: String,
+}
+
+impl Route {
+    /// A route, normalizing `path`.
+    pub fn new(method: Method, path: &str) -> Route {
+        Route {
+            method: method.to_string(),
+            path: normalize(path),
+        }
+    }
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} /{}", self.method, self.path)
+    }
+}
+
+/// A route listed in `docs/ENDPOINTS.md`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentedRoute {
+    /// the route
+    pub route: Route,
+    /// whether the route is ticked as implemented
+    pub implemented: bool,
+}
+
+/// How the schema, the endpoints and the docs disagree.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Drift {
+    /// routes of the schema without an endpoint
+    pub unimplemented: Vec<Route>,
+    /// routes of the schema missing from the docs
+    pub undocumented: Vec<Route>,
+    /// routes ticked in the docs without an endpoint
+    pub ticked_but_missing: Vec<Route>,
+    /// routes with an endpoint that are not ticked in the docs
+    pub implemented_but_unticked: Vec<Route>,
+}
+
+impl Drift {
+    /// Compare the routes of `schema` with the hand-written `endpoints` and the `docs`.
+    ///
+    /// Only routes of the schema are checked against the endpoints, but every documented route is checked against
+    /// the endpoints, so the docs stay correct for resources missing from a partial schema.
+    pub fn check(schema: &Schema, endpoints: &[Route], docs: &[DocumentedRoute]) -> Drift {
+        let mut drift = Drift::default();
+        for route in schema_routes(schema) {
+            if !endpoints.contains(&route) {
+                drift.unimplemented.push(route.clone());
+            }
+            if !docs.iter().any(|documented| documented.route == route) {
+                drift.undocumented.push(route);
+            }
+        }
+        for documented in docs {
+            let implemented = endpoints.contains(&documented.route);
+            if documented.implemented && !implemented {
+                drift.ticked_but_missing.push(documented.route.clone());
+            }
+            // the same route can be listed more than once, e.g. release create and rollback
+            let ticked = docs
+                .iter()
+                .any(|other| other.route == documented.route && other.implemented);
+            if implemented && !ticked {
+                drift
+                    .implemented_but_unticked
+                    .push(documented.route.clone());
+            }
+        }
+        for routes in [
+            &mut drift.unimplemented,
+            &mut drift.undocumented,
+            &mut drift.ticked_but_missing,
+            &mut drift.implemented_but_unticked,
+        ] {
+            routes.sort();
+            routes.dedup();
+        }
+        drift
+    }
+
+    /// Whether everything agrees.
+    pub fn is_empty(&self) -> bool {
+        *self == Drift::default()
+    }
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sections = [
+            ("not implemented", &self.unimplemented),
+            ("not in docs/ENDPOINTS.md", &self.undocumented),
+            (
+                "ticked in docs/ENDPOINTS.md but not implemented",
+                &self.ticked_but_missing,
+            ),
+            (
+                "implemented but not ticked in docs/ENDPOINTS.md",
+                &self.implemented_but_unticked,
+            ),
+        ];
+        for (title, routes) in sections.iter() {
+            if routes.is_empty() {
+                continue;
+            }
+            writeln!(f, "{}:", title)?;
+            for route in routes.iter() {
+                writeln!(f, "  {}", route)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every route of `schema`.
+pub fn schema_routes(schema: &Schema) -> Vec<Route> {
+    schema
+        .resources
+        .iter()
+        .flat_map(|resource| &resource.links)
+        .map(|link| Route::new(link.method, &link.path))
+        .collect()
+}
+
+/// The routes of the `HerokuEndpoint` impls in a source file.
+///
+/// Endpoints whose method or path is not a literal, like the custom endpoints, are skipped.
+pub fn scan_endpoints(source: &str) -> Vec<Route> {
+    let mut routes = Vec::new();
+    for block in source.split("fn method(&self) -> Method {").skip(1) {
+        let method_body = block.split('}').next().unwrap_or_default().trim();
+        let method = match method_body {
+            "Method::Get" => Method::Get,
+            "Method::Post" => Method::Post,
+            "Method::Put" => Method::Put,
+            "Method::Patch" => Method::Patch,
+            "Method::Delete" => Method::Delete,
+            _ => continue,
+        };
+        let path = block
+            .split("fn path(&self) -> String {")
+            .nth(1)
+            .and_then(|path_body| path_body.split('"').nth(1));
+        if let Some(path) = path {
+            routes.push(Route::new(method, path));
+        }
+    }
+    routes
+}
+
+/// The routes of every endpoint in the `.rs` files under `dir`, e.g. `src/endpoints`.
+pub fn scan_endpoint_dir<P: AsRef<Path>>(dir: P) -> io::Result<Vec<Route>> {
+    let mut routes = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            routes.extend(scan_endpoint_dir(&path)?);
+        } else if path.extension() == Some("rs".as_ref()) {
+            routes.extend(scan_endpoints(&fs::read_to_string(&path)?));
+        }
+    }
+    Ok(routes)
+}
+
+/// The routes listed in `docs/ENDPOINTS.md`, under their `## METHOD` headings.
+pub fn scan_docs(markdown: &str) -> Vec<DocumentedRoute> {
+    let mut method = None;
+    let mut routes = Vec::new();
+    for line in markdown.lines() {
+        let line = line.trim();
+        if let Some(heading) = line.strip_prefix("## ") {
+            method = match heading.trim() {
+                "GET" => Some(Method::Get),
+                "POST" => Some(Method::Post),
+                "PUT" => Some(Method::Put),
+                "PATCH" => Some(Method::Patch),
+                "DELETE" => Some(Method::Delete),
+                _ => None,
+            };
+            continue;
+        }
+        let (method, rest) = match (method, line.strip_prefix("- [")) {
+            (Some(method), Some(rest)) => (method, rest),
+            _ => continue,
+        };
+        let implemented = rest.starts_with('X') || rest.starts_with('x');
+        let path = rest
+            .split("] [")
+            .nth(1)
+            .and_then(|link| link.split(']').next());
+        if let Some(path) = path {
+            routes.push(DocumentedRoute {
+                route: Route::new(method, path),
+                implemented,
+            });
+        }
+    }
+    routes
+}
+
+/// A response of `resource` built from the schema examples, with every nullable property set to null.
+///
+/// Deserializing it into the hand-written model checks that the model accepts null wherever the schema allows it.
+pub fn null_example(resource: &Resource) -> Value {
+    object_example(&resource.properties)
+}
+
+fn object_example(properties: &[Property]) -> Value {
+    let mut object = Map::new();
+    for property in properties {
+        object.insert(property.name.clone(), property_example(property));
+    }
+    Value::Object(object)
+}
+
+fn property_example(property: &Property) -> Value {
+    if property.nullable {
+        return Value::Null;
+    }
+    match &property.kind {
+        PropertyType::Object(properties) => object_example(properties),
+        PropertyType::Array(items) => Value::Array(vec![property_example(items)]),
+        _ => match (&property.example, property.values.first()) {
+            (Some(example), _) if !example.is_null() => example.clone(),
+            (_, Some(value)) => Value::String(value.clone()),
+            _ => default_example(&property.kind),
+        },
+    }
+}
+
+fn default_example(kind: &PropertyType) -> Value {
+    match kind {
+        PropertyType::DateTime => Value::String(String::from("2012-01-01T12:00:00Z")),
+        PropertyType::Integer => Value::from(0),
+        PropertyType::Number => Value::from(0.0),
+        PropertyType::Boolean => Value::Bool(false),
+        PropertyType::Map => Value::Object(Map::new()),
+        _ => Value::String(String::from("example")),
+    }
+}
+
+/// Replace every path parameter by `{}`, including the `~` placeholder for the current user.
+fn normalize(path: &str) -> String {
+    path.trim()
+        .trim_start_matches('/')
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .map(|segment| {
+            if (segment.starts_with('{') && segment.ends_with('}')) || segment == "~" {
+                "{}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
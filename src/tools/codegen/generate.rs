@@ -0,0 +1,588 @@
+//! Emitting endpoint modules
+//!
+//! A resource becomes a module laid out like the hand-written ones in `src/endpoints`: the model and its nested
+//! structs in `mod.rs`, and the endpoints in one file per HTTP method.
+
+use super::schema::{Link, PathParam, Property, PropertyType, Resource};
+use super::CodegenError;
+use crate::framework::endpoint::Method;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while", "async",
+    "await", "dyn",
+];
+
+/// The files of a generated endpoint module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedModule {
+    /// name of the module, e.g. `account_feature`
+    pub name: String,
+    /// file name to source, e.g. `mod.rs`, `get.rs`
+    pub files: BTreeMap<String, String>,
+}
+
+impl GeneratedModule {
+    /// Write the files into `dir`/`name`, creating the directories if needed.
+    pub fn write_to<P: AsRef<Path>>(&self, dir: P) -> Result<(), CodegenError> {
+        let dir = dir.as_ref().join(&self.name);
+        fs::create_dir_all(&dir)?;
+        for (file, source) in &self.files {
+            fs::write(dir.join(file), source)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a struct is a response model or request parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flavor {
+    Model,
+    Params,
+}
+
+/// Generate the endpoint module of `resource`.
+pub fn generate(resource: &Resource) -> GeneratedModule {
+    let model = model_name(&resource.name);
+    let mut files = BTreeMap::new();
+
+    let mut by_method: BTreeMap<&str, Vec<&Link>> = BTreeMap::new();
+    for link in &resource.links {
+        by_method
+            .entry(method_module(link.method))
+            .or_default()
+            .push(link);
+    }
+
+    let mut exports = Vec::new();
+    for (module, links) in &by_method {
+        let mut body = String::new();
+        let mut names = Vec::new();
+        for link in links {
+            names.extend(emit_endpoint(resource, &model, link, &mut body));
+        }
+        let mut source = format!(
+            "//Anything related to {} requests for {} and it's properties goes here.\n",
+            module.to_ascii_uppercase(),
+            resource.title.to_ascii_lowercase()
+        );
+        source.push_str(&format!("use super::{};\n\n", model));
+        source.push_str(&imports(&body, true));
+        source.push('\n');
+        source.push_str(&body);
+        files.insert(
+            format!("{}.rs", module),
+            source.trim_end().to_string() + "\n",
+        );
+        exports.push((module.to_string(), names));
+    }
+
+    let mut structs = Vec::new();
+    emit_struct(
+        &model,
+        &resource.description,
+        &resource.properties,
+        Flavor::Model,
+        &mut structs,
+    );
+    let body: String = structs.iter().map(|(_, code)| code.as_str()).collect();
+
+    let mut source = imports(&body, false);
+    source.push_str("use crate::framework::response::ApiResult;\n\n");
+    for (module, _) in &exports {
+        writeln!(source, "pub mod {};", module).unwrap();
+    }
+    source.push('\n');
+    for (module, names) in &exports {
+        if let [name] = names.as_slice() {
+            writeln!(source, "pub use {}::{};", module, name).unwrap();
+        } else {
+            writeln!(source, "pub use {}::{{{}}};", module, names.join(", ")).unwrap();
+        }
+    }
+    write!(
+        source,
+        "\nimpl ApiResult for {model} {{}}\nimpl ApiResult for Vec<{model}> {{}}\n\n",
+        model = model
+    )
+    .unwrap();
+    source.push_str(&body);
+    files.insert(String::from("mod.rs"), source.trim_end().to_string() + "\n");
+
+    GeneratedModule {
+        name: resource.name.replace("add-on", "addon").replace('-', "_"),
+        files,
+    }
+}
+
+/// The `use` lines needed by `body`.
+fn imports(body: &str, endpoints: bool) -> String {
+    let mut imports = String::new();
+    let refs: Vec<&str> = ["AddonRef", "AppRef", "PipelineRef", "TeamRef"]
+        .iter()
+        .copied()
+        .filter(|name| body.contains(&format!("{}<", name)))
+        .collect();
+    if let [name] = refs.as_slice() {
+        writeln!(imports, "use crate::endpoints::ids::{};", name).unwrap();
+    } else if !refs.is_empty() {
+        writeln!(
+            imports,
+            "use crate::endpoints::ids::{{{}}};",
+            refs.join(", ")
+        )
+        .unwrap();
+    }
+    if endpoints {
+        imports.push_str("use crate::framework::endpoint::{HerokuEndpoint, Method};\n");
+    }
+    if body.contains("DateTime<Utc>") {
+        imports.push_str("use chrono::offset::Utc;\nuse chrono::DateTime;\n");
+    }
+    if body.contains(": Value,") || body.contains("<Value>") {
+        imports.push_str("use serde_json::Value;\n");
+    }
+    if body.contains("Cow<") {
+        imports.push_str("use std::borrow::Cow;\n");
+    }
+    imports
+}
+
+/// Emit the endpoint of `link` and its parameters into `code`, returning the names of the emitted structs.
+fn emit_endpoint(resource: &Resource, model: &str, link: &Link, code: &mut String) -> Vec<String> {
+    let name = endpoint_name(model, &link.title);
+    let params_name = format!("{}Params", name);
+    let has_params = !link.params.is_empty();
+    let has_lifetime = !link.path_params.is_empty() || (has_params && needs_lifetime(&link.params));
+    let lifetime = if has_lifetime { "<'a>" } else { "" };
+    let params_type = if has_params && needs_lifetime(&link.params) {
+        format!("{}<'a>", params_name)
+    } else {
+        params_name.clone()
+    };
+    let result = if link.rel.as_deref() == Some("instances") {
+        format!("Vec<{}>", model)
+    } else {
+        model.to_string()
+    };
+
+    let mut params_structs = Vec::new();
+    if has_params {
+        emit_struct(
+            &params_name,
+            &format!("{} {} parameters.", resource.title, link.title),
+            &link.params,
+            Flavor::Params,
+            &mut params_structs,
+        );
+    }
+
+    writeln!(code, "/// {} {}", resource.title, link.title).unwrap();
+    writeln!(code, "///").unwrap();
+    write_doc(code, "", &link.description);
+    writeln!(code, "///").unwrap();
+    writeln!(
+        code,
+        "/// [See Heroku documentation for more information about this endpoint](https://devcenter.heroku.com/articles/platform-api-reference#{}-{})",
+        resource.name,
+        link.title.to_ascii_lowercase().replace(' ', "-")
+    )
+    .unwrap();
+
+    // the endpoint struct
+    if link.path_params.is_empty() && !has_params {
+        writeln!(code, "pub struct {} {{}}\n", name).unwrap();
+    } else {
+        writeln!(code, "pub struct {}{} {{", name, lifetime).unwrap();
+        for param in &link.path_params {
+            writeln!(
+                code,
+                "    /// {} can be the {} id or name\n    pub {}: {},",
+                param.name,
+                param.resource,
+                param.name,
+                path_param_type(param)
+            )
+            .unwrap();
+        }
+        if has_params {
+            writeln!(
+                code,
+                "    /// The parameters to pass to the Heroku API\n    pub params: {},",
+                params_type
+            )
+            .unwrap();
+        }
+        code.push_str("}\n\n");
+    }
+
+    // the builder
+    writeln!(code, "#[cfg(feature = \"builder\")]").unwrap();
+    writeln!(code, "impl{} {}{} {{", lifetime, name, lifetime).unwrap();
+    let required: Vec<&Property> = link.params.iter().filter(|p| p.required).collect();
+    let optional: Vec<&Property> = link.params.iter().filter(|p| !p.required).collect();
+    let mut args = Vec::new();
+    for param in &link.path_params {
+        args.push(format!(
+            "{}: impl Into<{}>",
+            param.name,
+            path_param_type(param)
+        ));
+    }
+    for param in &required {
+        args.push(format!(
+            "{}: {}",
+            field_name(&param.name),
+            argument_type(param, &params_name)
+        ));
+    }
+    if !optional.is_empty() {
+        writeln!(
+            code,
+            "    /// Create a new {} without the optional parameters",
+            name
+        )
+        .unwrap();
+    }
+    writeln!(
+        code,
+        "    pub fn new({}) -> {}{} {{",
+        args.join(", "),
+        name,
+        lifetime
+    )
+    .unwrap();
+    write!(code, "        {} {{", name).unwrap();
+    let mut fields = Vec::new();
+    for param in &link.path_params {
+        fields.push(format!("{}: {}.into()", param.name, param.name));
+    }
+    if has_params {
+        let mut params = Vec::new();
+        for param in &link.params {
+            let field = field_name(&param.name);
+            params.push(if !param.required {
+                format!("{}: None", field)
+            } else if is_cow(param) {
+                format!("{}: {}.into()", field, field)
+            } else {
+                field
+            });
+        }
+        fields.push(format!(
+            "params: {} {{ {} }}",
+            params_name,
+            params.join(", ")
+        ));
+    }
+    if fields.is_empty() {
+        code.push_str("}\n");
+    } else {
+        writeln!(code, " {} }}", fields.join(", ")).unwrap();
+    }
+    code.push_str("    }\n");
+
+    for param in &optional {
+        let field = field_name(&param.name);
+        code.push('\n');
+        write_doc(
+            code,
+            "    ",
+            &format!("# {}: {}", param.name, param.description),
+        );
+        writeln!(
+            code,
+            "    pub fn {}(&mut self, {}: {}) -> &mut Self {{",
+            field,
+            field,
+            argument_type(param, &params_name)
+        )
+        .unwrap();
+        let value = if is_cow(param) {
+            format!("{}.into()", field)
+        } else {
+            field.clone()
+        };
+        writeln!(
+            code,
+            "        self.params.{} = Some({});\n        self\n    }}",
+            field, value
+        )
+        .unwrap();
+    }
+    if !optional.is_empty() {
+        let mut fields: Vec<String> = link
+            .path_params
+            .iter()
+            .map(|param| format!("{}: self.{}.clone()", param.name, param.name))
+            .collect();
+        fields.push(String::from("params: self.params.clone()"));
+        writeln!(
+            code,
+            "\n    pub fn build(&self) -> {}{} {{\n        {} {{ {} }}\n    }}",
+            name,
+            lifetime,
+            name,
+            fields.join(", ")
+        )
+        .unwrap();
+    }
+    code.push_str("}\n\n");
+
+    for (_, params) in &params_structs {
+        code.push_str(params);
+    }
+
+    // the endpoint
+    if has_params {
+        writeln!(
+            code,
+            "impl{} HerokuEndpoint<{}, (), {}> for {}{} {{",
+            lifetime, result, params_type, name, lifetime
+        )
+        .unwrap();
+    } else {
+        writeln!(
+            code,
+            "impl{} HerokuEndpoint<{}> for {}{} {{",
+            lifetime, result, name, lifetime
+        )
+        .unwrap();
+    }
+    writeln!(
+        code,
+        "    fn method(&self) -> Method {{\n        Method::{:?}\n    }}",
+        link.method
+    )
+    .unwrap();
+    let mut path = format!("\"{}\"", link.path);
+    for param in &link.path_params {
+        write!(path, ", self.{}", param.name).unwrap();
+    }
+    writeln!(
+        code,
+        "    fn path(&self) -> String {{\n        format!({})\n    }}",
+        path
+    )
+    .unwrap();
+    if has_params {
+        writeln!(
+            code,
+            "    fn body(&self) -> Option<{}> {{\n        Some(self.params.clone())\n    }}",
+            params_type
+        )
+        .unwrap();
+    }
+    code.push_str("}\n\n");
+
+    let mut names = vec![name];
+    names.extend(params_structs.into_iter().map(|(name, _)| name));
+    names
+}
+
+/// Emit the struct `name` and the structs of its nested objects, which are pushed first.
+fn emit_struct(
+    name: &str,
+    description: &str,
+    properties: &[Property],
+    flavor: Flavor,
+    out: &mut Vec<(String, String)>,
+) {
+    let mut fields = String::new();
+    for property in properties {
+        let kind = rust_type(property, name, flavor, out);
+        let optional = property.nullable || (flavor == Flavor::Params && !property.required);
+        write_doc(&mut fields, "    ", &property.description);
+        writeln!(
+            fields,
+            "    pub {}: {},",
+            field_name(&property.name),
+            if optional {
+                format!("Option<{}>", kind)
+            } else {
+                kind
+            }
+        )
+        .unwrap();
+    }
+
+    let mut code = String::new();
+    write_doc(&mut code, "", description);
+    match flavor {
+        Flavor::Model if has_float(properties) => {
+            code.push_str("#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]\n")
+        }
+        Flavor::Model => {
+            code.push_str("#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]\n")
+        }
+        Flavor::Params => {
+            if properties.iter().any(|p| !p.required || p.nullable) {
+                code.push_str("#[serde_with::skip_serializing_none]\n");
+            }
+            code.push_str("#[derive(Serialize, Clone, Debug)]\n");
+        }
+    }
+    let lifetime = if flavor == Flavor::Params && needs_lifetime(properties) {
+        "<'a>"
+    } else {
+        ""
+    };
+    writeln!(code, "pub struct {}{} {{\n{}}}\n", name, lifetime, fields).unwrap();
+    out.push((name.to_string(), code));
+}
+
+/// The type of a field, without the `Option` of nullable fields. Nested objects are emitted into `out`.
+fn rust_type(
+    property: &Property,
+    parent: &str,
+    flavor: Flavor,
+    out: &mut Vec<(String, String)>,
+) -> String {
+    match &property.kind {
+        PropertyType::String if flavor == Flavor::Params => String::from("Cow<'a, str>"),
+        PropertyType::String => String::from("String"),
+        PropertyType::DateTime => String::from("DateTime<Utc>"),
+        PropertyType::Integer => String::from("i64"),
+        PropertyType::Number => String::from("f64"),
+        PropertyType::Boolean => String::from("bool"),
+        PropertyType::Array(items) => {
+            let mut items = (**items).clone();
+            items.name = singular(&property.name);
+            if items.description.is_empty() {
+                items.description = property.description.clone();
+            }
+            format!("Vec<{}>", rust_type(&items, parent, flavor, out))
+        }
+        PropertyType::Object(properties) => {
+            let mut name = camel_case(&property.name);
+            if flavor == Flavor::Params || out.iter().any(|(emitted, _)| *emitted == name) {
+                name = format!("{}{}", parent.trim_end_matches("Params"), name);
+            }
+            if flavor == Flavor::Params {
+                name.push_str("Param");
+            }
+            emit_struct(&name, &property.description, properties, flavor, out);
+            if flavor == Flavor::Params && needs_lifetime(properties) {
+                format!("{}<'a>", name)
+            } else {
+                name
+            }
+        }
+        PropertyType::Map | PropertyType::Any => String::from("Value"),
+    }
+}
+
+/// The type a builder takes for a parameter.
+fn argument_type(property: &Property, params_name: &str) -> String {
+    if is_cow(property) {
+        return String::from("impl Into<Cow<'a, str>>");
+    }
+    let mut ignored = Vec::new();
+    rust_type(property, params_name, Flavor::Params, &mut ignored)
+}
+
+fn is_cow(property: &Property) -> bool {
+    property.kind == PropertyType::String
+}
+
+fn needs_lifetime(properties: &[Property]) -> bool {
+    properties
+        .iter()
+        .any(|property| kind_needs_lifetime(&property.kind))
+}
+
+fn kind_needs_lifetime(kind: &PropertyType) -> bool {
+    match kind {
+        PropertyType::String => true,
+        PropertyType::Array(items) => kind_needs_lifetime(&items.kind),
+        PropertyType::Object(properties) => needs_lifetime(properties),
+        _ => false,
+    }
+}
+
+fn has_float(properties: &[Property]) -> bool {
+    properties
+        .iter()
+        .any(|property| kind_has_float(&property.kind))
+}
+
+fn kind_has_float(kind: &PropertyType) -> bool {
+    match kind {
+        PropertyType::Number => true,
+        PropertyType::Array(items) => kind_has_float(&items.kind),
+        PropertyType::Object(properties) => has_float(properties),
+        _ => false,
+    }
+}
+
+fn path_param_type(param: &PathParam) -> &'static str {
+    match param.resource.as_str() {
+        "app" => "AppRef<'a>",
+        "add-on" => "AddonRef<'a>",
+        "pipeline" => "PipelineRef<'a>",
+        "team" => "TeamRef<'a>",
+        _ => "Cow<'a, str>",
+    }
+}
+
+fn method_module(method: Method) -> &'static str {
+    match method {
+        Method::Get => "get",
+        Method::Post => "post",
+        Method::Put => "put",
+        Method::Patch => "patch",
+        Method::Delete => "delete",
+    }
+}
+
+/// Name of the model of a resource, e.g. `account-feature` is `AccountFeature`.
+pub fn model_name(resource: &str) -> String {
+    camel_case(&resource.replace("add-on", "addon"))
+}
+
+/// Name of the endpoint of a link, e.g. `Info` of `build` is `BuildDetails`.
+pub fn endpoint_name(model: &str, title: &str) -> String {
+    let action: String = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .map(|word| if word == "Info" { "Details" } else { word })
+        .map(capitalize)
+        .collect();
+    format!("{}{}", model, action)
+}
+
+fn camel_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .map(capitalize)
+        .collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn singular(name: &str) -> String {
+    name.strip_suffix('s').unwrap_or(name).to_string()
+}
+
+fn field_name(name: &str) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.replace('-', "_")
+    }
+}
+
+fn write_doc(out: &mut String, indent: &str, text: &str) {
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        writeln!(out, "{}/// {}", indent, line.trim()).unwrap();
+    }
+}
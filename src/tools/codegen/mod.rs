@@ -0,0 +1,76 @@
+//! Code generation from Heroku's JSON hyper-schema
+//!
+//! Heroku publishes the platform API as a JSON hyper-schema at `https://api.heroku.com/schema`.
+//! This tool reads a local copy of it and emits endpoint modules laid out like the hand-written ones in
+//! `src/endpoints`: the model in `mod.rs`, the endpoints and their parameters in one file per HTTP method.
+//!
+//! The [`drift`][drift] module compares the schema with the hand-written endpoints and `docs/ENDPOINTS.md`,
+//! to find missing routes and models that don't accept null where the schema allows it.
+//!
+//! Generated code is a starting point, it is meant to be reviewed and documented before it is added to `src/endpoints`.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::tools::codegen::{self, Schema};
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // curl -o schema.json https://api.heroku.com/schema -H "Accept: application/vnd.heroku+json; version=3"
+//! let schema = Schema::from_file("schema.json")?;
+//! let resource = schema.resource("enterprise-account").unwrap();
+//!
+//! // writes generated/enterprise_account/{mod,get,patch}.rs
+//! codegen::generate(resource).write_to("generated")?;
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! The `heroku-rs-codegen` binary, built with the `codegen` feature, does the same from the command line.
+//!
+//! [drift]: drift/index.html
+
+pub mod drift;
+pub mod generate;
+pub mod schema;
+
+pub use drift::{Drift, Route};
+pub use generate::{generate, GeneratedModule};
+pub use schema::{Link, Property, PropertyType, Resource, Schema};
+
+use std::fmt;
+use std::io;
+
+/// Errors that can happen while reading a schema or writing the generated code.
+#[derive(Debug)]
+pub enum CodegenError {
+    /// the schema could not be read, or the generated code could not be written
+    Io(io::Error),
+    /// the schema is not valid JSON
+    Parse(serde_json::Error),
+    /// the schema is not a hyper-schema this tool understands
+    Schema(String),
+}
+
+impl std::error::Error for CodegenError {}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodegenError::Io(e) => write!(f, "{}", e),
+            CodegenError::Parse(e) => write!(f, "invalid schema: {}", e),
+            CodegenError::Schema(message) => write!(f, "unsupported schema: {}", message),
+        }
+    }
+}
+
+impl From<io::Error> for CodegenError {
+    fn from(error: io::Error) -> Self {
+        CodegenError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for CodegenError {
+    fn from(error: serde_json::Error) -> Self {
+        CodegenError::Parse(error)
+    }
+}
@@ -0,0 +1,360 @@
+//! Reading Heroku's JSON hyper-schema
+//!
+//! Only the parts needed to generate endpoints are read: the resources, their properties and their links.
+//! References are resolved while reading, so the types below don't point back into the schema.
+
+use super::CodegenError;
+use crate::framework::endpoint::Method;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// How deep `$ref`, nested objects and arrays are followed before the schema is considered invalid.
+const MAX_DEPTH: usize = 16;
+
+/// The resources of a platform API hyper-schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    /// every resource defined by the schema, sorted by name
+    pub resources: Vec<Resource>,
+}
+
+/// A resource, e.g. `build`, with its model and its links.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resource {
+    /// key of the resource in the schema definitions, e.g. `account-feature`
+    pub name: String,
+    /// title without the `Heroku Platform API - ` prefix, e.g. `Account Feature`
+    pub title: String,
+    /// description of the resource
+    pub description: String,
+    /// stability of the resource, e.g. `production`
+    pub stability: Option<String>,
+    /// properties of the resource model
+    pub properties: Vec<Property>,
+    /// the endpoints of the resource
+    pub links: Vec<Link>,
+}
+
+/// An endpoint of a resource.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Link {
+    /// title of the link, e.g. `Info` or `List By App`
+    pub title: String,
+    /// description of the link
+    pub description: String,
+    /// relation of the link, `instances` returns a list
+    pub rel: Option<String>,
+    /// HTTP method
+    pub method: Method,
+    /// path with `{}` for every parameter, without the leading slash, e.g. `apps/{}/builds`
+    pub path: String,
+    /// the path parameters, in order
+    pub path_params: Vec<PathParam>,
+    /// properties of the request body
+    pub params: Vec<Property>,
+}
+
+/// A parameter in the path of a link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathParam {
+    /// name of the parameter, e.g. `app_id`
+    pub name: String,
+    /// the resource the parameter identifies, e.g. `app`
+    pub resource: String,
+}
+
+/// A property of a model, or a parameter of a request body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    /// name of the property
+    pub name: String,
+    /// description of the property
+    pub description: String,
+    /// type of the property
+    pub kind: PropertyType,
+    /// whether the property can be null
+    pub nullable: bool,
+    /// whether the property must be sent, only meaningful for parameters
+    pub required: bool,
+    /// the allowed values, if the property is an enum
+    pub values: Vec<String>,
+    /// example value from the schema
+    pub example: Option<Value>,
+}
+
+/// Type of a property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyType {
+    String,
+    /// a string with the `date-time` format
+    DateTime,
+    Integer,
+    Number,
+    Boolean,
+    Array(Box<Property>),
+    /// an object with known properties
+    Object(Vec<Property>),
+    /// an object without known properties, e.g. config vars
+    Map,
+    /// no type in the schema
+    Any,
+}
+
+impl Schema {
+    /// Read a schema from a JSON file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Schema, CodegenError> {
+        Schema::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Read a schema from JSON.
+    pub fn from_json(json: &str) -> Result<Schema, CodegenError> {
+        let root: Value = serde_json::from_str(json)?;
+        let definitions = root
+            .get("definitions")
+            .and_then(Value::as_object)
+            .ok_or_else(|| CodegenError::Schema(String::from("no definitions")))?;
+
+        let mut resources = Vec::new();
+        for (name, definition) in definitions {
+            resources.push(read_resource(&root, name, definition)?);
+        }
+        Ok(Schema { resources })
+    }
+
+    /// The resource with the given definition key.
+    pub fn resource(&self, name: &str) -> Option<&Resource> {
+        self.resources.iter().find(|resource| resource.name == name)
+    }
+}
+
+fn read_resource(root: &Value, name: &str, definition: &Value) -> Result<Resource, CodegenError> {
+    let title = string(definition, "title");
+    let title = title
+        .strip_prefix("Heroku Platform API - ")
+        .unwrap_or(&title)
+        .to_string();
+
+    let properties = match definition.get("properties") {
+        Some(properties) => read_properties(root, properties, &[], 0)?,
+        None => Vec::new(),
+    };
+
+    let mut links = Vec::new();
+    for link in definition
+        .get("links")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        links.push(read_link(root, name, link)?);
+    }
+
+    Ok(Resource {
+        name: name.to_string(),
+        title,
+        description: string(definition, "description"),
+        stability: definition
+            .get("stability")
+            .and_then(Value::as_str)
+            .map(String::from),
+        properties,
+        links,
+    })
+}
+
+fn read_link(root: &Value, resource: &str, link: &Value) -> Result<Link, CodegenError> {
+    let title = string(link, "title");
+    let method = match string(link, "method").to_ascii_uppercase().as_str() {
+        "GET" => Method::Get,
+        "POST" => Method::Post,
+        "PUT" => Method::Put,
+        "PATCH" => Method::Patch,
+        "DELETE" => Method::Delete,
+        other => {
+            return Err(CodegenError::Schema(format!(
+                "{} {}: unsupported method `{}`",
+                resource, title, other
+            )))
+        }
+    };
+    let (path, path_params) = read_href(&string(link, "href"));
+
+    let params = match link.get("schema") {
+        Some(schema) => {
+            let schema = resolve(root, schema, 0)?;
+            let required: Vec<&str> = schema
+                .get("required")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect();
+            match schema.get("properties") {
+                Some(properties) => read_properties(root, properties, &required, 0)?,
+                None => Vec::new(),
+            }
+        }
+        None => Vec::new(),
+    };
+
+    Ok(Link {
+        title,
+        description: string(link, "description"),
+        rel: link.get("rel").and_then(Value::as_str).map(String::from),
+        method,
+        path,
+        path_params,
+        params,
+    })
+}
+
+/// Split an href like `/apps/{(%23%2Fdefinitions%2Fapp%2Fdefinitions%2Fidentity)}/builds` into a path with `{}`
+/// placeholders and the parameters they stand for.
+fn read_href(href: &str) -> (String, Vec<PathParam>) {
+    let mut params = Vec::new();
+    let segments: Vec<String> = href
+        .trim_start_matches('/')
+        .split('/')
+        .map(|segment| {
+            if segment.starts_with('{') && segment.ends_with('}') {
+                let pointer = percent_encoding::percent_decode_str(segment)
+                    .decode_utf8_lossy()
+                    .into_owned();
+                // #/definitions/<resource>/definitions/identity
+                let resource = pointer.split('/').nth(2).unwrap_or("resource").to_string();
+                params.push(PathParam {
+                    name: format!("{}_id", resource.replace('-', "_")),
+                    resource,
+                });
+                String::from("{}")
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect();
+    (segments.join("/"), params)
+}
+
+fn read_properties(
+    root: &Value,
+    properties: &Value,
+    required: &[&str],
+    depth: usize,
+) -> Result<Vec<Property>, CodegenError> {
+    let properties = match properties.as_object() {
+        Some(properties) => properties,
+        None => return Ok(Vec::new()),
+    };
+    let mut read = Vec::new();
+    for (name, property) in properties {
+        let mut property = read_property(root, name, property, depth)?;
+        property.required = required.contains(&name.as_str());
+        read.push(property);
+    }
+    Ok(read)
+}
+
+fn read_property(
+    root: &Value,
+    name: &str,
+    property: &Value,
+    depth: usize,
+) -> Result<Property, CodegenError> {
+    if depth > MAX_DEPTH {
+        return Err(CodegenError::Schema(format!(
+            "`{}` is nested too deeply",
+            name
+        )));
+    }
+    // a description next to a $ref wins over the one of the target
+    let description = property.get("description").and_then(Value::as_str);
+    let property = resolve(root, property, depth)?;
+
+    let types: Vec<&str> = match property.get("type") {
+        Some(Value::String(kind)) => vec![kind.as_str()],
+        Some(Value::Array(kinds)) => kinds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    let nullable = types.contains(&"null");
+    let kind = match types.iter().find(|kind| **kind != "null") {
+        Some(&"string") if property.get("format").and_then(Value::as_str) == Some("date-time") => {
+            PropertyType::DateTime
+        }
+        Some(&"string") => PropertyType::String,
+        Some(&"integer") => PropertyType::Integer,
+        Some(&"number") => PropertyType::Number,
+        Some(&"boolean") => PropertyType::Boolean,
+        Some(&"array") => {
+            let items = property.get("items").cloned().unwrap_or(Value::Null);
+            PropertyType::Array(Box::new(read_property(root, name, &items, depth + 1)?))
+        }
+        Some(&"object") => match property.get("properties") {
+            Some(properties) => {
+                let required: Vec<&str> = property
+                    .get("required")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .collect();
+                PropertyType::Object(read_properties(root, properties, &required, depth + 1)?)
+            }
+            None => PropertyType::Map,
+        },
+        _ => PropertyType::Any,
+    };
+
+    Ok(Property {
+        name: name.to_string(),
+        description: description
+            .map(String::from)
+            .unwrap_or_else(|| string(&property, "description")),
+        kind,
+        nullable,
+        required: false,
+        values: property
+            .get("enum")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect(),
+        example: property.get("example").cloned(),
+    })
+}
+
+/// Follow `$ref`, and the first alternative of `anyOf` and `oneOf`, until a plain schema is reached.
+fn resolve(root: &Value, value: &Value, depth: usize) -> Result<Value, CodegenError> {
+    if depth > MAX_DEPTH {
+        return Err(CodegenError::Schema(String::from(
+            "references are nested too deeply",
+        )));
+    }
+    if let Some(reference) = value.get("$ref").and_then(Value::as_str) {
+        let pointer = reference.trim_start_matches('#');
+        let target = root
+            .pointer(pointer)
+            .ok_or_else(|| CodegenError::Schema(format!("unresolved reference `{}`", reference)))?;
+        return resolve(root, target, depth + 1);
+    }
+    for key in &["anyOf", "oneOf"] {
+        if let Some(first) = value
+            .get(*key)
+            .and_then(Value::as_array)
+            .and_then(|alternatives| alternatives.first())
+        {
+            return resolve(root, first, depth + 1);
+        }
+    }
+    Ok(value.clone())
+}
+
+fn string(value: &Value, key: &str) -> String {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
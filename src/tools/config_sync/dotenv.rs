@@ -0,0 +1,291 @@
+//Anything related to parsing `.env` files goes here.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// DotenvError
+///
+/// Returned when a `.env` file could not be read or has an invalid line.
+#[derive(Debug)]
+pub enum DotenvError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file could be read, but the line number (starting at 1) is not valid
+    Parse { line: usize, message: String },
+}
+
+impl Error for DotenvError {}
+
+impl fmt::Display for DotenvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DotenvError::Io(err) => write!(f, "{}", err),
+            DotenvError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl From<std::io::Error> for DotenvError {
+    fn from(error: std::io::Error) -> Self {
+        DotenvError::Io(error)
+    }
+}
+
+/// Read and parse a `.env` file from disk.
+///
+/// See [`parse`](fn.parse.html) for the supported syntax.
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<BTreeMap<String, String>, DotenvError> {
+    let content = fs::read_to_string(path)?;
+    parse(&content)
+}
+
+/// Parse the content of a `.env` file into a map of config vars.
+///
+/// Supported syntax:
+///
+/// - `KEY=value` pairs, with an optional leading `export `.
+/// - Blank lines and lines starting with `#` are ignored. Unquoted values can have a trailing ` # comment`.
+/// - Single quoted values are taken literally, double quoted values support `\n`, `\r`, `\t`, `\"`, `\\` and `\$` escapes.
+/// - Quoted values can span multiple lines.
+///
+/// Variables are not expanded, `$OTHER` is kept as is. If a key is set more than once, the last value wins.
+pub fn parse(input: &str) -> Result<BTreeMap<String, String>, DotenvError> {
+    let mut vars = BTreeMap::new();
+    let chars: Vec<char> = input.chars().collect();
+    let mut pos = 0;
+    let mut line = 1;
+
+    while pos < chars.len() {
+        skip_blank(&chars, &mut pos);
+        if pos >= chars.len() {
+            break;
+        }
+
+        match chars[pos] {
+            '\n' => {
+                pos += 1;
+                line += 1;
+                continue;
+            }
+            '#' => {
+                skip_to_eol(&chars, &mut pos);
+                continue;
+            }
+            _ => {}
+        }
+
+        let start_line = line;
+        let mut key = read_key(&chars, &mut pos);
+        if key == "export" && pos < chars.len() && (chars[pos] == ' ' || chars[pos] == '\t') {
+            skip_blank(&chars, &mut pos);
+            key = read_key(&chars, &mut pos);
+        }
+        if key.is_empty() || !valid_key(&key) {
+            return Err(parse_error(start_line, "expected a config var name"));
+        }
+
+        skip_blank(&chars, &mut pos);
+        if pos >= chars.len() || chars[pos] != '=' {
+            return Err(parse_error(
+                start_line,
+                &format!("expected `=` after `{}`", key),
+            ));
+        }
+        pos += 1;
+        skip_blank(&chars, &mut pos);
+
+        let value = match chars.get(pos) {
+            Some('\'') => read_single_quoted(&chars, &mut pos, &mut line, start_line)?,
+            Some('"') => read_double_quoted(&chars, &mut pos, &mut line, start_line)?,
+            _ => read_unquoted(&chars, &mut pos),
+        };
+
+        // only whitespace or a comment can follow a value
+        skip_blank(&chars, &mut pos);
+        match chars.get(pos) {
+            None | Some('\n') => {}
+            Some('#') => skip_to_eol(&chars, &mut pos),
+            Some(_) => {
+                return Err(parse_error(
+                    line,
+                    &format!("unexpected characters after the value of `{}`", key),
+                ))
+            }
+        }
+
+        vars.insert(key, value);
+    }
+
+    Ok(vars)
+}
+
+fn parse_error(line: usize, message: &str) -> DotenvError {
+    DotenvError::Parse {
+        line,
+        message: message.to_string(),
+    }
+}
+
+fn valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+fn skip_blank(chars: &[char], pos: &mut usize) {
+    while *pos < chars.len() && (chars[*pos] == ' ' || chars[*pos] == '\t' || chars[*pos] == '\r') {
+        *pos += 1;
+    }
+}
+
+fn skip_to_eol(chars: &[char], pos: &mut usize) {
+    while *pos < chars.len() && chars[*pos] != '\n' {
+        *pos += 1;
+    }
+}
+
+fn read_key(chars: &[char], pos: &mut usize) -> String {
+    let start = *pos;
+    while *pos < chars.len() && !matches!(chars[*pos], '=' | ' ' | '\t' | '\r' | '\n') {
+        *pos += 1;
+    }
+    chars[start..*pos].iter().collect()
+}
+
+fn read_unquoted(chars: &[char], pos: &mut usize) -> String {
+    let start = *pos;
+    while *pos < chars.len() && chars[*pos] != '\n' {
+        // a `#` only starts a comment when preceded by whitespace
+        if chars[*pos] == '#' && *pos > start && chars[*pos - 1].is_whitespace() {
+            break;
+        }
+        *pos += 1;
+    }
+    let value: String = chars[start..*pos].iter().collect();
+    value.trim_end().to_string()
+}
+
+fn read_single_quoted(
+    chars: &[char],
+    pos: &mut usize,
+    line: &mut usize,
+    start_line: usize,
+) -> Result<String, DotenvError> {
+    *pos += 1;
+    let mut value = String::new();
+    while *pos < chars.len() {
+        let c = chars[*pos];
+        *pos += 1;
+        match c {
+            '\'' => return Ok(value),
+            '\n' => {
+                *line += 1;
+                value.push(c);
+            }
+            _ => value.push(c),
+        }
+    }
+    Err(parse_error(start_line, "unterminated single quoted value"))
+}
+
+fn read_double_quoted(
+    chars: &[char],
+    pos: &mut usize,
+    line: &mut usize,
+    start_line: usize,
+) -> Result<String, DotenvError> {
+    *pos += 1;
+    let mut value = String::new();
+    while *pos < chars.len() {
+        let c = chars[*pos];
+        *pos += 1;
+        match c {
+            '"' => return Ok(value),
+            '\\' if *pos < chars.len() => {
+                let escaped = chars[*pos];
+                *pos += 1;
+                match escaped {
+                    'n' => value.push('\n'),
+                    'r' => value.push('\r'),
+                    't' => value.push('\t'),
+                    '"' | '\\' | '$' => value.push(escaped),
+                    '\n' => {
+                        *line += 1;
+                        value.push('\\');
+                        value.push('\n');
+                    }
+                    _ => {
+                        value.push('\\');
+                        value.push(escaped);
+                    }
+                }
+            }
+            '\n' => {
+                *line += 1;
+                value.push(c);
+            }
+            _ => value.push(c),
+        }
+    }
+    Err(parse_error(start_line, "unterminated double quoted value"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_pairs_and_comments() {
+        let vars = parse("# a comment\n\nFOO=bar\nexport BAZ = qux # trailing\nEMPTY=\n").unwrap();
+        assert_eq!(vars.get("FOO").unwrap(), "bar");
+        assert_eq!(vars.get("BAZ").unwrap(), "qux");
+        assert_eq!(vars.get("EMPTY").unwrap(), "");
+        assert_eq!(vars.len(), 3);
+    }
+
+    #[test]
+    fn hash_inside_value() {
+        let vars = parse("URL=https://example.com/#anchor\nPASS='abc # def'\n").unwrap();
+        assert_eq!(vars.get("URL").unwrap(), "https://example.com/#anchor");
+        assert_eq!(vars.get("PASS").unwrap(), "abc # def");
+    }
+
+    #[test]
+    fn quoted_values() {
+        let vars = parse("A='$literal \\n'\nB=\"tab\\there \\\"q\\\"\"\r\n").unwrap();
+        assert_eq!(vars.get("A").unwrap(), "$literal \\n");
+        assert_eq!(vars.get("B").unwrap(), "tab\there \"q\"");
+    }
+
+    #[test]
+    fn multiline_values() {
+        let input = "KEY=\"-----BEGIN-----\nabc\n-----END-----\"\nNEXT='one\ntwo'\nLAST=1";
+        let vars = parse(input).unwrap();
+        assert_eq!(
+            vars.get("KEY").unwrap(),
+            "-----BEGIN-----\nabc\n-----END-----"
+        );
+        assert_eq!(vars.get("NEXT").unwrap(), "one\ntwo");
+        assert_eq!(vars.get("LAST").unwrap(), "1");
+    }
+
+    #[test]
+    fn errors_report_the_line() {
+        match parse("A=1\nB=\"open\n\n").unwrap_err() {
+            DotenvError::Parse { line, .. } => assert_eq!(line, 2),
+            e => panic!("unexpected error {}", e),
+        }
+        match parse("A=1\nnot a pair\n").unwrap_err() {
+            DotenvError::Parse { line, .. } => assert_eq!(line, 2),
+            e => panic!("unexpected error {}", e),
+        }
+        assert!(parse("A='x' trailing\n").is_err());
+        assert!(parse("1A=x\n").is_err());
+    }
+}
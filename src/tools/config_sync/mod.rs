@@ -0,0 +1,294 @@
+//! Config var sync
+//!
+//! Compares the config vars of an app with a desired set, usually read from a `.env` file, and produces a reviewable [`ConfigPlan`][plan].
+//!
+//! The plan lists the config vars that will be added, changed or removed, with their values masked, so it's safe to print on CI logs.
+//! Applying the plan sends a single PATCH request to Heroku.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::config_sync::{self, ConfigPlan};
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let desired = config_sync::dotenv::parse_file(".env")?;
+//! let plan = ConfigPlan::fetch(&api_client, "APP_ID", &desired)?;
+//!
+//! println!("{}", plan);
+//!
+//! if !plan.is_empty() {
+//!     plan.apply(&api_client, "APP_ID")?;
+//! }
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [plan]: struct.ConfigPlan.html
+
+pub mod dotenv;
+
+use crate::endpoints::config_vars::{AppConfigVarDelete, AppConfigVarDetails};
+use crate::framework::{apiclient::HerokuApiClient, response::ApiResponse};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// A single config var change in a [`ConfigPlan`](struct.ConfigPlan.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigChange {
+    /// config var does not exist on the app yet
+    Add { key: String, value: String },
+    /// config var exists on the app with a different value
+    Change {
+        key: String,
+        old_value: Option<String>,
+        new_value: String,
+    },
+    /// config var exists on the app but not in the desired set
+    Remove {
+        key: String,
+        old_value: Option<String>,
+    },
+}
+
+impl ConfigChange {
+    /// name of the config var this change is about
+    pub fn key(&self) -> &str {
+        match self {
+            ConfigChange::Add { key, .. } => key,
+            ConfigChange::Change { key, .. } => key,
+            ConfigChange::Remove { key, .. } => key,
+        }
+    }
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigChange::Add { key, value } => write!(f, "+ {}={}", key, mask(value)),
+            ConfigChange::Change {
+                key,
+                old_value,
+                new_value,
+            } => write!(
+                f,
+                "~ {}={} -> {}",
+                key,
+                mask(old_value.as_deref().unwrap_or("")),
+                mask(new_value)
+            ),
+            ConfigChange::Remove { key, old_value } => {
+                write!(f, "- {}={}", key, mask(old_value.as_deref().unwrap_or("")))
+            }
+        }
+    }
+}
+
+/// ConfigPlan
+///
+/// The set of changes needed to bring the config vars of an app to a desired state.
+///
+/// Changes are sorted by config var name. The `Display` implementation prints one change per line, with masked values.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigPlan {
+    /// the changes, sorted by config var name
+    pub changes: Vec<ConfigChange>,
+}
+
+impl ConfigPlan {
+    /// Diff the `current` config vars of an app, as returned by `AppConfigVarDetails`, against the `desired` ones.
+    ///
+    /// Config vars missing from `desired` are planned for removal, see [`skip_removals`](#method.skip_removals) to keep them.
+    pub fn diff(
+        current: &HashMap<String, Option<String>>,
+        desired: &BTreeMap<String, String>,
+    ) -> ConfigPlan {
+        let mut changes = Vec::new();
+
+        for (key, value) in desired {
+            match current.get(key) {
+                None => changes.push(ConfigChange::Add {
+                    key: key.clone(),
+                    value: value.clone(),
+                }),
+                Some(old_value) if old_value.as_deref() != Some(value.as_str()) => {
+                    changes.push(ConfigChange::Change {
+                        key: key.clone(),
+                        old_value: old_value.clone(),
+                        new_value: value.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (key, old_value) in current {
+            if !desired.contains_key(key) {
+                changes.push(ConfigChange::Remove {
+                    key: key.clone(),
+                    old_value: old_value.clone(),
+                });
+            }
+        }
+
+        changes.sort_by(|a, b| a.key().cmp(b.key()));
+        ConfigPlan { changes }
+    }
+
+    /// Fetch the current config vars of the app with `AppConfigVarDetails` and diff them against `desired`.
+    pub fn fetch<C: HerokuApiClient>(
+        api_client: &C,
+        app_id: &str,
+        desired: &BTreeMap<String, String>,
+    ) -> ApiResponse<ConfigPlan> {
+        let current = api_client.request(&AppConfigVarDetails {
+            app_id: app_id.into(),
+        })?;
+        Ok(ConfigPlan::diff(&current, desired))
+    }
+
+    /// Drop the removals from the plan, leaving config vars that are not in the desired set untouched.
+    pub fn skip_removals(mut self) -> ConfigPlan {
+        self.changes
+            .retain(|change| !matches!(change, ConfigChange::Remove { .. }));
+        self
+    }
+
+    /// true if applying the plan would not change anything
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// config vars that will be added
+    pub fn additions(&self) -> impl Iterator<Item = &ConfigChange> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, ConfigChange::Add { .. }))
+    }
+
+    /// config vars that will be changed
+    pub fn modifications(&self) -> impl Iterator<Item = &ConfigChange> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, ConfigChange::Change { .. }))
+    }
+
+    /// config vars that will be removed
+    pub fn removals(&self) -> impl Iterator<Item = &ConfigChange> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, ConfigChange::Remove { .. }))
+    }
+
+    /// The body of the PATCH request, removed config vars are set to `None`.
+    pub fn params(&self) -> HashMap<String, Option<String>> {
+        self.changes
+            .iter()
+            .map(|change| match change {
+                ConfigChange::Add { key, value } => (key.clone(), Some(value.clone())),
+                ConfigChange::Change { key, new_value, .. } => {
+                    (key.clone(), Some(new_value.clone()))
+                }
+                ConfigChange::Remove { key, .. } => (key.clone(), None),
+            })
+            .collect()
+    }
+
+    /// Apply the plan to the app with a single PATCH request, returning the resulting config vars.
+    ///
+    /// Additions, changes and removals are all sent at once, see [`AppConfigVarDelete`][delete] for how removals are encoded.
+    ///
+    /// [delete]: ../../endpoints/config_vars/delete/struct.AppConfigVarDelete.html
+    pub fn apply<C: HerokuApiClient>(
+        &self,
+        api_client: &C,
+        app_id: &str,
+    ) -> ApiResponse<HashMap<String, Option<String>>> {
+        api_client.request(&AppConfigVarDelete {
+            app_id: app_id.into(),
+            params: self.params(),
+        })
+    }
+}
+
+impl fmt::Display for ConfigPlan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No config var changes.");
+        }
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        writeln!(
+            f,
+            "{} to add, {} to change, {} to remove.",
+            self.additions().count(),
+            self.modifications().count(),
+            self.removals().count()
+        )
+    }
+}
+
+/// Mask a config var value so it can be printed.
+///
+/// Only the first few characters of long values are kept, short values are masked completely.
+pub fn mask(value: &str) -> String {
+    let len = value.chars().count();
+    if len == 0 {
+        return String::from("\"\"");
+    }
+    if len < 16 {
+        return String::from("********");
+    }
+    let prefix: String = value.chars().take(3).collect();
+    format!("{}******** ({} chars)", prefix, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current() -> HashMap<String, Option<String>> {
+        let mut current = HashMap::new();
+        current.insert(String::from("KEEP"), Some(String::from("same")));
+        current.insert(String::from("EDIT"), Some(String::from("old")));
+        current.insert(String::from("DROP"), Some(String::from("gone")));
+        current
+    }
+
+    #[test]
+    fn diff_plans_adds_changes_and_removals() {
+        let desired = dotenv::parse("KEEP=same\nEDIT=new\nADD=fresh\n").unwrap();
+        let plan = ConfigPlan::diff(&current(), &desired);
+
+        let keys: Vec<&str> = plan.changes.iter().map(|c| c.key()).collect();
+        assert_eq!(keys, vec!["ADD", "DROP", "EDIT"]);
+        assert_eq!(plan.additions().count(), 1);
+        assert_eq!(plan.modifications().count(), 1);
+        assert_eq!(plan.removals().count(), 1);
+
+        let params = plan.params();
+        assert_eq!(params.get("ADD").unwrap().as_deref(), Some("fresh"));
+        assert_eq!(params.get("EDIT").unwrap().as_deref(), Some("new"));
+        assert_eq!(params.get("DROP").unwrap(), &None);
+        assert!(!params.contains_key("KEEP"));
+
+        let plan = plan.skip_removals();
+        assert!(!plan.params().contains_key("DROP"));
+    }
+
+    #[test]
+    fn display_masks_values() {
+        let desired = dotenv::parse("EDIT=a-very-long-secret-value\n").unwrap();
+        let output = ConfigPlan::diff(&current(), &desired).to_string();
+        assert!(!output.contains("secret"));
+        assert!(!output.contains("old"));
+        assert!(!output.contains("gone"));
+        assert!(output.contains("0 to add, 1 to change, 2 to remove."));
+        assert_eq!(
+            ConfigPlan::default().to_string(),
+            "No config var changes.\n"
+        );
+    }
+}
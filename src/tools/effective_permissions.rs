@@ -0,0 +1,486 @@
+//! Effective app permissions
+//!
+//! Access to a team app comes from several places: team admins can do everything on every app of the team, team
+//! collaborators get the permissions granted to them on a single app, and app collaborators get theirs from the app
+//! itself. [`EffectivePermissions`][effective] combines all of them to answer "what can this user do on this app", and
+//! why, and turns the answer into a per team [`PermissionMatrix`][matrix].
+//!
+//! Team members without the `admin` or `owner` role only get app permissions through collaborator grants.
+//!
+//! [`for_team`][for_team] reads the grants of every team app from the permission entities of the team, in a single
+//! request. The collaborators of a team app are team collaborators, [`add_collaborators`][add_collaborators] is for
+//! personal apps, which have no team to list them.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::effective_permissions;
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let permissions = effective_permissions::for_team(&api_client, "TEAM_NAME")?;
+//!
+//! if permissions.can("someone@example.com", "APP_NAME", "deploy") {
+//!     println!("someone@example.com can deploy APP_NAME");
+//! }
+//! print!("{}", permissions.matrix().to_csv());
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [effective]: struct.EffectivePermissions.html
+//! [matrix]: struct.PermissionMatrix.html
+//! [for_team]: fn.for_team.html
+//! [add_collaborators]: struct.EffectivePermissions.html#method.add_collaborators
+
+use crate::endpoints::collaborators::{Collaborator, TeamCollaborator};
+use crate::endpoints::teams::{
+    PermissionEntity, TeamAppList, TeamAppPermission, TeamAppPermissionList, TeamMember,
+    TeamMemberList, TeamPermissionList,
+};
+use crate::framework::{apiclient::HerokuApiClient, response::ApiResponse};
+use std::collections::BTreeSet;
+
+/// The permission that collaborators without explicit permissions and without the `owner` role don't get.
+const MANAGE: &str = "manage";
+
+/// Where a permission comes from.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GrantSource {
+    /// the user has the given role in the team owning the app, e.g. `admin`
+    TeamRole(String),
+    /// the user is a collaborator on the team app
+    TeamCollaborator,
+    /// the user is a collaborator on the app
+    AppCollaborator,
+}
+
+/// A permission of a user on an app.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Grant {
+    /// email of the user
+    pub user: String,
+    /// name of the app
+    pub app: String,
+    /// name of the permission, e.g. `view` or `deploy`
+    pub permission: String,
+    /// why the user has the permission
+    pub source: GrantSource,
+}
+
+/// Effective permissions of users on apps.
+#[derive(Debug, Clone, Default)]
+pub struct EffectivePermissions {
+    /// names of the known permissions, in the order they were listed
+    pub permissions: Vec<String>,
+    /// names of the apps team roles apply to
+    pub apps: BTreeSet<String>,
+    /// emails and roles of the team members whose role applies to every app
+    pub team_roles: Vec<(String, String)>,
+    /// the permissions granted to collaborators
+    pub grants: Vec<Grant>,
+}
+
+impl EffectivePermissions {
+    /// Start from the permissions available on team apps, as listed by [`TeamAppPermissionList`][list].
+    ///
+    /// [list]: ../../endpoints/teams/get/struct.TeamAppPermissionList.html
+    pub fn new(permissions: &[TeamAppPermission]) -> EffectivePermissions {
+        EffectivePermissions {
+            permissions: permissions
+                .iter()
+                .map(|permission| permission.name.clone())
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Add the apps of the team, team admins get every permission on them.
+    pub fn add_apps<S: AsRef<str>>(&mut self, apps: &[S]) -> &mut Self {
+        self.apps
+            .extend(apps.iter().map(|app| app.as_ref().to_string()));
+        self
+    }
+
+    /// Add the members of the team, the ones with the `admin` or `owner` role get every permission on every team app.
+    pub fn add_team_members(&mut self, members: &[TeamMember]) -> &mut Self {
+        for member in members {
+            if let Some(role) = member.role.as_deref() {
+                if role == "admin" || role == "owner" {
+                    self.team_roles
+                        .push((member.email.clone(), role.to_string()));
+                }
+            }
+        }
+        self
+    }
+
+    /// Add the collaborators of team apps, they get the permissions granted to them on each app.
+    pub fn add_team_collaborators(&mut self, collaborators: &[TeamCollaborator]) -> &mut Self {
+        for collaborator in collaborators {
+            let permissions = collaborator
+                .permissions
+                .as_ref()
+                .map(|permissions| permissions.iter().map(|p| p.name.clone()).collect());
+            self.grant_all(
+                &collaborator.user.email,
+                &collaborator.app.name,
+                collaborator.role.as_deref(),
+                permissions,
+                GrantSource::TeamCollaborator,
+            );
+        }
+        self
+    }
+
+    /// Add the grants of the permission entities of a team, as listed by [`TeamPermissionList`][list].
+    ///
+    /// Only app entities are kept. The users are the team collaborators of each app, minus the team members added
+    /// with an `admin` or `owner` role, whose access comes from their role.
+    ///
+    /// [list]: ../../endpoints/teams/get/struct.TeamPermissionList.html
+    pub fn add_permission_entities(&mut self, entities: &[PermissionEntity]) -> &mut Self {
+        for entity in entities.iter().filter(|entity| entity.type_field == "app") {
+            for user in &entity.users {
+                if self
+                    .team_roles
+                    .iter()
+                    .any(|(email, _)| email == &user.email)
+                {
+                    continue;
+                }
+                self.grant_all(
+                    &user.email,
+                    &entity.name,
+                    None,
+                    Some(user.permissions.clone()),
+                    GrantSource::TeamCollaborator,
+                );
+            }
+        }
+        self
+    }
+
+    /// Add the collaborators of apps.
+    ///
+    /// Collaborators without explicit permissions get every permission but `manage`, unless they own the app.
+    pub fn add_collaborators(&mut self, collaborators: &[Collaborator]) -> &mut Self {
+        for collaborator in collaborators {
+            let permissions = collaborator
+                .permissions
+                .as_ref()
+                .map(|permissions| permissions.iter().map(|p| p.name.clone()).collect());
+            self.grant_all(
+                &collaborator.user.email,
+                &collaborator.app.name,
+                collaborator.role.as_deref(),
+                permissions,
+                GrantSource::AppCollaborator,
+            );
+        }
+        self
+    }
+
+    fn grant_all(
+        &mut self,
+        user: &str,
+        app: &str,
+        role: Option<&str>,
+        permissions: Option<Vec<String>>,
+        source: GrantSource,
+    ) {
+        let permissions = permissions.unwrap_or_else(|| {
+            self.permissions
+                .iter()
+                .filter(|permission| role == Some("owner") || *permission != MANAGE)
+                .cloned()
+                .collect()
+        });
+        for permission in permissions {
+            if !self.permissions.contains(&permission) {
+                self.permissions.push(permission.clone());
+            }
+            let grant = Grant {
+                user: user.to_string(),
+                app: app.to_string(),
+                permission,
+                source: source.clone(),
+            };
+            if !self.grants.contains(&grant) {
+                self.grants.push(grant);
+            }
+        }
+    }
+
+    /// Every grant of `user` on `app`, by permission then source.
+    pub fn permissions(&self, user: &str, app: &str) -> Vec<Grant> {
+        let mut grants: Vec<Grant> = self
+            .grants
+            .iter()
+            .filter(|grant| grant.user == user && grant.app == app)
+            .cloned()
+            .collect();
+        if self.apps.contains(app) {
+            for (email, role) in self.team_roles.iter().filter(|(email, _)| email == user) {
+                grants.extend(self.permissions.iter().map(|permission| Grant {
+                    user: email.clone(),
+                    app: app.to_string(),
+                    permission: permission.clone(),
+                    source: GrantSource::TeamRole(role.clone()),
+                }));
+            }
+        }
+        grants.sort_by(|a, b| {
+            (self.position(&a.permission), &a.source)
+                .cmp(&(self.position(&b.permission), &b.source))
+        });
+        grants
+    }
+
+    /// Whether `user` has `permission` on `app`.
+    pub fn can(&self, user: &str, app: &str, permission: &str) -> bool {
+        self.permissions(user, app)
+            .iter()
+            .any(|grant| grant.permission == permission)
+    }
+
+    /// The permissions of every user on every app they can access.
+    pub fn matrix(&self) -> PermissionMatrix {
+        let mut pairs: BTreeSet<(&str, &str)> = self
+            .grants
+            .iter()
+            .map(|grant| (grant.user.as_str(), grant.app.as_str()))
+            .collect();
+        for (email, _) in &self.team_roles {
+            pairs.extend(self.apps.iter().map(|app| (email.as_str(), app.as_str())));
+        }
+
+        let rows = pairs
+            .into_iter()
+            .map(|(user, app)| {
+                let mut permissions: Vec<String> = self
+                    .permissions(user, app)
+                    .into_iter()
+                    .map(|grant| grant.permission)
+                    .collect();
+                permissions.dedup();
+                MatrixRow {
+                    user: user.to_string(),
+                    app: app.to_string(),
+                    permissions,
+                }
+            })
+            .collect();
+        PermissionMatrix {
+            permissions: self.permissions.clone(),
+            rows,
+        }
+    }
+
+    fn position(&self, permission: &str) -> usize {
+        self.permissions
+            .iter()
+            .position(|known| known == permission)
+            .unwrap_or(self.permissions.len())
+    }
+}
+
+/// The permissions of users on apps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionMatrix {
+    /// names of the permissions, one column each
+    pub permissions: Vec<String>,
+    /// one row per user and app, by user then app
+    pub rows: Vec<MatrixRow>,
+}
+
+/// The permissions of a user on an app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixRow {
+    /// email of the user
+    pub user: String,
+    /// name of the app
+    pub app: String,
+    /// names of the permissions the user has on the app
+    pub permissions: Vec<String>,
+}
+
+impl PermissionMatrix {
+    /// The matrix as CSV, with a `user,app` header followed by one `x` or empty column per permission.
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("user,app,{}\n", self.permissions.join(","));
+        for row in &self.rows {
+            let columns: Vec<&str> = self
+                .permissions
+                .iter()
+                .map(|permission| {
+                    if row.permissions.contains(permission) {
+                        "x"
+                    } else {
+                        ""
+                    }
+                })
+                .collect();
+            csv.push_str(&format!("{},{},{}\n", row.user, row.app, columns.join(",")));
+        }
+        csv
+    }
+}
+
+/// The effective permissions of the members and collaborators of `team` on its apps.
+///
+/// Requests the available permissions, the team members, the team apps and the permission entities of the team,
+/// whatever the number of apps.
+pub fn for_team<C: HerokuApiClient>(
+    api_client: &C,
+    team: &str,
+) -> ApiResponse<EffectivePermissions> {
+    let permissions = api_client.request(&TeamAppPermissionList {})?;
+    let members = api_client.request(&TeamMemberList {
+        team_id: team.into(),
+    })?;
+    let apps = api_client.request(&TeamAppList {
+        team_id: team.into(),
+    })?;
+    let entities = api_client.request(&TeamPermissionList {
+        team_id: team.into(),
+    })?;
+
+    let mut effective = EffectivePermissions::new(&permissions);
+    let names: Vec<&str> = apps.iter().map(|app| app.name.as_str()).collect();
+    effective
+        .add_apps(&names)
+        .add_team_members(&members)
+        .add_permission_entities(&entities);
+    Ok(effective)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn effective() -> EffectivePermissions {
+        let permissions: Vec<TeamAppPermission> = ["view", "deploy", "operate", "manage"]
+            .iter()
+            .map(|name| serde_json::from_value(json!({"name": name, "description": name})).unwrap())
+            .collect();
+        EffectivePermissions::new(&permissions)
+    }
+
+    fn member(email: &str, role: &str) -> TeamMember {
+        serde_json::from_value(json!({
+            "created_at": "2012-01-01T12:00:00Z",
+            "email": email,
+            "federated": false,
+            "id": format!("{}-membership", email),
+            "identity_provider": null,
+            "role": role,
+            "two_factor_authentication": true,
+            "updated_at": "2012-01-01T12:00:00Z",
+            "user": {"email": email, "id": format!("{}-id", email), "name": null}
+        }))
+        .unwrap()
+    }
+
+    fn collaborator<T: serde::de::DeserializeOwned>(
+        email: &str,
+        app: &str,
+        role: &str,
+        permissions: Option<&[&str]>,
+    ) -> T {
+        let permissions = permissions.map(|names| {
+            names
+                .iter()
+                .map(|name| json!({"name": name, "description": name}))
+                .collect::<Vec<Value>>()
+        });
+        serde_json::from_value(json!({
+            "app": {"id": format!("{}-id", app), "name": app},
+            "created_at": "2012-01-01T12:00:00Z",
+            "id": format!("{}-{}", email, app),
+            "permissions": permissions,
+            "role": role,
+            "updated_at": "2012-01-01T12:00:00Z",
+            "user": {"email": email, "federated": false, "id": format!("{}-id", email)}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn admins_can_do_everything_on_team_apps() {
+        let mut effective = effective();
+        effective.add_apps(&["web", "api"]).add_team_members(&[
+            member("admin@example.com", "admin"),
+            member("dev@example.com", "member"),
+        ]);
+
+        assert!(effective.can("admin@example.com", "web", "manage"));
+        assert!(effective.can("admin@example.com", "api", "view"));
+        assert!(!effective.can("admin@example.com", "other", "view"));
+        assert!(!effective.can("dev@example.com", "web", "view"));
+        assert_eq!(
+            effective.permissions("admin@example.com", "web")[0].source,
+            GrantSource::TeamRole("admin".to_string())
+        );
+    }
+
+    #[test]
+    fn collaborators_get_their_grants() {
+        let mut effective = effective();
+        effective
+            .add_team_collaborators(&[collaborator(
+                "dev@example.com",
+                "web",
+                "member",
+                Some(&["view", "deploy"]),
+            )])
+            .add_collaborators(&[
+                collaborator("friend@example.com", "hobby", "collaborator", None),
+                collaborator("me@example.com", "hobby", "owner", None),
+            ]);
+
+        assert!(effective.can("dev@example.com", "web", "deploy"));
+        assert!(!effective.can("dev@example.com", "web", "operate"));
+        assert!(!effective.can("dev@example.com", "api", "view"));
+        assert!(effective.can("friend@example.com", "hobby", "operate"));
+        assert!(!effective.can("friend@example.com", "hobby", "manage"));
+        assert!(effective.can("me@example.com", "hobby", "manage"));
+        assert_eq!(
+            effective
+                .permissions("dev@example.com", "web")
+                .iter()
+                .map(|grant| (grant.permission.as_str(), &grant.source))
+                .collect::<Vec<_>>(),
+            vec![
+                ("view", &GrantSource::TeamCollaborator),
+                ("deploy", &GrantSource::TeamCollaborator)
+            ]
+        );
+    }
+
+    #[test]
+    fn matrix_combines_every_source() {
+        let mut effective = effective();
+        effective
+            .add_apps(&["web"])
+            .add_team_members(&[member("admin@example.com", "admin")])
+            .add_team_collaborators(&[
+                collaborator("admin@example.com", "web", "admin", Some(&["view"])),
+                collaborator(
+                    "dev@example.com",
+                    "web",
+                    "member",
+                    Some(&["view", "deploy"]),
+                ),
+            ]);
+
+        assert_eq!(
+            effective.matrix().to_csv(),
+            "user,app,view,deploy,operate,manage\n\
+             admin@example.com,web,x,x,x,x\n\
+             dev@example.com,web,x,x,,\n"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Module for higher level tooling built on top of the endpoints.
+//!
+//! Endpoints map one to one with the Heroku API, the tools in here combine several of them to solve a bigger task.
+
+/// searchable add-on service and plan catalog
+#[cfg(feature = "addon_catalog")]
+pub mod addon_catalog;
+/// team add-on allow-list checks
+#[cfg(feature = "addon_policy")]
+pub mod addon_policy;
+/// app cloning across regions, teams and accounts
+#[cfg(feature = "app_clone")]
+pub mod app_clone;
+/// app.json manifest parsing and validation
+#[cfg(feature = "app_json")]
+pub mod app_json;
+/// bulk app lookups by id
+#[cfg(feature = "app_lookup")]
+pub mod app_lookup;
+/// metrics of every process type of an app
+#[cfg(feature = "app_metrics")]
+pub mod app_metrics;
+/// enterprise audit trail archive downloads and queries
+#[cfg(feature = "audit_archive")]
+pub mod audit_archive;
+/// code generation from Heroku's JSON hyper-schema
+#[cfg(feature = "codegen")]
+pub mod codegen;
+/// config var sync from `.env` files
+#[cfg(feature = "config_sync")]
+pub mod config_sync;
+/// effective app permissions of team members and collaborators
+#[cfg(feature = "effective_permissions")]
+pub mod effective_permissions;
+/// Procfile and heroku.yml parsing
+#[cfg(feature = "procfile")]
+pub mod procfile;
+/// declarative infrastructure spec and reconciler
+#[cfg(feature = "reconcile")]
+pub mod reconcile;
+/// SAML identity provider metadata parsing
+#[cfg(feature = "saml")]
+pub mod saml;
+/// app configuration snapshots, export and restore
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+/// usage reports by app, month and team
+#[cfg(feature = "usage_report")]
+pub mod usage_report;
@@ -0,0 +1,605 @@
+//! Procfile and heroku.yml
+//!
+//! Parsers for the files declaring the process types of an app, the [`Procfile`][procfile] for buildpack apps and
+//! [`heroku.yml`][herokuyml] for container apps.
+//!
+//! Both produce a list of [`ProcessDefinition`][definition] that can be cross-checked against the process types of the current
+//! slug and the app formation before deploying.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::procfile::{self, Procfile};
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let procfile = Procfile::from_file("Procfile")?;
+//! let warnings = procfile::check_app_formation(&api_client, "APP_ID", &procfile.processes)?;
+//!
+//! for warning in warnings {
+//!     println!("warning: {}", warning);
+//! }
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [procfile]: https://devcenter.heroku.com/articles/procfile
+//! [herokuyml]: https://devcenter.heroku.com/articles/build-docker-images-heroku-yml
+//! [definition]: struct.ProcessDefinition.html
+
+use crate::endpoints::formations::{Formation, FormationList};
+use crate::framework::{apiclient::HerokuApiClient, response::ApiResponse};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// ProcfileError
+///
+/// Returned when a Procfile or heroku.yml can't be read or parsed.
+#[derive(Debug)]
+pub enum ProcfileError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The Procfile has an invalid line, the line number starts at 1
+    Parse { line: usize, message: String },
+    /// The heroku.yml is not valid YAML or doesn't match the heroku.yml format
+    Yaml(serde_yaml::Error),
+}
+
+impl Error for ProcfileError {}
+
+impl fmt::Display for ProcfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProcfileError::Io(err) => write!(f, "{}", err),
+            ProcfileError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            ProcfileError::Yaml(err) => write!(f, "invalid heroku.yml: {}", err),
+        }
+    }
+}
+
+impl From<std::io::Error> for ProcfileError {
+    fn from(error: std::io::Error) -> Self {
+        ProcfileError::Io(error)
+    }
+}
+
+impl From<serde_yaml::Error> for ProcfileError {
+    fn from(error: serde_yaml::Error) -> Self {
+        ProcfileError::Yaml(error)
+    }
+}
+
+/// A process type declared by the app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessDefinition {
+    /// type of process, e.g. `web` or `worker`. pattern: ^[-\w]{1,128}$
+    pub process_type: String,
+    /// command to launch the process, `None` when it comes from the image, e.g. the Dockerfile `CMD`
+    pub command: Option<String>,
+}
+
+/// Procfile
+///
+/// The process types of a buildpack app, one `<process type>: <command>` per line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Procfile {
+    /// the processes, in the order they are declared
+    pub processes: Vec<ProcessDefinition>,
+}
+
+impl Procfile {
+    /// Parse the content of a Procfile.
+    ///
+    /// Blank lines and lines starting with `#` are ignored. Declaring the same process type twice is an error.
+    pub fn parse(input: &str) -> Result<Procfile, ProcfileError> {
+        let mut processes: Vec<ProcessDefinition> = Vec::new();
+
+        for (index, raw_line) in input.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: String| ProcfileError::Parse {
+                line: index + 1,
+                message,
+            };
+
+            let separator = line
+                .find(':')
+                .ok_or_else(|| error(String::from("expected `<process type>: <command>`")))?;
+            let process_type = line[..separator].trim();
+            let command = line[separator + 1..].trim();
+
+            if !valid_process_type(process_type) {
+                return Err(error(format!(
+                    "`{}` is not a valid process type",
+                    process_type
+                )));
+            }
+            if command.is_empty() {
+                return Err(error(format!("`{}` has no command", process_type)));
+            }
+            if processes.iter().any(|p| p.process_type == process_type) {
+                return Err(error(format!("`{}` is declared twice", process_type)));
+            }
+
+            processes.push(ProcessDefinition {
+                process_type: process_type.to_string(),
+                command: Some(command.to_string()),
+            });
+        }
+
+        Ok(Procfile { processes })
+    }
+
+    /// Read and parse a Procfile from disk.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Procfile, ProcfileError> {
+        let content = fs::read_to_string(path)?;
+        Procfile::parse(&content)
+    }
+}
+
+/// heroku.yml
+///
+/// The build and run manifest of a container app.
+///
+/// [See Heroku documentation for more information about this file](https://devcenter.heroku.com/articles/build-docker-images-heroku-yml)
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct HerokuYml {
+    /// add-ons and config vars used when creating an app from the manifest
+    pub setup: Option<HerokuYmlSetup>,
+    /// the Docker images to build
+    pub build: Option<HerokuYmlBuild>,
+    /// the release phase
+    pub release: Option<HerokuYmlRelease>,
+    /// the process types and their commands
+    #[serde(default)]
+    pub run: BTreeMap<String, HerokuYmlRun>,
+}
+
+/// `setup` section of heroku.yml
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct HerokuYmlSetup {
+    /// add-ons to provision
+    #[serde(default)]
+    pub addons: Vec<HerokuYmlAddon>,
+    /// config vars to set
+    #[serde(default)]
+    pub config: BTreeMap<String, String>,
+}
+
+/// An add-on in the `setup` section of heroku.yml
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct HerokuYmlAddon {
+    /// add-on service and optional plan, e.g. `heroku-postgresql:mini`
+    pub plan: String,
+    /// name of the attachment
+    #[serde(rename = "as")]
+    pub attachment_name: Option<String>,
+}
+
+/// `build` section of heroku.yml
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct HerokuYmlBuild {
+    /// process type to Dockerfile mapping
+    #[serde(default)]
+    pub docker: BTreeMap<String, HerokuYmlDocker>,
+    /// build time arguments
+    #[serde(default)]
+    pub config: BTreeMap<String, String>,
+}
+
+/// The Dockerfile used to build the image of a process type.
+///
+/// The shorthand `web: Dockerfile` form deserializes to a Dockerfile without a target.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "HerokuYmlDockerRepr")]
+pub struct HerokuYmlDocker {
+    /// path of the Dockerfile
+    pub dockerfile: String,
+    /// build stage to target in a multi-stage Dockerfile
+    pub target: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HerokuYmlDockerRepr {
+    Path(String),
+    Detailed {
+        dockerfile: String,
+        target: Option<String>,
+    },
+}
+
+impl From<HerokuYmlDockerRepr> for HerokuYmlDocker {
+    fn from(repr: HerokuYmlDockerRepr) -> Self {
+        match repr {
+            HerokuYmlDockerRepr::Path(dockerfile) => HerokuYmlDocker {
+                dockerfile,
+                target: None,
+            },
+            HerokuYmlDockerRepr::Detailed { dockerfile, target } => {
+                HerokuYmlDocker { dockerfile, target }
+            }
+        }
+    }
+}
+
+/// `release` section of heroku.yml
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct HerokuYmlRelease {
+    /// the release command, split in arguments
+    #[serde(default)]
+    pub command: Vec<String>,
+    /// process type whose image runs the release command
+    pub image: Option<String>,
+}
+
+/// A process type in the `run` section of heroku.yml
+///
+/// The shorthand `web: bundle exec puma` form deserializes to a process with that command.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "HerokuYmlRunRepr")]
+pub struct HerokuYmlRun {
+    /// command to launch the process, `None` when the Dockerfile `CMD` is used
+    pub command: Option<String>,
+    /// process type whose image runs this process
+    pub image: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HerokuYmlRunRepr {
+    Command(String),
+    Detailed {
+        command: Option<HerokuYmlCommand>,
+        image: Option<String>,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HerokuYmlCommand {
+    Line(String),
+    Arguments(Vec<String>),
+}
+
+impl From<HerokuYmlRunRepr> for HerokuYmlRun {
+    fn from(repr: HerokuYmlRunRepr) -> Self {
+        match repr {
+            HerokuYmlRunRepr::Command(command) => HerokuYmlRun {
+                command: Some(command),
+                image: None,
+            },
+            HerokuYmlRunRepr::Detailed { command, image } => HerokuYmlRun {
+                command: command.map(|command| match command {
+                    HerokuYmlCommand::Line(line) => line,
+                    HerokuYmlCommand::Arguments(arguments) => arguments.join(" "),
+                }),
+                image,
+            },
+        }
+    }
+}
+
+impl HerokuYml {
+    /// Parse the content of a heroku.yml file.
+    pub fn parse(input: &str) -> Result<HerokuYml, ProcfileError> {
+        Ok(serde_yaml::from_str(input)?)
+    }
+
+    /// Read and parse a heroku.yml file from disk.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<HerokuYml, ProcfileError> {
+        let content = fs::read_to_string(path)?;
+        HerokuYml::parse(&content)
+    }
+
+    /// The process types of the app, sorted by name.
+    ///
+    /// Processes declared in `run` take their command from there, images built in `build.docker` without a `run` entry use the Dockerfile `CMD`.
+    pub fn processes(&self) -> Vec<ProcessDefinition> {
+        let mut processes: BTreeMap<&str, Option<String>> = BTreeMap::new();
+
+        if let Some(build) = &self.build {
+            for process_type in build.docker.keys() {
+                processes.insert(process_type, None);
+            }
+        }
+        for (process_type, run) in &self.run {
+            processes.insert(process_type, run.command.clone());
+        }
+
+        processes
+            .into_iter()
+            .map(|(process_type, command)| ProcessDefinition {
+                process_type: process_type.to_string(),
+                command,
+            })
+            .collect()
+    }
+}
+
+/// A mismatch between the declared process types and what is live on Heroku.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessWarning {
+    /// the formation runs dynos of a process type that is no longer declared
+    ScaledButUndefined { process_type: String, quantity: i32 },
+    /// a declared process type has no formation yet, it will be created scaled to zero on the next release
+    NotInFormation { process_type: String },
+    /// a declared process type is not in the current slug, it will be added on the next deploy
+    NotInSlug { process_type: String },
+    /// a process type of the current slug is no longer declared, it will be removed on the next deploy
+    RemovedFromSlug { process_type: String },
+    /// the command of a process type differs from the one in the current slug
+    CommandChanged {
+        process_type: String,
+        slug_command: String,
+        command: String,
+    },
+}
+
+impl fmt::Display for ProcessWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProcessWarning::ScaledButUndefined {
+                process_type,
+                quantity,
+            } => write!(
+                f,
+                "`{}` is scaled to {} but is no longer declared",
+                process_type, quantity
+            ),
+            ProcessWarning::NotInFormation { process_type } => {
+                write!(f, "`{}` is declared but not in the formation", process_type)
+            }
+            ProcessWarning::NotInSlug { process_type } => {
+                write!(
+                    f,
+                    "`{}` is declared but not in the current slug",
+                    process_type
+                )
+            }
+            ProcessWarning::RemovedFromSlug { process_type } => write!(
+                f,
+                "`{}` is in the current slug but is no longer declared",
+                process_type
+            ),
+            ProcessWarning::CommandChanged {
+                process_type,
+                slug_command,
+                command,
+            } => write!(
+                f,
+                "`{}` command changes from `{}` to `{}`",
+                process_type, slug_command, command
+            ),
+        }
+    }
+}
+
+/// Cross-check the declared processes against the app formation, as returned by `FormationList`.
+pub fn check_formation(
+    processes: &[ProcessDefinition],
+    formation: &[Formation],
+) -> Vec<ProcessWarning> {
+    let mut warnings = Vec::new();
+
+    for process in formation {
+        let declared = processes.iter().any(|p| p.process_type == process.r#type);
+        if !declared && process.quantity > 0 {
+            warnings.push(ProcessWarning::ScaledButUndefined {
+                process_type: process.r#type.clone(),
+                quantity: process.quantity,
+            });
+        }
+    }
+
+    for process in processes {
+        if !formation.iter().any(|f| f.r#type == process.process_type) {
+            warnings.push(ProcessWarning::NotInFormation {
+                process_type: process.process_type.clone(),
+            });
+        }
+    }
+
+    warnings
+}
+
+/// Cross-check the declared processes against the `process_types` of a `Slug`.
+///
+/// Processes without a command, see [`ProcessDefinition`](struct.ProcessDefinition.html), are not compared.
+pub fn check_slug(
+    processes: &[ProcessDefinition],
+    slug_process_types: &HashMap<String, String>,
+) -> Vec<ProcessWarning> {
+    let mut warnings = Vec::new();
+
+    for process in processes {
+        match (
+            slug_process_types.get(&process.process_type),
+            &process.command,
+        ) {
+            (None, _) => warnings.push(ProcessWarning::NotInSlug {
+                process_type: process.process_type.clone(),
+            }),
+            (Some(slug_command), Some(command)) if slug_command != command => {
+                warnings.push(ProcessWarning::CommandChanged {
+                    process_type: process.process_type.clone(),
+                    slug_command: slug_command.clone(),
+                    command: command.clone(),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    let mut removed: Vec<&String> = slug_process_types
+        .keys()
+        .filter(|process_type| !processes.iter().any(|p| &&p.process_type == process_type))
+        .collect();
+    removed.sort();
+    for process_type in removed {
+        warnings.push(ProcessWarning::RemovedFromSlug {
+            process_type: process_type.clone(),
+        });
+    }
+
+    warnings
+}
+
+/// Fetch the app formation with `FormationList` and cross-check it against the declared processes.
+pub fn check_app_formation<C: HerokuApiClient>(
+    api_client: &C,
+    app_id: &str,
+    processes: &[ProcessDefinition],
+) -> ApiResponse<Vec<ProcessWarning>> {
+    let formation = api_client.request(&FormationList {
+        app_id: app_id.into(),
+    })?;
+    Ok(check_formation(processes, &formation))
+}
+
+fn valid_process_type(process_type: &str) -> bool {
+    !process_type.is_empty()
+        && process_type.len() <= 128
+        && process_type
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::formations::App;
+
+    fn formation(process_type: &str, quantity: i32) -> Formation {
+        Formation {
+            app: App {
+                id: String::from("01234567-89ab-cdef-0123-456789abcdef"),
+                name: String::from("example"),
+            },
+            command: String::from("bin/run"),
+            created_at: "2012-01-01T12:00:00Z".parse().unwrap(),
+            id: String::from("01234567-89ab-cdef-0123-456789abcdef"),
+            quantity,
+            size: String::from("standard-1X"),
+            r#type: String::from(process_type),
+            updated_at: "2012-01-01T12:00:00Z".parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn parses_procfile() {
+        let procfile = Procfile::parse(
+            "# processes\nweb: bundle exec puma -C config/puma.rb\n\nworker:bundle exec sidekiq\n",
+        )
+        .unwrap();
+        assert_eq!(procfile.processes.len(), 2);
+        assert_eq!(procfile.processes[1].process_type, "worker");
+        assert_eq!(
+            procfile.processes[1].command.as_deref(),
+            Some("bundle exec sidekiq")
+        );
+
+        assert!(Procfile::parse("web bundle exec puma\n").is_err());
+        assert!(Procfile::parse("web:\n").is_err());
+        assert!(Procfile::parse("web: a\nweb: b\n").is_err());
+    }
+
+    #[test]
+    fn parses_heroku_yml() {
+        let yml = HerokuYml::parse(
+            r#"
+setup:
+  addons:
+    - plan: heroku-postgresql
+      as: DATABASE
+  config:
+    S3_BUCKET: my-bucket
+build:
+  docker:
+    web: Dockerfile
+    worker:
+      dockerfile: Dockerfile
+      target: worker
+  config:
+    RAILS_ENV: production
+release:
+  command:
+    - ./deployment-tasks.sh
+  image: worker
+run:
+  web: bundle exec puma -C config/puma.rb
+  worker:
+    command:
+      - python
+      - myworker.py
+    image: worker
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            yml.setup.as_ref().unwrap().addons[0].plan,
+            "heroku-postgresql"
+        );
+        let build = yml.build.as_ref().unwrap();
+        assert_eq!(build.docker["worker"].target.as_deref(), Some("worker"));
+        assert_eq!(build.docker["web"].dockerfile, "Dockerfile");
+        assert_eq!(
+            yml.release.as_ref().unwrap().command,
+            vec!["./deployment-tasks.sh"]
+        );
+
+        let processes = yml.processes();
+        assert_eq!(processes.len(), 2);
+        assert_eq!(processes[1].command.as_deref(), Some("python myworker.py"));
+    }
+
+    #[test]
+    fn warns_about_formation_and_slug_drift() {
+        let procfile = Procfile::parse("web: bin/web\nclock: bin/clock\n").unwrap();
+        let warnings = check_formation(
+            &procfile.processes,
+            &[
+                formation("web", 2),
+                formation("worker", 1),
+                formation("old", 0),
+            ],
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                ProcessWarning::ScaledButUndefined {
+                    process_type: String::from("worker"),
+                    quantity: 1
+                },
+                ProcessWarning::NotInFormation {
+                    process_type: String::from("clock")
+                },
+            ]
+        );
+
+        let mut slug = HashMap::new();
+        slug.insert(String::from("web"), String::from("bin/old-web"));
+        slug.insert(String::from("worker"), String::from("bin/worker"));
+        let warnings = check_slug(&procfile.processes, &slug);
+        assert_eq!(warnings.len(), 3);
+        assert!(matches!(warnings[0], ProcessWarning::CommandChanged { .. }));
+        assert!(matches!(warnings[1], ProcessWarning::NotInSlug { .. }));
+        assert!(matches!(
+            warnings[2],
+            ProcessWarning::RemovedFromSlug { .. }
+        ));
+    }
+}
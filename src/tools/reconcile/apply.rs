@@ -0,0 +1,321 @@
+//Anything related to applying a plan goes here.
+
+use super::plan::{Action, AppPlan, Plan};
+use super::ReconcileError;
+use crate::endpoints::addons::post::Attachment;
+use crate::endpoints::addons::{
+    AddonCreate, AddonCreateParams, AddonDelete, AddonUpdate, AddonUpdateParams, AttachmentCreate,
+    AttachmentCreateParams,
+};
+use crate::endpoints::apps::{
+    AppCreate, AppCreateParams, AppFeatureUpdate, AppFeatureUpdateParams, AppUpdate,
+    AppUpdateParams, AppWebhookCreate, AppWebhookCreateParams, AppWebhookDelete, AppWebhookUpdate,
+    AppWebhookUpdateParams, SNICreate, SNICreateParams, SNIDelete,
+};
+use crate::endpoints::builds::{
+    BuildpackInstallationUpdate, BuildpackInstallationUpdateParams, Update,
+};
+use crate::endpoints::collaborators::{
+    CollaboratorCreate, CollaboratorCreateParams, CollaboratorDelete, TeamCollaboratorCreate,
+    TeamCollaboratorCreateParams, TeamCollaboratorDelete,
+};
+use crate::endpoints::domains::{DomainCreate, DomainCreateParams, DomainDelete};
+use crate::endpoints::formations::{FormationUpdate, FormationUpdateParams};
+use crate::endpoints::logs::{LogDrainCreate, LogDrainCreateParams, LogDrainDelete};
+use crate::endpoints::pipelines::{
+    PipelineCouplingCreate, PipelineCouplingCreateParams, PipelineCouplingDelete,
+    PipelineCouplingUpdate, PipelineCouplingUpdateParams,
+};
+use crate::endpoints::teams::{TeamAppCreate, TeamAppCreateParams};
+use crate::framework::apiclient::HerokuApiClient;
+use crate::framework::response::HerokuApiFailure;
+use std::borrow::Cow;
+use std::fs;
+
+/// Apply every action of the plan, app by app, stopping at the first failure.
+pub fn apply<C: HerokuApiClient>(api_client: &C, plan: &Plan) -> Result<(), ReconcileError> {
+    for app in &plan.apps {
+        for action in &app.actions {
+            apply_action(api_client, app, action).map_err(|error| match error {
+                ReconcileError::Api(error) => ReconcileError::Apply {
+                    app: app.app.clone(),
+                    action: action.to_string(),
+                    error,
+                },
+                other => other,
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn apply_action<C: HerokuApiClient>(
+    api_client: &C,
+    plan: &AppPlan,
+    action: &Action,
+) -> Result<(), ReconcileError> {
+    let app_id = plan.app.as_str();
+
+    let result: Result<(), HerokuApiFailure> = match action {
+        Action::CreateApp {
+            region,
+            stack,
+            team,
+            space,
+        } => match team {
+            Some(team) => api_client
+                .request(&TeamAppCreate {
+                    params: TeamAppCreateParams {
+                        locked: None,
+                        name: Some(app_id.into()),
+                        team: Some(team.into()),
+                        personal: None,
+                        region: region.as_deref().map(Cow::from),
+                        space: space.as_deref().map(Cow::from),
+                        stack: stack.as_deref().map(Cow::from),
+                        internal_routing: None,
+                    },
+                })
+                .map(|_| ()),
+            None => api_client
+                .request(&AppCreate {
+                    params: AppCreateParams {
+                        name: Some(app_id.into()),
+                        region: region.as_deref().map(Cow::from),
+                        stack: stack.as_deref().map(Cow::from),
+                    },
+                })
+                .map(|_| ()),
+        },
+        Action::UpdateStack { stack } => api_client
+            .request(&AppUpdate {
+                app_id: app_id.into(),
+                params: AppUpdateParams {
+                    build_stack: Some(stack.into()),
+                    maintenance: None,
+                    name: None,
+                },
+            })
+            .map(|_| ()),
+        Action::UpdateConfig(config) => config.apply(api_client, app_id).map(|_| ()),
+        Action::CreateAddon {
+            plan,
+            name,
+            attachment_name,
+        } => api_client
+            .request(&AddonCreate {
+                app_id: app_id.into(),
+                params: AddonCreateParams {
+                    attachment: attachment_name.as_deref().map(|name| Attachment {
+                        name: Some(name.into()),
+                    }),
+                    config: None,
+                    confirm: None,
+                    plan: plan.into(),
+                    name: name.as_deref().map(Cow::from),
+                },
+            })
+            .map(|_| ()),
+        Action::UpdateAddonPlan { addon, to, .. } => api_client
+            .request(&AddonUpdate {
+                app_id: app_id.into(),
+                addon_id: addon.into(),
+                params: AddonUpdateParams {
+                    plan: to.into(),
+                    name: None,
+                },
+            })
+            .map(|_| ()),
+        Action::AttachAddon { addon, name } => api_client
+            .request(&AttachmentCreate {
+                params: AttachmentCreateParams {
+                    addon: addon.into(),
+                    app: app_id.into(),
+                    confirm: None,
+                    name: Some(name.into()),
+                    namespace: None,
+                },
+            })
+            .map(|_| ()),
+        Action::DeleteAddon { addon, .. } => api_client
+            .request(&AddonDelete {
+                app_id: app_id.into(),
+                addon_id: addon.into(),
+            })
+            .map(|_| ()),
+        Action::CreateDomain { hostname } => api_client
+            .request(&DomainCreate {
+                app_id: app_id.into(),
+                params: DomainCreateParams {
+                    hostname: hostname.into(),
+                },
+            })
+            .map(|_| ()),
+        Action::DeleteDomain { hostname } => api_client
+            .request(&DomainDelete {
+                app_id: app_id.into(),
+                domain_id: hostname.into(),
+            })
+            .map(|_| ()),
+        Action::CreateSni {
+            certificate_chain,
+            private_key,
+        } => {
+            let certificate_chain = fs::read_to_string(certificate_chain)?;
+            let private_key = fs::read_to_string(private_key)?;
+            api_client
+                .request(&SNICreate {
+                    app_id: app_id.into(),
+                    params: SNICreateParams {
+                        certificate_chain: certificate_chain.as_str().into(),
+                        private_key: private_key.as_str().into(),
+                    },
+                })
+                .map(|_| ())
+        }
+        Action::DeleteSni { id, .. } => api_client
+            .request(&SNIDelete {
+                app_id: app_id.into(),
+                sni_id: id.into(),
+            })
+            .map(|_| ()),
+        Action::SetBuildpacks { buildpacks } => api_client
+            .request(&BuildpackInstallationUpdate {
+                app_id: app_id.into(),
+                params: BuildpackInstallationUpdateParams {
+                    updates: buildpacks
+                        .iter()
+                        .map(|buildpack| Update {
+                            buildpack: buildpack.clone(),
+                        })
+                        .collect(),
+                },
+            })
+            .map(|_| ()),
+        Action::UpdateFormation {
+            process_type,
+            quantity,
+            size,
+        } => api_client
+            .request(&FormationUpdate {
+                app_id: app_id.into(),
+                formation_id: process_type.into(),
+                params: FormationUpdateParams {
+                    quantity: *quantity,
+                    size: size.as_deref().map(Cow::from),
+                },
+            })
+            .map(|_| ()),
+        Action::CreateLogDrain { url } => api_client
+            .request(&LogDrainCreate {
+                app_id: app_id.into(),
+                params: LogDrainCreateParams { url: url.into() },
+            })
+            .map(|_| ()),
+        Action::DeleteLogDrain { id, .. } => api_client
+            .request(&LogDrainDelete {
+                app_id: app_id.into(),
+                drain_id: id.into(),
+            })
+            .map(|_| ()),
+        Action::CreateWebhook(webhook) => api_client
+            .request(&AppWebhookCreate {
+                app_id: app_id.into(),
+                params: AppWebhookCreateParams {
+                    authorization: webhook.authorization.as_deref().map(Cow::from),
+                    include: webhook.include.iter().map(Cow::from).collect(),
+                    level: webhook.level.as_str().into(),
+                    secret: webhook.secret.as_deref().map(Cow::from),
+                    url: webhook.url.as_str().into(),
+                },
+            })
+            .map(|_| ()),
+        Action::UpdateWebhook { id, webhook } => api_client
+            .request(&AppWebhookUpdate {
+                app_id: app_id.into(),
+                webhook_id: id.into(),
+                params: AppWebhookUpdateParams {
+                    authorization: webhook.authorization.as_deref().map(Cow::from),
+                    include: Some(webhook.include.iter().map(Cow::from).collect()),
+                    level: Some(webhook.level.as_str().into()),
+                    secret: webhook.secret.as_deref().map(Cow::from),
+                    url: None,
+                },
+            })
+            .map(|_| ()),
+        Action::DeleteWebhook { id, .. } => api_client
+            .request(&AppWebhookDelete {
+                app_id: app_id.into(),
+                webhook_id: id.into(),
+            })
+            .map(|_| ()),
+        Action::SetFeature { name, enabled } => api_client
+            .request(&AppFeatureUpdate {
+                app_id: app_id.into(),
+                feature_id: name.into(),
+                params: AppFeatureUpdateParams { enabled: *enabled },
+            })
+            .map(|_| ()),
+        Action::AddCollaborator { email } => match plan.team {
+            Some(_) => api_client
+                .request(&TeamCollaboratorCreate {
+                    app_id: app_id.into(),
+                    params: TeamCollaboratorCreateParams {
+                        user: email.into(),
+                        silent: None,
+                        permissions: None,
+                    },
+                })
+                .map(|_| ()),
+            None => api_client
+                .request(&CollaboratorCreate {
+                    app_id: app_id.into(),
+                    params: CollaboratorCreateParams {
+                        user: email.into(),
+                        silent: None,
+                    },
+                })
+                .map(|_| ()),
+        },
+        Action::RemoveCollaborator { id, .. } => match plan.team {
+            Some(_) => api_client
+                .request(&TeamCollaboratorDelete {
+                    app_id: app_id.into(),
+                    collaborator_id: id.into(),
+                })
+                .map(|_| ()),
+            None => api_client
+                .request(&CollaboratorDelete {
+                    app_id: app_id.into(),
+                    collaborator_id: id.into(),
+                })
+                .map(|_| ()),
+        },
+        Action::CouplePipeline {
+            pipeline_id, stage, ..
+        } => api_client
+            .request(&PipelineCouplingCreate {
+                params: PipelineCouplingCreateParams {
+                    app: app_id.into(),
+                    pipeline: pipeline_id.into(),
+                    stage: stage.into(),
+                },
+            })
+            .map(|_| ()),
+        Action::UpdateCouplingStage { coupling_id, stage } => api_client
+            .request(&PipelineCouplingUpdate {
+                coupling_id: coupling_id.into(),
+                params: PipelineCouplingUpdateParams {
+                    stage: Some(stage.into()),
+                },
+            })
+            .map(|_| ()),
+        Action::DecouplePipeline { coupling_id } => api_client
+            .request(&PipelineCouplingDelete {
+                coupling_id: coupling_id.into(),
+            })
+            .map(|_| ()),
+    };
+
+    result.map_err(ReconcileError::Api)
+}
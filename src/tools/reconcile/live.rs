@@ -0,0 +1,145 @@
+//Anything related to reading the live state of an app goes here.
+
+use super::spec::AppSpec;
+use crate::endpoints::addons::{Addon, AddonListByApp};
+use crate::endpoints::apps::{
+    App, AppDetails, AppFeature, AppFeatureList, AppWebhook, AppWebhookList, SNIList, SNI,
+};
+use crate::endpoints::builds::{BuildPackInstallationList, BuildpackInstallation};
+use crate::endpoints::collaborators::{Collaborator, CollaboratorList};
+use crate::endpoints::config_vars::AppConfigVarDetails;
+use crate::endpoints::domains::{Domain, DomainList};
+use crate::endpoints::formations::{Formation, FormationList};
+use crate::endpoints::logs::{LogDrain, LogDrainList};
+use crate::endpoints::pipelines::{PipelineCoupling, PipelineCouplingByAppDetails};
+use crate::framework::apiclient::HerokuApiClient;
+use crate::framework::response::{ApiResponse, HerokuApiFailure};
+use std::collections::HashMap;
+
+/// LiveApp
+///
+/// The state of an app on Heroku, as returned by the GET endpoints.
+///
+/// Only the sections managed by the [`AppSpec`](../spec/struct.AppSpec.html) are fetched, the others are left empty.
+#[derive(Debug, Clone, Default)]
+pub struct LiveApp {
+    /// the app, `None` if it doesn't exist yet
+    pub app: Option<App>,
+    /// config vars of the app
+    pub config: HashMap<String, Option<String>>,
+    /// add-ons of the app, including the ones attached from other apps
+    pub addons: Vec<Addon>,
+    /// domains of the app
+    pub domains: Vec<Domain>,
+    /// SNI endpoints of the app
+    pub sni_endpoints: Vec<SNI>,
+    /// buildpack installations, sorted by ordinal
+    pub buildpacks: Vec<BuildpackInstallation>,
+    /// formation of the app
+    pub formation: Vec<Formation>,
+    /// log drains of the app
+    pub log_drains: Vec<LogDrain>,
+    /// webhooks of the app
+    pub webhooks: Vec<AppWebhook>,
+    /// Heroku labs features of the app
+    pub features: Vec<AppFeature>,
+    /// collaborators of the app, including the owner
+    pub collaborators: Vec<Collaborator>,
+    /// pipeline coupling of the app
+    pub pipeline_coupling: Option<PipelineCoupling>,
+}
+
+impl LiveApp {
+    /// Fetch the live state of the app `name`.
+    ///
+    /// If the app doesn't exist, an empty `LiveApp` is returned.
+    pub fn fetch<C: HerokuApiClient>(
+        api_client: &C,
+        name: &str,
+        spec: &AppSpec,
+    ) -> ApiResponse<LiveApp> {
+        let app = match api_client.request(&AppDetails {
+            app_id: name.into(),
+        }) {
+            Ok(app) => app,
+            Err(ref e) if is_not_found(e) => return Ok(LiveApp::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut live = LiveApp::default();
+        let app_id = app.id.as_str();
+
+        if spec.config.is_some() || spec.addons.is_some() {
+            // add-ons are needed to know which config vars they own
+            live.addons = api_client.request(&AddonListByApp {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.config.is_some() {
+            live.config = api_client.request(&AppConfigVarDetails {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.domains.is_some() {
+            live.domains = api_client.request(&DomainList {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.sni_endpoints.is_some() {
+            live.sni_endpoints = api_client.request(&SNIList {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.buildpacks.is_some() {
+            live.buildpacks = api_client.request(&BuildPackInstallationList {
+                app_id: app_id.into(),
+            })?;
+            live.buildpacks.sort_by_key(|b| b.ordinal);
+        }
+        if spec.formation.is_some() {
+            live.formation = api_client.request(&FormationList {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.log_drains.is_some() {
+            live.log_drains = api_client.request(&LogDrainList {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.webhooks.is_some() {
+            live.webhooks = api_client.request(&AppWebhookList {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.features.is_some() {
+            live.features = api_client.request(&AppFeatureList {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.collaborators.is_some() {
+            live.collaborators = api_client.request(&CollaboratorList {
+                app_id: app_id.into(),
+            })?;
+        }
+        if spec.pipeline.is_some() {
+            live.pipeline_coupling = match api_client.request(&PipelineCouplingByAppDetails {
+                app_id: app_id.into(),
+            }) {
+                Ok(coupling) => Some(coupling),
+                Err(ref e) if is_not_found(e) => None,
+                Err(e) => return Err(e),
+            };
+        }
+
+        live.app = Some(app);
+        Ok(live)
+    }
+}
+
+/// true if Heroku answered with a 404
+pub(crate) fn is_not_found(error: &HerokuApiFailure) -> bool {
+    match error {
+        HerokuApiFailure::Error(status, _) => *status == reqwest::StatusCode::NOT_FOUND,
+        _ => false,
+    }
+}
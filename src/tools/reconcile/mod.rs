@@ -0,0 +1,155 @@
+//! Declarative infrastructure
+//!
+//! Describe a set of apps in a TOML or YAML [`InfraSpec`][spec] and let the reconciler compare it with what is running on Heroku.
+//!
+//! Reconciling is done in two steps, first a [`Plan`][plan] is computed from the live state of every app, then the plan is applied.
+//! The plan can be printed and reviewed before anything is changed, which makes it a good fit for CI.
+//!
+//! Only the sections present in the spec are managed. Resources that exist on Heroku but not in the spec are left alone,
+//! unless the reconciler runs in [`ReconcileMode::Prune`][mode].
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::reconcile::{self, InfraSpec, ReconcileMode};
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let spec = InfraSpec::from_file("heroku.toml")?;
+//!
+//! // only compute and print the plan
+//! let plan = reconcile::reconcile(&api_client, &spec, ReconcileMode::Plan)?;
+//! println!("{}", plan);
+//!
+//! // apply it, removing anything that is not in the spec
+//! reconcile::reconcile(&api_client, &spec, ReconcileMode::Prune)?;
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [spec]: spec/struct.InfraSpec.html
+//! [plan]: plan/struct.Plan.html
+//! [mode]: enum.ReconcileMode.html
+
+pub mod apply;
+pub mod live;
+pub mod plan;
+pub mod spec;
+
+pub use apply::apply;
+pub use live::LiveApp;
+pub use plan::{Action, AppPlan, Plan};
+pub use spec::InfraSpec;
+
+use crate::endpoints::pipelines::PipelineDetails;
+use crate::framework::apiclient::HerokuApiClient;
+use crate::framework::response::HerokuApiFailure;
+use std::fmt;
+use std::fs;
+use std::io;
+
+/// Errors that can happen while reading a spec, planning or applying it.
+#[derive(Debug)]
+pub enum ReconcileError {
+    /// the spec or a file it references could not be read
+    Io(io::Error),
+    /// the spec could not be parsed or is invalid
+    Spec(String),
+    /// fetching the live state from Heroku failed
+    Api(HerokuApiFailure),
+    /// an action failed, the actions before it were applied
+    Apply {
+        app: String,
+        action: String,
+        error: HerokuApiFailure,
+    },
+}
+
+impl std::error::Error for ReconcileError {}
+
+impl fmt::Display for ReconcileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReconcileError::Io(e) => write!(f, "{}", e),
+            ReconcileError::Spec(message) => write!(f, "invalid spec: {}", message),
+            ReconcileError::Api(e) => write!(f, "{}", e),
+            ReconcileError::Apply { app, action, error } => {
+                write!(f, "{}: `{}` failed: {}", app, action, error)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for ReconcileError {
+    fn from(error: io::Error) -> Self {
+        ReconcileError::Io(error)
+    }
+}
+
+impl From<HerokuApiFailure> for ReconcileError {
+    fn from(error: HerokuApiFailure) -> Self {
+        ReconcileError::Api(error)
+    }
+}
+
+/// What [`reconcile`](fn.reconcile.html) does with the plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconcileMode {
+    /// only compute the plan
+    Plan,
+    /// apply the plan, without removing anything
+    Apply,
+    /// apply the plan, removing the resources of managed sections that are not in the spec
+    Prune,
+}
+
+/// Compute the plan to bring every app of the spec to its desired state.
+///
+/// Removals are only planned when `prune` is true.
+pub fn plan<C: HerokuApiClient>(
+    api_client: &C,
+    spec: &InfraSpec,
+    prune: bool,
+) -> Result<Plan, ReconcileError> {
+    spec.validate()?;
+
+    let mut plan = Plan::default();
+    for (name, app_spec) in &spec.apps {
+        let mut resolved = plan::Resolved::default();
+        if let Some(endpoints) = &app_spec.sni_endpoints {
+            for endpoint in endpoints {
+                resolved
+                    .certificate_chains
+                    .push(fs::read_to_string(&endpoint.certificate_chain)?);
+            }
+        }
+        if let Some(pipeline) = &app_spec.pipeline {
+            resolved.pipeline_id = match api_client.request(&PipelineDetails {
+                pipeline_id: pipeline.name.as_str().into(),
+            }) {
+                Ok(pipeline) => Some(pipeline.id),
+                Err(ref e) if live::is_not_found(e) => None,
+                Err(e) => return Err(e.into()),
+            };
+        }
+
+        let live = LiveApp::fetch(api_client, name, app_spec)?;
+        plan.apps
+            .push(plan::plan_app(name, app_spec, &live, &resolved, prune));
+    }
+    Ok(plan)
+}
+
+/// Plan and, depending on the mode, apply the spec. The computed plan is returned.
+pub fn reconcile<C: HerokuApiClient>(
+    api_client: &C,
+    spec: &InfraSpec,
+    mode: ReconcileMode,
+) -> Result<Plan, ReconcileError> {
+    let plan = plan(api_client, spec, mode == ReconcileMode::Prune)?;
+    if mode != ReconcileMode::Plan {
+        apply(api_client, &plan)?;
+    }
+    Ok(plan)
+}
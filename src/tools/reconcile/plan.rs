@@ -0,0 +1,959 @@
+//Anything related to diffing a spec against the live state goes here.
+
+use super::live::LiveApp;
+use super::spec::{AppSpec, WebhookSpec};
+use crate::endpoints::addons::Addon;
+use crate::tools::config_sync::{ConfigChange, ConfigPlan};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
+
+/// A single change to apply to an app.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// create the app
+    CreateApp {
+        region: Option<String>,
+        stack: Option<String>,
+        team: Option<String>,
+        space: Option<String>,
+    },
+    /// change the stack used by the next build
+    UpdateStack { stack: String },
+    /// add, change and remove config vars with a single PATCH
+    UpdateConfig(ConfigPlan),
+    /// provision an add-on
+    CreateAddon {
+        plan: String,
+        name: Option<String>,
+        attachment_name: Option<String>,
+    },
+    /// change the plan of an add-on
+    UpdateAddonPlan {
+        addon: String,
+        from: String,
+        to: String,
+    },
+    /// attach an add-on owned by another app
+    AttachAddon { addon: String, name: String },
+    /// deprovision an add-on
+    DeleteAddon { addon: String, plan: String },
+    /// add a custom domain
+    CreateDomain { hostname: String },
+    /// remove a custom domain
+    DeleteDomain { hostname: String },
+    /// create an SNI endpoint from the certificate and key files
+    CreateSni {
+        certificate_chain: PathBuf,
+        private_key: PathBuf,
+    },
+    /// remove an SNI endpoint
+    DeleteSni { id: String, name: String },
+    /// replace the buildpacks of the app
+    SetBuildpacks { buildpacks: Vec<String> },
+    /// scale or resize a process type
+    UpdateFormation {
+        process_type: String,
+        quantity: Option<i32>,
+        size: Option<String>,
+    },
+    /// add a log drain
+    CreateLogDrain { url: String },
+    /// remove a log drain
+    DeleteLogDrain { id: String, url: String },
+    /// subscribe a webhook
+    CreateWebhook(WebhookSpec),
+    /// change the entities or level of a webhook, the authorization and secret of the spec are sent again
+    UpdateWebhook { id: String, webhook: WebhookSpec },
+    /// remove a webhook
+    DeleteWebhook { id: String, url: String },
+    /// enable or disable a Heroku labs feature
+    SetFeature { name: String, enabled: bool },
+    /// add a collaborator
+    AddCollaborator { email: String },
+    /// remove a collaborator
+    RemoveCollaborator { id: String, email: String },
+    /// couple the app to a pipeline
+    CouplePipeline {
+        pipeline_id: String,
+        pipeline: String,
+        stage: String,
+    },
+    /// move the app to another stage of its pipeline
+    UpdateCouplingStage { coupling_id: String, stage: String },
+    /// remove the app from its pipeline
+    DecouplePipeline { coupling_id: String },
+}
+
+impl Action {
+    /// true if the action removes something from the app
+    pub fn is_removal(&self) -> bool {
+        match self {
+            Action::DeleteAddon { .. }
+            | Action::DeleteDomain { .. }
+            | Action::DeleteSni { .. }
+            | Action::DeleteLogDrain { .. }
+            | Action::DeleteWebhook { .. }
+            | Action::RemoveCollaborator { .. }
+            | Action::DecouplePipeline { .. } => true,
+            Action::UpdateConfig(config) => config.removals().next().is_some(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::CreateApp {
+                region,
+                stack,
+                team,
+                space,
+            } => {
+                write!(f, "+ app")?;
+                let attributes = [
+                    ("region", region),
+                    ("stack", stack),
+                    ("team", team),
+                    ("space", space),
+                ];
+                for (name, value) in attributes.iter() {
+                    if let Some(value) = value {
+                        write!(f, " {}={}", name, value)?;
+                    }
+                }
+                Ok(())
+            }
+            Action::UpdateStack { stack } => write!(f, "~ stack {}", stack),
+            Action::UpdateConfig(config) => {
+                write!(f, "~ config vars")?;
+                for change in &config.changes {
+                    write!(f, "\n    {}", change)?;
+                }
+                Ok(())
+            }
+            Action::CreateAddon { plan, name, .. } => match name {
+                Some(name) => write!(f, "+ addon {} ({})", name, plan),
+                None => write!(f, "+ addon {}", plan),
+            },
+            Action::UpdateAddonPlan { addon, from, to } => {
+                write!(f, "~ addon {} {} -> {}", addon, from, to)
+            }
+            Action::AttachAddon { addon, name } => write!(f, "+ attachment {} ({})", name, addon),
+            Action::DeleteAddon { addon, plan } => write!(f, "- addon {} ({})", addon, plan),
+            Action::CreateDomain { hostname } => write!(f, "+ domain {}", hostname),
+            Action::DeleteDomain { hostname } => write!(f, "- domain {}", hostname),
+            Action::CreateSni {
+                certificate_chain, ..
+            } => write!(f, "+ sni endpoint {}", certificate_chain.display()),
+            Action::DeleteSni { name, .. } => write!(f, "- sni endpoint {}", name),
+            Action::SetBuildpacks { buildpacks } => {
+                write!(f, "~ buildpacks [{}]", buildpacks.join(", "))
+            }
+            Action::UpdateFormation {
+                process_type,
+                quantity,
+                size,
+            } => {
+                write!(f, "~ formation {}", process_type)?;
+                if let Some(quantity) = quantity {
+                    write!(f, " quantity={}", quantity)?;
+                }
+                if let Some(size) = size {
+                    write!(f, " size={}", size)?;
+                }
+                Ok(())
+            }
+            Action::CreateLogDrain { url } => write!(f, "+ log drain {}", url),
+            Action::DeleteLogDrain { url, .. } => write!(f, "- log drain {}", url),
+            Action::CreateWebhook(webhook) => write!(
+                f,
+                "+ webhook {} level={} include=[{}]",
+                webhook.url,
+                webhook.level,
+                webhook.include.join(", ")
+            ),
+            Action::UpdateWebhook { webhook, .. } => write!(
+                f,
+                "~ webhook {} level={} include=[{}]",
+                webhook.url,
+                webhook.level,
+                webhook.include.join(", ")
+            ),
+            Action::DeleteWebhook { url, .. } => write!(f, "- webhook {}", url),
+            Action::SetFeature { name, enabled } => {
+                let state = if *enabled { "enabled" } else { "disabled" };
+                write!(f, "~ feature {} {}", name, state)
+            }
+            Action::AddCollaborator { email } => write!(f, "+ collaborator {}", email),
+            Action::RemoveCollaborator { email, .. } => write!(f, "- collaborator {}", email),
+            Action::CouplePipeline {
+                pipeline, stage, ..
+            } => write!(f, "+ pipeline {} stage={}", pipeline, stage),
+            Action::UpdateCouplingStage { stage, .. } => write!(f, "~ pipeline stage={}", stage),
+            Action::DecouplePipeline { .. } => write!(f, "- pipeline coupling"),
+        }
+    }
+}
+
+/// The actions to reconcile a single app, in the order they will be applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppPlan {
+    /// name of the app
+    pub app: String,
+    /// team owning the app, collaborators are managed through the team endpoints when set
+    pub team: Option<String>,
+    /// the actions to apply
+    pub actions: Vec<Action>,
+    /// drift that can't be reconciled, e.g. a different region on an existing app
+    pub warnings: Vec<String>,
+}
+
+/// Plan
+///
+/// The actions needed to bring every app of a spec to its desired state.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plan {
+    /// one plan per app of the spec, sorted by app name
+    pub apps: Vec<AppPlan>,
+}
+
+impl Plan {
+    /// true if applying the plan would not change anything
+    pub fn is_empty(&self) -> bool {
+        self.apps.iter().all(|app| app.actions.is_empty())
+    }
+
+    /// number of actions across all apps
+    pub fn len(&self) -> usize {
+        self.apps.iter().map(|app| app.actions.len()).sum()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for app in &self.apps {
+            writeln!(f, "{}:", app.app)?;
+            if app.actions.is_empty() {
+                writeln!(f, "  up to date")?;
+            }
+            for action in &app.actions {
+                writeln!(f, "  {}", action)?;
+            }
+            for warning in &app.warnings {
+                writeln!(f, "  ! {}", warning)?;
+            }
+        }
+        let removals = self
+            .apps
+            .iter()
+            .flat_map(|app| app.actions.iter())
+            .filter(|action| action.is_removal())
+            .count();
+        writeln!(
+            f,
+            "{} actions, {} removing resources.",
+            self.len(),
+            removals
+        )
+    }
+}
+
+/// Values needed by the diff that come from outside the live app state.
+#[derive(Debug, Clone, Default)]
+pub struct Resolved {
+    /// id of the pipeline named in the spec
+    pub pipeline_id: Option<String>,
+    /// content of the certificate chain files of the spec, in the same order
+    pub certificate_chains: Vec<String>,
+}
+
+/// Diff the spec of an app against its live state.
+///
+/// Removals are only planned when `prune` is true.
+pub fn plan_app(
+    name: &str,
+    spec: &AppSpec,
+    live: &LiveApp,
+    resolved: &Resolved,
+    prune: bool,
+) -> AppPlan {
+    let mut plan = AppPlan {
+        app: name.to_string(),
+        team: spec.team.clone(),
+        actions: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    diff_app(spec, live, &mut plan);
+    diff_addons(spec, live, prune, &mut plan);
+    diff_config(spec, live, prune, &mut plan);
+    diff_buildpacks(spec, live, &mut plan);
+    diff_domains(spec, live, prune, &mut plan);
+    diff_sni(spec, live, resolved, prune, &mut plan);
+    diff_formation(spec, live, prune, &mut plan);
+    diff_log_drains(spec, live, prune, &mut plan);
+    diff_webhooks(spec, live, prune, &mut plan);
+    diff_features(spec, live, &mut plan);
+    diff_collaborators(spec, live, prune, &mut plan);
+    diff_pipeline(spec, live, resolved, prune, &mut plan);
+
+    plan
+}
+
+fn diff_app(spec: &AppSpec, live: &LiveApp, plan: &mut AppPlan) {
+    let app = match &live.app {
+        Some(app) => app,
+        None => {
+            plan.actions.push(Action::CreateApp {
+                region: spec.region.clone(),
+                stack: spec.stack.clone(),
+                team: spec.team.clone(),
+                space: spec.space.clone(),
+            });
+            return;
+        }
+    };
+
+    if let Some(region) = &spec.region {
+        if &app.region.name != region {
+            plan.warnings.push(format!(
+                "region is {}, an app can't change region",
+                app.region.name
+            ));
+        }
+    }
+    if let Some(team) = &spec.team {
+        let live_team = app.team.as_ref().map(|t| t.name.as_str());
+        if live_team != Some(team.as_str()) {
+            plan.warnings.push(format!(
+                "team is {}, transfer the app to change it",
+                live_team.unwrap_or("none")
+            ));
+        }
+    }
+    if let Some(space) = &spec.space {
+        let live_space = app.space.as_ref().map(|s| s.name.as_str());
+        if live_space != Some(space.as_str()) {
+            plan.warnings.push(format!(
+                "space is {}, an app can't change space",
+                live_space.unwrap_or("none")
+            ));
+        }
+    }
+    if let Some(stack) = &spec.stack {
+        if &app.build_stack.name != stack {
+            plan.actions.push(Action::UpdateStack {
+                stack: stack.clone(),
+            });
+        }
+    }
+}
+
+fn owned_addons(live: &LiveApp) -> impl Iterator<Item = &Addon> {
+    let app_id = live.app.as_ref().map(|app| app.id.clone());
+    live.addons
+        .iter()
+        .filter(move |addon| Some(&addon.app.id) == app_id.as_ref())
+}
+
+fn diff_addons(spec: &AppSpec, live: &LiveApp, prune: bool, plan: &mut AppPlan) {
+    let addons = match &spec.addons {
+        Some(addons) => addons,
+        None => return,
+    };
+
+    let mut matched: HashSet<String> = HashSet::new();
+    for addon in addons {
+        let found = owned_addons(live).find(|live_addon| {
+            !matched.contains(&live_addon.id)
+                && match &addon.name {
+                    Some(name) => &live_addon.name == name,
+                    None => live_addon.addon_service.name == addon.service(),
+                }
+        });
+
+        match found {
+            Some(live_addon) => {
+                matched.insert(live_addon.id.clone());
+                // a plan without `:` means the default plan, which can't be compared
+                if addon.plan.contains(':') && live_addon.plan.name != addon.plan {
+                    plan.actions.push(Action::UpdateAddonPlan {
+                        addon: live_addon.name.clone(),
+                        from: live_addon.plan.name.clone(),
+                        to: addon.plan.clone(),
+                    });
+                }
+            }
+            None => plan.actions.push(Action::CreateAddon {
+                plan: addon.plan.clone(),
+                name: addon.name.clone(),
+                attachment_name: addon.attachment_name.clone(),
+            }),
+        }
+    }
+
+    if prune {
+        for live_addon in owned_addons(live) {
+            if !matched.contains(&live_addon.id) {
+                plan.actions.push(Action::DeleteAddon {
+                    addon: live_addon.name.clone(),
+                    plan: live_addon.plan.name.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn diff_config(spec: &AppSpec, live: &LiveApp, prune: bool, plan: &mut AppPlan) {
+    let desired = match &spec.config {
+        Some(config) => config,
+        None => return,
+    };
+
+    let addon_vars: HashSet<&String> = live
+        .addons
+        .iter()
+        .flat_map(|addon| addon.config_vars.iter())
+        .collect();
+
+    let mut config = ConfigPlan::diff(&live.config, desired);
+    config.changes.retain(|change| match change {
+        ConfigChange::Remove { key, .. } => prune && !addon_vars.contains(key),
+        _ => true,
+    });
+
+    if !config.is_empty() {
+        plan.actions.push(Action::UpdateConfig(config));
+    }
+}
+
+fn diff_buildpacks(spec: &AppSpec, live: &LiveApp, plan: &mut AppPlan) {
+    let buildpacks = match &spec.buildpacks {
+        Some(buildpacks) => buildpacks,
+        None => return,
+    };
+
+    let same = buildpacks.len() == live.buildpacks.len()
+        && buildpacks
+            .iter()
+            .zip(live.buildpacks.iter())
+            .all(|(desired, installed)| {
+                &installed.buildpack.url == desired
+                    || installed.buildpack.name.as_ref() == Some(desired)
+            });
+
+    if !same {
+        plan.actions.push(Action::SetBuildpacks {
+            buildpacks: buildpacks.clone(),
+        });
+    }
+}
+
+fn diff_domains(spec: &AppSpec, live: &LiveApp, prune: bool, plan: &mut AppPlan) {
+    let domains = match &spec.domains {
+        Some(domains) => domains,
+        None => return,
+    };
+
+    let custom: Vec<&str> = live
+        .domains
+        .iter()
+        .filter(|domain| domain.kind != "heroku")
+        .map(|domain| domain.hostname.as_str())
+        .collect();
+
+    for hostname in domains {
+        if !custom
+            .iter()
+            .any(|live| live.eq_ignore_ascii_case(hostname))
+        {
+            plan.actions.push(Action::CreateDomain {
+                hostname: hostname.clone(),
+            });
+        }
+    }
+
+    if prune {
+        for hostname in custom {
+            if !domains.iter().any(|d| d.eq_ignore_ascii_case(hostname)) {
+                plan.actions.push(Action::DeleteDomain {
+                    hostname: hostname.to_string(),
+                });
+            }
+        }
+    }
+}
+
+fn diff_sni(spec: &AppSpec, live: &LiveApp, resolved: &Resolved, prune: bool, plan: &mut AppPlan) {
+    let endpoints = match &spec.sni_endpoints {
+        Some(endpoints) => endpoints,
+        None => return,
+    };
+
+    let mut matched: HashSet<&str> = HashSet::new();
+    for (endpoint, chain) in endpoints.iter().zip(resolved.certificate_chains.iter()) {
+        let found = live
+            .sni_endpoints
+            .iter()
+            .find(|sni| sni.certificate_chain.trim() == chain.trim());
+        match found {
+            Some(sni) => {
+                matched.insert(&sni.id);
+            }
+            None => plan.actions.push(Action::CreateSni {
+                certificate_chain: endpoint.certificate_chain.clone(),
+                private_key: endpoint.private_key.clone(),
+            }),
+        }
+    }
+
+    if prune {
+        for sni in &live.sni_endpoints {
+            if !matched.contains(sni.id.as_str()) {
+                plan.actions.push(Action::DeleteSni {
+                    id: sni.id.clone(),
+                    name: sni.name.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn diff_formation(spec: &AppSpec, live: &LiveApp, prune: bool, plan: &mut AppPlan) {
+    let formation = match &spec.formation {
+        Some(formation) => formation,
+        None => return,
+    };
+
+    for (process_type, desired) in formation {
+        let current = live.formation.iter().find(|f| &f.r#type == process_type);
+        let current = match current {
+            Some(current) => current,
+            None => {
+                plan.warnings.push(format!(
+                    "process type `{}` doesn't exist yet, deploy the app before scaling it",
+                    process_type
+                ));
+                continue;
+            }
+        };
+
+        let quantity = desired.quantity.filter(|q| *q != current.quantity);
+        let size = desired
+            .size
+            .clone()
+            .filter(|size| !size.eq_ignore_ascii_case(&current.size));
+        if quantity.is_some() || size.is_some() {
+            plan.actions.push(Action::UpdateFormation {
+                process_type: process_type.clone(),
+                quantity,
+                size,
+            });
+        }
+    }
+
+    if prune {
+        for current in &live.formation {
+            if !formation.contains_key(&current.r#type) && current.quantity > 0 {
+                plan.actions.push(Action::UpdateFormation {
+                    process_type: current.r#type.clone(),
+                    quantity: Some(0),
+                    size: None,
+                });
+            }
+        }
+    }
+}
+
+fn diff_log_drains(spec: &AppSpec, live: &LiveApp, prune: bool, plan: &mut AppPlan) {
+    let drains = match &spec.log_drains {
+        Some(drains) => drains,
+        None => return,
+    };
+
+    // drains created by add-ons are managed by the add-on
+    let app_drains: Vec<_> = live
+        .log_drains
+        .iter()
+        .filter(|drain| drain.addon.is_none())
+        .collect();
+
+    for url in drains {
+        if !app_drains.iter().any(|drain| &drain.url == url) {
+            plan.actions
+                .push(Action::CreateLogDrain { url: url.clone() });
+        }
+    }
+
+    if prune {
+        for drain in app_drains {
+            if !drains.contains(&drain.url) {
+                plan.actions.push(Action::DeleteLogDrain {
+                    id: drain.id.clone(),
+                    url: drain.url.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn diff_webhooks(spec: &AppSpec, live: &LiveApp, prune: bool, plan: &mut AppPlan) {
+    let webhooks = match &spec.webhooks {
+        Some(webhooks) => webhooks,
+        None => return,
+    };
+
+    for webhook in webhooks {
+        match live.webhooks.iter().find(|live| live.url == webhook.url) {
+            Some(current) => {
+                let mut desired_include = webhook.include.clone();
+                let mut current_include = current.include.clone();
+                desired_include.sort();
+                current_include.sort();
+                if desired_include != current_include || current.level != webhook.level {
+                    plan.actions.push(Action::UpdateWebhook {
+                        id: current.id.clone(),
+                        webhook: webhook.clone(),
+                    });
+                }
+            }
+            None => plan.actions.push(Action::CreateWebhook(webhook.clone())),
+        }
+    }
+
+    if prune {
+        for current in &live.webhooks {
+            if !webhooks.iter().any(|webhook| webhook.url == current.url) {
+                plan.actions.push(Action::DeleteWebhook {
+                    id: current.id.clone(),
+                    url: current.url.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn diff_features(spec: &AppSpec, live: &LiveApp, plan: &mut AppPlan) {
+    let features = match &spec.features {
+        Some(features) => features,
+        None => return,
+    };
+
+    for (name, enabled) in features {
+        match live.features.iter().find(|feature| &feature.name == name) {
+            Some(feature) if feature.enabled == *enabled => {}
+            Some(_) => plan.actions.push(Action::SetFeature {
+                name: name.clone(),
+                enabled: *enabled,
+            }),
+            // features of a new app are only known once it exists
+            None if live.app.is_none() => plan.actions.push(Action::SetFeature {
+                name: name.clone(),
+                enabled: *enabled,
+            }),
+            None => plan
+                .warnings
+                .push(format!("feature `{}` is not available on the app", name)),
+        }
+    }
+}
+
+fn diff_collaborators(spec: &AppSpec, live: &LiveApp, prune: bool, plan: &mut AppPlan) {
+    let collaborators = match &spec.collaborators {
+        Some(collaborators) => collaborators,
+        None => return,
+    };
+
+    let current: Vec<_> = live
+        .collaborators
+        .iter()
+        .filter(|c| c.role.as_deref() != Some("owner"))
+        .collect();
+
+    for email in collaborators {
+        if !current
+            .iter()
+            .any(|c| c.user.email.eq_ignore_ascii_case(email))
+        {
+            plan.actions.push(Action::AddCollaborator {
+                email: email.clone(),
+            });
+        }
+    }
+
+    if prune {
+        for collaborator in current {
+            if !collaborators
+                .iter()
+                .any(|email| email.eq_ignore_ascii_case(&collaborator.user.email))
+            {
+                plan.actions.push(Action::RemoveCollaborator {
+                    id: collaborator.id.clone(),
+                    email: collaborator.user.email.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn diff_pipeline(
+    spec: &AppSpec,
+    live: &LiveApp,
+    resolved: &Resolved,
+    prune: bool,
+    plan: &mut AppPlan,
+) {
+    let pipeline = match &spec.pipeline {
+        Some(pipeline) => pipeline,
+        None => return,
+    };
+    let pipeline_id = match &resolved.pipeline_id {
+        Some(id) => id,
+        None => {
+            plan.warnings
+                .push(format!("pipeline `{}` was not found", pipeline.name));
+            return;
+        }
+    };
+
+    match &live.pipeline_coupling {
+        Some(coupling) if &coupling.pipeline.id == pipeline_id => {
+            if coupling.stage != pipeline.stage {
+                plan.actions.push(Action::UpdateCouplingStage {
+                    coupling_id: coupling.id.clone(),
+                    stage: pipeline.stage.clone(),
+                });
+            }
+        }
+        // an app is in one pipeline at most, moving it removes the current coupling
+        Some(coupling) if !prune => plan.warnings.push(format!(
+            "app is coupled to pipeline {}, prune to move it to `{}`",
+            coupling.pipeline.id, pipeline.name
+        )),
+        current => {
+            if let Some(coupling) = current {
+                plan.actions.push(Action::DecouplePipeline {
+                    coupling_id: coupling.id.clone(),
+                });
+            }
+            plan.actions.push(Action::CouplePipeline {
+                pipeline_id: pipeline_id.clone(),
+                pipeline: pipeline.name.clone(),
+                stage: pipeline.stage.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::domains::Domain;
+    use crate::endpoints::formations::Formation;
+    use crate::tools::reconcile::spec::{FormationSpec, PipelineSpec};
+    use std::collections::BTreeMap;
+
+    fn formation(process_type: &str, quantity: i32, size: &str) -> Formation {
+        serde_json::from_value(serde_json::json!({
+            "app": { "id": "app-id", "name": "my-app" },
+            "command": "run",
+            "created_at": "2020-01-01T00:00:00Z",
+            "id": format!("{}-id", process_type),
+            "quantity": quantity,
+            "size": size,
+            "type": process_type,
+            "updated_at": "2020-01-01T00:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    fn domain(hostname: &str, kind: &str) -> Domain {
+        serde_json::from_value(serde_json::json!({
+            "acm_status": null,
+            "acm_status_reason": null,
+            "app": { "id": "app-id", "name": "my-app" },
+            "cname": null,
+            "created_at": "2020-01-01T00:00:00Z",
+            "hostname": hostname,
+            "id": format!("{}-id", hostname),
+            "kind": kind,
+            "updated_at": "2020-01-01T00:00:00Z",
+            "status": "succeeded",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_plan_missing_app() {
+        let spec = AppSpec {
+            region: Some("eu".to_string()),
+            domains: Some(vec!["www.example.com".to_string()]),
+            ..AppSpec::default()
+        };
+        let plan = plan_app(
+            "my-app",
+            &spec,
+            &LiveApp::default(),
+            &Resolved::default(),
+            false,
+        );
+
+        assert_eq!(
+            plan.actions,
+            vec![
+                Action::CreateApp {
+                    region: Some("eu".to_string()),
+                    stack: None,
+                    team: None,
+                    space: None,
+                },
+                Action::CreateDomain {
+                    hostname: "www.example.com".to_string()
+                },
+            ]
+        );
+    }
+
+    fn empty_plan() -> AppPlan {
+        AppPlan {
+            app: "my-app".to_string(),
+            team: None,
+            actions: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_plan_formation() {
+        let mut formation_spec = BTreeMap::new();
+        formation_spec.insert(
+            "web".to_string(),
+            FormationSpec {
+                quantity: Some(2),
+                size: Some("standard-2x".to_string()),
+            },
+        );
+        formation_spec.insert(
+            "release".to_string(),
+            FormationSpec {
+                quantity: Some(1),
+                size: None,
+            },
+        );
+        let spec = AppSpec {
+            formation: Some(formation_spec),
+            ..AppSpec::default()
+        };
+        let live = LiveApp {
+            formation: vec![
+                formation("web", 1, "Standard-2X"),
+                formation("worker", 1, "Standard-1X"),
+            ],
+            ..LiveApp::default()
+        };
+
+        let mut plan = empty_plan();
+        diff_formation(&spec, &live, false, &mut plan);
+        assert_eq!(
+            plan.actions,
+            vec![Action::UpdateFormation {
+                process_type: "web".to_string(),
+                quantity: Some(2),
+                size: None,
+            }]
+        );
+        assert_eq!(plan.warnings.len(), 1);
+
+        let mut plan = empty_plan();
+        diff_formation(&spec, &live, true, &mut plan);
+        assert_eq!(
+            plan.actions[1],
+            Action::UpdateFormation {
+                process_type: "worker".to_string(),
+                quantity: Some(0),
+                size: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_plan_domains_prune() {
+        let spec = AppSpec {
+            domains: Some(vec!["WWW.example.com".to_string()]),
+            ..AppSpec::default()
+        };
+        let live = LiveApp {
+            domains: vec![
+                domain("my-app.herokuapp.com", "heroku"),
+                domain("www.example.com", "custom"),
+                domain("old.example.com", "custom"),
+            ],
+            ..LiveApp::default()
+        };
+
+        let mut plan = empty_plan();
+        diff_domains(&spec, &live, false, &mut plan);
+        assert!(plan.actions.is_empty());
+
+        diff_domains(&spec, &live, true, &mut plan);
+        assert_eq!(
+            plan.actions,
+            vec![Action::DeleteDomain {
+                hostname: "old.example.com".to_string()
+            }]
+        );
+        assert!(plan.actions[0].is_removal());
+    }
+
+    #[test]
+    fn test_plan_pipeline_move_needs_prune() {
+        let spec = AppSpec {
+            pipeline: Some(PipelineSpec {
+                name: "shop".to_string(),
+                stage: "staging".to_string(),
+            }),
+            ..AppSpec::default()
+        };
+        let live = LiveApp {
+            pipeline_coupling: Some(
+                serde_json::from_value(serde_json::json!({
+                    "app": { "id": "app-id" },
+                    "created_at": "2020-01-01T00:00:00Z",
+                    "id": "coupling-id",
+                    "pipeline": { "id": "other-pipeline-id" },
+                    "stage": "staging",
+                    "updated_at": "2020-01-01T00:00:00Z",
+                }))
+                .unwrap(),
+            ),
+            ..LiveApp::default()
+        };
+        let resolved = Resolved {
+            pipeline_id: Some("shop-id".to_string()),
+            ..Resolved::default()
+        };
+
+        let mut plan = empty_plan();
+        diff_pipeline(&spec, &live, &resolved, false, &mut plan);
+        assert!(plan.actions.is_empty());
+        assert_eq!(plan.warnings.len(), 1);
+
+        let mut plan = empty_plan();
+        diff_pipeline(&spec, &live, &resolved, true, &mut plan);
+        assert_eq!(
+            plan.actions,
+            vec![
+                Action::DecouplePipeline {
+                    coupling_id: "coupling-id".to_string()
+                },
+                Action::CouplePipeline {
+                    pipeline_id: "shop-id".to_string(),
+                    pipeline: "shop".to_string(),
+                    stage: "staging".to_string(),
+                },
+            ]
+        );
+    }
+}
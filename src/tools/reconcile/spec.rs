@@ -0,0 +1,306 @@
+//Anything related to the infrastructure spec file goes here.
+
+use super::ReconcileError;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// InfraSpec
+///
+/// The desired state of a set of apps, keyed by app name.
+///
+/// Every section of an [`AppSpec`](struct.AppSpec.html) is optional. A section that is left out is not managed at all,
+/// a section that is present is reconciled, and pruned in prune mode.
+///
+/// # Example (TOML):
+///
+/// ```toml
+/// [apps.my-app]
+/// region = "eu"
+/// stack = "heroku-22"
+/// team = "acme"
+/// buildpacks = ["heroku/ruby"]
+/// domains = ["www.example.com"]
+///
+/// [apps.my-app.config]
+/// RAILS_ENV = "production"
+///
+/// [[apps.my-app.addons]]
+/// plan = "heroku-postgresql:standard-0"
+///
+/// [apps.my-app.formation.web]
+/// quantity = 2
+/// size = "standard-2x"
+///
+/// [apps.my-app.pipeline]
+/// name = "my-pipeline"
+/// stage = "production"
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct InfraSpec {
+    /// the apps, keyed by app name
+    #[serde(default)]
+    pub apps: BTreeMap<String, AppSpec>,
+}
+
+/// The desired state of a single app.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct AppSpec {
+    /// name of region, only used when the app is created
+    pub region: Option<String>,
+    /// name of stack
+    pub stack: Option<String>,
+    /// name of the team owning the app, the app is personal when not set
+    pub team: Option<String>,
+    /// name of the private space, requires a team
+    pub space: Option<String>,
+    /// config vars, config vars set by add-ons are never removed
+    pub config: Option<BTreeMap<String, String>>,
+    /// add-ons owned by the app
+    pub addons: Option<Vec<AddonSpec>>,
+    /// custom domains, the default herokuapp.com domain is never removed
+    pub domains: Option<Vec<String>>,
+    /// SNI endpoints, read from certificate and key files
+    pub sni_endpoints: Option<Vec<SniSpec>>,
+    /// buildpacks, in order
+    pub buildpacks: Option<Vec<String>>,
+    /// process types, keyed by type
+    pub formation: Option<BTreeMap<String, FormationSpec>>,
+    /// log drain URLs, drains created by add-ons are never removed
+    pub log_drains: Option<Vec<String>>,
+    /// webhook subscriptions, keyed by URL
+    pub webhooks: Option<Vec<WebhookSpec>>,
+    /// Heroku labs features, by name. Features that are not listed are left as they are
+    pub features: Option<BTreeMap<String, bool>>,
+    /// emails of the collaborators, the owner is never removed
+    pub collaborators: Option<Vec<String>>,
+    /// pipeline coupling
+    pub pipeline: Option<PipelineSpec>,
+}
+
+/// An add-on owned by the app.
+///
+/// Add-ons are matched by `name` when set, otherwise by the service of the plan.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AddonSpec {
+    /// add-on service and plan, e.g. `heroku-postgresql:standard-0`
+    pub plan: String,
+    /// globally unique name of the add-on
+    pub name: Option<String>,
+    /// name of the attachment, only used when the add-on is created
+    #[serde(rename = "as")]
+    pub attachment_name: Option<String>,
+}
+
+impl AddonSpec {
+    /// the add-on service, e.g. `heroku-postgresql` for `heroku-postgresql:standard-0`
+    pub fn service(&self) -> &str {
+        self.plan.split(':').next().unwrap_or(&self.plan)
+    }
+}
+
+/// An SNI endpoint, the files are read when planning.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SniSpec {
+    /// path of the PEM encoded certificate chain
+    pub certificate_chain: PathBuf,
+    /// path of the PEM encoded private key
+    pub private_key: PathBuf,
+}
+
+/// The desired quantity and size of a process type.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct FormationSpec {
+    /// number of processes to maintain
+    pub quantity: Option<i32>,
+    /// dyno size
+    pub size: Option<String>,
+}
+
+/// A webhook subscription.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSpec {
+    /// the URL where the webhook's notification requests are sent
+    pub url: String,
+    /// the entities that the subscription provides notifications for
+    pub include: Vec<String>,
+    /// one of: "notify" or "sync"
+    pub level: String,
+    /// a custom Authorization header, only used when the webhook is created
+    pub authorization: Option<String>,
+    /// a value used to sign the notifications, only used when the webhook is created
+    pub secret: Option<String>,
+}
+
+/// The pipeline the app is coupled to.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PipelineSpec {
+    /// pipeline id or name
+    pub name: String,
+    /// one of: "test", "review", "development", "staging" or "production"
+    pub stage: String,
+}
+
+impl InfraSpec {
+    /// Parse a spec from TOML.
+    pub fn from_toml(input: &str) -> Result<InfraSpec, ReconcileError> {
+        toml::from_str(input).map_err(|e| ReconcileError::Spec(e.to_string()))
+    }
+
+    /// Parse a spec from YAML.
+    pub fn from_yaml(input: &str) -> Result<InfraSpec, ReconcileError> {
+        serde_yaml::from_str(input).map_err(|e| ReconcileError::Spec(e.to_string()))
+    }
+
+    /// Read a spec from disk, `.yml` and `.yaml` files are parsed as YAML, anything else as TOML.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<InfraSpec, ReconcileError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("yml") | Some("yaml") => InfraSpec::from_yaml(&content),
+            _ => InfraSpec::from_toml(&content),
+        }
+    }
+
+    /// Check the spec for values Heroku would reject.
+    pub fn validate(&self) -> Result<(), ReconcileError> {
+        let mut problems = Vec::new();
+
+        for (name, app) in &self.apps {
+            let valid_name = name.len() >= 3
+                && name.len() <= 30
+                && name.starts_with(|c: char| c.is_ascii_lowercase())
+                && !name.ends_with('-')
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+            if !valid_name {
+                problems.push(format!("`{}` is not a valid app name", name));
+            }
+            if app.space.is_some() && app.team.is_none() {
+                problems.push(format!("{}: a space requires a team", name));
+            }
+            if let Some(formation) = &app.formation {
+                for (process_type, settings) in formation {
+                    if settings.quantity.unwrap_or(0) < 0 {
+                        problems.push(format!(
+                            "{}: formation.{}.quantity can't be negative",
+                            name, process_type
+                        ));
+                    }
+                }
+            }
+            if let Some(webhooks) = &app.webhooks {
+                for webhook in webhooks {
+                    if webhook.level != "notify" && webhook.level != "sync" {
+                        problems.push(format!(
+                            "{}: webhook {} level must be `notify` or `sync`",
+                            name, webhook.url
+                        ));
+                    }
+                }
+            }
+            if let Some(pipeline) = &app.pipeline {
+                let stages = ["test", "review", "development", "staging", "production"];
+                if !stages.contains(&pipeline.stage.as_str()) {
+                    problems.push(format!(
+                        "{}: `{}` is not a valid pipeline stage",
+                        name, pipeline.stage
+                    ));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ReconcileError::Spec(problems.join(", ")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = r#"
+[apps.my-app]
+region = "eu"
+domains = ["www.example.com"]
+
+[apps.my-app.config]
+RAILS_ENV = "production"
+
+[[apps.my-app.addons]]
+plan = "heroku-postgresql:standard-0"
+as = "DATABASE"
+
+[apps.my-app.formation.web]
+quantity = 2
+"#;
+
+    #[test]
+    fn test_from_toml() {
+        let spec = InfraSpec::from_toml(TOML).unwrap();
+        let app = &spec.apps["my-app"];
+
+        assert_eq!(app.region.as_deref(), Some("eu"));
+        assert_eq!(app.config.as_ref().unwrap()["RAILS_ENV"], "production");
+        let addon = &app.addons.as_ref().unwrap()[0];
+        assert_eq!(addon.service(), "heroku-postgresql");
+        assert_eq!(addon.attachment_name.as_deref(), Some("DATABASE"));
+        assert_eq!(app.formation.as_ref().unwrap()["web"].quantity, Some(2));
+        assert!(app.buildpacks.is_none());
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_from_yaml_matches_toml() {
+        let yaml = "
+apps:
+  my-app:
+    region: eu
+    domains: [www.example.com]
+    config:
+      RAILS_ENV: production
+    addons:
+      - plan: heroku-postgresql:standard-0
+        as: DATABASE
+    formation:
+      web:
+        quantity: 2
+";
+        assert_eq!(
+            InfraSpec::from_yaml(yaml).unwrap(),
+            InfraSpec::from_toml(TOML).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let spec = InfraSpec::from_toml(
+            r#"
+[apps.Bad_Name]
+space = "private"
+
+[apps.ok-app.pipeline]
+name = "pipe"
+stage = "prod"
+"#,
+        )
+        .unwrap();
+
+        match spec.validate() {
+            Err(ReconcileError::Spec(message)) => {
+                assert!(message.contains("`Bad_Name` is not a valid app name"));
+                assert!(message.contains("a space requires a team"));
+                assert!(message.contains("`prod` is not a valid pipeline stage"));
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
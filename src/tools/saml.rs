@@ -0,0 +1,281 @@
+//! SAML identity provider metadata
+//!
+//! Identity providers (Okta, Azure AD, OneLogin...) publish their SAML configuration as a metadata XML file.
+//! [`IdpMetadata`][metadata] reads the entity id, signing certificate, single sign on and single log out URLs out of it,
+//! which are the parameters Heroku needs to set up SSO for a team.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::saml::IdpMetadata;
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let metadata = IdpMetadata::from_file("okta-metadata.xml")?;
+//! let provider = api_client.request(&metadata.team_create("TEAM_NAME"))?;
+//!
+//! println!("Identity provider {} created", provider.id);
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [metadata]: struct.IdpMetadata.html
+
+use crate::endpoints::ids::TeamRef;
+use crate::endpoints::teams::{TeamIdentityProviderCreate, TeamIdentityProviderCreateParams};
+use roxmltree::{Document, Node};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Bindings of the sign on and log out services, by order of preference. Heroku redirects the browser to them.
+const BINDINGS: [&str; 2] = [
+    "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect",
+    "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST",
+];
+
+/// SamlError
+///
+/// Returned when an identity provider metadata file can't be read or doesn't describe a SAML identity provider.
+#[derive(Debug)]
+pub enum SamlError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not valid XML
+    Xml(roxmltree::Error),
+    /// An element or attribute required by Heroku is missing, e.g. `IDPSSODescriptor`
+    Missing(&'static str),
+}
+
+impl Error for SamlError {}
+
+impl fmt::Display for SamlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SamlError::Io(err) => write!(f, "{}", err),
+            SamlError::Xml(err) => write!(f, "invalid metadata XML: {}", err),
+            SamlError::Missing(name) => write!(f, "the metadata has no {}", name),
+        }
+    }
+}
+
+impl From<std::io::Error> for SamlError {
+    fn from(error: std::io::Error) -> Self {
+        SamlError::Io(error)
+    }
+}
+
+impl From<roxmltree::Error> for SamlError {
+    fn from(error: roxmltree::Error) -> Self {
+        SamlError::Xml(error)
+    }
+}
+
+/// The SAML configuration of an identity provider, as published in its metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdpMetadata {
+    /// `entityID` of the identity provider
+    pub entity_id: String,
+    /// signing certificate of the identity provider, PEM encoded
+    pub certificate: String,
+    /// location of the single sign on service
+    pub sso_target_url: String,
+    /// location of the single log out service, if the identity provider has one
+    pub slo_target_url: Option<String>,
+}
+
+impl IdpMetadata {
+    /// Read the metadata XML file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<IdpMetadata, SamlError> {
+        IdpMetadata::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Parse metadata XML.
+    ///
+    /// The document is either an `EntityDescriptor` or an `EntitiesDescriptor`, in which case the first entity with an
+    /// `IDPSSODescriptor` is used. Redirect bindings are preferred over POST bindings for the service URLs.
+    pub fn parse(xml: &str) -> Result<IdpMetadata, SamlError> {
+        let document = Document::parse(xml)?;
+        let descriptor = document
+            .descendants()
+            .find(|node| is(node, "IDPSSODescriptor"))
+            .ok_or(SamlError::Missing("IDPSSODescriptor"))?;
+        let entity_id = descriptor
+            .ancestors()
+            .find(|node| is(node, "EntityDescriptor"))
+            .and_then(|entity| entity.attribute("entityID"))
+            .ok_or(SamlError::Missing("entityID"))?;
+
+        let certificate = descriptor
+            .children()
+            .filter(|node| is(node, "KeyDescriptor"))
+            .filter(|key| key.attribute("use") != Some("encryption"))
+            .flat_map(|key| key.descendants())
+            .find(|node| is(node, "X509Certificate"))
+            .and_then(|certificate| certificate.text())
+            .ok_or(SamlError::Missing("signing X509Certificate"))?;
+
+        let sso_target_url = service_location(descriptor, "SingleSignOnService").ok_or(
+            SamlError::Missing("SingleSignOnService with HTTP-Redirect or HTTP-POST binding"),
+        )?;
+        let slo_target_url = service_location(descriptor, "SingleLogoutService");
+
+        Ok(IdpMetadata {
+            entity_id: entity_id.trim().to_string(),
+            certificate: pem(certificate),
+            sso_target_url: sso_target_url.to_string(),
+            slo_target_url: slo_target_url.map(str::to_string),
+        })
+    }
+
+    /// The parameters to create an identity provider with this configuration.
+    pub fn create_params(&self) -> TeamIdentityProviderCreateParams<'_> {
+        TeamIdentityProviderCreateParams {
+            certificate: Cow::from(&self.certificate),
+            entity_id: Cow::from(&self.entity_id),
+            slo_target_url: self.slo_target_url.as_deref().map(Cow::from),
+            sso_target_url: Cow::from(&self.sso_target_url),
+        }
+    }
+
+    /// The endpoint creating an identity provider with this configuration for `team_id`.
+    pub fn team_create<'a>(
+        &'a self,
+        team_id: impl Into<TeamRef<'a>>,
+    ) -> TeamIdentityProviderCreate<'a> {
+        TeamIdentityProviderCreate {
+            team_id: team_id.into(),
+            params: self.create_params(),
+        }
+    }
+}
+
+/// Whether `node` is the element `name`, whatever its namespace prefix.
+fn is(node: &Node, name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == name
+}
+
+/// Location of the preferred binding of the `service` elements of `descriptor`, none without a Redirect or POST one.
+fn service_location<'a>(descriptor: Node<'a, '_>, service: &str) -> Option<&'a str> {
+    let services: Vec<Node> = descriptor
+        .children()
+        .filter(|node| is(node, service))
+        .collect();
+    BINDINGS
+        .iter()
+        .find_map(|binding| {
+            services
+                .iter()
+                .find(|node| node.attribute("Binding") == Some(binding))
+        })
+        .and_then(|node| node.attribute("Location"))
+}
+
+/// PEM encode the base64 contents of an `X509Certificate` element, which may be wrapped at any width.
+fn pem(base64: &str) -> String {
+    let base64: String = base64.split_whitespace().collect();
+    let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+    for line in base64.as_bytes().chunks(64) {
+        pem.push_str(&String::from_utf8_lossy(line));
+        pem.push('\n');
+    }
+    pem.push_str("-----END CERTIFICATE-----\n");
+    pem
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<md:EntityDescriptor xmlns:md="urn:oasis:names:tc:SAML:2.0:metadata" entityID="http://www.okta.com/exk1abcd">
+  <md:IDPSSODescriptor WantAuthnRequestsSigned="false" protocolSupportEnumeration="urn:oasis:names:tc:SAML:2.0:protocol">
+    <md:KeyDescriptor use="encryption">
+      <ds:KeyInfo xmlns:ds="http://www.w3.org/2000/09/xmldsig#">
+        <ds:X509Data><ds:X509Certificate>RU5DUllQVElPTg==</ds:X509Certificate></ds:X509Data>
+      </ds:KeyInfo>
+    </md:KeyDescriptor>
+    <md:KeyDescriptor use="signing">
+      <ds:KeyInfo xmlns:ds="http://www.w3.org/2000/09/xmldsig#">
+        <ds:X509Data>
+          <ds:X509Certificate>
+            MIIDpDCCAoygAwIBAgIGAXa1X2ZHMA0GCSqGSIb3DQEBCwUAMIGSMQswCQYDVQQGEwJVUzETMBEG
+            A1UECAwKQ2FsaWZvcm5pYQ==
+          </ds:X509Certificate>
+        </ds:X509Data>
+      </ds:KeyInfo>
+    </md:KeyDescriptor>
+    <md:SingleLogoutService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST" Location="https://example.okta.com/app/slo"/>
+    <md:SingleSignOnService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST" Location="https://example.okta.com/app/sso/post"/>
+    <md:SingleSignOnService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect" Location="https://example.okta.com/app/sso/redirect"/>
+  </md:IDPSSODescriptor>
+</md:EntityDescriptor>"#;
+
+    #[test]
+    fn parses_idp_metadata() {
+        let metadata = IdpMetadata::parse(METADATA).unwrap();
+        assert_eq!(metadata.entity_id, "http://www.okta.com/exk1abcd");
+        assert_eq!(
+            metadata.certificate,
+            "-----BEGIN CERTIFICATE-----\n\
+             MIIDpDCCAoygAwIBAgIGAXa1X2ZHMA0GCSqGSIb3DQEBCwUAMIGSMQswCQYDVQQG\n\
+             EwJVUzETMBEGA1UECAwKQ2FsaWZvcm5pYQ==\n\
+             -----END CERTIFICATE-----\n"
+        );
+        assert_eq!(
+            metadata.sso_target_url,
+            "https://example.okta.com/app/sso/redirect"
+        );
+        assert_eq!(
+            metadata.slo_target_url.as_deref(),
+            Some("https://example.okta.com/app/slo")
+        );
+
+        let params = metadata.create_params();
+        assert_eq!(params.entity_id, "http://www.okta.com/exk1abcd");
+        assert_eq!(params.sso_target_url, metadata.sso_target_url);
+    }
+
+    #[test]
+    fn slo_is_optional() {
+        let xml = r#"<EntitiesDescriptor xmlns="urn:oasis:names:tc:SAML:2.0:metadata">
+  <EntityDescriptor entityID="https://sp.example.com"><SPSSODescriptor/></EntityDescriptor>
+  <EntityDescriptor entityID="https://idp.example.com">
+    <IDPSSODescriptor>
+      <KeyDescriptor><KeyInfo><X509Data><X509Certificate>QUJD</X509Certificate></X509Data></KeyInfo></KeyDescriptor>
+      <SingleSignOnService Binding="urn:oasis:names:tc:SAML:2.0:bindings:SOAP" Location="https://idp.example.com/soap"/>
+      <SingleLogoutService Binding="urn:oasis:names:tc:SAML:2.0:bindings:SOAP" Location="https://idp.example.com/slo"/>
+    </IDPSSODescriptor>
+  </EntityDescriptor>
+</EntitiesDescriptor>"#;
+        let err = IdpMetadata::parse(xml).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the metadata has no SingleSignOnService with HTTP-Redirect or HTTP-POST binding"
+        );
+
+        let xml = xml.replace(
+            r#"bindings:SOAP" Location="https://idp.example.com/soap""#,
+            r#"bindings:HTTP-POST" Location="https://idp.example.com/sso""#,
+        );
+        let metadata = IdpMetadata::parse(&xml).unwrap();
+        assert_eq!(metadata.entity_id, "https://idp.example.com");
+        assert_eq!(metadata.sso_target_url, "https://idp.example.com/sso");
+        assert_eq!(metadata.slo_target_url, None);
+        assert_eq!(metadata.create_params().slo_target_url, None);
+    }
+
+    #[test]
+    fn rejects_metadata_without_idp() {
+        let xml = r#"<EntityDescriptor entityID="https://sp.example.com"><SPSSODescriptor/></EntityDescriptor>"#;
+        let err = IdpMetadata::parse(xml).unwrap_err();
+        assert_eq!(err.to_string(), "the metadata has no IDPSSODescriptor");
+        assert!(matches!(
+            IdpMetadata::parse("<not xml"),
+            Err(SamlError::Xml(_))
+        ));
+    }
+}
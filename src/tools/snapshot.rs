@@ -0,0 +1,552 @@
+//! App snapshots
+//!
+//! Captures the complete configuration of an app into a single versioned JSON document, and restores it on a new or existing app.
+//!
+//! Restoring goes through the [`reconcile`][reconcile] planner, so the changes can be reviewed before they are applied.
+//! Some values can't be read back from Heroku and are not restored, the plan lists them as warnings:
+//! - the private keys of SNI endpoints
+//! - the authorization and secret of webhooks
+//! - config vars set by add-ons, the restored add-ons set them again
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::snapshot::Snapshot;
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let snapshot = Snapshot::export(&api_client, "APP_ID")?;
+//! snapshot.to_file("my-app.snapshot.json")?;
+//!
+//! // later on
+//! let snapshot = Snapshot::from_file("my-app.snapshot.json")?;
+//! let plan = snapshot.restore_plan(&api_client, "my-app-restored", false)?;
+//! println!("{}", plan);
+//! snapshot.restore(&api_client, &plan)?;
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [reconcile]: ../reconcile/index.html
+
+use crate::endpoints::addons::{Addon, AddonAttachment, AddonListByApp, AttachmentListByApp};
+use crate::endpoints::apps::{
+    App, AppDetails, AppFeature, AppFeatureList, AppWebhook, AppWebhookList, SNIList, SNI,
+};
+use crate::endpoints::builds::{BuildPackInstallationList, BuildpackInstallation};
+use crate::endpoints::collaborators::{Collaborator, CollaboratorList};
+use crate::endpoints::config_vars::AppConfigVarDetails;
+use crate::endpoints::domains::{Domain, DomainList};
+use crate::endpoints::formations::{Formation, FormationList};
+use crate::endpoints::logs::{LogDrain, LogDrainList};
+use crate::endpoints::pipelines::{PipelineCoupling, PipelineCouplingByAppDetails};
+use crate::framework::apiclient::HerokuApiClient;
+use crate::framework::response::HerokuApiFailure;
+use crate::tools::reconcile::plan::{self, Action, Plan, Resolved};
+use crate::tools::reconcile::spec::{
+    AddonSpec, AppSpec, FormationSpec, InfraSpec, PipelineSpec, WebhookSpec,
+};
+use crate::tools::reconcile::{self, LiveApp, ReconcileError};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Version of the snapshot format written by this crate.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Errors that can happen while exporting or restoring a snapshot.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// the snapshot file could not be read or written
+    Io(io::Error),
+    /// the snapshot is not valid JSON or doesn't match the format
+    Json(serde_json::Error),
+    /// the snapshot was written by a newer version of the format
+    UnsupportedVersion(u32),
+    /// a request to Heroku failed
+    Api(HerokuApiFailure),
+    /// planning or applying the restore failed
+    Reconcile(ReconcileError),
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnapshotError::Io(e) => write!(f, "{}", e),
+            SnapshotError::Json(e) => write!(f, "invalid snapshot: {}", e),
+            SnapshotError::UnsupportedVersion(version) => write!(
+                f,
+                "snapshot version {} is not supported, the latest supported version is {}",
+                version, SNAPSHOT_VERSION
+            ),
+            SnapshotError::Api(e) => write!(f, "{}", e),
+            SnapshotError::Reconcile(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        SnapshotError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(error: serde_json::Error) -> Self {
+        SnapshotError::Json(error)
+    }
+}
+
+impl From<HerokuApiFailure> for SnapshotError {
+    fn from(error: HerokuApiFailure) -> Self {
+        SnapshotError::Api(error)
+    }
+}
+
+impl From<ReconcileError> for SnapshotError {
+    fn from(error: ReconcileError) -> Self {
+        SnapshotError::Reconcile(error)
+    }
+}
+
+/// Snapshot
+///
+/// The configuration of an app at a point in time, as returned by the GET endpoints.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Snapshot {
+    /// version of the snapshot format
+    pub version: u32,
+    /// when the snapshot was taken
+    pub created_at: DateTime<Utc>,
+    /// the app
+    pub app: App,
+    /// config vars of the app
+    pub config_vars: HashMap<String, Option<String>>,
+    /// add-ons of the app, including the ones attached from other apps
+    pub addons: Vec<Addon>,
+    /// add-on attachments of the app
+    pub addon_attachments: Vec<AddonAttachment>,
+    /// buildpack installations, sorted by ordinal
+    pub buildpacks: Vec<BuildpackInstallation>,
+    /// formation of the app
+    pub formation: Vec<Formation>,
+    /// domains of the app
+    pub domains: Vec<Domain>,
+    /// SNI endpoints of the app
+    pub sni_endpoints: Vec<SNI>,
+    /// log drains of the app
+    pub log_drains: Vec<LogDrain>,
+    /// webhooks of the app
+    pub webhooks: Vec<AppWebhook>,
+    /// Heroku labs features of the app
+    pub features: Vec<AppFeature>,
+    /// collaborators of the app, including the owner
+    pub collaborators: Vec<Collaborator>,
+    /// pipeline coupling of the app
+    pub pipeline_coupling: Option<PipelineCoupling>,
+}
+
+impl Snapshot {
+    /// Capture the configuration of the app `app_id`.
+    pub fn export<C: HerokuApiClient>(
+        api_client: &C,
+        app_id: &str,
+    ) -> Result<Snapshot, SnapshotError> {
+        let app = api_client.request(&AppDetails {
+            app_id: app_id.into(),
+        })?;
+        let app_id = app.id.as_str();
+
+        let mut buildpacks = api_client.request(&BuildPackInstallationList {
+            app_id: app_id.into(),
+        })?;
+        buildpacks.sort_by_key(|b| b.ordinal);
+
+        let pipeline_coupling = match api_client.request(&PipelineCouplingByAppDetails {
+            app_id: app_id.into(),
+        }) {
+            Ok(coupling) => Some(coupling),
+            Err(ref e) if reconcile::live::is_not_found(e) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Snapshot {
+            version: SNAPSHOT_VERSION,
+            created_at: Utc::now(),
+            config_vars: api_client.request(&AppConfigVarDetails {
+                app_id: app_id.into(),
+            })?,
+            addons: api_client.request(&AddonListByApp {
+                app_id: app_id.into(),
+            })?,
+            addon_attachments: api_client.request(&AttachmentListByApp {
+                app_id: app_id.into(),
+            })?,
+            buildpacks,
+            formation: api_client.request(&FormationList {
+                app_id: app_id.into(),
+            })?,
+            domains: api_client.request(&DomainList {
+                app_id: app_id.into(),
+            })?,
+            sni_endpoints: api_client.request(&SNIList {
+                app_id: app_id.into(),
+            })?,
+            log_drains: api_client.request(&LogDrainList {
+                app_id: app_id.into(),
+            })?,
+            webhooks: api_client.request(&AppWebhookList {
+                app_id: app_id.into(),
+            })?,
+            features: api_client.request(&AppFeatureList {
+                app_id: app_id.into(),
+            })?,
+            collaborators: api_client.request(&CollaboratorList {
+                app_id: app_id.into(),
+            })?,
+            pipeline_coupling,
+            app,
+        })
+    }
+
+    /// Parse a snapshot, rejecting versions newer than [`SNAPSHOT_VERSION`](constant.SNAPSHOT_VERSION.html).
+    pub fn from_json(input: &str) -> Result<Snapshot, SnapshotError> {
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+
+        let version: Version = serde_json::from_str(input)?;
+        if version.version > SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version.version));
+        }
+        Ok(serde_json::from_str(input)?)
+    }
+
+    /// Serialize the snapshot as pretty printed JSON.
+    pub fn to_json(&self) -> Result<String, SnapshotError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Read a snapshot from disk.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Snapshot, SnapshotError> {
+        Snapshot::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Write the snapshot to disk.
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), SnapshotError> {
+        Ok(fs::write(path, self.to_json()?)?)
+    }
+
+    /// true if the config var is set by one of the add-ons of the app, as listed by Heroku for each add-on
+    fn is_addon_config_var(&self, key: &str) -> bool {
+        self.addons
+            .iter()
+            .any(|addon| addon.config_vars.iter().any(|var| var == key))
+    }
+
+    /// The add-ons owned by the snapshot app.
+    fn owned_addons(&self) -> impl Iterator<Item = &Addon> {
+        self.addons
+            .iter()
+            .filter(move |addon| addon.app.id == self.app.id)
+    }
+
+    /// Convert the snapshot to the desired state of an app.
+    ///
+    /// SNI endpoints are left out, their private keys can't be exported.
+    pub fn to_spec(&self) -> AppSpec {
+        let config = self
+            .config_vars
+            .iter()
+            .filter(|(key, _)| !self.is_addon_config_var(key))
+            .filter_map(|(key, value)| value.clone().map(|value| (key.clone(), value)))
+            .collect();
+
+        let addons = self
+            .owned_addons()
+            .map(|addon| AddonSpec {
+                plan: addon.plan.name.clone(),
+                // add-on names are globally unique, the restored add-on gets a new one
+                name: None,
+                attachment_name: self
+                    .addon_attachments
+                    .iter()
+                    .find(|attachment| attachment.addon.id == addon.id)
+                    .map(|attachment| attachment.name.clone()),
+            })
+            .collect();
+
+        let formation = self
+            .formation
+            .iter()
+            .map(|formation| {
+                (
+                    formation.r#type.clone(),
+                    FormationSpec {
+                        quantity: Some(formation.quantity),
+                        size: Some(formation.size.clone()),
+                    },
+                )
+            })
+            .collect();
+
+        AppSpec {
+            region: Some(self.app.region.name.clone()),
+            stack: Some(self.app.build_stack.name.clone()),
+            team: self.app.team.as_ref().map(|team| team.name.clone()),
+            space: self.app.space.as_ref().map(|space| space.name.clone()),
+            config: Some(config),
+            addons: Some(addons),
+            domains: Some(
+                self.domains
+                    .iter()
+                    .filter(|domain| domain.kind != "heroku")
+                    .map(|domain| domain.hostname.clone())
+                    .collect(),
+            ),
+            sni_endpoints: None,
+            buildpacks: Some(
+                self.buildpacks
+                    .iter()
+                    .map(|installation| {
+                        installation
+                            .buildpack
+                            .name
+                            .clone()
+                            .unwrap_or_else(|| installation.buildpack.url.clone())
+                    })
+                    .collect(),
+            ),
+            formation: Some(formation),
+            log_drains: Some(
+                self.log_drains
+                    .iter()
+                    .filter(|drain| drain.addon.is_none())
+                    .map(|drain| drain.url.clone())
+                    .collect(),
+            ),
+            webhooks: Some(
+                self.webhooks
+                    .iter()
+                    .map(|webhook| WebhookSpec {
+                        url: webhook.url.clone(),
+                        include: webhook.include.clone(),
+                        level: webhook.level.clone(),
+                        authorization: None,
+                        secret: None,
+                    })
+                    .collect(),
+            ),
+            features: Some(
+                self.features
+                    .iter()
+                    .map(|feature| (feature.name.clone(), feature.enabled))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+            collaborators: Some(
+                self.collaborators
+                    .iter()
+                    .filter(|c| c.role.as_deref() != Some("owner"))
+                    .map(|c| c.user.email.clone())
+                    .collect(),
+            ),
+            pipeline: self
+                .pipeline_coupling
+                .as_ref()
+                .map(|coupling| PipelineSpec {
+                    name: coupling.pipeline.id.clone(),
+                    stage: coupling.stage.clone(),
+                }),
+        }
+    }
+
+    /// Compute the plan to restore the snapshot on the app `app_name`, which is created if it doesn't exist.
+    ///
+    /// Removals, e.g. domains added after the snapshot was taken, are only planned when `prune` is true.
+    pub fn restore_plan<C: HerokuApiClient>(
+        &self,
+        api_client: &C,
+        app_name: &str,
+        prune: bool,
+    ) -> Result<Plan, SnapshotError> {
+        let spec = self.to_spec();
+        let mut infra = InfraSpec::default();
+        infra.apps.insert(app_name.to_string(), spec.clone());
+        infra.validate()?;
+
+        let mut resolved = Resolved::default();
+        if let Some(coupling) = &self.pipeline_coupling {
+            resolved.pipeline_id = Some(coupling.pipeline.id.clone());
+        }
+
+        let live = LiveApp::fetch(api_client, app_name, &spec)?;
+        let mut app_plan = plan::plan_app(app_name, &spec, &live, &resolved, prune);
+
+        // attachments of add-ons owned by other apps are shared, not provisioned again
+        for attachment in &self.addon_attachments {
+            if attachment.addon.app.id == self.app.id {
+                continue;
+            }
+            let attached = live
+                .addons
+                .iter()
+                .any(|addon| addon.id == attachment.addon.id);
+            if !attached {
+                app_plan.actions.push(Action::AttachAddon {
+                    addon: attachment.addon.name.clone(),
+                    name: attachment.name.clone(),
+                });
+            }
+        }
+
+        for sni in &self.sni_endpoints {
+            app_plan.warnings.push(format!(
+                "SNI endpoint {} is not restored, its private key can't be exported",
+                sni.name
+            ));
+        }
+        for webhook in &self.webhooks {
+            app_plan.warnings.push(format!(
+                "webhook {} is restored without its authorization and secret",
+                webhook.url
+            ));
+        }
+
+        Ok(Plan {
+            apps: vec![app_plan],
+        })
+    }
+
+    /// Apply a plan computed by [`restore_plan`](#method.restore_plan).
+    pub fn restore<C: HerokuApiClient>(
+        &self,
+        api_client: &C,
+        plan: &Plan,
+    ) -> Result<(), SnapshotError> {
+        Ok(reconcile::apply(api_client, plan)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot_json(version: u32) -> String {
+        serde_json::json!({
+            "version": version,
+            "created_at": "2020-06-01T12:00:00Z",
+            "app": {
+                "acm": false,
+                "archived_at": null,
+                "buildpack_provided_description": null,
+                "build_stack": { "id": "stack-id", "name": "heroku-20" },
+                "created_at": "2020-01-01T00:00:00Z",
+                "git_url": "https://git.heroku.com/my-app.git",
+                "id": "app-id",
+                "internal_routing": null,
+                "maintenance": false,
+                "name": "my-app",
+                "owner": { "email": "owner@example.com", "id": "owner-id" },
+                "organization": null,
+                "team": null,
+                "region": { "id": "region-id", "name": "eu" },
+                "released_at": null,
+                "repo_size": null,
+                "slug_size": null,
+                "space": null,
+                "stack": { "id": "stack-id", "name": "heroku-20" },
+                "updated_at": "2020-01-01T00:00:00Z",
+                "web_url": "https://my-app.herokuapp.com/"
+            },
+            "config_vars": {
+                "RAILS_ENV": "production",
+                "DATABASE_URL": "postgres://localhost/db",
+                "DATABASE_POOL_SIZE": "10"
+            },
+            "addons": [{
+                "actions": [],
+                "addon_service": { "id": "service-id", "name": "heroku-postgresql" },
+                "billing_entity": { "id": "other-app-id", "name": "other-app", "type": "app" },
+                "app": { "id": "other-app-id", "name": "other-app" },
+                "billed_price": null,
+                "config_vars": ["DATABASE_URL"],
+                "created_at": "2020-01-01T00:00:00Z",
+                "id": "addon-id",
+                "name": "postgresql-curved-12345",
+                "plan": { "id": "plan-id", "name": "heroku-postgresql:standard-0" },
+                "provider_id": "provider-id",
+                "state": "provisioned",
+                "updated_at": "2020-01-01T00:00:00Z",
+                "web_url": null
+            }],
+            "addon_attachments": [{
+                "addon": {
+                    "id": "addon-id",
+                    "name": "postgresql-curved-12345",
+                    "app": { "id": "other-app-id", "name": "other-app" }
+                },
+                "app": { "id": "app-id", "name": "my-app" },
+                "created_at": "2020-01-01T00:00:00Z",
+                "id": "attachment-id",
+                "name": "DATABASE",
+                "namespace": null,
+                "updated_at": "2020-01-01T00:00:00Z",
+                "web_url": null,
+                "log_input_url": null
+            }],
+            "buildpacks": [],
+            "formation": [],
+            "domains": [],
+            "sni_endpoints": [],
+            "log_drains": [],
+            "webhooks": [],
+            "features": [],
+            "collaborators": [],
+            "pipeline_coupling": null
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let snapshot = Snapshot::from_json(&snapshot_json(SNAPSHOT_VERSION)).unwrap();
+        let again = Snapshot::from_json(&snapshot.to_json().unwrap()).unwrap();
+
+        assert_eq!(again.app.name, "my-app");
+        assert_eq!(again.created_at, snapshot.created_at);
+        assert_eq!(again.config_vars, snapshot.config_vars);
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        match Snapshot::from_json(&snapshot_json(SNAPSHOT_VERSION + 1)) {
+            Err(SnapshotError::UnsupportedVersion(version)) => {
+                assert_eq!(version, SNAPSHOT_VERSION + 1)
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_to_spec_skips_addon_config_vars() {
+        let snapshot = Snapshot::from_json(&snapshot_json(SNAPSHOT_VERSION)).unwrap();
+        let spec = snapshot.to_spec();
+
+        let config = spec.config.unwrap();
+        assert_eq!(config.len(), 2);
+        assert_eq!(config["RAILS_ENV"], "production");
+        // set by the user, it only shares the prefix of the add-on attachment
+        assert_eq!(config["DATABASE_POOL_SIZE"], "10");
+        assert_eq!(spec.region.as_deref(), Some("eu"));
+        // the attached add-on belongs to another app, it is attached again rather than provisioned
+        assert!(spec.addons.unwrap().is_empty());
+        assert!(spec.sni_endpoints.is_none());
+    }
+}
@@ -0,0 +1,311 @@
+//! Usage reports
+//!
+//! Rolls the usage returned by the team and enterprise account usage endpoints up by app, by month and by team,
+//! e.g. to hand a per-app cost breakdown to finance every month.
+//!
+//! Every usage response is split into [`UsageRecord`][record]s: one with the totals of each team, and one per app
+//! of the team. Totals by team and by month are summed from the team records, since they include usage that is not
+//! attributed to an app, totals by app are summed from the app records.
+//!
+//! Daily and monthly usage of the same period should not be added to the same report, they would be counted twice.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use heroku_rs::prelude::*;
+//! use heroku_rs::tools::usage_report::UsageReport;
+//!
+//!# fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!#    let api_client = HttpApiClient::create("API_KEY")?;
+//! let usage = api_client.request(&EnterpriseAccountMonthlyUsageList::new("ACCOUNT_ID", "2020-01"))?;
+//! let report = UsageReport::from_usage(&usage);
+//!
+//! for ((month, app), usage) in report.by_month_and_app() {
+//!     println!("{} {}: {} dyno units, {} add-on credits", month, app, usage.dynos, usage.addons);
+//! }
+//! std::fs::write("usage-2020-01.csv", report.to_csv())?;
+//!# Ok(())
+//!# }
+//! ```
+//!
+//! [record]: struct.UsageRecord.html
+
+use crate::endpoints::enterprise::{EnterpriseAccountDailyUsage, EnterpriseAccountMonthlyUsage};
+use crate::endpoints::teams::{TeamDailyUsage, TeamMonthlyUsage};
+use crate::endpoints::usage::{AppUsage, Usage};
+use std::collections::BTreeMap;
+
+/// The usage of a team, or of one of its apps, in a month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageRecord {
+    /// year and month of the usage, e.g. `2020-01`
+    pub month: String,
+    /// name of the team
+    pub team: String,
+    /// name of the app, `None` for the totals of the team
+    pub app: Option<String>,
+    /// the usage
+    pub usage: Usage,
+}
+
+/// Usage responses that can be split into [`UsageRecord`][record]s.
+///
+/// [record]: struct.UsageRecord.html
+pub trait IntoUsageRecords {
+    /// The records of the team totals and of every app.
+    fn usage_records(&self) -> Vec<UsageRecord>;
+}
+
+impl IntoUsageRecords for TeamDailyUsage {
+    fn usage_records(&self) -> Vec<UsageRecord> {
+        team_records(month_of(&self.date), &self.name, &self.usage, &self.apps)
+    }
+}
+
+impl IntoUsageRecords for TeamMonthlyUsage {
+    fn usage_records(&self) -> Vec<UsageRecord> {
+        team_records(&self.month, &self.name, &self.usage, &self.apps)
+    }
+}
+
+impl IntoUsageRecords for EnterpriseAccountDailyUsage {
+    fn usage_records(&self) -> Vec<UsageRecord> {
+        let month = month_of(&self.date);
+        self.teams
+            .iter()
+            .flat_map(|team| team_records(month, &team.name, &team.usage, &team.apps))
+            .collect()
+    }
+}
+
+impl IntoUsageRecords for EnterpriseAccountMonthlyUsage {
+    fn usage_records(&self) -> Vec<UsageRecord> {
+        self.teams
+            .iter()
+            .flat_map(|team| team_records(&self.month, &team.name, &team.usage, &team.apps))
+            .collect()
+    }
+}
+
+/// Usage records, rolled up on demand.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageReport {
+    /// every record added to the report
+    pub records: Vec<UsageRecord>,
+}
+
+impl UsageReport {
+    /// An empty report.
+    pub fn new() -> UsageReport {
+        UsageReport::default()
+    }
+
+    /// A report of the records of `usage`.
+    pub fn from_usage<T: IntoUsageRecords>(usage: &[T]) -> UsageReport {
+        let mut report = UsageReport::new();
+        report.add(usage);
+        report
+    }
+
+    /// Add the records of `usage`, e.g. the usage of another team.
+    pub fn add<T: IntoUsageRecords>(&mut self, usage: &[T]) -> &mut Self {
+        self.records
+            .extend(usage.iter().flat_map(IntoUsageRecords::usage_records));
+        self
+    }
+
+    /// Total usage of every app, by app name.
+    pub fn by_app(&self) -> BTreeMap<String, Usage> {
+        let mut totals = BTreeMap::new();
+        for record in &self.records {
+            if let Some(app) = &record.app {
+                *totals.entry(app.clone()).or_insert_with(Usage::default) += record.usage;
+            }
+        }
+        totals
+    }
+
+    /// Total usage of every team, by team name.
+    pub fn by_team(&self) -> BTreeMap<String, Usage> {
+        self.team_totals(|record| record.team.clone())
+    }
+
+    /// Total usage of every month, by `YYYY-MM`.
+    pub fn by_month(&self) -> BTreeMap<String, Usage> {
+        self.team_totals(|record| record.month.clone())
+    }
+
+    /// Usage of every app in every month, by `(YYYY-MM, app name)`.
+    pub fn by_month_and_app(&self) -> BTreeMap<(String, String), Usage> {
+        let mut totals = BTreeMap::new();
+        for record in &self.records {
+            if let Some(app) = &record.app {
+                *totals
+                    .entry((record.month.clone(), app.clone()))
+                    .or_insert_with(Usage::default) += record.usage;
+            }
+        }
+        totals
+    }
+
+    /// Total usage of every team in the report.
+    pub fn total(&self) -> Usage {
+        self.records
+            .iter()
+            .filter(|record| record.app.is_none())
+            .map(|record| record.usage)
+            .sum()
+    }
+
+    /// The per-app breakdown as CSV, one line per month and app, sorted by month then app.
+    pub fn to_csv(&self) -> String {
+        let mut teams = BTreeMap::new();
+        for record in &self.records {
+            if let Some(app) = &record.app {
+                teams.insert((record.month.clone(), app.clone()), record.team.clone());
+            }
+        }
+
+        let mut csv = String::from("month,team,app,dynos,addons,data,partner,space,connect\n");
+        for ((month, app), usage) in self.by_month_and_app() {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{}\n",
+                month,
+                csv_field(&teams[&(month.clone(), app.clone())]),
+                csv_field(&app),
+                usage.dynos,
+                usage.addons,
+                usage.data,
+                usage.partner,
+                usage.space,
+                usage.connect,
+            ));
+        }
+        csv
+    }
+
+    fn team_totals<F: Fn(&UsageRecord) -> String>(&self, key: F) -> BTreeMap<String, Usage> {
+        let mut totals = BTreeMap::new();
+        for record in self.records.iter().filter(|record| record.app.is_none()) {
+            *totals.entry(key(record)).or_insert_with(Usage::default) += record.usage;
+        }
+        totals
+    }
+}
+
+fn team_records(month: &str, team: &str, usage: &Usage, apps: &[AppUsage]) -> Vec<UsageRecord> {
+    let mut records = vec![UsageRecord {
+        month: month.to_string(),
+        team: team.to_string(),
+        app: None,
+        usage: *usage,
+    }];
+    records.extend(apps.iter().map(|app| UsageRecord {
+        month: month.to_string(),
+        team: team.to_string(),
+        app: Some(app.app_name.clone()),
+        usage: app.usage,
+    }));
+    records
+}
+
+/// `2020-01` of `2020-01-31`.
+fn month_of(date: &str) -> &str {
+    date.get(..7).unwrap_or(date)
+}
+
+/// Quote a CSV field if needed, team and app names can't contain commas but better safe than sorry.
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn enterprise_daily(date: &str, dynos: f64) -> EnterpriseAccountDailyUsage {
+        serde_json::from_value(json!({
+            "id": "ea-id",
+            "name": "acme",
+            "date": date,
+            "addons": 3.0, "data": 1.0, "dynos": dynos + 1.0, "partner": 2.0, "space": 0.0,
+            "teams": [{
+                "id": "team-id",
+                "name": "platform",
+                "addons": 3.0, "data": 1.0, "dynos": dynos + 1.0, "partner": 2.0, "space": 0.0,
+                "apps": [
+                    {"app_name": "api", "addons": 2.0, "data": 1.0, "dynos": dynos, "partner": 1.0},
+                    {"app_name": "web", "addons": 1.0, "data": 0.0, "dynos": 1.0, "partner": 1.0}
+                ]
+            }]
+        }))
+        .unwrap()
+    }
+
+    fn team_monthly(team: &str, month: &str) -> TeamMonthlyUsage {
+        serde_json::from_value(json!({
+            "id": "team-id",
+            "name": team,
+            "month": month,
+            "addons": 10.0, "connect": 5.0, "data": 4.0, "dynos": 20.0, "partner": 6.0, "space": 100.0,
+            "apps": [
+                {"app_name": format!("{}-app", team), "addons": 10.0, "data": 4.0, "dynos": 20.0, "partner": 6.0}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn daily_usage_rolls_up_by_month_and_app() {
+        let report = UsageReport::from_usage(&[
+            enterprise_daily("2020-01-30", 2.0),
+            enterprise_daily("2020-01-31", 3.0),
+            enterprise_daily("2020-02-01", 4.0),
+        ]);
+
+        let by_app = report.by_app();
+        assert_eq!(by_app["api"].dynos, 9.0);
+        assert_eq!(by_app["web"].addons, 3.0);
+
+        let by_month = report.by_month();
+        assert_eq!(
+            by_month.keys().collect::<Vec<_>>(),
+            vec!["2020-01", "2020-02"]
+        );
+        assert_eq!(by_month["2020-01"].dynos, 7.0);
+
+        let by_month_and_app = report.by_month_and_app();
+        let key = (String::from("2020-01"), String::from("api"));
+        assert_eq!(by_month_and_app[&key].dynos, 5.0);
+    }
+
+    #[test]
+    fn team_totals_include_usage_outside_apps() {
+        let mut report = UsageReport::new();
+        report
+            .add(&[team_monthly("platform", "2020-01")])
+            .add(&[team_monthly("data", "2020-01")]);
+
+        let by_team = report.by_team();
+        assert_eq!(by_team["platform"].space, 100.0);
+        assert_eq!(by_team["data"].connect, 5.0);
+        assert_eq!(report.total().space, 200.0);
+        assert_eq!(report.by_app()["data-app"].space, 0.0);
+    }
+
+    #[test]
+    fn csv_lists_every_app_of_every_month() {
+        let report = UsageReport::from_usage(&[team_monthly("platform", "2020-01")]);
+        assert_eq!(
+            report.to_csv(),
+            "month,team,app,dynos,addons,data,partner,space,connect\n\
+             2020-01,platform,platform-app,20,10,4,6,0,0\n"
+        );
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+    }
+}
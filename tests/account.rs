@@ -0,0 +1,37 @@
+use heroku_rs::endpoints::account;
+use heroku_rs::framework::envelope::RequestEnvelope;
+use serde_json::json;
+use util::assert_valid_url;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    // run with `cargo test -- --nocapture` for  the logs
+
+    #[test]
+    fn assert_valid_url_user_preferences() {
+        let response = util::get_client().request(&account::UserPreferencesDetails::new());
+        let endpoint = String::from("users/~/preferences");
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn user_preferences_update_uses_dashed_names() {
+        let update = account::UserPreferencesUpdate::new()
+            .timezone("UTC")
+            .dismissed_pipelines_github_banners(vec!["pipeline-id"])
+            .build();
+        let envelope = RequestEnvelope::new(&update).unwrap();
+
+        assert_eq!(envelope.path, "users/~/preferences");
+        assert_eq!(
+            envelope.body,
+            Some(json!({
+                "timezone": "UTC",
+                "dismissed-pipelines-github-banners": ["pipeline-id"]
+            }))
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use heroku_rs::endpoints::addons;
+use heroku_rs::tools::addon_policy;
+use serde_json::json;
+use stand_in::{route, StandIn};
+use util::assert_valid_url;
+mod stand_in;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    // run with `cargo test -- --nocapture` for  the logs
+
+    #[test]
+    fn assert_valid_url_plan_details() {
+        let plan_id = "heroku-postgresql:standard-0";
+        let response = util::get_client().request(&addons::PlanDetails::new(plan_id));
+        let endpoint = format!("{}{}", "plans/", plan_id);
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_plan_details_by_service() {
+        let service_id = "heroku-postgresql";
+        let plan_id = "standard-0";
+        let response =
+            util::get_client().request(&addons::PlanDetailsByService::new(service_id, plan_id));
+        let endpoint = format!(
+            "{}{}{}{}",
+            "addon-services/", service_id, "/plans/", plan_id
+        );
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_plan_list() {
+        let service_id = "heroku-postgresql";
+        let response = util::get_client().request(&addons::PlanList::new(service_id));
+        let endpoint = format!("{}{}{}", "addon-services/", service_id, "/plans");
+        assert_valid_url(response, endpoint)
+    }
+
+    fn addon(app: &str, service: &str) -> serde_json::Value {
+        json!({
+            "actions": [],
+            "addon_service": {"id": format!("{}-id", service), "name": service},
+            "billing_entity": {"id": "app-id", "name": app, "type": "app"},
+            "app": {"id": format!("{}-id", app), "name": app},
+            "billed_price": null,
+            "config_vars": [],
+            "created_at": "2012-01-01T12:00:00Z",
+            "id": format!("{}-{}", app, service),
+            "name": format!("{}-{}", service, app),
+            "plan": {"id": "plan-id", "name": format!("{}:basic", service)},
+            "provider_id": "abcd1234",
+            "state": "provisioned",
+            "updated_at": "2012-01-01T12:00:00Z",
+            "web_url": null
+        })
+    }
+
+    fn whitelisted(service: &str) -> serde_json::Value {
+        json!({
+            "added_at": "2012-01-01T12:00:00Z",
+            "added_by": {"email": "security@example.com", "id": "user-id"},
+            "addon_service": {"id": format!("{}-id", service), "name": service, "human_name": service},
+            "id": format!("{}-entity", service)
+        })
+    }
+
+    #[test]
+    fn addon_policy_follows_every_page() {
+        let stand_in = StandIn::start(vec![
+            route(
+                "GET",
+                "/teams/acme/preferences",
+                200,
+                json!({"default-permission": "member", "whitelisting-enabled": true}),
+            ),
+            route(
+                "GET",
+                "/teams/acme/whitelisted-addon-services",
+                200,
+                json!([whitelisted("heroku-redis")]),
+            )
+            .for_range("id ]heroku-postgresql..; max=1"),
+            route(
+                "GET",
+                "/teams/acme/whitelisted-addon-services",
+                206,
+                json!([whitelisted("heroku-postgresql")]),
+            )
+            .header("Next-Range", "id ]heroku-postgresql..; max=1"),
+            route(
+                "GET",
+                "/teams/acme/addons",
+                200,
+                json!([addon("api", "sendgrid"), addon("api", "heroku-redis")]),
+            )
+            .for_range("id ]web-papertrail..; max=2"),
+            route(
+                "GET",
+                "/teams/acme/addons",
+                206,
+                json!([addon("web", "heroku-postgresql"), addon("web", "papertrail")]),
+            )
+            .header("Next-Range", "id ]web-papertrail..; max=2"),
+        ]);
+
+        let report = addon_policy::check_team(&stand_in.client(), "acme").unwrap();
+
+        assert_eq!(report.checked, 4);
+        assert_eq!(
+            report
+                .violations
+                .iter()
+                .map(|violation| violation.addon.as_str())
+                .collect::<Vec<_>>(),
+            vec!["sendgrid-api", "papertrail-web"]
+        );
+        let pages: Vec<_> = stand_in
+            .requests()
+            .into_iter()
+            .filter(|request| request.path == "/teams/acme/addons")
+            .map(|request| request.range)
+            .collect();
+        assert_eq!(
+            pages,
+            vec![None, Some(String::from("id ]web-papertrail..; max=2"))]
+        );
+    }
+}
@@ -17,7 +17,7 @@ mod tests {
     #[test]
     fn assert_valid_url_get_app_details() {
         let app_id = "123xyz";
-        let response = util::get_client().request(&apps::AppDetails { app_id: app_id });
+        let response = util::get_client().request(&apps::AppDetails { app_id: app_id.into() });
         let endpoint = format!("{}{}", "apps/", app_id);
         assert_valid_url(response, endpoint)
     }
@@ -0,0 +1,84 @@
+use heroku_rs::endpoints::{account, builds, enterprise, formations};
+use heroku_rs::framework::endpoint::Method;
+use heroku_rs::tools::codegen::{self, drift, Drift, Route, Schema};
+use std::env;
+use std::fs;
+
+const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// The schema in `HEROKU_SCHEMA` if set, e.g. a fresh copy of https://api.heroku.com/schema, or the trimmed fixture.
+fn schema() -> Schema {
+    let path = env::var("HEROKU_SCHEMA")
+        .unwrap_or_else(|_| format!("{}/tests/fixtures/platform-api-schema.json", CRATE_DIR));
+    Schema::from_file(path).expect("invalid schema")
+}
+
+fn fixture() -> Schema {
+    Schema::from_file(format!(
+        "{}/tests/fixtures/platform-api-schema.json",
+        CRATE_DIR
+    ))
+    .unwrap()
+}
+
+#[test]
+fn endpoints_and_docs_match_the_schema() {
+    let schema = schema();
+    let endpoints = drift::scan_endpoint_dir(format!("{}/src/endpoints", CRATE_DIR)).unwrap();
+    let docs =
+        drift::scan_docs(&fs::read_to_string(format!("{}/docs/ENDPOINTS.md", CRATE_DIR)).unwrap());
+    let drift = Drift::check(&schema, &endpoints, &docs);
+
+    assert!(drift.undocumented.is_empty(), "{}", drift);
+    assert!(drift.ticked_but_missing.is_empty(), "{}", drift);
+    assert!(drift.implemented_but_unticked.is_empty(), "{}", drift);
+
+    // routes of the fixture that are known to be missing
+    if env::var("HEROKU_SCHEMA").is_err() {
+        let known = vec![Route::new(Method::Patch, "apps/{}/formation")];
+        assert_eq!(drift.unimplemented, known, "{}", drift);
+    }
+}
+
+#[test]
+fn models_accept_null_where_the_schema_allows_it() {
+    let schema = schema();
+    let example = |name: &str| drift::null_example(schema.resource(name).unwrap());
+
+    serde_json::from_value::<builds::Build>(example("build")).unwrap();
+    serde_json::from_value::<formations::Formation>(example("formation")).unwrap();
+    serde_json::from_value::<account::AccountFeature>(example("account-feature")).unwrap();
+    serde_json::from_value::<enterprise::EnterpriseAccount>(example("enterprise-account")).unwrap();
+}
+
+#[test]
+fn generate_enterprise_account() {
+    let schema = fixture();
+    let module = codegen::generate(schema.resource("enterprise-account").unwrap());
+
+    assert_eq!(module.name, "enterprise_account");
+    let files: Vec<&str> = module.files.keys().map(String::as_str).collect();
+    assert_eq!(files, vec!["get.rs", "mod.rs", "patch.rs"]);
+
+    let patch = &module.files["patch.rs"];
+    assert!(patch.contains("pub struct EnterpriseAccountUpdate<'a>"));
+    assert!(patch.contains("Method::Patch"));
+    assert!(patch.contains("format!(\"enterprise-accounts/{}\", self.enterprise_account_id)"));
+
+    let get = &module.files["get.rs"];
+    assert!(get.contains("pub struct EnterpriseAccountDetails<'a>"));
+    assert!(get.contains("pub struct EnterpriseAccountList"));
+    assert!(get.contains("HerokuEndpoint<Vec<EnterpriseAccount>>"));
+
+    let model = &module.files["mod.rs"];
+    assert!(model.contains("pub struct EnterpriseAccount {"));
+    assert!(model.contains("Option<IdentityProvider>"));
+    assert!(model.contains("pub created_at: DateTime<Utc>"));
+}
+
+#[test]
+fn unknown_resources_and_invalid_schemas() {
+    assert!(fixture().resource("no-such-resource").is_none());
+    assert!(Schema::from_json("{}").is_err());
+    assert!(Schema::from_json("not json").is_err());
+}
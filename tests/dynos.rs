@@ -11,7 +11,7 @@ mod tests {
     #[test]
     fn assert_valid_url_get_dyno_list() {
         let app_id = "123xyz";
-        let response = util::get_client().request(&dynos::DynoList { app_id: app_id });
+        let response = util::get_client().request(&dynos::DynoList { app_id: app_id.into() });
         let endpoint = format!("{}{}{}", "apps/", app_id, "/dynos");
         assert_valid_url(response, endpoint)
     }
@@ -21,8 +21,8 @@ mod tests {
         let app_id = "123xyz";
         let dyno_id = "xyz123";
         let response = util::get_client().request(&dynos::DynoActionStop {
-            app_id: app_id,
-            dyno_id: dyno_id,
+            app_id: app_id.into(),
+            dyno_id: dyno_id.into(),
         });
         let endpoint = format!(
             "{}{}{}{}{}",
@@ -35,9 +35,9 @@ mod tests {
     fn assert_valid_url_dyno_create() {
         let app_id = "123xyz";
         let response = util::get_client().request(&dynos::DynoCreate {
-            app_id: app_id,
+            app_id: app_id.into(),
             params: dynos::DynoCreateParams {
-                command: "bash",
+                command: "bash".into(),
                 attach: None,
                 env: None,
                 force_no_tty: None,
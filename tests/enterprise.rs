@@ -0,0 +1,145 @@
+use heroku_rs::endpoints::{enterprise, teams};
+use util::assert_valid_url;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    use heroku_rs::framework::envelope::RequestEnvelope;
+    use serde_json::json;
+    // run with `cargo test -- --nocapture` for  the logs
+
+    #[test]
+    fn assert_valid_url_enterprise_account_details() {
+        let account_id = "123xyz";
+        let response =
+            util::get_client().request(&enterprise::EnterpriseAccountDetails::new(account_id));
+        let endpoint = format!("{}{}", "enterprise-accounts/", account_id);
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_enterprise_account_member_delete() {
+        let account_id = "123xyz";
+        let member_id = "user@example.com";
+        let response = util::get_client().request(&enterprise::EnterpriseAccountMemberDelete::new(
+            account_id, member_id,
+        ));
+        let endpoint = format!(
+            "{}{}{}{}",
+            "enterprise-accounts/", account_id, "/members/", member_id
+        );
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_audit_trail_archive_details() {
+        let account_id = "123xyz";
+        let response = util::get_client().request(&enterprise::AuditTrailArchiveDetails::new(
+            account_id, 2020, 9,
+        ));
+        let endpoint = format!(
+            "{}{}{}",
+            "enterprise-accounts/", account_id, "/archives/2020/09"
+        );
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn usage_dates_are_sent_as_query_parameters() {
+        let daily = enterprise::EnterpriseAccountDailyUsageList::new("123xyz", "2020-01-01")
+            .end("2020-01-31")
+            .build();
+        let envelope = RequestEnvelope::new(&daily).unwrap();
+        assert_eq!(envelope.path, "enterprise-accounts/123xyz/usage/daily");
+        assert_eq!(
+            envelope.query,
+            Some(json!({"start": "2020-01-01", "end": "2020-01-31"}))
+        );
+        assert_eq!(envelope.body, None);
+
+        let monthly = enterprise::EnterpriseAccountMonthlyUsageList::new("123xyz", "2020-01");
+        let envelope = RequestEnvelope::new(&monthly).unwrap();
+        assert_eq!(envelope.query, Some(json!({"start": "2020-01"})));
+
+        let events = enterprise::AuditTrailEventList::new("123xyz")
+            .event_type("app")
+            .action("create")
+            .build();
+        let envelope = RequestEnvelope::new(&events).unwrap();
+        assert_eq!(
+            envelope.query,
+            Some(json!({"type": "app", "action": "create"}))
+        );
+    }
+
+    #[test]
+    fn member_permissions_are_sent_in_the_body() {
+        let create = enterprise::EnterpriseAccountMemberCreate::new(
+            "123xyz",
+            "user@example.com",
+            vec!["view", "create"],
+        );
+        let envelope = RequestEnvelope::new(&create).unwrap();
+        assert_eq!(
+            envelope.body,
+            Some(json!({"user": "user@example.com", "permissions": ["view", "create"]}))
+        );
+    }
+
+    #[test]
+    fn deserialize_enterprise_account_member() {
+        let member: enterprise::EnterpriseAccountMember = serde_json::from_value(json!({
+            "enterprise_account": {"id": "01234567-89ab-cdef-0123-456789abcdef", "name": "example"},
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "permissions": [{"description": "View enterprise account members and teams.", "name": "view"}],
+            "user": {"email": "username@example.com", "id": "01234567-89ab-cdef-0123-456789abcdef"},
+            "two_factor_authentication": false,
+            "identity_provider": null
+        }))
+        .unwrap();
+        assert_eq!(member.permissions[0].name, "view");
+        assert_eq!(member.identity_provider, None);
+    }
+
+    #[test]
+    fn assert_valid_url_team_monthly_usage() {
+        let team_id = "123xyz";
+        let response =
+            util::get_client().request(&teams::TeamMonthlyUsageList::new(team_id, "2020-01"));
+        let endpoint = format!("{}{}{}", "teams/", team_id, "/usage/monthly");
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn deserialize_enterprise_account_monthly_usage() {
+        let usage: enterprise::EnterpriseAccountMonthlyUsage = serde_json::from_value(json!({
+            "addons": 250.0,
+            "teams": [{
+                "addons": 250.0,
+                "apps": [{"addons": 250.0, "app_name": "example", "data": 34.89, "dynos": 1.548, "partner": 12.34}],
+                "connect": 0,
+                "data": 34.89,
+                "dynos": 1.548,
+                "id": "01234567-89ab-cdef-0123-456789abcdef",
+                "name": "ops",
+                "partner": 12.34,
+                "space": 1.548
+            }],
+            "connect": 0,
+            "data": 34.89,
+            "dynos": 1.548,
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "month": "2019-01",
+            "name": "example",
+            "partner": 12.34,
+            "space": 1.548
+        }))
+        .unwrap();
+        assert_eq!(usage.usage.addons, 250.0);
+        assert_eq!(usage.teams[0].apps[0].app_name, "example");
+        assert_eq!(usage.teams[0].apps[0].usage.dynos, 1.548);
+        assert_eq!(usage.teams[0].apps[0].usage.space, 0.0);
+    }
+}
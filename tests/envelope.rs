@@ -0,0 +1,89 @@
+use heroku_rs::endpoints::{apps, formations, postgres};
+use heroku_rs::framework::endpoint::Method;
+use heroku_rs::framework::envelope::RequestEnvelope;
+use serde_json::json;
+use util::assert_valid_url;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    use heroku_rs::framework::endpoint::HerokuEndpoint;
+    use heroku_rs::framework::{ApiEnvironment, DataApi};
+
+    #[test]
+    fn envelope_captures_the_request() {
+        let update = formations::FormationUpdate::new("my-app", "web")
+            .quantity(2)
+            .build();
+        let envelope = RequestEnvelope::new(&update).unwrap();
+
+        assert_eq!(envelope.endpoint, "FormationUpdate");
+        assert_eq!(envelope.method, Method::Patch);
+        assert_eq!(envelope.path, "apps/my-app/formation/web");
+        assert_eq!(envelope.query, None);
+        assert_eq!(envelope.body, Some(json!({ "quantity": 2 })));
+        assert_eq!(
+            envelope.to_string(),
+            "FormationUpdate: PATCH apps/my-app/formation/web"
+        );
+    }
+
+    #[test]
+    fn envelope_round_trips_through_json() {
+        let envelope = RequestEnvelope::new(&apps::AppDelete::new("my-app")).unwrap();
+        let stored = serde_json::to_value(&envelope).unwrap();
+
+        assert_eq!(stored["method"], "DELETE");
+        assert_eq!(stored["body"], json!(null));
+        assert_eq!(
+            serde_json::from_value::<RequestEnvelope>(stored).unwrap(),
+            envelope
+        );
+    }
+
+    #[test]
+    fn envelope_keeps_the_data_api_host() {
+        let details = postgres::PostgresDetails::new("postgresql-curved-12345");
+        let envelope = RequestEnvelope::new(&details).unwrap();
+        let stored = serde_json::to_value(&envelope).unwrap();
+        assert_eq!(stored["api"], "postgres");
+
+        let replayed: RequestEnvelope = serde_json::from_value(stored).unwrap();
+        assert_eq!(replayed.api, Some(DataApi::Postgres));
+        assert_eq!(
+            HerokuEndpoint::<serde_json::Value, _, _>::url(&replayed, &ApiEnvironment::Production)
+                .as_str(),
+            "https://postgres-api.heroku.com/client/v11/databases/postgresql-curved-12345"
+        );
+        assert_eq!(
+            HerokuEndpoint::<serde_json::Value, _, _>::url(&replayed, &ApiEnvironment::Production),
+            details.url(&ApiEnvironment::Production)
+        );
+    }
+
+    #[test]
+    fn envelope_defaults_the_headers() {
+        let envelope: RequestEnvelope = serde_json::from_value(json!({
+            "endpoint": "AppDetails",
+            "method": "GET",
+            "path": "apps/my-app",
+            "query": null,
+            "body": null
+        }))
+        .unwrap();
+
+        assert_eq!(
+            envelope,
+            RequestEnvelope::new(&apps::AppDetails::new("my-app")).unwrap()
+        );
+    }
+
+    #[test]
+    fn assert_valid_url_execute_envelope() {
+        let envelope = RequestEnvelope::new(&apps::AppDelete::new("123xyz")).unwrap();
+        let response = util::get_client().execute::<apps::App>(&envelope);
+        assert_valid_url(response, String::from("apps/123xyz"))
+    }
+}
@@ -0,0 +1,219 @@
+use heroku_rs::endpoints::{addons, kafka};
+use stand_in::{route, StandIn};
+mod stand_in;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::endpoints::ids::AddonRef;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    use heroku_rs::framework::endpoint::HerokuEndpoint;
+    use heroku_rs::framework::ApiEnvironment;
+    use serde_json::json;
+    // run with `cargo test -- --nocapture` for  the logs
+
+    const ADDON_ID: &str = "01234567-89ab-cdef-0123-456789abcdef";
+
+    fn cluster_path(rest: &str) -> String {
+        format!("/data/kafka/v0/clusters/{}{}", ADDON_ID, rest)
+    }
+
+    #[test]
+    fn production_targets_the_data_api_host() {
+        let addon: addons::Addon = serde_json::from_value(json!({
+            "actions": [],
+            "addon_service": {"id": "fedcba98-7654-3210-fedc-ba9876543210", "name": "heroku-kafka"},
+            "billing_entity": {"id": "fedcba98-7654-3210-fedc-ba9876543211", "name": "my-app", "type": "app"},
+            "app": {"id": "fedcba98-7654-3210-fedc-ba9876543211", "name": "my-app"},
+            "billed_price": null,
+            "config_vars": ["KAFKA_URL"],
+            "created_at": "2020-01-01T12:00:00Z",
+            "id": ADDON_ID,
+            "name": "kafka-shallow-12345",
+            "plan": {"id": "fedcba98-7654-3210-fedc-ba9876543212", "name": "heroku-kafka:basic-0"},
+            "provider_id": "abcd",
+            "state": "provisioned",
+            "updated_at": "2020-01-01T12:00:00Z",
+            "web_url": null
+        }))
+        .unwrap();
+        let list = kafka::KafkaTopicList::new(AddonRef::from(&addon));
+        assert_eq!(
+            list.url(&ApiEnvironment::Production).as_str(),
+            format!(
+                "https://api.data.heroku.com/data/kafka/v0/clusters/{}/topics",
+                ADDON_ID
+            )
+        );
+    }
+
+    #[test]
+    fn topics_create_list_and_configure() {
+        let stand_in = StandIn::start(vec![
+            route(
+                "POST",
+                cluster_path("/topics"),
+                200,
+                json!({"message": "created"}),
+            ),
+            route(
+                "GET",
+                cluster_path("/topics"),
+                200,
+                json!({
+                    "attachment_name": "KAFKA",
+                    "topics": [{
+                        "name": "orders",
+                        "partitions": 8,
+                        "replication_factor": 3,
+                        "retention_time_ms": 86_400_000,
+                        "compaction": false,
+                        "messages_in_per_second": 1.5,
+                        "bytes_in_per_second": 120.0,
+                        "bytes_out_per_second": 240.0
+                    }]
+                }),
+            ),
+            route(
+                "PUT",
+                cluster_path("/topics/orders"),
+                200,
+                json!({"message": "updated"}),
+            ),
+            route(
+                "GET",
+                cluster_path("/topics/orders"),
+                200,
+                json!({"name": "orders", "partitions": 8, "retention_time_ms": 3_600_000, "compaction": true}),
+            ),
+            route("DELETE", cluster_path("/topics/orders"), 200, json!({})),
+        ]);
+        let api_client = stand_in.client();
+
+        api_client
+            .request(
+                &kafka::KafkaTopicCreate::new(ADDON_ID, "orders")
+                    .partition_count(8)
+                    .retention_time_ms(86_400_000)
+                    .build(),
+            )
+            .unwrap();
+        let topics = api_client
+            .request(&kafka::KafkaTopicList::new(ADDON_ID))
+            .unwrap();
+        api_client
+            .request(
+                &kafka::KafkaTopicUpdate::new(ADDON_ID, "orders")
+                    .retention_time_ms(3_600_000)
+                    .compaction(true)
+                    .build(),
+            )
+            .unwrap();
+        let topic = api_client
+            .request(&kafka::KafkaTopicDetails::new(ADDON_ID, "orders"))
+            .unwrap();
+        api_client
+            .request(&kafka::KafkaTopicDelete::new(ADDON_ID, "orders"))
+            .unwrap();
+
+        assert_eq!(topics.attachment_name.as_deref(), Some("KAFKA"));
+        assert_eq!(topics.topics[0].partitions, Some(8));
+        assert_eq!(topic.retention_time_ms, Some(3_600_000));
+        assert_eq!(topic.compaction, Some(true));
+
+        let requests = stand_in.requests();
+        assert_eq!(
+            requests
+                .iter()
+                .map(|request| request.method.as_str())
+                .collect::<Vec<_>>(),
+            vec!["POST", "GET", "PUT", "GET", "DELETE"]
+        );
+        assert_eq!(
+            requests[0].body,
+            Some(
+                json!({"topic": {"name": "orders", "partition_count": 8, "retention_time_ms": 86_400_000}})
+            )
+        );
+        assert_eq!(
+            requests[2].body,
+            Some(
+                json!({"topic": {"name": "orders", "retention_time_ms": 3_600_000, "compaction": true}})
+            )
+        );
+    }
+
+    #[test]
+    fn consumer_groups() {
+        let stand_in = StandIn::start(vec![
+            route("POST", cluster_path("/consumer_groups"), 200, json!({})),
+            route(
+                "GET",
+                cluster_path("/consumer_groups"),
+                200,
+                json!({"consumer_groups": [{"name": "billing"}, {"name": "shipping"}]}),
+            ),
+            route("DELETE", cluster_path("/consumer_groups"), 200, json!({})),
+        ]);
+        let api_client = stand_in.client();
+
+        api_client
+            .request(&kafka::KafkaConsumerGroupCreate::new(ADDON_ID, "billing"))
+            .unwrap();
+        let groups = api_client
+            .request(&kafka::KafkaConsumerGroupList::new(ADDON_ID))
+            .unwrap();
+        api_client
+            .request(&kafka::KafkaConsumerGroupDelete::new(ADDON_ID, "shipping"))
+            .unwrap();
+
+        assert_eq!(
+            groups
+                .consumer_groups
+                .iter()
+                .map(|group| group.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["billing", "shipping"]
+        );
+        let requests = stand_in.requests();
+        assert_eq!(
+            requests[0].body,
+            Some(json!({"consumer_group": {"name": "billing"}}))
+        );
+        assert_eq!(requests[2].method, "DELETE");
+        assert_eq!(
+            requests[2].body,
+            Some(json!({"consumer_group": {"name": "shipping"}}))
+        );
+    }
+
+    #[test]
+    fn credential_rotation() {
+        let stand_in = StandIn::start(vec![route(
+            "POST",
+            cluster_path("/rotate-credentials"),
+            200,
+            json!({"message": "Credentials are being rotated"}),
+        )]);
+
+        let response = stand_in
+            .client()
+            .request(&kafka::KafkaCredentialRotate::new(ADDON_ID))
+            .unwrap();
+
+        assert_eq!(response["message"], "Credentials are being rotated");
+        assert_eq!(stand_in.requests()[0].body, None);
+    }
+
+    #[test]
+    fn unknown_cluster_is_an_error() {
+        let stand_in = StandIn::start(vec![]);
+
+        let response = stand_in
+            .client()
+            .request(&kafka::KafkaTopicList::new(ADDON_ID));
+
+        assert!(response.is_err());
+        assert_eq!(stand_in.requests()[0].path, cluster_path("/topics"));
+    }
+}
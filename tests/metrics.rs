@@ -0,0 +1,167 @@
+use heroku_rs::endpoints::metrics;
+use heroku_rs::tools::app_metrics;
+use stand_in::{route, StandIn};
+mod stand_in;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    use heroku_rs::framework::endpoint::HerokuEndpoint;
+    use heroku_rs::framework::ApiEnvironment;
+    use serde_json::json;
+    // run with `cargo test -- --nocapture` for  the logs
+
+    const APP_NAME: &str = "my-app";
+
+    fn series(data: serde_json::Value) -> serde_json::Value {
+        json!({
+            "start_time": "2020-01-01T00:00:00Z",
+            "end_time": "2020-01-01T01:00:00Z",
+            "step": "10m",
+            "data": data
+        })
+    }
+
+    fn formation(process_type: &str, quantity: i32) -> serde_json::Value {
+        json!({
+            "app": {"id": "01234567-89ab-cdef-0123-456789abcdef", "name": APP_NAME},
+            "command": "bin/start",
+            "created_at": "2020-01-01T12:00:00Z",
+            "id": "fedcba98-7654-3210-fedc-ba9876543210",
+            "quantity": quantity,
+            "size": "standard-1X",
+            "type": process_type,
+            "updated_at": "2020-01-01T12:00:00Z"
+        })
+    }
+
+    #[test]
+    fn production_targets_the_metrics_host() {
+        let latency = metrics::RouterLatencyMetrics::new(APP_NAME);
+        assert_eq!(
+            latency.url(&ApiEnvironment::Production).as_str(),
+            "https://api.metrics.heroku.com/metrics/my-app/router/latency"
+        );
+    }
+
+    #[test]
+    fn resolution_and_time_range_are_sent_as_query() {
+        let stand_in = StandIn::start(vec![route(
+            "GET",
+            "/metrics/my-app/dyno/memory",
+            200,
+            series(json!({
+                "memory.total.mb.mean": [200.0, 210.5],
+                "memory.total.mb.max": [250.0, null],
+                "memory.quota.mb.max": [512.0, 512.0]
+            })),
+        )]);
+
+        let memory = stand_in
+            .client()
+            .request(
+                &metrics::DynoMemoryMetrics::new(APP_NAME)
+                    .process_type("web")
+                    .start_time(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+                    .end_time(Utc.with_ymd_and_hms(2020, 1, 1, 1, 0, 0).unwrap())
+                    .step(metrics::MetricsResolution::TenMinutes)
+                    .build(),
+            )
+            .unwrap();
+
+        assert_eq!(memory.step, metrics::MetricsResolution::TenMinutes);
+        assert_eq!(memory.data.total_max, vec![Some(250.0), None]);
+        assert!(memory.data.rss_mean.is_empty());
+        assert_eq!(
+            stand_in.requests()[0].query.as_deref(),
+            Some("process_type=web&start_time=2020-01-01T00%3A00%3A00Z&end_time=2020-01-01T01%3A00%3A00Z&step=10m")
+        );
+    }
+
+    #[test]
+    fn every_process_type_of_the_formation() {
+        let stand_in = StandIn::start(vec![
+            route(
+                "GET",
+                "/apps/my-app/formation",
+                200,
+                json!([formation("web", 2), formation("worker", 1)]),
+            ),
+            route(
+                "GET",
+                "/metrics/my-app/dyno/memory",
+                200,
+                series(json!({"memory.total.mb.max": [250.0, 300.0]})),
+            ),
+            route(
+                "GET",
+                "/metrics/my-app/dyno/load",
+                200,
+                series(json!({"load.avg.1m.mean": [0.5, 0.75], "load.avg.1m.max": [1.0, 2.5]})),
+            ),
+            route(
+                "GET",
+                "/metrics/my-app/dyno/errors",
+                200,
+                series(json!({"R14": [null, 3]})),
+            ),
+            route(
+                "GET",
+                "/metrics/my-app/router/latency",
+                200,
+                series(json!({"latency.ms.p50": [12.0, 14.0], "latency.ms.p95": [80.0, 95.0]})),
+            ),
+            route(
+                "GET",
+                "/metrics/my-app/router/status",
+                200,
+                series(json!({"200": [1200, 1350], "503": [null, 4]})),
+            ),
+            route(
+                "GET",
+                "/metrics/my-app/router/errors",
+                200,
+                series(json!({"H12": [null, 4]})),
+            ),
+        ]);
+
+        let end_time = Utc.with_ymd_and_hms(2020, 1, 1, 1, 0, 0).unwrap();
+        let metrics = app_metrics::fetch(
+            &stand_in.client(),
+            APP_NAME,
+            end_time - chrono::Duration::hours(1),
+            end_time,
+            metrics::MetricsResolution::TenMinutes,
+        )
+        .unwrap();
+
+        let web = metrics.process("web").unwrap();
+        let worker = metrics.process("worker").unwrap();
+        assert_eq!(web.quantity, 2);
+        assert_eq!(web.peak_memory(), Some(300.0));
+        assert_eq!(web.router.as_ref().unwrap().latency.data.p95[1], Some(95.0));
+        assert_eq!(web.error_counts()["H12"], 4.0);
+        assert_eq!(worker.peak_load(), Some(2.5));
+        assert!(worker.router.is_none());
+
+        let requests = stand_in.requests();
+        assert_eq!(requests.len(), 1 + 6 + 3);
+        assert!(requests
+            .iter()
+            .filter(|request| request.path.starts_with("/metrics/"))
+            .all(|request| request.query.as_deref().unwrap().contains("step=10m")));
+        assert_eq!(
+            requests
+                .iter()
+                .filter(|request| request
+                    .query
+                    .as_deref()
+                    .unwrap_or_default()
+                    .starts_with("process_type=worker&"))
+                .count(),
+            3
+        );
+    }
+}
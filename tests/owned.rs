@@ -0,0 +1,56 @@
+use heroku_rs::endpoints::{apps, builds};
+use heroku_rs::framework::endpoint::HerokuEndpoint;
+use std::thread;
+
+fn assert_owned<T: Send + 'static>(value: T) -> T {
+    value
+}
+
+/// A webhook built from Strings that don't outlive this function.
+fn webhook(app: &str, url: &str) -> apps::AppWebhookCreate<'static> {
+    apps::AppWebhookCreate::new(
+        app.to_string(),
+        vec![String::from("api:release")],
+        String::from("notify"),
+        url.to_string(),
+    )
+    .secret(String::from("hunter2"))
+    .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_built_from_strings_are_owned() {
+        let webhook = assert_owned(webhook("my-app", "https://example.com/hooks"));
+
+        let body = thread::spawn(move || (webhook.path(), webhook.body()))
+            .join()
+            .unwrap();
+
+        assert_eq!(body.0, "apps/my-app/webhooks");
+        assert_eq!(
+            serde_json::to_value(body.1.unwrap()).unwrap(),
+            serde_json::json!({
+                "authorization": null,
+                "include": ["api:release"],
+                "level": "notify",
+                "secret": "hunter2",
+                "url": "https://example.com/hooks"
+            })
+        );
+    }
+
+    #[test]
+    fn requests_can_still_borrow() {
+        let source = String::from("https://example.com/source.tgz");
+        let build = builds::BuildCreate::new("my-app", source.as_str())
+            .checksum("SHA256:abc")
+            .build();
+
+        assert_eq!(build.params.source_blob.url, source);
+        assert_eq!(build.path(), "apps/my-app/builds");
+    }
+}
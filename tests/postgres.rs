@@ -0,0 +1,119 @@
+use heroku_rs::endpoints::postgres;
+use util::assert_valid_url;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    use heroku_rs::framework::endpoint::HerokuEndpoint;
+    use heroku_rs::framework::envelope::RequestEnvelope;
+    use heroku_rs::framework::ApiEnvironment;
+    use serde_json::json;
+    // run with `cargo test -- --nocapture` for  the logs
+
+    const ADDON_ID: &str = "01234567-89ab-cdef-0123-456789abcdef";
+
+    #[test]
+    fn production_targets_the_data_api_host() {
+        let details = postgres::PostgresDetails::new(ADDON_ID);
+        assert_eq!(
+            details.url(&ApiEnvironment::Production).as_str(),
+            format!(
+                "https://postgres-api.heroku.com/client/v11/databases/{}",
+                ADDON_ID
+            )
+        );
+    }
+
+    #[test]
+    fn assert_valid_url_postgres_details() {
+        let response = util::get_client().request(&postgres::PostgresDetails::new(ADDON_ID));
+        let endpoint = format!("{}{}", "client/v11/databases/", ADDON_ID);
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_credential_rotate() {
+        let response = util::get_client()
+            .request(&postgres::PostgresCredentialRotate::new(ADDON_ID, "default").build());
+        let endpoint = format!(
+            "{}{}{}",
+            "postgres/v0/databases/", ADDON_ID, "/credentials/default/credentials_rotation"
+        );
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_backup_url() {
+        let app_name = "my-app";
+        let response =
+            util::get_client().request(&postgres::PostgresBackupUrlCreate::new(app_name, 12));
+        let endpoint = format!(
+            "{}{}{}",
+            "client/v11/apps/", app_name, "/transfers/12/actions/public-url"
+        );
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn backup_schedule_create_body() {
+        let create = postgres::PostgresBackupScheduleCreate::new(ADDON_ID, "DATABASE_URL", 2)
+            .timezone("UTC")
+            .build();
+        let envelope = RequestEnvelope::new(&create).unwrap();
+
+        assert_eq!(
+            envelope.body,
+            Some(json!({ "schedule_name": "DATABASE_URL", "hour": 2, "timezone": "UTC" }))
+        );
+    }
+
+    #[test]
+    fn database_followers_and_transfer_names() {
+        let database: postgres::PostgresDatabase = serde_json::from_value(json!({
+            "addon_id": ADDON_ID,
+            "name": "postgresql-curved-12345",
+            "plan": "standard-0",
+            "resource_url": "https://postgres-api.heroku.com/client/v11/databases/postgresql-curved-12345",
+            "following": null,
+            "num_bytes": 7_500_000,
+            "num_tables": 12,
+            "info": [
+                {"name": "Status", "values": ["Available"]},
+                {"name": "Connections", "values": [3]},
+                {"name": "Followers", "values": ["postgresql-flat-67890"], "resolve_db_name": true}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(database.followers(), vec!["postgresql-flat-67890"]);
+        assert_eq!(database.info("Connections"), vec!["3"]);
+
+        let transfer: postgres::PostgresTransfer = serde_json::from_value(json!({
+            "uuid": "ffffffff-89ab-cdef-0123-456789abcdef",
+            "num": 7,
+            "from_name": "DATABASE",
+            "from_type": "pg_dump",
+            "to_name": "BACKUP",
+            "to_type": "gof3r",
+            "source_bytes": 7_500_000,
+            "processed_bytes": 1_200_000,
+            "succeeded": true,
+            "warnings": 0,
+            "created_at": "2020-01-01 12:00:00 +0000",
+            "started_at": "2020-01-01 12:00:01 +0000",
+            "finished_at": "2020-01-01 12:01:00 +0000",
+            "canceled_at": null,
+            "deleted_at": null,
+            "schedule": {"uuid": "eeeeeeee-89ab-cdef-0123-456789abcdef"}
+        }))
+        .unwrap();
+        assert!(transfer.is_backup());
+        assert_eq!(transfer.name(), "a007");
+        assert_eq!(
+            transfer.finished_at.unwrap() - transfer.created_at,
+            chrono::Duration::seconds(60)
+        );
+        assert_eq!(transfer.canceled_at, None);
+    }
+}
@@ -1,29 +1,114 @@
 use heroku_rs::endpoints::releases;
+use stand_in::{route, StandIn};
 use util::assert_valid_url;
+mod stand_in;
 mod util;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use heroku_rs::framework::apiclient::HerokuApiClient;
+    use heroku_rs::framework::endpoint::Ranged;
     // run with `cargo test -- --nocapture` for  the logs
 
     #[test]
     fn assert_valid_url_get_release_list() {
         let app_id = "123xyz";
-        let response = util::get_client().request(&releases::ReleaseList { app_id: app_id });
+        let response = util::get_client().request(&releases::ReleaseList { app_id: app_id.into() });
 
         let endpoint = format!("{}{}{}", "apps/", app_id, "/releases");
         assert_valid_url(response, endpoint)
     }
 
+    #[test]
+    fn release_list_sends_the_range() {
+        let stand_in = StandIn::start(vec![route(
+            "GET",
+            "/apps/123xyz/releases",
+            206,
+            serde_json::json!([]),
+        )]);
+        let list = releases::ReleaseList {
+            app_id: "123xyz".into(),
+        };
+        let response = stand_in.client().request(&Ranged::new(&list, "version ..; order=desc, max=25"));
+
+        assert!(response.unwrap().is_empty());
+        let requests = stand_in.requests();
+        assert_eq!(
+            requests[0].range.as_deref(),
+            Some("version ..; order=desc, max=25")
+        );
+    }
+
+    fn release(version: i64) -> serde_json::Value {
+        serde_json::json!({
+            "addon_plan_names": [],
+            "app": { "id": "01234567-89ab-cdef-0123-456789abcdef", "name": "example" },
+            "created_at": "2012-01-01T12:00:00Z",
+            "current": false,
+            "description": format!("Deploy v{}", version),
+            "id": format!("release-{}", version),
+            "output_stream_url": null,
+            "slug": null,
+            "status": "succeeded",
+            "updated_at": "2012-01-01T12:00:00Z",
+            "user": { "id": "01234567-89ab-cdef-0123-456789abcdef", "email": "username@example.com" },
+            "version": version
+        })
+    }
+
+    #[test]
+    fn request_all_follows_next_range() {
+        let stand_in = StandIn::start(vec![
+            route(
+                "GET",
+                "/apps/123xyz/releases",
+                200,
+                serde_json::json!([release(3)]),
+            )
+            .for_range("version ]2..; max=2"),
+            route(
+                "GET",
+                "/apps/123xyz/releases",
+                206,
+                serde_json::json!([release(1), release(2)]),
+            )
+            .header("Next-Range", "version ]2..; max=2"),
+        ]);
+        let list = releases::ReleaseList {
+            app_id: "123xyz".into(),
+        };
+        let releases = stand_in
+            .client()
+            .request_all(&Ranged::new(&list, "version ..; max=2"))
+            .unwrap();
+
+        assert_eq!(
+            releases.iter().map(|release| release.version).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        let ranges: Vec<_> = stand_in
+            .requests()
+            .into_iter()
+            .map(|request| request.range)
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                Some(String::from("version ..; max=2")),
+                Some(String::from("version ]2..; max=2"))
+            ]
+        );
+    }
+
     #[test]
     fn assert_valid_url_get_app_info() {
         let app_id = "123xyz";
-        let release_id = "456abc";
+        let release_id: u64 = 42;
         let response = util::get_client().request(&releases::ReleaseInfo {
-            app_id: app_id,
-            release_id: release_id,
+            app_id: app_id.into(),
+            release_id: release_id.into(),
         });
 
         let endpoint = format!("{}{}{}{}", "apps/", app_id, "/releases/", release_id);
@@ -34,10 +119,10 @@ mod tests {
     fn assert_valid_url_release_create() {
         let app_id = "123xyz";
         let response = util::get_client().request(&releases::ReleaseCreate {
-            app_id: app_id,
+            app_id: app_id.into(),
             params: releases::ReleaseCreateParams {
-                slug: "fooslug",
-                description: Some("releasing the thing"),
+                slug: "fooslug".into(),
+                description: Some("releasing the thing".into()),
             },
         });
         let endpoint = format!("{}{}{}", "apps/", app_id, "/releases");
@@ -49,8 +134,8 @@ mod tests {
         let app_id = "123xyz";
 
         let response = util::get_client().request(&releases::ReleaseRollback {
-            app_id: app_id,
-            params: releases::ReleaseRollbackParams { release: "v40" },
+            app_id: app_id.into(),
+            params: releases::ReleaseRollbackParams { release: "v40".into() },
         });
         let endpoint = format!("{}{}{}", "apps/", app_id, "/releases");
         assert_valid_url(response, endpoint)
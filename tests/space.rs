@@ -0,0 +1,81 @@
+use heroku_rs::endpoints::space;
+use util::assert_valid_url;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::endpoints::status::PeeringStatus;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    use serde_json::json;
+    // run with `cargo test -- --nocapture` for  the logs
+
+    fn space() -> space::Space {
+        serde_json::from_value(json!({
+            "created_at": "2012-01-01T12:00:00Z",
+            "id": "123xyz",
+            "name": "nasa",
+            "organization": {"name": "example"},
+            "team": {"id": "01234567-89ab-cdef-0123-456789abcdef", "name": "example"},
+            "region": {"id": "01234567-89ab-cdef-0123-456789abcdef", "name": "virginia"},
+            "shield": false,
+            "state": "allocated",
+            "updated_at": "2012-01-01T12:00:00Z",
+            "cidr": "10.0.0.0/16",
+            "data_cidr": "172.23.0.0/20"
+        }))
+        .unwrap()
+    }
+
+    fn peering(cidr: &str) -> space::Peering {
+        serde_json::from_value(json!({
+            "type": "customer-managed",
+            "pcx_id": "pcx-123456789012",
+            "cidr_blocks": [cidr],
+            "status": "pending-acceptance",
+            "aws_vpc_id": "vpc-1234567890",
+            "aws_region": "us-east-1",
+            "aws_account_id": "123456789012",
+            "expires": "2020-01-01T12:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn assert_valid_url_team_space_list() {
+        let team_name = "my-team";
+        let response = util::get_client().request(&space::TeamSpaceList::new(team_name));
+        let endpoint = format!("{}{}{}", "teams/", team_name, "/spaces");
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_peering_info() {
+        let space_id = "123xyz";
+        let response = util::get_client().request(&space::PeeringInfoDetails::new(space_id));
+        let endpoint = format!("{}{}{}", "spaces/", space_id, "/peering-info");
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_checked_peering_accept() {
+        let space = space();
+        let peering = peering("192.168.0.0/16");
+        assert_eq!(peering.status, PeeringStatus::PendingAcceptance);
+
+        let accept = space::PeeringAccept::checked(&space, &peering).unwrap();
+        let response = util::get_client().request(&accept);
+        let endpoint = format!(
+            "{}{}{}{}{}",
+            "spaces/", space.id, "/peerings/", peering.pcx_id, "/actions/accept"
+        );
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn overlapping_peering_is_not_accepted() {
+        let space = space();
+        let peering = peering("10.0.0.0/8");
+        assert!(space::PeeringAccept::checked(&space, &peering).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! A local HTTP stand-in for the Heroku APIs.
+//!
+//! It answers the requests of a test client with canned JSON bodies and records them, so the endpoints can be
+//! exercised end to end without reaching Heroku. Requests without a route get a `404`.
+
+// every test binary includes this module, not all of them use every helper
+#![allow(dead_code)]
+
+extern crate heroku_rs;
+
+use heroku_rs::framework::{auth::Credentials, ApiEnvironment, HttpApiClient, HttpApiClientConfig};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// A canned response to the requests with `method` on `path`.
+pub struct Route {
+    pub method: &'static str,
+    pub path: String,
+    /// only answer requests with this `Range` header, any request when `None`
+    pub range: Option<String>,
+    pub status: u16,
+    /// extra response headers, e.g. `Next-Range`
+    pub headers: Vec<(&'static str, String)>,
+    pub body: serde_json::Value,
+}
+
+impl Route {
+    /// Only answer the requests with the `Range` header `range`.
+    pub fn for_range(mut self, range: impl Into<String>) -> Route {
+        self.range = Some(range.into());
+        self
+    }
+
+    /// Add the response header `name`.
+    pub fn header(mut self, name: &'static str, value: impl Into<String>) -> Route {
+        self.headers.push((name, value.into()));
+        self
+    }
+}
+
+/// A request received by the stand-in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recorded {
+    pub method: String,
+    pub path: String,
+    pub query: Option<String>,
+    /// the `Range` header, if any
+    pub range: Option<String>,
+    pub body: Option<serde_json::Value>,
+}
+
+pub fn route(
+    method: &'static str,
+    path: impl Into<String>,
+    status: u16,
+    body: serde_json::Value,
+) -> Route {
+    Route {
+        method,
+        path: path.into(),
+        range: None,
+        status,
+        headers: Vec::new(),
+        body,
+    }
+}
+
+pub struct StandIn {
+    url: url::Url,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl StandIn {
+    /// Serve `routes` on a free local port until the test process exits.
+    pub fn start(routes: Vec<Route>) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = url::Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                serve(stream, &routes, &recorded);
+            }
+        });
+
+        StandIn { url, requests }
+    }
+
+    /// A client sending every request, data APIs included, to the stand-in.
+    pub fn client(&self) -> HttpApiClient {
+        let credentials = Credentials::UserAuthToken {
+            token: String::from("TOKEN_HERE"),
+        };
+        HttpApiClient::new(
+            credentials,
+            HttpApiClientConfig {
+                http_timeout: Duration::from_secs(10),
+                default_headers: http::HeaderMap::default(),
+            },
+            ApiEnvironment::Custom(self.url.clone()),
+        )
+        .unwrap()
+    }
+
+    /// The requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn serve(stream: TcpStream, routes: &[Route], recorded: &Mutex<Vec<Recorded>>) {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let mut target = target.splitn(2, '?');
+    let path = target.next().unwrap_or_default().to_string();
+    let query = target.next().map(String::from);
+
+    let mut content_length = 0;
+    let mut range = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).is_err() || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            } else if name.eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    if reader.read_exact(&mut body).is_err() {
+        return;
+    }
+    let body = serde_json::from_slice(&body).ok();
+
+    let (status, headers, response) = match routes.iter().find(|route| {
+        route.method == method
+            && route.path == path
+            && (route.range.is_none() || route.range == range)
+    }) {
+        Some(route) => (
+            route.status,
+            route
+                .headers
+                .iter()
+                .map(|(name, value)| format!("{}: {}\r\n", name, value))
+                .collect(),
+            route.body.to_string(),
+        ),
+        None => (
+            404,
+            String::new(),
+            serde_json::json!({"id": "not_found", "message": "No route in the stand-in."})
+                .to_string(),
+        ),
+    };
+    recorded.lock().unwrap().push(Recorded {
+        method,
+        path,
+        query,
+        range,
+        body,
+    });
+
+    let mut stream = reader.into_inner();
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} Stand-in\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+        status,
+        response.len(),
+        headers,
+        response
+    );
+    let _ = stream.flush();
+}
@@ -0,0 +1,265 @@
+use heroku_rs::endpoints::teams;
+use heroku_rs::framework::envelope::RequestEnvelope;
+use heroku_rs::tools::effective_permissions;
+use serde_json::json;
+use stand_in::{route, StandIn};
+use util::assert_valid_url;
+mod stand_in;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    // run with `cargo test -- --nocapture` for  the logs
+
+    #[test]
+    fn assert_valid_url_permission_list() {
+        let team_name = "my-team";
+        let response = util::get_client().request(&teams::TeamPermissionList::new(team_name));
+        let endpoint = format!("{}{}{}", "teams/", team_name, "/permissions");
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_identity_provider_list() {
+        let team_name = "my-team";
+        let response = util::get_client().request(&teams::TeamIdentityProviderList::new(team_name));
+        let endpoint = format!("{}{}{}", "teams/", team_name, "/identity-providers");
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_identity_provider_delete() {
+        let team_name = "my-team";
+        let provider_id = "123xyz";
+        let response = util::get_client().request(&teams::TeamIdentityProviderDelete::new(
+            team_name,
+            provider_id,
+        ));
+        let endpoint = format!(
+            "{}{}{}{}",
+            "teams/", team_name, "/identity-providers/", provider_id
+        );
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn identity_provider_create_body() {
+        let create = teams::TeamIdentityProviderCreate::new(
+            "my-team",
+            "CERTIFICATE",
+            "https://idp.example.com",
+            "https://idp.example.com/sso",
+        )
+        .slo_target_url("https://idp.example.com/slo")
+        .build();
+        let envelope = RequestEnvelope::new(&create).unwrap();
+
+        assert_eq!(envelope.path, "teams/my-team/identity-providers");
+        assert_eq!(
+            envelope.body,
+            Some(json!({
+                "certificate": "CERTIFICATE",
+                "entity_id": "https://idp.example.com",
+                "slo_target_url": "https://idp.example.com/slo",
+                "sso_target_url": "https://idp.example.com/sso"
+            }))
+        );
+    }
+
+    #[test]
+    fn identity_provider_update_only_sends_set_fields() {
+        let update = teams::TeamIdentityProviderUpdate::new("my-team", "123xyz")
+            .certificate("CERTIFICATE")
+            .build();
+        let envelope = RequestEnvelope::new(&update).unwrap();
+
+        assert_eq!(envelope.path, "teams/my-team/identity-providers/123xyz");
+        assert_eq!(envelope.body, Some(json!({ "certificate": "CERTIFICATE" })));
+    }
+
+    #[test]
+    fn identity_provider_deserializes() {
+        let provider: teams::TeamIdentityProvider = serde_json::from_value(json!({
+            "certificate": "CERTIFICATE",
+            "created_at": "2012-01-01T12:00:00Z",
+            "entity_id": "https://idp.example.com",
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "slo_target_url": null,
+            "sso_target_url": "https://idp.example.com/sso",
+            "organization": {"name": "example"},
+            "updated_at": "2012-01-01T12:00:00Z",
+            "owner": {
+                "id": "01234567-89ab-cdef-0123-456789abcdef",
+                "name": "acme",
+                "type": "team"
+            }
+        }))
+        .unwrap();
+        assert_eq!(provider.slo_target_url, None);
+        assert_eq!(provider.owner.type_field, "team");
+    }
+
+    #[test]
+    fn assert_valid_url_whitelisted_addon_service_list() {
+        let team_name = "my-team";
+        let response =
+            util::get_client().request(&teams::WhitelistedAddonServiceList::new(team_name));
+        let endpoint = format!("{}{}{}", "teams/", team_name, "/whitelisted-addon-services");
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn assert_valid_url_whitelisted_addon_service_delete() {
+        let team_name = "my-team";
+        let service = "heroku-postgresql";
+        let response = util::get_client().request(&teams::WhitelistedAddonServiceDelete::new(
+            team_name, service,
+        ));
+        let endpoint = format!(
+            "{}{}{}{}",
+            "teams/", team_name, "/whitelisted-addon-services/", service
+        );
+        assert_valid_url(response, endpoint)
+    }
+
+    #[test]
+    fn whitelisted_addon_service_create_body() {
+        let create = teams::WhitelistedAddonServiceCreate::new("my-team")
+            .addon_service("heroku-postgresql")
+            .build();
+        let envelope = RequestEnvelope::new(&create).unwrap();
+
+        assert_eq!(envelope.path, "teams/my-team/whitelisted-addon-services");
+        assert_eq!(
+            envelope.body,
+            Some(json!({ "addon_service": "heroku-postgresql" }))
+        );
+    }
+
+    #[test]
+    fn app_filter_body() {
+        let filter = teams::AppFilter::new()
+            .app_id("app-1")
+            .app_ids(vec!["app-2", "app-3"])
+            .build();
+        let envelope = RequestEnvelope::new(&filter).unwrap();
+
+        assert_eq!(envelope.path, "filters/apps");
+        assert_eq!(
+            envelope.body,
+            Some(json!({ "in": { "id": ["app-1", "app-2", "app-3"] } }))
+        );
+    }
+
+    fn team_app(name: &str) -> serde_json::Value {
+        json!({
+            "archived_at": null,
+            "buildpack_provided_description": null,
+            "build_stack": { "id": "stack-id", "name": "heroku-20" },
+            "created_at": "2012-01-01T12:00:00Z",
+            "git_url": format!("https://git.heroku.com/{}.git", name),
+            "id": format!("{}-id", name),
+            "internal_routing": null,
+            "joined": false,
+            "locked": false,
+            "maintenance": false,
+            "name": name,
+            "team": { "name": "my-team" },
+            "owner": null,
+            "region": { "id": "region-id", "name": "us" },
+            "released_at": null,
+            "repo_size": null,
+            "slug_size": null,
+            "space": null,
+            "stack": { "id": "stack-id", "name": "heroku-20" },
+            "updated_at": "2012-01-01T12:00:00Z",
+            "web_url": format!("https://{}.herokuapp.com/", name)
+        })
+    }
+
+    #[test]
+    fn effective_permissions_for_team() {
+        let stand_in = StandIn::start(vec![
+            route(
+                "GET",
+                "/teams/permissions",
+                200,
+                json!(["view", "deploy", "operate", "manage"]
+                    .iter()
+                    .map(|name| json!({ "name": name, "description": name }))
+                    .collect::<Vec<_>>()),
+            ),
+            route(
+                "GET",
+                "/teams/my-team/members",
+                200,
+                json!([{
+                    "created_at": "2012-01-01T12:00:00Z",
+                    "email": "admin@example.com",
+                    "federated": false,
+                    "id": "membership-id",
+                    "identity_provider": null,
+                    "role": "admin",
+                    "two_factor_authentication": true,
+                    "updated_at": "2012-01-01T12:00:00Z",
+                    "user": { "email": "admin@example.com", "id": "admin-id", "name": null }
+                }]),
+            ),
+            route(
+                "GET",
+                "/teams/my-team/apps",
+                200,
+                json!([team_app("web"), team_app("api")]),
+            ),
+            route(
+                "GET",
+                "/teams/my-team/permissions",
+                200,
+                json!([
+                    {
+                        "id": "web-id",
+                        "name": "web",
+                        "team_id": "team-id",
+                        "type": "app",
+                        "users": [
+                            { "email": "admin@example.com", "id": "admin-id", "permissions": ["view", "deploy", "operate", "manage"] },
+                            { "email": "dev@example.com", "id": "dev-id", "permissions": ["view", "deploy"] }
+                        ]
+                    },
+                    {
+                        "id": "space-id",
+                        "name": "my-space",
+                        "team_id": "team-id",
+                        "type": "space",
+                        "users": [
+                            { "email": "dev@example.com", "id": "dev-id", "permissions": ["view"] }
+                        ]
+                    }
+                ]),
+            ),
+        ]);
+
+        let permissions =
+            effective_permissions::for_team(&stand_in.client(), "my-team").unwrap();
+
+        assert!(permissions.can("admin@example.com", "api", "manage"));
+        assert!(permissions.can("dev@example.com", "web", "deploy"));
+        assert!(!permissions.can("dev@example.com", "web", "operate"));
+        assert!(!permissions.can("dev@example.com", "api", "view"));
+        assert!(!permissions.can("dev@example.com", "my-space", "view"));
+        assert_eq!(
+            permissions.permissions("admin@example.com", "web")[0].source,
+            effective_permissions::GrantSource::TeamRole("admin".to_string())
+        );
+        assert_eq!(
+            permissions.matrix().to_csv(),
+            "user,app,view,deploy,operate,manage\n\
+             admin@example.com,api,x,x,x,x\n\
+             admin@example.com,web,x,x,x,x\n\
+             dev@example.com,web,x,x,,\n"
+        );
+        assert_eq!(stand_in.requests().len(), 4);
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::{TimeZone, Utc};
+use heroku_rs::endpoints::{builds, domains, dynos, formations, postgres, releases};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Deserialize `value`, serialize it back and check nothing changed on the way.
+fn round_trip<T: DeserializeOwned + Serialize>(value: Value) -> T {
+    let model: T = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&model).unwrap(), value);
+    model
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_timestamps_round_trip() {
+        let build: builds::Build = round_trip(json!({
+            "app": { "id": "01234567-89ab-cdef-0123-456789abcdef" },
+            "buildpacks": null,
+            "created_at": "2012-01-01T12:00:00Z",
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "output_stream_url": "https://build-output.heroku.com/streams/01234567",
+            "source_blob": { "checksum": null, "url": "https://example.com/source.tgz", "version": null },
+            "release": null,
+            "slug": null,
+            "stack": "heroku-20",
+            "status": "succeeded",
+            "updated_at": "2012-01-01T12:05:30Z",
+            "user": { "id": "01234567-89ab-cdef-0123-456789abcdef", "email": "username@example.com" }
+        }));
+
+        assert_eq!(
+            build.created_at,
+            Utc.with_ymd_and_hms(2012, 1, 1, 12, 0, 0).unwrap()
+        );
+        assert!(build.updated_at > build.created_at);
+    }
+
+    #[test]
+    fn release_timestamps_round_trip() {
+        let release: releases::Release = round_trip(json!({
+            "addon_plan_names": ["heroku-postgresql:dev"],
+            "app": { "id": "01234567-89ab-cdef-0123-456789abcdef", "name": "example" },
+            "created_at": "2012-01-01T12:00:00Z",
+            "current": true,
+            "description": "Added new feature",
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "output_stream_url": null,
+            "slug": null,
+            "status": "succeeded",
+            "updated_at": "2012-01-01T12:00:00.123Z",
+            "user": { "id": "01234567-89ab-cdef-0123-456789abcdef", "email": "username@example.com" },
+            "version": 11
+        }));
+
+        assert_eq!(release.updated_at.timestamp_subsec_millis(), 123);
+    }
+
+    #[test]
+    fn dyno_timestamps_round_trip() {
+        let dyno: dynos::Dyno = round_trip(json!({
+            "app": { "id": "01234567-89ab-cdef-0123-456789abcdef", "name": "example" },
+            "attach_url": null,
+            "command": "bash",
+            "created_at": "2012-01-01T12:00:00Z",
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "name": "run.1",
+            "release": { "id": "01234567-89ab-cdef-0123-456789abcdef", "version": 11 },
+            "size": "standard-1X",
+            "state": "up",
+            "type": "run",
+            "updated_at": "2012-01-01T12:00:00Z"
+        }));
+
+        assert_eq!(dyno.created_at, dyno.updated_at);
+    }
+
+    #[test]
+    fn formation_and_domain_timestamps_round_trip() {
+        let formation: formations::Formation = round_trip(json!({
+            "app": { "id": "01234567-89ab-cdef-0123-456789abcdef", "name": "example" },
+            "command": "bundle exec rails server -p $PORT",
+            "created_at": "2012-01-01T12:00:00Z",
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "quantity": 1,
+            "size": "standard-1X",
+            "type": "web",
+            "updated_at": "2012-01-01T12:00:00Z"
+        }));
+        let domain: domains::Domain = round_trip(json!({
+            "acm_status": null,
+            "acm_status_reason": null,
+            "app": { "name": "example", "id": "01234567-89ab-cdef-0123-456789abcdef" },
+            "cname": "example.herokudns.com",
+            "created_at": "2012-01-01T12:00:00Z",
+            "hostname": "subdomain.example.com",
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "kind": "custom",
+            "updated_at": "2012-01-02T12:00:00Z",
+            "status": "pending"
+        }));
+
+        assert!(domain.updated_at > formation.updated_at);
+    }
+
+    #[test]
+    fn offset_timestamps_are_normalized_to_utc() {
+        let formation: formations::Formation = serde_json::from_value(json!({
+            "app": { "id": "01234567-89ab-cdef-0123-456789abcdef", "name": "example" },
+            "command": "bundle exec rails server -p $PORT",
+            "created_at": "2012-01-01T14:00:00+02:00",
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "quantity": 1,
+            "size": "standard-1X",
+            "type": "web",
+            "updated_at": "2012-01-01T12:00:00Z"
+        }))
+        .unwrap();
+
+        assert_eq!(formation.created_at, formation.updated_at);
+        assert_eq!(
+            serde_json::to_value(&formation).unwrap()["created_at"],
+            "2012-01-01T12:00:00Z"
+        );
+    }
+
+    #[test]
+    fn data_api_timestamps_round_trip() {
+        let backup_url: postgres::PostgresBackupUrl = round_trip(json!({
+            "url": "https://example.com/backup.dump",
+            "expires_at": "2012-01-01 12:00:00 +0000"
+        }));
+
+        assert_eq!(
+            backup_url.expires_at,
+            Utc.with_ymd_and_hms(2012, 1, 1, 12, 0, 0).unwrap()
+        );
+        assert!(serde_json::from_value::<postgres::PostgresBackupUrl>(json!({
+            "url": "https://example.com/backup.dump",
+            "expires_at": "2012-01-01T12:00:00Z"
+        }))
+        .is_err());
+    }
+}